@@ -4,44 +4,190 @@
  * Original https://github.com/ironthree/libkrb5-rs/blob/master/libkrb5-sys/build.rs
  *
  * Modified to add PKG_CONFIG_PATH to the environment so the Homebrew Heimdal libkrb5 is found.
+ *
+ * The bindings are generated with bindgen, which needs libclang, for Heimdal and with the
+ * `buildtime-bindgen` feature. Otherwise the pre-generated bindings for MIT krb5 in
+ * src/bindings are used, if there are some for the architecture, operating system and pointer
+ * width of the target, e.g. in src/bindings/x86_64-linux-64; other targets fall back to
+ * bindgen. Their first lines name the release and the command to regenerate them with. Either
+ * way the bindings end up in bindings.rs in the OUT_DIR.
+ *
+ * If pkg-config does not find libkrb5, `krb5-config` is asked, and only then Homebrew. On
+ * macOS, the Kerberos framework of the system is linked if Homebrew's Heimdal is not installed.
+ *
+ * The environment variables `KRB5_LIB_DIR` and `KRB5_INCLUDE_DIR` point the build at a libkrb5
+ * without pkg-config or Homebrew, e.g. for cross-compilation, Nix or Bazel. `KRB5_STATIC` links
+ * libkrb5 statically, from `KRB5_LIB_DIR` or as found by pkg-config.
+ *
+ * When cross-compiling, pkg-config needs to be set up for the target (e.g.
+ * `PKG_CONFIG_SYSROOT_DIR`), or `KRB5_LIB_DIR` pointed at the libkrb5 of the target, as the
+ * krb5-config and Homebrew of the host are not asked. libkrb5 is linked statically by default
+ * if the C runtime is, e.g. for the musl targets.
+ *
+ * With the `vendored` feature, the MIT krb5 sources bundled with the krb5-src crate are built
+ * and linked statically instead of the installed library.
+ *
+ * With the `dlopen` feature, libkrb5 is not linked at all. The functions of the bindings are
+ * replaced by wrappers that call the functions of the library loaded at run time, see
+ * src/dlopen.rs. Only MIT krb5 can be loaded, so the MIT bindings are used regardless of the
+ * installed library.
+ *
+ * The version of the library is reported to dependent build scripts as `DEP_KRB5_VERSION`, from
+ * pkg-config or krb5-config, or guessed from the newest function in the krb5.h of
+ * `KRB5_INCLUDE_DIR`. libkrb5 uses it to gate wrappers of functions older releases lack.
+ *
+ * With the `kadm5` feature, the kadm5 client library (libkadm5clnt) is linked as well, and the
+ * bindings include the kadm5 administration API from src/kadm5.h.
  */
 
 use std::env;
+use std::fs;
+#[cfg(not(any(feature = "vendored", feature = "dlopen")))]
 use std::ops::Deref;
+use std::path::Path;
 use std::path::PathBuf;
+#[cfg(not(any(feature = "vendored", feature = "dlopen")))]
 use std::process;
+#[cfg(not(any(feature = "vendored", feature = "dlopen")))]
 use std::process::Command;
 
-use pkg_config::probe_library;
+#[cfg(not(any(feature = "vendored", feature = "dlopen")))]
+use pkg_config::Config;
+
+const MIT: &str = "mit";
+#[cfg(not(any(feature = "vendored", feature = "dlopen")))]
+const HEIMDAL: &str = "heimdal";
 
 fn main() {
+  /* only MIT krb5 is loaded at run time, so no library is probed, see src/dlopen.rs */
+  #[cfg(feature = "dlopen")]
+  let flavor = MIT;
+  #[cfg(all(feature = "vendored", not(feature = "dlopen")))]
+  let flavor = link_vendored_library();
+  #[cfg(not(any(feature = "vendored", feature = "dlopen")))]
+  let flavor = link_system_library();
+
+  #[cfg(all(feature = "vendored", feature = "dlopen"))]
+  panic!("The `vendored` and `dlopen` features can not be combined");
+
+  #[cfg(all(feature = "kadm5", feature = "dlopen"))]
+  panic!("The `kadm5` and `dlopen` features can not be combined");
+
+  #[cfg(all(feature = "kadm5", feature = "vendored", not(feature = "dlopen")))]
+  link_vendored_kadm5_library();
+  #[cfg(all(feature = "kadm5", not(any(feature = "vendored", feature = "dlopen"))))]
+  link_kadm5_library();
+
+  println!("cargo:rustc-check-cfg=cfg(krb5_mit)");
+  println!("cargo:rustc-check-cfg=cfg(krb5_heimdal)");
+  println!("cargo:rustc-cfg=krb5_{}", flavor);
+  println!("cargo:flavor={}", flavor);
+
+  /* the pre-generated bindings are for MIT krb5, so Heimdal always needs bindgen; `dlopen` uses
+   * them without the `pregenerated-bindings` feature as well */
+  let pregenerated = cfg!(any(feature = "pregenerated-bindings", feature = "dlopen"))
+    && !cfg!(feature = "buildtime-bindgen")
+    && flavor == MIT;
+  match pregenerated.then(pregenerated_bindings_dir).flatten() {
+    Some(dir) => copy_pregenerated_bindings(&dir),
+    None => generate_bindings(),
+  }
+
+  #[cfg(feature = "dlopen")]
+  generate_dlopen_wrappers();
+}
+
+/**
+ * Link the static libraries built by krb5-src, and the system libraries they depend on.
+ */
+#[cfg(all(feature = "vendored", not(feature = "dlopen")))]
+fn link_vendored_library() -> &'static str {
+  let root = PathBuf::from(env::var("DEP_KRB5_SRC_ROOT").expect("krb5-src did not report its install directory"));
+  println!("cargo:rustc-link-search=native={}", root.join("lib").display());
+  for lib in &["krb5", "k5crypto", "com_err", "krb5support"] {
+    println!("cargo:rustc-link-lib=static={}", lib);
+  }
+  link_resolver();
+
+  let pc_file = fs::read_to_string(root.join("lib/pkgconfig/krb5.pc")).unwrap_or_default();
+  if let Some(version) = pc_file.lines().find_map(|line| line.strip_prefix("Version:")) {
+    report_version(version);
+  }
+
+  MIT
+}
+
+/**
+ * Link the static kadm5 client library built by krb5-src, and the RPC and GSSAPI libraries it
+ * uses to talk to kadmind.
+ */
+#[cfg(all(feature = "kadm5", feature = "vendored", not(feature = "dlopen")))]
+fn link_vendored_kadm5_library() {
+  for lib in &["kadm5clnt_mit", "gssrpc", "gssapi_krb5"] {
+    println!("cargo:rustc-link-lib=static={}", lib);
+  }
+}
+
+/**
+ * Link the resolver library, which static MIT krb5 libraries need for the DNS lookups of KDCs
+ * and realms. It is part of libc on macOS.
+ */
+#[cfg(not(feature = "dlopen"))]
+fn link_resolver() {
+  if env::var("CARGO_CFG_TARGET_OS").as_deref() == Ok("linux") {
+    println!("cargo:rustc-link-lib=resolv");
+  }
+}
+
+/**
+ * Locate the installed libkrb5 with pkg-config, or Homebrew's Heimdal, and link it.
+ */
+#[cfg(not(any(feature = "vendored", feature = "dlopen")))]
+fn link_system_library() -> &'static str {
+  println!("cargo:rerun-if-env-changed=KRB5_LIB_DIR");
+  println!("cargo:rerun-if-env-changed=KRB5_INCLUDE_DIR");
+  println!("cargo:rerun-if-env-changed=KRB5_STATIC");
+
+  if let Some(lib_dir) = env::var_os("KRB5_LIB_DIR") {
+    return link_library_dir(Path::new(&lib_dir));
+  }
+
+  let probe_library = |name| Config::new().statik(link_statically()).probe(name);
   let mut library_ret = probe_library("krb5");
   if library_ret.is_err() {
     /* pkg-config failed to find krb5 library.
-     * Check if there's Homebrew installed; if so, we use it to locate heimdal kerberos lib.
-     * Calling `brew --prefix heimdal` should yield the heimdal's installation path to stdout.
+     * MIT krb5 and Heimdal both ship krb5-config, which knows how to link them.
      */
-    let brew_out = Command::new("brew")
-      .arg("--prefix")
-      .arg("heimdal")
-      .output()
-      .expect("krb5 library not found and failed to execute `brew --prefix` to locate it");
-    if !brew_out.status.success() {
+    if let Some(flavor) = probe_krb5_config() {
+      return flavor;
+    }
+
+    /* macOS ships Heimdal as the Kerberos framework, which Homebrew's Heimdal is preferred to */
+    let target_macos = env::var("CARGO_CFG_TARGET_OS").as_deref() == Ok("macos");
+
+    /* krb5-config and Homebrew would locate the libkrb5 of the host */
+    if cross_compiling() {
+      if target_macos {
+        return link_kerberos_framework();
+      }
       eprintln!(
-        "Failed to run brew to locate heimdal krb5: code {}",
-        brew_out.status.code().unwrap()
+        "krb5 library for {} not found with pkg-config: configure pkg-config for cross-compilation, e.g. with PKG_CONFIG_SYSROOT_DIR, or set KRB5_LIB_DIR",
+        env::var("TARGET").unwrap_or_default()
       );
       process::exit(1);
     }
-    if brew_out.stdout[0] != b'/' {
-      /* we expect an absolute path, so we treat this as an error */
-      eprintln!("Failed to locate krb5: {}", String::from_utf8_lossy(&brew_out.stdout));
-      process::exit(2);
-    }
+
+    let heimdal_path = match brew_heimdal_prefix() {
+      Ok(path) => path,
+      Err(_) if target_macos => return link_kerberos_framework(),
+      Err((message, code)) => {
+        eprintln!("{}", message);
+        process::exit(code);
+      },
+    };
 
     /* the krb5.pc file should be in <heimdal path>/lib/pkgconfig */
-    let mut heimdal_pc_path = PathBuf::from(String::from_utf8_lossy(&brew_out.stdout).deref().trim_end());
-    heimdal_pc_path.push("lib/pkgconfig");
+    let heimdal_pc_path = heimdal_path.join("lib/pkgconfig");
 
     /* prepend to or create PKG_CONFIG_PATH env var */
     let pkg_path = if let Ok(val) = env::var("PKG_CONFIG_PATH") {
@@ -60,15 +206,398 @@ fn main() {
   }
 
   let library = library_ret.expect("Failed to probe krb5");
-  for lib in library.libs {
+  for lib in &library.libs {
     println!("cargo:rustc-link-lib={}", lib);
   }
+  report_version(&library.version);
+
+  detect_flavor(&library)
+}
+
+/**
+ * Locate Homebrew's Heimdal with `brew --prefix heimdal`, which should yield its installation
+ * path. Returns the error message and exit code of the build script if Homebrew or its Heimdal
+ * are not installed.
+ */
+#[cfg(not(any(feature = "vendored", feature = "dlopen")))]
+fn brew_heimdal_prefix() -> Result<PathBuf, (String, i32)> {
+  let brew_out = Command::new("brew")
+    .arg("--prefix")
+    .arg("heimdal")
+    .output()
+    .map_err(|error| {
+      (
+        format!(
+        "krb5 library not found with pkg-config or krb5-config, and failed to execute `brew --prefix` to locate it: {}",
+        error
+      ),
+        1,
+      )
+    })?;
+  if !brew_out.status.success() {
+    return Err((
+      format!(
+        "Failed to run brew to locate heimdal krb5: code {:?}",
+        brew_out.status.code()
+      ),
+      1,
+    ));
+  }
+  if brew_out.stdout.first() != Some(&b'/') {
+    /* we expect an absolute path, so we treat this as an error */
+    return Err((
+      format!("Failed to locate krb5: {}", String::from_utf8_lossy(&brew_out.stdout)),
+      2,
+    ));
+  }
+
+  Ok(PathBuf::from(
+    String::from_utf8_lossy(&brew_out.stdout).deref().trim_end(),
+  ))
+}
+
+/**
+ * Link the Kerberos framework of macOS, Apple's build of Heimdal, from the SDK. Its headers are
+ * used by bindgen, unless `KRB5_INCLUDE_DIR` is set. The SDK is taken from `SDKROOT`, or asked
+ * from `xcrun`.
+ */
+#[cfg(not(any(feature = "vendored", feature = "dlopen")))]
+fn link_kerberos_framework() -> &'static str {
+  eprintln!("Homebrew's Heimdal not found, linking the Kerberos framework of macOS");
+  println!("cargo:rustc-link-lib=framework=Kerberos");
+
+  println!("cargo:rerun-if-env-changed=SDKROOT");
+  let sdk_root = env::var_os("SDKROOT").map(PathBuf::from).or_else(|| {
+    Command::new("xcrun")
+      .args(["--sdk", "macosx", "--show-sdk-path"])
+      .output()
+      .ok()
+      .filter(|output| output.status.success())
+      .map(|output| PathBuf::from(String::from_utf8_lossy(&output.stdout).trim_end()))
+  });
+  match sdk_root {
+    Some(sdk_root) if env::var_os("KRB5_INCLUDE_DIR").is_none() => {
+      let headers = sdk_root.join("System/Library/Frameworks/Kerberos.framework/Headers");
+      env::set_var("KRB5_INCLUDE_DIR", headers);
+    },
+    Some(_) => {},
+    None => eprintln!("macOS SDK not found, set SDKROOT or KRB5_INCLUDE_DIR for the headers of the Kerberos framework"),
+  }
+
+  HEIMDAL
+}
+
+/**
+ * Link the kadm5 client library next to the libkrb5 that was linked: from `KRB5_LIB_DIR`, with
+ * pkg-config, or with `krb5-config`. MIT krb5 calls it libkadm5clnt_mit, with libkadm5clnt as a
+ * compatibility link, Heimdal only libkadm5clnt.
+ */
+#[cfg(all(feature = "kadm5", not(any(feature = "vendored", feature = "dlopen"))))]
+fn link_kadm5_library() {
+  if let Some(lib_dir) = env::var_os("KRB5_LIB_DIR") {
+    let lib_dir = Path::new(&lib_dir);
+    if has_library(lib_dir, "kadm5clnt_mit") {
+      if link_statically() {
+        for lib in &["kadm5clnt_mit", "gssrpc", "gssapi_krb5"] {
+          println!("cargo:rustc-link-lib=static={}", lib);
+        }
+      } else {
+        println!("cargo:rustc-link-lib=kadm5clnt_mit");
+      }
+    } else {
+      println!(
+        "cargo:rustc-link-lib={}kadm5clnt",
+        if link_statically() { "static=" } else { "" }
+      );
+    }
+    return;
+  }
+
+  if let Ok(library) = Config::new().statik(link_statically()).probe("kadm-client") {
+    for lib in &library.libs {
+      println!("cargo:rustc-link-lib={}", lib);
+    }
+    return;
+  }
+
+  let flags = if cross_compiling() {
+    None
+  } else {
+    Command::new("krb5-config")
+      .args(["--libs", "kadm-client"])
+      .output()
+      .ok()
+      .filter(|output| output.status.success())
+      .map(|output| String::from_utf8_lossy(&output.stdout).into_owned())
+  };
+  match flags {
+    Some(flags) => {
+      for flag in flags.split_whitespace() {
+        if let Some(dir) = flag.strip_prefix("-L") {
+          println!("cargo:rustc-link-search=native={}", dir);
+        } else if let Some(lib) = flag.strip_prefix("-l") {
+          println!("cargo:rustc-link-lib={}", lib);
+        }
+      }
+    },
+    None => println!("cargo:rustc-link-lib=kadm5clnt"),
+  }
+}
+
+/**
+ * Locate libkrb5 with `krb5-config --libs --cflags` and link it. The include directory is used
+ * by bindgen, unless `KRB5_INCLUDE_DIR` is set. Returns `None` if krb5-config is not installed
+ * or fails, or when cross-compiling, as it describes the libkrb5 of the host.
+ */
+#[cfg(not(any(feature = "vendored", feature = "dlopen")))]
+fn probe_krb5_config() -> Option<&'static str> {
+  if cross_compiling() {
+    return None;
+  }
+
+  let krb5_config = |args: &[&str]| {
+    Command::new("krb5-config")
+      .args(args)
+      .output()
+      .ok()
+      .filter(|output| output.status.success())
+      .map(|output| String::from_utf8_lossy(&output.stdout).into_owned())
+  };
+
+  let flags = krb5_config(&["--libs", "--cflags", "krb5"])?;
+  for flag in flags.split_whitespace() {
+    if let Some(dir) = flag.strip_prefix("-L") {
+      println!("cargo:rustc-link-search=native={}", dir);
+    } else if let Some(lib) = flag.strip_prefix("-l") {
+      println!("cargo:rustc-link-lib={}", lib);
+    } else if let Some(dir) = flag.strip_prefix("-I") {
+      if env::var_os("KRB5_INCLUDE_DIR").is_none() {
+        env::set_var("KRB5_INCLUDE_DIR", dir);
+      }
+    }
+  }
+
+  /* "Kerberos 5 release 1.19.2" for MIT krb5, "heimdal 7.7.0" for Heimdal */
+  if let Some(version) = krb5_config(&["--version"])
+    .as_deref()
+    .and_then(|version| version.split_whitespace().last())
+  {
+    report_version(version);
+  }
+
+  /* MIT krb5 reports "Massachusetts Institute of Technology" */
+  match krb5_config(&["--vendor"]) {
+    Some(vendor) if vendor.contains("Heimdal") => Some(HEIMDAL),
+    _ => Some(MIT),
+  }
+}
+
+/**
+ * Link the libkrb5 in `KRB5_LIB_DIR`, dynamically unless `KRB5_STATIC` is set. Heimdal is told
+ * apart from MIT krb5 by its libroken next to libkrb5, or its krb5-types.h in
+ * `KRB5_INCLUDE_DIR`.
+ */
+#[cfg(not(any(feature = "vendored", feature = "dlopen")))]
+fn link_library_dir(lib_dir: &Path) -> &'static str {
+  let heimdal = has_library(lib_dir, "roken")
+    || env::var_os("KRB5_INCLUDE_DIR").is_some_and(|include_dir| Path::new(&include_dir).join("krb5-types.h").exists());
+
+  println!("cargo:rustc-link-search=native={}", lib_dir.display());
+
+  if let Some(version) = env::var_os("KRB5_INCLUDE_DIR").and_then(|include_dir| header_version(Path::new(&include_dir)))
+  {
+    report_version(&version);
+  }
+
+  if heimdal {
+    println!(
+      "cargo:rustc-link-lib={}krb5",
+      if link_statically() { "static=" } else { "" }
+    );
+    return HEIMDAL;
+  }
+
+  if link_statically() {
+    for lib in &["krb5", "k5crypto", "com_err", "krb5support"] {
+      println!("cargo:rustc-link-lib=static={}", lib);
+    }
+    link_resolver();
+  } else {
+    for lib in &["krb5", "k5crypto", "com_err"] {
+      println!("cargo:rustc-link-lib={}", lib);
+    }
+  }
+
+  MIT
+}
+
+/**
+ * Guess the release of MIT krb5 from its krb5/krb5.h, which marks the functions added since 1.7
+ * with "New in 1.x". The newest of them is the oldest release the header can be from.
+ */
+#[cfg(not(any(feature = "vendored", feature = "dlopen")))]
+fn header_version(include_dir: &Path) -> Option<String> {
+  let header = fs::read_to_string(include_dir.join("krb5").join("krb5.h")).ok()?;
+
+  header
+    .split("New in 1.")
+    .skip(1)
+    .filter_map(|rest| {
+      let minor: String = rest.chars().take_while(char::is_ascii_digit).collect();
+      minor.parse::<u32>().ok()
+    })
+    .max()
+    .map(|minor| format!("1.{}", minor))
+}
+
+/**
+ * Return whether a directory has a static or shared library, e.g. `libroken.so.18`.
+ */
+#[cfg(not(any(feature = "vendored", feature = "dlopen")))]
+fn has_library(dir: &Path, name: &str) -> bool {
+  let prefix = format!("lib{}.", name);
+
+  fs::read_dir(dir).is_ok_and(|entries| {
+    entries
+      .filter_map(Result::ok)
+      .any(|entry| entry.file_name().to_string_lossy().starts_with(&prefix))
+  })
+}
+
+/**
+ * Report the version of the linked library to the build scripts of dependent crates, as
+ * `DEP_KRB5_VERSION`.
+ */
+#[cfg(not(feature = "dlopen"))]
+fn report_version(version: &str) {
+  println!("cargo:version={}", version.trim());
+}
+
+/**
+ * Return whether libkrb5 is linked statically: if `KRB5_STATIC` is set to anything but `0`, or,
+ * if it is not set, if the C runtime is linked statically, as with the musl targets. A static
+ * binary can not load shared libraries, e.g. in a scratch-based container.
+ */
+#[cfg(not(any(feature = "vendored", feature = "dlopen")))]
+fn link_statically() -> bool {
+  match env::var_os("KRB5_STATIC") {
+    Some(value) => value != "0",
+    None => env::var("CARGO_CFG_TARGET_FEATURE").is_ok_and(|features| features.split(',').any(|f| f == "crt-static")),
+  }
+}
+
+/**
+ * Return whether the crate is built for another target than the build script runs on.
+ */
+#[cfg(not(any(feature = "vendored", feature = "dlopen")))]
+fn cross_compiling() -> bool {
+  env::var("HOST") != env::var("TARGET")
+}
+
+/**
+ * Tell MIT krb5 and Heimdal apart by the `vendor` variable of their krb5.pc, or by the
+ * libraries only Heimdal links if it is missing.
+ */
+#[cfg(not(any(feature = "vendored", feature = "dlopen")))]
+fn detect_flavor(library: &pkg_config::Library) -> &'static str {
+  match pkg_config::get_variable("krb5", "vendor").as_deref() {
+    Ok("MIT") => MIT,
+    Ok("Heimdal") => HEIMDAL,
+    _ if library.libs.iter().any(|lib| lib == "roken" || lib == "hx509") => HEIMDAL,
+    _ => MIT,
+  }
+}
+
+/**
+ * Return the directory of the pre-generated bindings for the target in src/bindings, named
+ * after its architecture, operating system and pointer width, or `None` if there is none.
+ */
+fn pregenerated_bindings_dir() -> Option<PathBuf> {
+  let target = format!(
+    "{}-{}-{}",
+    env::var("CARGO_CFG_TARGET_ARCH").unwrap(),
+    env::var("CARGO_CFG_TARGET_OS").unwrap(),
+    env::var("CARGO_CFG_TARGET_POINTER_WIDTH").unwrap()
+  );
+  let dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap())
+    .join("src/bindings")
+    .join(&target);
+
+  if dir.is_dir() {
+    Some(dir)
+  } else {
+    eprintln!(
+      "No pre-generated bindings for {} in src/bindings, generating them with bindgen, which needs libclang",
+      target
+    );
+    None
+  }
+}
+
+/**
+ * Copy the pre-generated bindings in `dir` to bindings.rs in the OUT_DIR, followed by the kadm5
+ * bindings with the `kadm5` feature.
+ */
+fn copy_pregenerated_bindings(dir: &Path) {
+  let mut sources = vec![dir.join("mit.rs")];
+  if cfg!(feature = "kadm5") {
+    sources.push(dir.join("kadm5_mit.rs"));
+  }
+
+  let mut bindings = String::new();
+  for source in &sources {
+    println!("cargo:rerun-if-changed={}", source.display());
+    bindings.push_str(&fs::read_to_string(source).expect("Unable to read pre-generated bindings."));
+  }
+
+  let out_path = PathBuf::from(env::var("OUT_DIR").unwrap());
+  fs::write(out_path.join("bindings.rs"), bindings).expect("Unable to write bindings to file.");
+}
+
+fn generate_bindings() {
+  let builder = bindgen::Builder::default();
 
-  let bindings = bindgen::Builder::default()
+  println!("cargo:rerun-if-env-changed=KRB5_INCLUDE_DIR");
+  let builder = match env::var_os("KRB5_INCLUDE_DIR").map(PathBuf::from) {
+    Some(include_dir) => {
+      let builder = builder.clang_arg(format!("-I{}", include_dir.display()));
+      /* the headers of a framework include each other as <Kerberos/...>, which needs the
+       * directory of the framework as a framework search path */
+      match include_dir
+        .parent()
+        .filter(|dir| dir.extension() == Some("framework".as_ref()))
+      {
+        Some(framework) => builder.clang_arg(format!("-F{}", framework.parent().unwrap().display())),
+        None => builder,
+      }
+    },
+    None => builder,
+  };
+
+  #[cfg(feature = "vendored")]
+  let builder = builder.clang_arg(format!(
+    "-I{}",
+    PathBuf::from(env::var("DEP_KRB5_SRC_ROOT").unwrap())
+      .join("include")
+      .display()
+  ));
+
+  #[cfg(feature = "kadm5")]
+  let builder = builder
+    .header("src/kadm5.h")
+    .whitelist_type("(_|)kadm5.*")
+    .whitelist_function("kadm5.*")
+    .whitelist_var("KADM5.*");
+
+  let bindings = builder
     .rust_target(bindgen::RustTarget::Stable_1_40)
     .header("src/wrapper.h")
     .whitelist_type("(_|)krb5.*")
+    .whitelist_type("profile_.*")
     .whitelist_function("krb5.*")
+    .whitelist_function("profile_.*")
+    .whitelist_function("com_err")
+    .whitelist_function("error_message")
     .whitelist_var("ADDRTYPE_.*")
     .whitelist_var("AD_TYPE_.*")
     .whitelist_var("AP_OPTS_.*")
@@ -79,6 +608,7 @@ fn main() {
     .whitelist_var("LR_TYPE_.*")
     .whitelist_var("MAX_KEYTAB_NAME_LEN")
     .whitelist_var("MSEC_.*")
+    .whitelist_var("PROF_.*")
     .whitelist_var("TKT_FLG_.*")
     .generate()
     .expect("Unable to generate bindings.");
@@ -89,3 +619,171 @@ fn main() {
     .write_to_file(out_path.join("bindings.rs"))
     .expect("Unable to write bindings to file.");
 }
+
+/**
+ * Rewrite the bindings for loading libkrb5 at run time: the `extern "C"` declarations are
+ * replaced by a table of function pointers, `Krb5Functions`, and wrappers with the same
+ * signatures that call through it. Everything else is kept as it is.
+ *
+ * Functions missing from the loaded library return `KRB5_PLUGIN_OP_NOTSUPP`, null or zero, see
+ * [missing_function_result]. Variadic functions can not be defined in Rust, so they are statics
+ * that dereference to the function pointer instead, which can be called the same way; these are
+ * present in every release and still panic when missing.
+ */
+#[cfg(feature = "dlopen")]
+fn generate_dlopen_wrappers() {
+  let out_path = PathBuf::from(env::var("OUT_DIR").unwrap());
+  let bindings = fs::read_to_string(out_path.join("bindings.rs")).expect("Unable to read bindings.");
+
+  let mut items = String::new();
+  let mut fields = String::new();
+  let mut loaders = String::new();
+  let mut wrappers = String::new();
+
+  let mut rest = bindings.as_str();
+  while let Some(start) = rest.find("extern \"C\" {") {
+    items.push_str(&rest[..start]);
+    let block = &rest[start + "extern \"C\" {".len()..];
+    let end = find_outside_strings(block, '}').expect("Unbalanced extern block in bindings.");
+
+    for declaration in split_outside_strings(&block[..end], ';') {
+      let declaration = strip_attributes(declaration);
+      let signature = match declaration.strip_prefix("pub fn ") {
+        Some(signature) => signature,
+        None if declaration.is_empty() => continue,
+        None => panic!("Unsupported declaration in bindings: {}", declaration),
+      };
+
+      let open = signature.find('(').unwrap();
+      let name = signature[..open].trim();
+      let close =
+        open + 1 + find_outside_strings(&signature[open + 1..], ')').expect("Unbalanced parameter list in bindings.");
+      let params: Vec<&str> = split_outside_strings(&signature[open + 1..close], ',')
+        .into_iter()
+        .map(str::trim)
+        .filter(|param| !param.is_empty())
+        .collect();
+      let ret = signature[close + 1..].trim();
+
+      let fn_type = format!("unsafe extern \"C\" fn({}) {}", params.join(", "), ret);
+      fields.push_str(&format!("  {}: Option<{}>,\n", name, fn_type));
+      loaders.push_str(&format!(
+        "      {name}: library.get::<{ty}>(b\"{name}\\0\").ok().map(|symbol| *symbol),\n",
+        name = name,
+        ty = fn_type
+      ));
+
+      if params.last() == Some(&"...") {
+        wrappers.push_str(&format!(
+          "::lazy_static::lazy_static! {{\n  pub static ref {name}: {ty} =\n    crate::dlopen::functions().{name}.expect(\"{name} is missing from libkrb5\");\n}}\n",
+          name = name,
+          ty = fn_type
+        ));
+      } else {
+        let args: Vec<&str> = params
+          .iter()
+          .map(|param| param.split(':').next().unwrap().trim())
+          .collect();
+        wrappers.push_str(&format!(
+          "pub unsafe extern \"C\" fn {name}({params}) {ret} {{\n  match crate::dlopen::functions().{name} {{\n    Some(function) => function({args}),\n    None => {missing},\n  }}\n}}\n",
+          name = name,
+          params = params.join(", "),
+          ret = ret,
+          args = args.join(", "),
+          missing = missing_function_result(ret)
+        ));
+      }
+    }
+
+    rest = &block[end + 1..];
+  }
+  items.push_str(rest);
+
+  let generated = format!(
+    "{items}\n/// The functions of libkrb5, looked up when it is loaded.\npub struct Krb5Functions {{\n{fields}}}\n\nimpl Krb5Functions {{\n  pub(crate) unsafe fn load(library: &::libloading::Library) -> Krb5Functions {{\n    Krb5Functions {{\n{loaders}    }}\n  }}\n}}\n\n{wrappers}",
+    items = items,
+    fields = fields,
+    loaders = loaders,
+    wrappers = wrappers
+  );
+
+  fs::write(out_path.join("dlopen_bindings.rs"), generated).expect("Unable to write bindings to file.");
+}
+
+/**
+ * The value a wrapper returns when its function is missing from the loaded libkrb5: error
+ * codes become `KRB5_PLUGIN_OP_NOTSUPP`, pointers null and everything else zero.
+ */
+#[cfg(feature = "dlopen")]
+fn missing_function_result(ret: &str) -> &'static str {
+  match ret.trim_start_matches("->").trim() {
+    "" => "()",
+    "krb5_error_code" | "errcode_t" | "::std::os::raw::c_long" => "KRB5_PLUGIN_OP_NOTSUPP as _",
+    _ => "::std::mem::zeroed()",
+  }
+}
+
+/**
+ * Return the position of the first `needle` at the top level of `text`, outside of string
+ * literals and brackets.
+ */
+#[cfg(feature = "dlopen")]
+fn find_outside_strings(text: &str, needle: char) -> Option<usize> {
+  let mut depth = 0;
+  let mut in_string = false;
+  let mut escaped = false;
+
+  for (position, c) in text.char_indices() {
+    if in_string {
+      match c {
+        _ if escaped => escaped = false,
+        '\\' => escaped = true,
+        '"' => in_string = false,
+        _ => {},
+      }
+      continue;
+    }
+
+    match c {
+      _ if c == needle && depth == 0 => return Some(position),
+      '"' => in_string = true,
+      '(' | '[' | '{' => depth += 1,
+      ')' | ']' | '}' => depth -= 1,
+      _ => {},
+    }
+  }
+
+  None
+}
+
+/**
+ * Split `text` at the top level occurrences of `separator`, see [find_outside_strings].
+ */
+#[cfg(feature = "dlopen")]
+fn split_outside_strings(text: &str, separator: char) -> Vec<&str> {
+  let mut parts = Vec::new();
+  let mut rest = text;
+
+  while let Some(end) = find_outside_strings(rest, separator) {
+    parts.push(&rest[..end]);
+    rest = &rest[end + 1..];
+  }
+  parts.push(rest);
+
+  parts
+}
+
+/**
+ * Remove the attributes (e.g. doc comments) in front of a declaration.
+ */
+#[cfg(feature = "dlopen")]
+fn strip_attributes(declaration: &str) -> &str {
+  let mut declaration = declaration.trim();
+
+  while let Some(attribute) = declaration.strip_prefix("#[") {
+    let end = find_outside_strings(attribute, ']').expect("Unbalanced attribute in bindings.");
+    declaration = attribute[end + 1..].trim();
+  }
+
+  declaration
+}
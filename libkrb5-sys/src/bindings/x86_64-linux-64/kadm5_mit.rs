@@ -0,0 +1,1564 @@
+/*
+ * Pre-generated bindings for MIT kadm5 1.19.2 (the headers of krb5-src 0.3.4) on x86_64 Linux,
+ * from src/kadm5.h with the allowlist of build.rs.
+ *
+ * Regenerate with `cargo build -p libkrb5-sys --target x86_64-unknown-linux-gnu --features
+ * vendored,buildtime-bindgen,kadm5`, and copy the items of bindings.rs that are not in mit.rs
+ * from the OUT_DIR.
+ */
+
+pub const KADM5_ADMIN_SERVICE: &'static [u8; 13] = b"kadmin/admin\0";
+pub const KADM5_API_VERSION_2: u32 = 305420034;
+pub const KADM5_API_VERSION_3: u32 = 305420035;
+pub const KADM5_API_VERSION_4: u32 = 305420036;
+pub const KADM5_API_VERSION_MASK: u32 = 305420032;
+pub const KADM5_ATTRIBUTES: u32 = 16;
+pub const KADM5_AUTH_ADD: u32 = 43787522;
+pub const KADM5_AUTH_CHANGEPW: u32 = 43787565;
+pub const KADM5_AUTH_DELETE: u32 = 43787524;
+pub const KADM5_AUTH_EXTRACT: u32 = 43787580;
+pub const KADM5_AUTH_GET: u32 = 43787521;
+pub const KADM5_AUTH_INITIAL: u32 = 43787582;
+pub const KADM5_AUTH_INSUFFICIENT: u32 = 43787525;
+pub const KADM5_AUTH_LIST: u32 = 43787564;
+pub const KADM5_AUTH_MODIFY: u32 = 43787523;
+pub const KADM5_AUTH_SETKEY: u32 = 43787570;
+pub const KADM5_AUX_ATTRIBUTES: u32 = 1024;
+pub const KADM5_BAD_API_VERSION: u32 = 43787555;
+pub const KADM5_BAD_AUX_ATTR: u32 = 43787539;
+pub const KADM5_BAD_CLASS: u32 = 43787535;
+pub const KADM5_BAD_CLIENT_PARAMS: u32 = 43787562;
+pub const KADM5_BAD_DB: u32 = 43787526;
+pub const KADM5_BAD_HISTORY: u32 = 43787540;
+pub const KADM5_BAD_HIST_KEY: u32 = 43787530;
+pub const KADM5_BAD_KEYSALTS: u32 = 43787578;
+pub const KADM5_BAD_LENGTH: u32 = 43787536;
+pub const KADM5_BAD_MASK: u32 = 43787534;
+pub const KADM5_BAD_MIN_PASS_LIFE: u32 = 43787541;
+pub const KADM5_BAD_PASSWORD: u32 = 43787549;
+pub const KADM5_BAD_POLICY: u32 = 43787537;
+pub const KADM5_BAD_PRINCIPAL: u32 = 43787538;
+pub const KADM5_BAD_SERVER_HANDLE: u32 = 43787551;
+pub const KADM5_BAD_SERVER_NAME: u32 = 43787569;
+pub const KADM5_BAD_SERVER_PARAMS: u32 = 43787563;
+pub const KADM5_BAD_STRUCT_VERSION: u32 = 43787552;
+pub const KADM5_BAD_TL_TYPE: u32 = 43787567;
+pub const KADM5_CANT_RESOLVE: u32 = 43787576;
+pub const KADM5_CHANGEPW_SERVICE: &'static [u8; 16] = b"kadmin/changepw\0";
+pub const KADM5_CONFIG_ACL_FILE: u32 = 8192;
+pub const KADM5_CONFIG_ADBNAME: u32 = 1024;
+pub const KADM5_CONFIG_ADB_LOCKFILE: u32 = 2048;
+pub const KADM5_CONFIG_ADMIN_SERVER: u32 = 65536;
+pub const KADM5_CONFIG_AUTH_NOFALLBACK: u32 = 4194304;
+pub const KADM5_CONFIG_DBNAME: u32 = 2;
+pub const KADM5_CONFIG_DICT_FILE: u32 = 131072;
+pub const KADM5_CONFIG_ENCTYPE: u32 = 512;
+pub const KADM5_CONFIG_ENCTYPES: u32 = 32768;
+pub const KADM5_CONFIG_EXPIRATION: u32 = 32;
+pub const KADM5_CONFIG_FLAGS: u32 = 64;
+pub const KADM5_CONFIG_IPROP_ENABLED: u32 = 16777216;
+pub const KADM5_CONFIG_IPROP_LISTEN: u32 = 2147483648;
+pub const KADM5_CONFIG_IPROP_LOGFILE: u32 = 134217728;
+pub const KADM5_CONFIG_IPROP_PORT: u32 = 268435456;
+pub const KADM5_CONFIG_IPROP_RESYNC_TIMEOUT: u32 = 1073741824;
+pub const KADM5_CONFIG_KADMIND_LISTEN: u32 = 4096;
+pub const KADM5_CONFIG_KADMIND_PORT: u32 = 16384;
+pub const KADM5_CONFIG_KPASSWD_LISTEN: u32 = 8388608;
+pub const KADM5_CONFIG_KPASSWD_PORT: u32 = 524288;
+pub const KADM5_CONFIG_KVNO: u32 = 536870912;
+pub const KADM5_CONFIG_MAX_LIFE: u32 = 8;
+pub const KADM5_CONFIG_MAX_RLIFE: u32 = 16;
+pub const KADM5_CONFIG_MKEY_FROM_KBD: u32 = 262144;
+pub const KADM5_CONFIG_MKEY_NAME: u32 = 4;
+pub const KADM5_CONFIG_NO_AUTH: u32 = 2097152;
+pub const KADM5_CONFIG_OLD_AUTH_GSSAPI: u32 = 1048576;
+pub const KADM5_CONFIG_POLL_TIME: u32 = 67108864;
+pub const KADM5_CONFIG_REALM: u32 = 1;
+pub const KADM5_CONFIG_STASH_FILE: u32 = 256;
+pub const KADM5_CONFIG_ULOG_SIZE: u32 = 33554432;
+pub const KADM5_DUP: u32 = 43787527;
+pub const KADM5_FAILURE: u32 = 43787520;
+pub const KADM5_FAIL_AUTH_COUNT: u32 = 65536;
+pub const KADM5_GSS_ERROR: u32 = 43787566;
+pub const KADM5_HIST_PRINCIPAL: &'static [u8; 15] = b"kadmin/history\0";
+pub const KADM5_INIT: u32 = 43787548;
+pub const KADM5_KEY_DATA: u32 = 131072;
+pub const KADM5_KEY_HIST: u32 = 4194304;
+pub const KADM5_KIPROP_HOST_SERVICE: &'static [u8; 7] = b"kiprop\0";
+pub const KADM5_KVNO: u32 = 256;
+pub const KADM5_LAST_FAILED: u32 = 32768;
+pub const KADM5_LAST_PWD_CHANGE: u32 = 8;
+pub const KADM5_LAST_SUCCESS: u32 = 16384;
+pub const KADM5_LOAD: u32 = 2097152;
+pub const KADM5_MASK_BITS: u32 = 4294967040;
+pub const KADM5_MAX_LIFE: u32 = 32;
+pub const KADM5_MAX_RLIFE: u32 = 8192;
+pub const KADM5_MISSING_CONF_PARAMS: u32 = 43787568;
+pub const KADM5_MISSING_KRB5_CONF_PARAMS: u32 = 43787574;
+pub const KADM5_MKVNO: u32 = 512;
+pub const KADM5_MOD_NAME: u32 = 128;
+pub const KADM5_MOD_TIME: u32 = 64;
+pub const KADM5_NEW_LIB_API_VERSION: u32 = 43787558;
+pub const KADM5_NEW_SERVER_API_VERSION: u32 = 43787559;
+pub const KADM5_NEW_STRUCT_VERSION: u32 = 43787554;
+pub const KADM5_NOT_INIT: u32 = 43787531;
+pub const KADM5_NO_RENAME_SALT: u32 = 43787561;
+pub const KADM5_NO_SRV: u32 = 43787529;
+pub const KADM5_OK: u32 = 0;
+pub const KADM5_OLD_LIB_API_VERSION: u32 = 43787556;
+pub const KADM5_OLD_SERVER_API_VERSION: u32 = 43787557;
+pub const KADM5_OLD_STRUCT_VERSION: u32 = 43787553;
+pub const KADM5_PASS_Q_CLASS: u32 = 43787543;
+pub const KADM5_PASS_Q_DICT: u32 = 43787544;
+pub const KADM5_PASS_Q_GENERIC: u32 = 43787577;
+pub const KADM5_PASS_Q_TOOSHORT: u32 = 43787542;
+pub const KADM5_PASS_REUSE: u32 = 43787545;
+pub const KADM5_PASS_TOOSOON: u32 = 43787546;
+pub const KADM5_POLICY: u32 = 2048;
+pub const KADM5_POLICY_ALLOWED_KEYSALTS: u32 = 67108864;
+pub const KADM5_POLICY_ATTRIBUTES: u32 = 8388608;
+pub const KADM5_POLICY_CLR: u32 = 4096;
+pub const KADM5_POLICY_MAX_LIFE: u32 = 16777216;
+pub const KADM5_POLICY_MAX_RLIFE: u32 = 33554432;
+pub const KADM5_POLICY_REF: u32 = 43787547;
+pub const KADM5_POLICY_TL_DATA: u32 = 134217728;
+pub const KADM5_PRINCIPAL: u32 = 1;
+pub const KADM5_PRINCIPAL_NORMAL_MASK: u32 = 4325375;
+pub const KADM5_PRINC_EXPIRE_TIME: u32 = 2;
+pub const KADM5_PRIV_ADD: u32 = 2;
+pub const KADM5_PRIV_DELETE: u32 = 8;
+pub const KADM5_PRIV_GET: u32 = 1;
+pub const KADM5_PRIV_MODIFY: u32 = 4;
+pub const KADM5_PROTECT_KEYS: u32 = 43787581;
+pub const KADM5_PROTECT_PRINCIPAL: u32 = 43787550;
+pub const KADM5_PW_EXPIRATION: u32 = 4;
+pub const KADM5_PW_FAILURE_COUNT_INTERVAL: u32 = 2097152;
+pub const KADM5_PW_HISTORY_NUM: u32 = 262144;
+pub const KADM5_PW_LOCKOUT_DURATION: u32 = 4194304;
+pub const KADM5_PW_MAX_FAILURE: u32 = 1048576;
+pub const KADM5_PW_MAX_LIFE: u32 = 16384;
+pub const KADM5_PW_MIN_CLASSES: u32 = 131072;
+pub const KADM5_PW_MIN_LENGTH: u32 = 65536;
+pub const KADM5_PW_MIN_LIFE: u32 = 32768;
+pub const KADM5_REF_COUNT: u32 = 524288;
+pub const KADM5_RPC_ERROR: u32 = 43787528;
+pub const KADM5_SECURE_PRINC_MISSING: u32 = 43787560;
+pub const KADM5_SETKEY3_ETYPE_MISMATCH: u32 = 43787573;
+pub const KADM5_SETKEY_BAD_KVNO: u32 = 43787579;
+pub const KADM5_SETKEY_DUP_ENCTYPES: u32 = 43787571;
+pub const KADM5_SETV4KEY_INVAL_ENCTYPE: u32 = 43787572;
+pub const KADM5_STRUCT_VERSION: u32 = 305419777;
+pub const KADM5_STRUCT_VERSION_1: u32 = 305419777;
+pub const KADM5_STRUCT_VERSION_MASK: u32 = 305419776;
+pub const KADM5_TL_DATA: u32 = 262144;
+pub const KADM5_UNK_POLICY: u32 = 43787533;
+pub const KADM5_UNK_PRINC: u32 = 43787532;
+pub const KADM5_XDR_FAILURE: u32 = 43787575;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct __krb5_key_salt_tuple {
+  pub ks_enctype: krb5_enctype,
+  pub ks_salttype: krb5_int32,
+}
+#[test]
+fn bindgen_test_layout___krb5_key_salt_tuple() {
+  const UNINIT: ::std::mem::MaybeUninit<__krb5_key_salt_tuple> = ::std::mem::MaybeUninit::uninit();
+  let ptr = UNINIT.as_ptr();
+  assert_eq!(
+    ::std::mem::size_of::<__krb5_key_salt_tuple>(),
+    8usize,
+    concat!("Size of: ", stringify!(__krb5_key_salt_tuple))
+  );
+  assert_eq!(
+    ::std::mem::align_of::<__krb5_key_salt_tuple>(),
+    4usize,
+    concat!("Alignment of ", stringify!(__krb5_key_salt_tuple))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).ks_enctype) as usize - ptr as usize },
+    0usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(__krb5_key_salt_tuple),
+      "::",
+      stringify!(ks_enctype)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).ks_salttype) as usize - ptr as usize },
+    4usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(__krb5_key_salt_tuple),
+      "::",
+      stringify!(ks_salttype)
+    )
+  );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _kadm5_config_params {
+  pub mask: ::std::os::raw::c_long,
+  pub realm: *mut ::std::os::raw::c_char,
+  pub kadmind_port: ::std::os::raw::c_int,
+  pub kpasswd_port: ::std::os::raw::c_int,
+  pub admin_server: *mut ::std::os::raw::c_char,
+  pub dbname: *mut ::std::os::raw::c_char,
+  pub acl_file: *mut ::std::os::raw::c_char,
+  pub dict_file: *mut ::std::os::raw::c_char,
+  pub mkey_from_kbd: ::std::os::raw::c_int,
+  pub stash_file: *mut ::std::os::raw::c_char,
+  pub mkey_name: *mut ::std::os::raw::c_char,
+  pub enctype: krb5_enctype,
+  pub max_life: krb5_deltat,
+  pub max_rlife: krb5_deltat,
+  pub expiration: krb5_timestamp,
+  pub flags: krb5_flags,
+  pub keysalts: *mut krb5_key_salt_tuple,
+  pub num_keysalts: krb5_int32,
+  pub kvno: krb5_kvno,
+  pub iprop_enabled: ::std::os::raw::c_int,
+  pub iprop_ulogsize: uint32_t,
+  pub iprop_poll_time: krb5_deltat,
+  pub iprop_logfile: *mut ::std::os::raw::c_char,
+  pub iprop_port: ::std::os::raw::c_int,
+  pub iprop_resync_timeout: ::std::os::raw::c_int,
+  pub kadmind_listen: *mut ::std::os::raw::c_char,
+  pub kpasswd_listen: *mut ::std::os::raw::c_char,
+  pub iprop_listen: *mut ::std::os::raw::c_char,
+}
+#[test]
+fn bindgen_test_layout__kadm5_config_params() {
+  const UNINIT: ::std::mem::MaybeUninit<_kadm5_config_params> = ::std::mem::MaybeUninit::uninit();
+  let ptr = UNINIT.as_ptr();
+  assert_eq!(
+    ::std::mem::size_of::<_kadm5_config_params>(),
+    176usize,
+    concat!("Size of: ", stringify!(_kadm5_config_params))
+  );
+  assert_eq!(
+    ::std::mem::align_of::<_kadm5_config_params>(),
+    8usize,
+    concat!("Alignment of ", stringify!(_kadm5_config_params))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).mask) as usize - ptr as usize },
+    0usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_kadm5_config_params),
+      "::",
+      stringify!(mask)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).realm) as usize - ptr as usize },
+    8usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_kadm5_config_params),
+      "::",
+      stringify!(realm)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).kadmind_port) as usize - ptr as usize },
+    16usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_kadm5_config_params),
+      "::",
+      stringify!(kadmind_port)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).kpasswd_port) as usize - ptr as usize },
+    20usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_kadm5_config_params),
+      "::",
+      stringify!(kpasswd_port)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).admin_server) as usize - ptr as usize },
+    24usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_kadm5_config_params),
+      "::",
+      stringify!(admin_server)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).dbname) as usize - ptr as usize },
+    32usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_kadm5_config_params),
+      "::",
+      stringify!(dbname)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).acl_file) as usize - ptr as usize },
+    40usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_kadm5_config_params),
+      "::",
+      stringify!(acl_file)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).dict_file) as usize - ptr as usize },
+    48usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_kadm5_config_params),
+      "::",
+      stringify!(dict_file)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).mkey_from_kbd) as usize - ptr as usize },
+    56usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_kadm5_config_params),
+      "::",
+      stringify!(mkey_from_kbd)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).stash_file) as usize - ptr as usize },
+    64usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_kadm5_config_params),
+      "::",
+      stringify!(stash_file)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).mkey_name) as usize - ptr as usize },
+    72usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_kadm5_config_params),
+      "::",
+      stringify!(mkey_name)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).enctype) as usize - ptr as usize },
+    80usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_kadm5_config_params),
+      "::",
+      stringify!(enctype)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).max_life) as usize - ptr as usize },
+    84usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_kadm5_config_params),
+      "::",
+      stringify!(max_life)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).max_rlife) as usize - ptr as usize },
+    88usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_kadm5_config_params),
+      "::",
+      stringify!(max_rlife)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).expiration) as usize - ptr as usize },
+    92usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_kadm5_config_params),
+      "::",
+      stringify!(expiration)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).flags) as usize - ptr as usize },
+    96usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_kadm5_config_params),
+      "::",
+      stringify!(flags)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).keysalts) as usize - ptr as usize },
+    104usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_kadm5_config_params),
+      "::",
+      stringify!(keysalts)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).num_keysalts) as usize - ptr as usize },
+    112usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_kadm5_config_params),
+      "::",
+      stringify!(num_keysalts)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).kvno) as usize - ptr as usize },
+    116usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_kadm5_config_params),
+      "::",
+      stringify!(kvno)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).iprop_enabled) as usize - ptr as usize },
+    120usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_kadm5_config_params),
+      "::",
+      stringify!(iprop_enabled)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).iprop_ulogsize) as usize - ptr as usize },
+    124usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_kadm5_config_params),
+      "::",
+      stringify!(iprop_ulogsize)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).iprop_poll_time) as usize - ptr as usize },
+    128usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_kadm5_config_params),
+      "::",
+      stringify!(iprop_poll_time)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).iprop_logfile) as usize - ptr as usize },
+    136usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_kadm5_config_params),
+      "::",
+      stringify!(iprop_logfile)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).iprop_port) as usize - ptr as usize },
+    144usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_kadm5_config_params),
+      "::",
+      stringify!(iprop_port)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).iprop_resync_timeout) as usize - ptr as usize },
+    148usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_kadm5_config_params),
+      "::",
+      stringify!(iprop_resync_timeout)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).kadmind_listen) as usize - ptr as usize },
+    152usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_kadm5_config_params),
+      "::",
+      stringify!(kadmind_listen)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).kpasswd_listen) as usize - ptr as usize },
+    160usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_kadm5_config_params),
+      "::",
+      stringify!(kpasswd_listen)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).iprop_listen) as usize - ptr as usize },
+    168usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_kadm5_config_params),
+      "::",
+      stringify!(iprop_listen)
+    )
+  );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _kadm5_key_data {
+  pub kvno: krb5_kvno,
+  pub key: krb5_keyblock,
+  pub salt: krb5_keysalt,
+}
+#[test]
+fn bindgen_test_layout__kadm5_key_data() {
+  const UNINIT: ::std::mem::MaybeUninit<_kadm5_key_data> = ::std::mem::MaybeUninit::uninit();
+  let ptr = UNINIT.as_ptr();
+  assert_eq!(
+    ::std::mem::size_of::<_kadm5_key_data>(),
+    56usize,
+    concat!("Size of: ", stringify!(_kadm5_key_data))
+  );
+  assert_eq!(
+    ::std::mem::align_of::<_kadm5_key_data>(),
+    8usize,
+    concat!("Alignment of ", stringify!(_kadm5_key_data))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).kvno) as usize - ptr as usize },
+    0usize,
+    concat!("Offset of field: ", stringify!(_kadm5_key_data), "::", stringify!(kvno))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).key) as usize - ptr as usize },
+    8usize,
+    concat!("Offset of field: ", stringify!(_kadm5_key_data), "::", stringify!(key))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).salt) as usize - ptr as usize },
+    32usize,
+    concat!("Offset of field: ", stringify!(_kadm5_key_data), "::", stringify!(salt))
+  );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _kadm5_policy_ent_t {
+  pub policy: *mut ::std::os::raw::c_char,
+  pub pw_min_life: ::std::os::raw::c_long,
+  pub pw_max_life: ::std::os::raw::c_long,
+  pub pw_min_length: ::std::os::raw::c_long,
+  pub pw_min_classes: ::std::os::raw::c_long,
+  pub pw_history_num: ::std::os::raw::c_long,
+  pub policy_refcnt: ::std::os::raw::c_long,
+  pub pw_max_fail: krb5_kvno,
+  pub pw_failcnt_interval: krb5_deltat,
+  pub pw_lockout_duration: krb5_deltat,
+  pub attributes: krb5_flags,
+  pub max_life: krb5_deltat,
+  pub max_renewable_life: krb5_deltat,
+  pub allowed_keysalts: *mut ::std::os::raw::c_char,
+  pub n_tl_data: krb5_int16,
+  pub tl_data: *mut krb5_tl_data,
+}
+#[test]
+fn bindgen_test_layout__kadm5_policy_ent_t() {
+  const UNINIT: ::std::mem::MaybeUninit<_kadm5_policy_ent_t> = ::std::mem::MaybeUninit::uninit();
+  let ptr = UNINIT.as_ptr();
+  assert_eq!(
+    ::std::mem::size_of::<_kadm5_policy_ent_t>(),
+    104usize,
+    concat!("Size of: ", stringify!(_kadm5_policy_ent_t))
+  );
+  assert_eq!(
+    ::std::mem::align_of::<_kadm5_policy_ent_t>(),
+    8usize,
+    concat!("Alignment of ", stringify!(_kadm5_policy_ent_t))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).policy) as usize - ptr as usize },
+    0usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_kadm5_policy_ent_t),
+      "::",
+      stringify!(policy)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).pw_min_life) as usize - ptr as usize },
+    8usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_kadm5_policy_ent_t),
+      "::",
+      stringify!(pw_min_life)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).pw_max_life) as usize - ptr as usize },
+    16usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_kadm5_policy_ent_t),
+      "::",
+      stringify!(pw_max_life)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).pw_min_length) as usize - ptr as usize },
+    24usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_kadm5_policy_ent_t),
+      "::",
+      stringify!(pw_min_length)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).pw_min_classes) as usize - ptr as usize },
+    32usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_kadm5_policy_ent_t),
+      "::",
+      stringify!(pw_min_classes)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).pw_history_num) as usize - ptr as usize },
+    40usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_kadm5_policy_ent_t),
+      "::",
+      stringify!(pw_history_num)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).policy_refcnt) as usize - ptr as usize },
+    48usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_kadm5_policy_ent_t),
+      "::",
+      stringify!(policy_refcnt)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).pw_max_fail) as usize - ptr as usize },
+    56usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_kadm5_policy_ent_t),
+      "::",
+      stringify!(pw_max_fail)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).pw_failcnt_interval) as usize - ptr as usize },
+    60usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_kadm5_policy_ent_t),
+      "::",
+      stringify!(pw_failcnt_interval)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).pw_lockout_duration) as usize - ptr as usize },
+    64usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_kadm5_policy_ent_t),
+      "::",
+      stringify!(pw_lockout_duration)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).attributes) as usize - ptr as usize },
+    68usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_kadm5_policy_ent_t),
+      "::",
+      stringify!(attributes)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).max_life) as usize - ptr as usize },
+    72usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_kadm5_policy_ent_t),
+      "::",
+      stringify!(max_life)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).max_renewable_life) as usize - ptr as usize },
+    76usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_kadm5_policy_ent_t),
+      "::",
+      stringify!(max_renewable_life)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).allowed_keysalts) as usize - ptr as usize },
+    80usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_kadm5_policy_ent_t),
+      "::",
+      stringify!(allowed_keysalts)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).n_tl_data) as usize - ptr as usize },
+    88usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_kadm5_policy_ent_t),
+      "::",
+      stringify!(n_tl_data)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).tl_data) as usize - ptr as usize },
+    96usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_kadm5_policy_ent_t),
+      "::",
+      stringify!(tl_data)
+    )
+  );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _kadm5_principal_ent_t {
+  pub principal: krb5_principal,
+  pub princ_expire_time: krb5_timestamp,
+  pub last_pwd_change: krb5_timestamp,
+  pub pw_expiration: krb5_timestamp,
+  pub max_life: krb5_deltat,
+  pub mod_name: krb5_principal,
+  pub mod_date: krb5_timestamp,
+  pub attributes: krb5_flags,
+  pub kvno: krb5_kvno,
+  pub mkvno: krb5_kvno,
+  pub policy: *mut ::std::os::raw::c_char,
+  pub aux_attributes: ::std::os::raw::c_long,
+  pub max_renewable_life: krb5_deltat,
+  pub last_success: krb5_timestamp,
+  pub last_failed: krb5_timestamp,
+  pub fail_auth_count: krb5_kvno,
+  pub n_key_data: krb5_int16,
+  pub n_tl_data: krb5_int16,
+  pub tl_data: *mut krb5_tl_data,
+  pub key_data: *mut krb5_key_data,
+}
+#[test]
+fn bindgen_test_layout__kadm5_principal_ent_t() {
+  const UNINIT: ::std::mem::MaybeUninit<_kadm5_principal_ent_t> = ::std::mem::MaybeUninit::uninit();
+  let ptr = UNINIT.as_ptr();
+  assert_eq!(
+    ::std::mem::size_of::<_kadm5_principal_ent_t>(),
+    104usize,
+    concat!("Size of: ", stringify!(_kadm5_principal_ent_t))
+  );
+  assert_eq!(
+    ::std::mem::align_of::<_kadm5_principal_ent_t>(),
+    8usize,
+    concat!("Alignment of ", stringify!(_kadm5_principal_ent_t))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).principal) as usize - ptr as usize },
+    0usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_kadm5_principal_ent_t),
+      "::",
+      stringify!(principal)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).princ_expire_time) as usize - ptr as usize },
+    8usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_kadm5_principal_ent_t),
+      "::",
+      stringify!(princ_expire_time)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).last_pwd_change) as usize - ptr as usize },
+    12usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_kadm5_principal_ent_t),
+      "::",
+      stringify!(last_pwd_change)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).pw_expiration) as usize - ptr as usize },
+    16usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_kadm5_principal_ent_t),
+      "::",
+      stringify!(pw_expiration)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).max_life) as usize - ptr as usize },
+    20usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_kadm5_principal_ent_t),
+      "::",
+      stringify!(max_life)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).mod_name) as usize - ptr as usize },
+    24usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_kadm5_principal_ent_t),
+      "::",
+      stringify!(mod_name)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).mod_date) as usize - ptr as usize },
+    32usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_kadm5_principal_ent_t),
+      "::",
+      stringify!(mod_date)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).attributes) as usize - ptr as usize },
+    36usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_kadm5_principal_ent_t),
+      "::",
+      stringify!(attributes)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).kvno) as usize - ptr as usize },
+    40usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_kadm5_principal_ent_t),
+      "::",
+      stringify!(kvno)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).mkvno) as usize - ptr as usize },
+    44usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_kadm5_principal_ent_t),
+      "::",
+      stringify!(mkvno)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).policy) as usize - ptr as usize },
+    48usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_kadm5_principal_ent_t),
+      "::",
+      stringify!(policy)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).aux_attributes) as usize - ptr as usize },
+    56usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_kadm5_principal_ent_t),
+      "::",
+      stringify!(aux_attributes)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).max_renewable_life) as usize - ptr as usize },
+    64usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_kadm5_principal_ent_t),
+      "::",
+      stringify!(max_renewable_life)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).last_success) as usize - ptr as usize },
+    68usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_kadm5_principal_ent_t),
+      "::",
+      stringify!(last_success)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).last_failed) as usize - ptr as usize },
+    72usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_kadm5_principal_ent_t),
+      "::",
+      stringify!(last_failed)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).fail_auth_count) as usize - ptr as usize },
+    76usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_kadm5_principal_ent_t),
+      "::",
+      stringify!(fail_auth_count)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).n_key_data) as usize - ptr as usize },
+    80usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_kadm5_principal_ent_t),
+      "::",
+      stringify!(n_key_data)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).n_tl_data) as usize - ptr as usize },
+    82usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_kadm5_principal_ent_t),
+      "::",
+      stringify!(n_tl_data)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).tl_data) as usize - ptr as usize },
+    88usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_kadm5_principal_ent_t),
+      "::",
+      stringify!(tl_data)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).key_data) as usize - ptr as usize },
+    96usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_kadm5_principal_ent_t),
+      "::",
+      stringify!(key_data)
+    )
+  );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _krb5_key_data {
+  pub key_data_ver: krb5_int16,
+  pub key_data_kvno: krb5_ui_2,
+  pub key_data_type: [krb5_int16; 2usize],
+  pub key_data_length: [krb5_ui_2; 2usize],
+  pub key_data_contents: [*mut krb5_octet; 2usize],
+}
+#[test]
+fn bindgen_test_layout__krb5_key_data() {
+  const UNINIT: ::std::mem::MaybeUninit<_krb5_key_data> = ::std::mem::MaybeUninit::uninit();
+  let ptr = UNINIT.as_ptr();
+  assert_eq!(
+    ::std::mem::size_of::<_krb5_key_data>(),
+    32usize,
+    concat!("Size of: ", stringify!(_krb5_key_data))
+  );
+  assert_eq!(
+    ::std::mem::align_of::<_krb5_key_data>(),
+    8usize,
+    concat!("Alignment of ", stringify!(_krb5_key_data))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).key_data_ver) as usize - ptr as usize },
+    0usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_key_data),
+      "::",
+      stringify!(key_data_ver)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).key_data_kvno) as usize - ptr as usize },
+    2usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_key_data),
+      "::",
+      stringify!(key_data_kvno)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).key_data_type) as usize - ptr as usize },
+    4usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_key_data),
+      "::",
+      stringify!(key_data_type)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).key_data_length) as usize - ptr as usize },
+    8usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_key_data),
+      "::",
+      stringify!(key_data_length)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).key_data_contents) as usize - ptr as usize },
+    16usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_key_data),
+      "::",
+      stringify!(key_data_contents)
+    )
+  );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _krb5_keysalt {
+  pub type_: krb5_int16,
+  pub data: krb5_data,
+}
+#[test]
+fn bindgen_test_layout__krb5_keysalt() {
+  const UNINIT: ::std::mem::MaybeUninit<_krb5_keysalt> = ::std::mem::MaybeUninit::uninit();
+  let ptr = UNINIT.as_ptr();
+  assert_eq!(
+    ::std::mem::size_of::<_krb5_keysalt>(),
+    24usize,
+    concat!("Size of: ", stringify!(_krb5_keysalt))
+  );
+  assert_eq!(
+    ::std::mem::align_of::<_krb5_keysalt>(),
+    8usize,
+    concat!("Alignment of ", stringify!(_krb5_keysalt))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).type_) as usize - ptr as usize },
+    0usize,
+    concat!("Offset of field: ", stringify!(_krb5_keysalt), "::", stringify!(type_))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).data) as usize - ptr as usize },
+    8usize,
+    concat!("Offset of field: ", stringify!(_krb5_keysalt), "::", stringify!(data))
+  );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _krb5_tl_data {
+  pub tl_data_next: *mut _krb5_tl_data,
+  pub tl_data_type: krb5_int16,
+  pub tl_data_length: krb5_ui_2,
+  pub tl_data_contents: *mut krb5_octet,
+}
+#[test]
+fn bindgen_test_layout__krb5_tl_data() {
+  const UNINIT: ::std::mem::MaybeUninit<_krb5_tl_data> = ::std::mem::MaybeUninit::uninit();
+  let ptr = UNINIT.as_ptr();
+  assert_eq!(
+    ::std::mem::size_of::<_krb5_tl_data>(),
+    24usize,
+    concat!("Size of: ", stringify!(_krb5_tl_data))
+  );
+  assert_eq!(
+    ::std::mem::align_of::<_krb5_tl_data>(),
+    8usize,
+    concat!("Alignment of ", stringify!(_krb5_tl_data))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).tl_data_next) as usize - ptr as usize },
+    0usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_tl_data),
+      "::",
+      stringify!(tl_data_next)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).tl_data_type) as usize - ptr as usize },
+    8usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_tl_data),
+      "::",
+      stringify!(tl_data_type)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).tl_data_length) as usize - ptr as usize },
+    10usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_tl_data),
+      "::",
+      stringify!(tl_data_length)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).tl_data_contents) as usize - ptr as usize },
+    16usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_tl_data),
+      "::",
+      stringify!(tl_data_contents)
+    )
+  );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct krb5_string_attr_st {
+  pub key: *mut ::std::os::raw::c_char,
+  pub value: *mut ::std::os::raw::c_char,
+}
+#[test]
+fn bindgen_test_layout_krb5_string_attr_st() {
+  const UNINIT: ::std::mem::MaybeUninit<krb5_string_attr_st> = ::std::mem::MaybeUninit::uninit();
+  let ptr = UNINIT.as_ptr();
+  assert_eq!(
+    ::std::mem::size_of::<krb5_string_attr_st>(),
+    16usize,
+    concat!("Size of: ", stringify!(krb5_string_attr_st))
+  );
+  assert_eq!(
+    ::std::mem::align_of::<krb5_string_attr_st>(),
+    8usize,
+    concat!("Alignment of ", stringify!(krb5_string_attr_st))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).key) as usize - ptr as usize },
+    0usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(krb5_string_attr_st),
+      "::",
+      stringify!(key)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).value) as usize - ptr as usize },
+    8usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(krb5_string_attr_st),
+      "::",
+      stringify!(value)
+    )
+  );
+}
+pub type kadm5_config_params = _kadm5_config_params;
+pub type kadm5_key_data = _kadm5_key_data;
+pub type kadm5_policy_ent_rec = _kadm5_policy_ent_t;
+pub type kadm5_policy_ent_t = *mut _kadm5_policy_ent_t;
+pub type kadm5_policy_t = *mut ::std::os::raw::c_char;
+pub type kadm5_princ_t = krb5_principal;
+pub type kadm5_principal_ent_rec = _kadm5_principal_ent_t;
+pub type kadm5_principal_ent_t = *mut _kadm5_principal_ent_t;
+pub type kadm5_ret_t = ::std::os::raw::c_long;
+pub type krb5_key_data = _krb5_key_data;
+pub type krb5_key_salt_tuple = __krb5_key_salt_tuple;
+pub type krb5_keysalt = _krb5_keysalt;
+pub type krb5_string_attr = krb5_string_attr_st;
+pub type krb5_tl_data = _krb5_tl_data;
+extern "C" {
+  pub fn kadm5_get_config_params(
+    context: krb5_context,
+    use_kdc_config: ::std::os::raw::c_int,
+    params_in: *mut kadm5_config_params,
+    params_out: *mut kadm5_config_params,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn kadm5_free_config_params(context: krb5_context, params: *mut kadm5_config_params) -> krb5_error_code;
+}
+extern "C" {
+  pub fn kadm5_get_admin_service_name(
+    arg1: krb5_context,
+    arg2: *mut ::std::os::raw::c_char,
+    arg3: *mut ::std::os::raw::c_char,
+    arg4: size_t,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn kadm5_init(
+    context: krb5_context,
+    client_name: *mut ::std::os::raw::c_char,
+    pass: *mut ::std::os::raw::c_char,
+    service_name: *mut ::std::os::raw::c_char,
+    params: *mut kadm5_config_params,
+    struct_version: krb5_ui_4,
+    api_version: krb5_ui_4,
+    db_args: *mut *mut ::std::os::raw::c_char,
+    server_handle: *mut *mut ::std::os::raw::c_void,
+  ) -> kadm5_ret_t;
+}
+extern "C" {
+  pub fn kadm5_init_anonymous(
+    context: krb5_context,
+    client_name: *mut ::std::os::raw::c_char,
+    service_name: *mut ::std::os::raw::c_char,
+    params: *mut kadm5_config_params,
+    struct_version: krb5_ui_4,
+    api_version: krb5_ui_4,
+    db_args: *mut *mut ::std::os::raw::c_char,
+    server_handle: *mut *mut ::std::os::raw::c_void,
+  ) -> kadm5_ret_t;
+}
+extern "C" {
+  pub fn kadm5_init_with_password(
+    context: krb5_context,
+    client_name: *mut ::std::os::raw::c_char,
+    pass: *mut ::std::os::raw::c_char,
+    service_name: *mut ::std::os::raw::c_char,
+    params: *mut kadm5_config_params,
+    struct_version: krb5_ui_4,
+    api_version: krb5_ui_4,
+    db_args: *mut *mut ::std::os::raw::c_char,
+    server_handle: *mut *mut ::std::os::raw::c_void,
+  ) -> kadm5_ret_t;
+}
+extern "C" {
+  pub fn kadm5_init_with_skey(
+    context: krb5_context,
+    client_name: *mut ::std::os::raw::c_char,
+    keytab: *mut ::std::os::raw::c_char,
+    service_name: *mut ::std::os::raw::c_char,
+    params: *mut kadm5_config_params,
+    struct_version: krb5_ui_4,
+    api_version: krb5_ui_4,
+    db_args: *mut *mut ::std::os::raw::c_char,
+    server_handle: *mut *mut ::std::os::raw::c_void,
+  ) -> kadm5_ret_t;
+}
+extern "C" {
+  pub fn kadm5_init_with_creds(
+    context: krb5_context,
+    client_name: *mut ::std::os::raw::c_char,
+    cc: krb5_ccache,
+    service_name: *mut ::std::os::raw::c_char,
+    params: *mut kadm5_config_params,
+    struct_version: krb5_ui_4,
+    api_version: krb5_ui_4,
+    db_args: *mut *mut ::std::os::raw::c_char,
+    server_handle: *mut *mut ::std::os::raw::c_void,
+  ) -> kadm5_ret_t;
+}
+extern "C" {
+  pub fn kadm5_lock(server_handle: *mut ::std::os::raw::c_void) -> kadm5_ret_t;
+}
+extern "C" {
+  pub fn kadm5_unlock(server_handle: *mut ::std::os::raw::c_void) -> kadm5_ret_t;
+}
+extern "C" {
+  pub fn kadm5_flush(server_handle: *mut ::std::os::raw::c_void) -> kadm5_ret_t;
+}
+extern "C" {
+  pub fn kadm5_destroy(server_handle: *mut ::std::os::raw::c_void) -> kadm5_ret_t;
+}
+extern "C" {
+  pub fn kadm5_create_principal(
+    server_handle: *mut ::std::os::raw::c_void,
+    ent: kadm5_principal_ent_t,
+    mask: ::std::os::raw::c_long,
+    pass: *mut ::std::os::raw::c_char,
+  ) -> kadm5_ret_t;
+}
+extern "C" {
+  pub fn kadm5_create_principal_3(
+    server_handle: *mut ::std::os::raw::c_void,
+    ent: kadm5_principal_ent_t,
+    mask: ::std::os::raw::c_long,
+    n_ks_tuple: ::std::os::raw::c_int,
+    ks_tuple: *mut krb5_key_salt_tuple,
+    pass: *mut ::std::os::raw::c_char,
+  ) -> kadm5_ret_t;
+}
+extern "C" {
+  pub fn kadm5_delete_principal(server_handle: *mut ::std::os::raw::c_void, principal: krb5_principal) -> kadm5_ret_t;
+}
+extern "C" {
+  pub fn kadm5_modify_principal(
+    server_handle: *mut ::std::os::raw::c_void,
+    ent: kadm5_principal_ent_t,
+    mask: ::std::os::raw::c_long,
+  ) -> kadm5_ret_t;
+}
+extern "C" {
+  pub fn kadm5_rename_principal(
+    server_handle: *mut ::std::os::raw::c_void,
+    arg2: krb5_principal,
+    arg3: krb5_principal,
+  ) -> kadm5_ret_t;
+}
+extern "C" {
+  pub fn kadm5_get_principal(
+    server_handle: *mut ::std::os::raw::c_void,
+    principal: krb5_principal,
+    ent: kadm5_principal_ent_t,
+    mask: ::std::os::raw::c_long,
+  ) -> kadm5_ret_t;
+}
+extern "C" {
+  pub fn kadm5_chpass_principal(
+    server_handle: *mut ::std::os::raw::c_void,
+    principal: krb5_principal,
+    pass: *mut ::std::os::raw::c_char,
+  ) -> kadm5_ret_t;
+}
+extern "C" {
+  pub fn kadm5_chpass_principal_3(
+    server_handle: *mut ::std::os::raw::c_void,
+    principal: krb5_principal,
+    keepold: krb5_boolean,
+    n_ks_tuple: ::std::os::raw::c_int,
+    ks_tuple: *mut krb5_key_salt_tuple,
+    pass: *mut ::std::os::raw::c_char,
+  ) -> kadm5_ret_t;
+}
+extern "C" {
+  pub fn kadm5_randkey_principal(
+    server_handle: *mut ::std::os::raw::c_void,
+    principal: krb5_principal,
+    keyblocks: *mut *mut krb5_keyblock,
+    n_keys: *mut ::std::os::raw::c_int,
+  ) -> kadm5_ret_t;
+}
+extern "C" {
+  pub fn kadm5_randkey_principal_3(
+    server_handle: *mut ::std::os::raw::c_void,
+    principal: krb5_principal,
+    keepold: krb5_boolean,
+    n_ks_tuple: ::std::os::raw::c_int,
+    ks_tuple: *mut krb5_key_salt_tuple,
+    keyblocks: *mut *mut krb5_keyblock,
+    n_keys: *mut ::std::os::raw::c_int,
+  ) -> kadm5_ret_t;
+}
+extern "C" {
+  pub fn kadm5_setkey_principal(
+    server_handle: *mut ::std::os::raw::c_void,
+    principal: krb5_principal,
+    keyblocks: *mut krb5_keyblock,
+    n_keys: ::std::os::raw::c_int,
+  ) -> kadm5_ret_t;
+}
+extern "C" {
+  pub fn kadm5_setkey_principal_3(
+    server_handle: *mut ::std::os::raw::c_void,
+    principal: krb5_principal,
+    keepold: krb5_boolean,
+    n_ks_tuple: ::std::os::raw::c_int,
+    ks_tuple: *mut krb5_key_salt_tuple,
+    keyblocks: *mut krb5_keyblock,
+    n_keys: ::std::os::raw::c_int,
+  ) -> kadm5_ret_t;
+}
+extern "C" {
+  pub fn kadm5_setkey_principal_4(
+    server_handle: *mut ::std::os::raw::c_void,
+    principal: krb5_principal,
+    keepold: krb5_boolean,
+    key_data: *mut kadm5_key_data,
+    n_key_data: ::std::os::raw::c_int,
+  ) -> kadm5_ret_t;
+}
+extern "C" {
+  pub fn kadm5_decrypt_key(
+    server_handle: *mut ::std::os::raw::c_void,
+    entry: kadm5_principal_ent_t,
+    ktype: krb5_int32,
+    stype: krb5_int32,
+    kvno: krb5_int32,
+    keyblock: *mut krb5_keyblock,
+    keysalt: *mut krb5_keysalt,
+    kvnop: *mut ::std::os::raw::c_int,
+  ) -> kadm5_ret_t;
+}
+extern "C" {
+  pub fn kadm5_create_policy(
+    server_handle: *mut ::std::os::raw::c_void,
+    ent: kadm5_policy_ent_t,
+    mask: ::std::os::raw::c_long,
+  ) -> kadm5_ret_t;
+}
+extern "C" {
+  pub fn kadm5_delete_policy(server_handle: *mut ::std::os::raw::c_void, policy: kadm5_policy_t) -> kadm5_ret_t;
+}
+extern "C" {
+  pub fn kadm5_modify_policy(
+    server_handle: *mut ::std::os::raw::c_void,
+    ent: kadm5_policy_ent_t,
+    mask: ::std::os::raw::c_long,
+  ) -> kadm5_ret_t;
+}
+extern "C" {
+  pub fn kadm5_get_policy(
+    server_handle: *mut ::std::os::raw::c_void,
+    policy: kadm5_policy_t,
+    ent: kadm5_policy_ent_t,
+  ) -> kadm5_ret_t;
+}
+extern "C" {
+  pub fn kadm5_get_privs(server_handle: *mut ::std::os::raw::c_void, privs: *mut ::std::os::raw::c_long)
+    -> kadm5_ret_t;
+}
+extern "C" {
+  pub fn kadm5_chpass_principal_util(
+    server_handle: *mut ::std::os::raw::c_void,
+    princ: krb5_principal,
+    new_pw: *mut ::std::os::raw::c_char,
+    ret_pw: *mut *mut ::std::os::raw::c_char,
+    msg_ret: *mut ::std::os::raw::c_char,
+    msg_len: ::std::os::raw::c_uint,
+  ) -> kadm5_ret_t;
+}
+extern "C" {
+  pub fn kadm5_free_principal_ent(
+    server_handle: *mut ::std::os::raw::c_void,
+    ent: kadm5_principal_ent_t,
+  ) -> kadm5_ret_t;
+}
+extern "C" {
+  pub fn kadm5_free_policy_ent(server_handle: *mut ::std::os::raw::c_void, ent: kadm5_policy_ent_t) -> kadm5_ret_t;
+}
+extern "C" {
+  pub fn kadm5_get_principals(
+    server_handle: *mut ::std::os::raw::c_void,
+    exp: *mut ::std::os::raw::c_char,
+    princs: *mut *mut *mut ::std::os::raw::c_char,
+    count: *mut ::std::os::raw::c_int,
+  ) -> kadm5_ret_t;
+}
+extern "C" {
+  pub fn kadm5_get_policies(
+    server_handle: *mut ::std::os::raw::c_void,
+    exp: *mut ::std::os::raw::c_char,
+    pols: *mut *mut *mut ::std::os::raw::c_char,
+    count: *mut ::std::os::raw::c_int,
+  ) -> kadm5_ret_t;
+}
+extern "C" {
+  pub fn kadm5_free_key_data(
+    server_handle: *mut ::std::os::raw::c_void,
+    n_key_data: *mut krb5_int16,
+    key_data: *mut krb5_key_data,
+  ) -> kadm5_ret_t;
+}
+extern "C" {
+  pub fn kadm5_free_name_list(
+    server_handle: *mut ::std::os::raw::c_void,
+    names: *mut *mut ::std::os::raw::c_char,
+    count: ::std::os::raw::c_int,
+  ) -> kadm5_ret_t;
+}
+extern "C" {
+  pub fn kadm5_init_krb5_context(arg1: *mut krb5_context) -> krb5_error_code;
+}
+extern "C" {
+  pub fn kadm5_init_iprop(
+    server_handle: *mut ::std::os::raw::c_void,
+    db_args: *mut *mut ::std::os::raw::c_char,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn kadm5_get_principal_keys(
+    server_handle: *mut ::std::os::raw::c_void,
+    principal: krb5_principal,
+    kvno: krb5_kvno,
+    key_data: *mut *mut kadm5_key_data,
+    n_key_data: *mut ::std::os::raw::c_int,
+  ) -> kadm5_ret_t;
+}
+extern "C" {
+  pub fn kadm5_purgekeys(
+    server_handle: *mut ::std::os::raw::c_void,
+    principal: krb5_principal,
+    keepkvno: ::std::os::raw::c_int,
+  ) -> kadm5_ret_t;
+}
+extern "C" {
+  pub fn kadm5_get_strings(
+    server_handle: *mut ::std::os::raw::c_void,
+    principal: krb5_principal,
+    strings_out: *mut *mut krb5_string_attr,
+    count_out: *mut ::std::os::raw::c_int,
+  ) -> kadm5_ret_t;
+}
+extern "C" {
+  pub fn kadm5_set_string(
+    server_handle: *mut ::std::os::raw::c_void,
+    principal: krb5_principal,
+    key: *const ::std::os::raw::c_char,
+    value: *const ::std::os::raw::c_char,
+  ) -> kadm5_ret_t;
+}
+extern "C" {
+  pub fn kadm5_free_strings(
+    server_handle: *mut ::std::os::raw::c_void,
+    strings: *mut krb5_string_attr,
+    count: ::std::os::raw::c_int,
+  ) -> kadm5_ret_t;
+}
+extern "C" {
+  pub fn kadm5_free_kadm5_key_data(
+    context: krb5_context,
+    n_key_data: ::std::os::raw::c_int,
+    key_data: *mut kadm5_key_data,
+  ) -> kadm5_ret_t;
+}
@@ -0,0 +1,6756 @@
+/*
+ * Pre-generated bindings for MIT krb5 1.19.2 (the headers of krb5-src 0.3.4) on x86_64 Linux,
+ * from src/wrapper.h with the allowlist of build.rs.
+ *
+ * Regenerate with `cargo build -p libkrb5-sys --target x86_64-unknown-linux-gnu --features
+ * vendored,buildtime-bindgen` and copy bindings.rs from the OUT_DIR.
+ */
+
+pub const ADDRTYPE_ADDRPORT: u32 = 256;
+pub const ADDRTYPE_CHAOS: u32 = 5;
+pub const ADDRTYPE_DDP: u32 = 16;
+pub const ADDRTYPE_INET: u32 = 2;
+pub const ADDRTYPE_INET6: u32 = 24;
+pub const ADDRTYPE_IPPORT: u32 = 257;
+pub const ADDRTYPE_ISO: u32 = 7;
+pub const ADDRTYPE_NETBIOS: u32 = 20;
+pub const ADDRTYPE_XNS: u32 = 6;
+pub const AD_TYPE_EXTERNAL: u32 = 16384;
+pub const AD_TYPE_FIELD_TYPE_MASK: u32 = 8191;
+pub const AD_TYPE_REGISTERED: u32 = 8192;
+pub const AD_TYPE_RESERVED: u32 = 32768;
+pub const AP_OPTS_ETYPE_NEGOTIATION: u32 = 2;
+pub const AP_OPTS_MUTUAL_REQUIRED: u32 = 536870912;
+pub const AP_OPTS_RESERVED: u32 = 2147483648;
+pub const AP_OPTS_USE_SESSION_KEY: u32 = 1073741824;
+pub const AP_OPTS_USE_SUBKEY: u32 = 1;
+pub const AP_OPTS_WIRE_MASK: u32 = 4294967280;
+pub const CKSUMTYPE_CMAC_CAMELLIA128: u32 = 17;
+pub const CKSUMTYPE_CMAC_CAMELLIA256: u32 = 18;
+pub const CKSUMTYPE_CRC32: u32 = 1;
+pub const CKSUMTYPE_DESCBC: u32 = 4;
+pub const CKSUMTYPE_HMAC_MD5_ARCFOUR: i32 = -138;
+pub const CKSUMTYPE_HMAC_SHA1_96_AES128: u32 = 15;
+pub const CKSUMTYPE_HMAC_SHA1_96_AES256: u32 = 16;
+pub const CKSUMTYPE_HMAC_SHA1_DES3: u32 = 12;
+pub const CKSUMTYPE_HMAC_SHA256_128_AES128: u32 = 19;
+pub const CKSUMTYPE_HMAC_SHA384_192_AES256: u32 = 20;
+pub const CKSUMTYPE_MD5_HMAC_ARCFOUR: i32 = -137;
+pub const CKSUMTYPE_NIST_SHA: u32 = 9;
+pub const CKSUMTYPE_RSA_MD4: u32 = 2;
+pub const CKSUMTYPE_RSA_MD4_DES: u32 = 3;
+pub const CKSUMTYPE_RSA_MD5: u32 = 7;
+pub const CKSUMTYPE_RSA_MD5_DES: u32 = 8;
+pub const ENCTYPE_AES128_CTS_HMAC_SHA1_96: u32 = 17;
+pub const ENCTYPE_AES128_CTS_HMAC_SHA256_128: u32 = 19;
+pub const ENCTYPE_AES256_CTS_HMAC_SHA1_96: u32 = 18;
+pub const ENCTYPE_AES256_CTS_HMAC_SHA384_192: u32 = 20;
+pub const ENCTYPE_ARCFOUR_HMAC: u32 = 23;
+pub const ENCTYPE_ARCFOUR_HMAC_EXP: u32 = 24;
+pub const ENCTYPE_CAMELLIA128_CTS_CMAC: u32 = 25;
+pub const ENCTYPE_CAMELLIA256_CTS_CMAC: u32 = 26;
+pub const ENCTYPE_DES3_CBC_ENV: u32 = 15;
+pub const ENCTYPE_DES3_CBC_RAW: u32 = 6;
+pub const ENCTYPE_DES3_CBC_SHA: u32 = 5;
+pub const ENCTYPE_DES3_CBC_SHA1: u32 = 16;
+pub const ENCTYPE_DES_CBC_CRC: u32 = 1;
+pub const ENCTYPE_DES_CBC_MD4: u32 = 2;
+pub const ENCTYPE_DES_CBC_MD5: u32 = 3;
+pub const ENCTYPE_DES_CBC_RAW: u32 = 4;
+pub const ENCTYPE_DES_HMAC_SHA1: u32 = 8;
+pub const ENCTYPE_DSA_SHA1_CMS: u32 = 9;
+pub const ENCTYPE_MD5_RSA_CMS: u32 = 10;
+pub const ENCTYPE_NULL: u32 = 0;
+pub const ENCTYPE_RC2_CBC_ENV: u32 = 12;
+pub const ENCTYPE_RSA_ENV: u32 = 13;
+pub const ENCTYPE_RSA_ES_OAEP_ENV: u32 = 14;
+pub const ENCTYPE_SHA1_RSA_CMS: u32 = 11;
+pub const ENCTYPE_UNKNOWN: u32 = 511;
+pub const KDC_OPT_ALLOW_POSTDATE: u32 = 67108864;
+pub const KDC_OPT_CANONICALIZE: u32 = 65536;
+pub const KDC_OPT_CNAME_IN_ADDL_TKT: u32 = 131072;
+pub const KDC_OPT_DISABLE_TRANSITED_CHECK: u32 = 32;
+pub const KDC_OPT_ENC_TKT_IN_SKEY: u32 = 8;
+pub const KDC_OPT_FORWARDABLE: u32 = 1073741824;
+pub const KDC_OPT_FORWARDED: u32 = 536870912;
+pub const KDC_OPT_POSTDATED: u32 = 33554432;
+pub const KDC_OPT_PROXIABLE: u32 = 268435456;
+pub const KDC_OPT_PROXY: u32 = 134217728;
+pub const KDC_OPT_RENEW: u32 = 2;
+pub const KDC_OPT_RENEWABLE: u32 = 8388608;
+pub const KDC_OPT_RENEWABLE_OK: u32 = 16;
+pub const KDC_OPT_REQUEST_ANONYMOUS: u32 = 32768;
+pub const KDC_OPT_VALIDATE: u32 = 1;
+pub const KRB524_BADADDR: i32 = -1750206207;
+pub const KRB524_BADKEY: i32 = -1750206208;
+pub const KRB524_BADPRINC: i32 = -1750206206;
+pub const KRB524_BADREALM: i32 = -1750206205;
+pub const KRB524_DECEMPTY: i32 = -1750206202;
+pub const KRB524_ENCFULL: i32 = -1750206203;
+pub const KRB524_KRB4_DISABLED: i32 = -1750206200;
+pub const KRB524_NOTRESP: i32 = -1750206201;
+pub const KRB524_V4ERR: i32 = -1750206204;
+pub const KRB5DES_BAD_KEYPAR: i32 = -1765328198;
+pub const KRB5DES_WEAK_KEY: i32 = -1765328197;
+pub const KRB5KDC_ERR_BADOPTION: i32 = -1765328371;
+pub const KRB5KDC_ERR_BAD_PVNO: i32 = -1765328381;
+pub const KRB5KDC_ERR_CANNOT_POSTDATE: i32 = -1765328374;
+pub const KRB5KDC_ERR_CANT_VERIFY_CERTIFICATE: i32 = -1765328314;
+pub const KRB5KDC_ERR_CERTIFICATE_MISMATCH: i32 = -1765328318;
+pub const KRB5KDC_ERR_CLIENT_NAME_MISMATCH: i32 = -1765328309;
+pub const KRB5KDC_ERR_CLIENT_NOTYET: i32 = -1765328363;
+pub const KRB5KDC_ERR_CLIENT_NOT_TRUSTED: i32 = -1765328322;
+pub const KRB5KDC_ERR_CLIENT_REVOKED: i32 = -1765328366;
+pub const KRB5KDC_ERR_C_OLD_MAST_KVNO: i32 = -1765328380;
+pub const KRB5KDC_ERR_C_PRINCIPAL_UNKNOWN: i32 = -1765328378;
+pub const KRB5KDC_ERR_DH_KEY_PARAMETERS_NOT_ACCEPTED: i32 = -1765328319;
+pub const KRB5KDC_ERR_DIGEST_IN_CERT_NOT_ACCEPTED: i32 = -1765328306;
+pub const KRB5KDC_ERR_DIGEST_IN_SIGNED_DATA_NOT_ACCEPTED: i32 = -1765328304;
+pub const KRB5KDC_ERR_DISCARD: i32 = -1750600189;
+pub const KRB5KDC_ERR_ETYPE_NOSUPP: i32 = -1765328370;
+pub const KRB5KDC_ERR_INCONSISTENT_KEY_PURPOSE: i32 = -1765328307;
+pub const KRB5KDC_ERR_INVALID_CERTIFICATE: i32 = -1765328313;
+pub const KRB5KDC_ERR_INVALID_SIG: i32 = -1765328320;
+pub const KRB5KDC_ERR_KDC_NAME_MISMATCH: i32 = -1765328308;
+pub const KRB5KDC_ERR_KDC_NOT_TRUSTED: i32 = -1765328321;
+pub const KRB5KDC_ERR_KEY_EXP: i32 = -1765328361;
+pub const KRB5KDC_ERR_MORE_PREAUTH_DATA_REQUIRED: i32 = -1765328293;
+pub const KRB5KDC_ERR_MUST_USE_USER2USER: i32 = -1765328357;
+pub const KRB5KDC_ERR_NAME_EXP: i32 = -1765328383;
+pub const KRB5KDC_ERR_NEVER_VALID: i32 = -1765328373;
+pub const KRB5KDC_ERR_NONE: i32 = -1765328384;
+pub const KRB5KDC_ERR_NO_ACCEPTABLE_KDF: i32 = -1765328284;
+pub const KRB5KDC_ERR_NULL_KEY: i32 = -1765328375;
+pub const KRB5KDC_ERR_PADATA_TYPE_NOSUPP: i32 = -1765328368;
+pub const KRB5KDC_ERR_PATH_NOT_ACCEPTED: i32 = -1765328356;
+pub const KRB5KDC_ERR_PA_CHECKSUM_MUST_BE_INCLUDED: i32 = -1765328305;
+pub const KRB5KDC_ERR_POLICY: i32 = -1765328372;
+pub const KRB5KDC_ERR_PREAUTH_EXPIRED: i32 = -1765328294;
+pub const KRB5KDC_ERR_PREAUTH_FAILED: i32 = -1765328360;
+pub const KRB5KDC_ERR_PREAUTH_REQUIRED: i32 = -1765328359;
+pub const KRB5KDC_ERR_PRINCIPAL_NOT_UNIQUE: i32 = -1765328376;
+pub const KRB5KDC_ERR_PUBLIC_KEY_ENCRYPTION_NOT_SUPPORTED: i32 = -1765328303;
+pub const KRB5KDC_ERR_REVOCATION_STATUS_UNAVAILABLE: i32 = -1765328310;
+pub const KRB5KDC_ERR_REVOCATION_STATUS_UNKNOWN: i32 = -1765328311;
+pub const KRB5KDC_ERR_REVOKED_CERTIFICATE: i32 = -1765328312;
+pub const KRB5KDC_ERR_SERVER_NOMATCH: i32 = -1765328358;
+pub const KRB5KDC_ERR_SERVICE_EXP: i32 = -1765328382;
+pub const KRB5KDC_ERR_SERVICE_NOTYET: i32 = -1765328362;
+pub const KRB5KDC_ERR_SERVICE_REVOKED: i32 = -1765328365;
+pub const KRB5KDC_ERR_SUMTYPE_NOSUPP: i32 = -1765328369;
+pub const KRB5KDC_ERR_SVC_UNAVAILABLE: i32 = -1765328355;
+pub const KRB5KDC_ERR_S_OLD_MAST_KVNO: i32 = -1765328379;
+pub const KRB5KDC_ERR_S_PRINCIPAL_UNKNOWN: i32 = -1765328377;
+pub const KRB5KDC_ERR_TGT_REVOKED: i32 = -1765328364;
+pub const KRB5KDC_ERR_TRTYPE_NOSUPP: i32 = -1765328367;
+pub const KRB5KDC_ERR_UNKNOWN_CRITICAL_FAST_OPTION: i32 = -1765328291;
+pub const KRB5KDC_ERR_WRONG_REALM: i32 = -1765328316;
+pub const KRB5KRB_AP_ERR_BADADDR: i32 = -1765328346;
+pub const KRB5KRB_AP_ERR_BADDIRECTION: i32 = -1765328337;
+pub const KRB5KRB_AP_ERR_BADKEYVER: i32 = -1765328340;
+pub const KRB5KRB_AP_ERR_BADMATCH: i32 = -1765328348;
+pub const KRB5KRB_AP_ERR_BADORDER: i32 = -1765328342;
+pub const KRB5KRB_AP_ERR_BADSEQ: i32 = -1765328335;
+pub const KRB5KRB_AP_ERR_BADVERSION: i32 = -1765328345;
+pub const KRB5KRB_AP_ERR_BAD_INTEGRITY: i32 = -1765328353;
+pub const KRB5KRB_AP_ERR_IAKERB_KDC_NOT_FOUND: i32 = -1765328299;
+pub const KRB5KRB_AP_ERR_IAKERB_KDC_NO_RESPONSE: i32 = -1765328298;
+pub const KRB5KRB_AP_ERR_ILL_CR_TKT: i32 = -1765328341;
+pub const KRB5KRB_AP_ERR_INAPP_CKSUM: i32 = -1765328334;
+pub const KRB5KRB_AP_ERR_METHOD: i32 = -1765328336;
+pub const KRB5KRB_AP_ERR_MODIFIED: i32 = -1765328343;
+pub const KRB5KRB_AP_ERR_MSG_TYPE: i32 = -1765328344;
+pub const KRB5KRB_AP_ERR_MUT_FAIL: i32 = -1765328338;
+pub const KRB5KRB_AP_ERR_NOKEY: i32 = -1765328339;
+pub const KRB5KRB_AP_ERR_NOT_US: i32 = -1765328349;
+pub const KRB5KRB_AP_ERR_NO_TGT: i32 = -1765328317;
+pub const KRB5KRB_AP_ERR_REPEAT: i32 = -1765328350;
+pub const KRB5KRB_AP_ERR_SKEW: i32 = -1765328347;
+pub const KRB5KRB_AP_ERR_TKT_EXPIRED: i32 = -1765328352;
+pub const KRB5KRB_AP_ERR_TKT_INVALID: i32 = -1765328239;
+pub const KRB5KRB_AP_ERR_TKT_NYV: i32 = -1765328351;
+pub const KRB5KRB_AP_ERR_USER_TO_USER_REQUIRED: i32 = -1765328315;
+pub const KRB5KRB_AP_ERR_V4_REPLY: i32 = -1765328165;
+pub const KRB5KRB_AP_PATH_NOT_ACCEPTED: i32 = -1765328333;
+pub const KRB5KRB_AP_WRONG_PRINC: i32 = -1765328240;
+pub const KRB5KRB_ERR_FIELD_TOOLONG: i32 = -1765328323;
+pub const KRB5KRB_ERR_GENERIC: i32 = -1765328324;
+pub const KRB5KRB_ERR_RESPONSE_TOO_BIG: i32 = -1765328332;
+pub const KRB5PLACEHOLD_101: i32 = -1765328283;
+pub const KRB5PLACEHOLD_102: i32 = -1765328282;
+pub const KRB5PLACEHOLD_103: i32 = -1765328281;
+pub const KRB5PLACEHOLD_104: i32 = -1765328280;
+pub const KRB5PLACEHOLD_105: i32 = -1765328279;
+pub const KRB5PLACEHOLD_106: i32 = -1765328278;
+pub const KRB5PLACEHOLD_107: i32 = -1765328277;
+pub const KRB5PLACEHOLD_108: i32 = -1765328276;
+pub const KRB5PLACEHOLD_109: i32 = -1765328275;
+pub const KRB5PLACEHOLD_110: i32 = -1765328274;
+pub const KRB5PLACEHOLD_111: i32 = -1765328273;
+pub const KRB5PLACEHOLD_112: i32 = -1765328272;
+pub const KRB5PLACEHOLD_113: i32 = -1765328271;
+pub const KRB5PLACEHOLD_114: i32 = -1765328270;
+pub const KRB5PLACEHOLD_115: i32 = -1765328269;
+pub const KRB5PLACEHOLD_116: i32 = -1765328268;
+pub const KRB5PLACEHOLD_117: i32 = -1765328267;
+pub const KRB5PLACEHOLD_118: i32 = -1765328266;
+pub const KRB5PLACEHOLD_119: i32 = -1765328265;
+pub const KRB5PLACEHOLD_120: i32 = -1765328264;
+pub const KRB5PLACEHOLD_121: i32 = -1765328263;
+pub const KRB5PLACEHOLD_122: i32 = -1765328262;
+pub const KRB5PLACEHOLD_123: i32 = -1765328261;
+pub const KRB5PLACEHOLD_124: i32 = -1765328260;
+pub const KRB5PLACEHOLD_125: i32 = -1765328259;
+pub const KRB5PLACEHOLD_126: i32 = -1765328258;
+pub const KRB5PLACEHOLD_127: i32 = -1765328257;
+pub const KRB5PLACEHOLD_30: i32 = -1765328354;
+pub const KRB5PLACEHOLD_53: i32 = -1765328331;
+pub const KRB5PLACEHOLD_54: i32 = -1765328330;
+pub const KRB5PLACEHOLD_55: i32 = -1765328329;
+pub const KRB5PLACEHOLD_56: i32 = -1765328328;
+pub const KRB5PLACEHOLD_57: i32 = -1765328327;
+pub const KRB5PLACEHOLD_58: i32 = -1765328326;
+pub const KRB5PLACEHOLD_59: i32 = -1765328325;
+pub const KRB5PLACEHOLD_82: i32 = -1765328302;
+pub const KRB5PLACEHOLD_83: i32 = -1765328301;
+pub const KRB5PLACEHOLD_84: i32 = -1765328300;
+pub const KRB5PLACEHOLD_87: i32 = -1765328297;
+pub const KRB5PLACEHOLD_88: i32 = -1765328296;
+pub const KRB5PLACEHOLD_89: i32 = -1765328295;
+pub const KRB5PLACEHOLD_92: i32 = -1765328292;
+pub const KRB5PLACEHOLD_94: i32 = -1765328290;
+pub const KRB5PLACEHOLD_95: i32 = -1765328289;
+pub const KRB5PLACEHOLD_96: i32 = -1765328288;
+pub const KRB5PLACEHOLD_97: i32 = -1765328287;
+pub const KRB5PLACEHOLD_98: i32 = -1765328286;
+pub const KRB5PLACEHOLD_99: i32 = -1765328285;
+pub const KRB5_ALTAUTH_ATT_CHALLENGE_RESPONSE: u32 = 64;
+pub const KRB5_ANONYMOUS_PRINCSTR: &'static [u8; 10] = b"ANONYMOUS\0";
+pub const KRB5_ANONYMOUS_REALMSTR: &'static [u8; 20] = b"WELLKNOWN:ANONYMOUS\0";
+pub const KRB5_APPL_EXPIRED: i32 = -1765328153;
+pub const KRB5_AUTHDATA_AND_OR: u32 = 5;
+pub const KRB5_AUTHDATA_AP_OPTIONS: u32 = 143;
+pub const KRB5_AUTHDATA_AUTH_INDICATOR: u32 = 97;
+pub const KRB5_AUTHDATA_CAMMAC: u32 = 96;
+pub const KRB5_AUTHDATA_ETYPE_NEGOTIATION: u32 = 129;
+pub const KRB5_AUTHDATA_FX_ARMOR: u32 = 71;
+pub const KRB5_AUTHDATA_IF_RELEVANT: u32 = 1;
+pub const KRB5_AUTHDATA_INITIAL_VERIFIED_CAS: u32 = 9;
+pub const KRB5_AUTHDATA_KDC_ISSUED: u32 = 4;
+pub const KRB5_AUTHDATA_MANDATORY_FOR_KDC: u32 = 8;
+pub const KRB5_AUTHDATA_OSF_DCE: u32 = 64;
+pub const KRB5_AUTHDATA_SESAME: u32 = 65;
+pub const KRB5_AUTHDATA_SIGNTICKET: u32 = 512;
+pub const KRB5_AUTHDATA_WIN2K_PAC: u32 = 128;
+pub const KRB5_AUTH_CONTEXT_DO_SEQUENCE: u32 = 4;
+pub const KRB5_AUTH_CONTEXT_DO_TIME: u32 = 1;
+pub const KRB5_AUTH_CONTEXT_GENERATE_LOCAL_ADDR: u32 = 1;
+pub const KRB5_AUTH_CONTEXT_GENERATE_LOCAL_FULL_ADDR: u32 = 4;
+pub const KRB5_AUTH_CONTEXT_GENERATE_REMOTE_ADDR: u32 = 2;
+pub const KRB5_AUTH_CONTEXT_GENERATE_REMOTE_FULL_ADDR: u32 = 8;
+pub const KRB5_AUTH_CONTEXT_PERMIT_ALL: u32 = 16;
+pub const KRB5_AUTH_CONTEXT_RET_SEQUENCE: u32 = 8;
+pub const KRB5_AUTH_CONTEXT_RET_TIME: u32 = 2;
+pub const KRB5_AUTH_CONTEXT_USE_SUBKEY: u32 = 32;
+pub const KRB5_BADMSGTYPE: i32 = -1765328246;
+pub const KRB5_BAD_ENCTYPE: i32 = -1765328196;
+pub const KRB5_BAD_KEYSIZE: i32 = -1765328195;
+pub const KRB5_BAD_MSIZE: i32 = -1765328194;
+pub const KRB5_CCACHE_BADVNO: i32 = -1765328172;
+pub const KRB5_CC_BADNAME: i32 = -1765328245;
+pub const KRB5_CC_END: i32 = -1765328242;
+pub const KRB5_CC_FORMAT: i32 = -1765328185;
+pub const KRB5_CC_IO: i32 = -1765328191;
+pub const KRB5_CC_NOMEM: i32 = -1765328186;
+pub const KRB5_CC_NOSUPP: i32 = -1765328137;
+pub const KRB5_CC_NOTFOUND: i32 = -1765328243;
+pub const KRB5_CC_NOT_KTYPE: i32 = -1765328184;
+pub const KRB5_CC_READONLY: i32 = -1765328138;
+pub const KRB5_CC_TYPE_EXISTS: i32 = -1765328193;
+pub const KRB5_CC_UNKNOWN_TYPE: i32 = -1765328244;
+pub const KRB5_CC_WRITE: i32 = -1765328187;
+pub const KRB5_CERTAUTH_HWAUTH: i32 = -1750600180;
+pub const KRB5_CHPW_FAIL: i32 = -1765328150;
+pub const KRB5_CHPW_PWDNULL: i32 = -1765328151;
+pub const KRB5_CONFIG_BADFORMAT: i32 = -1765328248;
+pub const KRB5_CONFIG_CANTOPEN: i32 = -1765328249;
+pub const KRB5_CONFIG_ETYPE_NOSUPP: i32 = -1765328147;
+pub const KRB5_CONFIG_NODEFREALM: i32 = -1765328160;
+pub const KRB5_CONFIG_NOTENUFSPACE: i32 = -1765328247;
+pub const KRB5_CRYPTO_INTERNAL: i32 = -1765328206;
+pub const KRB5_CRYPTO_TYPE_CHECKSUM: u32 = 6;
+pub const KRB5_CRYPTO_TYPE_DATA: u32 = 2;
+pub const KRB5_CRYPTO_TYPE_EMPTY: u32 = 0;
+pub const KRB5_CRYPTO_TYPE_HEADER: u32 = 1;
+pub const KRB5_CRYPTO_TYPE_PADDING: u32 = 4;
+pub const KRB5_CRYPTO_TYPE_SIGN_ONLY: u32 = 3;
+pub const KRB5_CRYPTO_TYPE_STREAM: u32 = 7;
+pub const KRB5_CRYPTO_TYPE_TRAILER: u32 = 5;
+pub const KRB5_CYBERSAFE_SECUREID: u32 = 9;
+pub const KRB5_DCC_CANNOT_CREATE: i32 = -1750600188;
+pub const KRB5_DELTAT_BADFORMAT: i32 = -1765328136;
+pub const KRB5_DEPRECATED: u32 = 0;
+pub const KRB5_DOMAIN_X500_COMPRESS: u32 = 1;
+pub const KRB5_EAI_FAIL: i32 = -1765328145;
+pub const KRB5_EAI_NODATA: i32 = -1765328144;
+pub const KRB5_EAI_NONAME: i32 = -1765328143;
+pub const KRB5_EAI_SERVICE: i32 = -1765328142;
+pub const KRB5_ENCPADATA_REQ_ENC_PA_REP: u32 = 149;
+pub const KRB5_ERR_BAD_HOSTNAME: i32 = -1765328168;
+pub const KRB5_ERR_BAD_S2K_PARAMS: i32 = -1765328140;
+pub const KRB5_ERR_FAST_REQUIRED: i32 = -1765328132;
+pub const KRB5_ERR_HOST_REALM_UNKNOWN: i32 = -1765328167;
+pub const KRB5_ERR_INVALID_UTF8: i32 = -1765328133;
+pub const KRB5_ERR_NO_SERVICE: i32 = -1765328139;
+pub const KRB5_ERR_NUMERIC_REALM: i32 = -1765328141;
+pub const KRB5_ERR_RCSID: i32 = -1765328256;
+pub const KRB5_FAST_REQUIRED: u32 = 1;
+pub const KRB5_FCC_INTERNAL: i32 = -1765328188;
+pub const KRB5_FCC_NOFILE: i32 = -1765328189;
+pub const KRB5_FCC_PERM: i32 = -1765328190;
+pub const KRB5_FWD_BAD_PRINCIPAL: i32 = -1765328162;
+pub const KRB5_GC_CACHED: u32 = 2;
+pub const KRB5_GC_CANONICALIZE: u32 = 4;
+pub const KRB5_GC_CONSTRAINED_DELEGATION: u32 = 64;
+pub const KRB5_GC_FORWARDABLE: u32 = 16;
+pub const KRB5_GC_NO_STORE: u32 = 8;
+pub const KRB5_GC_NO_TRANSIT_CHECK: u32 = 32;
+pub const KRB5_GC_USER_USER: u32 = 1;
+pub const KRB5_GET_INIT_CREDS_OPT_ADDRESS_LIST: u32 = 32;
+pub const KRB5_GET_INIT_CREDS_OPT_ANONYMOUS: u32 = 1024;
+pub const KRB5_GET_INIT_CREDS_OPT_CANONICALIZE: u32 = 512;
+pub const KRB5_GET_INIT_CREDS_OPT_CHG_PWD_PRMPT: u32 = 256;
+pub const KRB5_GET_INIT_CREDS_OPT_ETYPE_LIST: u32 = 16;
+pub const KRB5_GET_INIT_CREDS_OPT_FORWARDABLE: u32 = 4;
+pub const KRB5_GET_INIT_CREDS_OPT_PREAUTH_LIST: u32 = 64;
+pub const KRB5_GET_INIT_CREDS_OPT_PROXIABLE: u32 = 8;
+pub const KRB5_GET_INIT_CREDS_OPT_RENEW_LIFE: u32 = 2;
+pub const KRB5_GET_INIT_CREDS_OPT_SALT: u32 = 128;
+pub const KRB5_GET_INIT_CREDS_OPT_TKT_LIFE: u32 = 1;
+pub const KRB5_GET_IN_TKT_LOOP: i32 = -1765328161;
+pub const KRB5_INIT_CONTEXT_KDC: u32 = 2;
+pub const KRB5_INIT_CONTEXT_SECURE: u32 = 1;
+pub const KRB5_INIT_CREDS_STEP_FLAG_CONTINUE: u32 = 1;
+pub const KRB5_INT16_MAX: u32 = 65535;
+pub const KRB5_INT16_MIN: i32 = -65536;
+pub const KRB5_INT32_MAX: u32 = 2147483647;
+pub const KRB5_INT32_MIN: i32 = -2147483648;
+pub const KRB5_INVALID_FLAGS: i32 = -1765328183;
+pub const KRB5_IN_TKT_REALM_MISMATCH: i32 = -1765328235;
+pub const KRB5_KCC_INVALID_ANCHOR: i32 = -1750600187;
+pub const KRB5_KCC_INVALID_UID: i32 = -1750600185;
+pub const KRB5_KCC_UNKNOWN_VERSION: i32 = -1750600186;
+pub const KRB5_KCM_MALFORMED_REPLY: i32 = -1750600184;
+pub const KRB5_KCM_NO_SERVER: i32 = -1750600181;
+pub const KRB5_KCM_REPLY_TOO_BIG: i32 = -1750600182;
+pub const KRB5_KCM_RPC_ERROR: i32 = -1750600183;
+pub const KRB5_KDB_ACCESS_ERROR: i32 = -1780008412;
+pub const KRB5_KDB_BADLOCKMODE: i32 = -1780008436;
+pub const KRB5_KDB_BADMASTERKEY: i32 = -1780008431;
+pub const KRB5_KDB_BADSTORED_MKEY: i32 = -1780008428;
+pub const KRB5_KDB_BAD_CREATEFLAGS: i32 = -1780008419;
+pub const KRB5_KDB_BAD_ENCTYPE: i32 = -1780008420;
+pub const KRB5_KDB_BAD_SALTTYPE: i32 = -1780008421;
+pub const KRB5_KDB_BAD_VERSION: i32 = -1780008422;
+pub const KRB5_KDB_CANTLOCK_DB: i32 = -1780008424;
+pub const KRB5_KDB_CANTREAD_STORED: i32 = -1780008429;
+pub const KRB5_KDB_CONSTRAINT_VIOLATION: i32 = -1780008410;
+pub const KRB5_KDB_DBINITED: i32 = -1780008434;
+pub const KRB5_KDB_DBNOTINITED: i32 = -1780008435;
+pub const KRB5_KDB_DBTYPE_INIT: i32 = -1780008414;
+pub const KRB5_KDB_DBTYPE_MISMATCH: i32 = -1780008405;
+pub const KRB5_KDB_DBTYPE_NOSUP: i32 = -1780008415;
+pub const KRB5_KDB_DBTYPE_NOTFOUND: i32 = -1780008416;
+pub const KRB5_KDB_DB_CHANGED: i32 = -1780008440;
+pub const KRB5_KDB_DB_CORRUPT: i32 = -1780008423;
+pub const KRB5_KDB_DB_INUSE: i32 = -1780008441;
+pub const KRB5_KDB_ILLDIRECTION: i32 = -1780008433;
+pub const KRB5_KDB_ILL_WILDCARD: i32 = -1780008442;
+pub const KRB5_KDB_INTERNAL_ERROR: i32 = -1780008411;
+pub const KRB5_KDB_INUSE: i32 = -1780008447;
+pub const KRB5_KDB_INVALIDKEYSIZE: i32 = -1780008430;
+pub const KRB5_KDB_KVNONOMATCH: i32 = -1780008426;
+pub const KRB5_KDB_NOACTMASTERKEY: i32 = -1780008427;
+pub const KRB5_KDB_NOENTRY: i32 = -1780008443;
+pub const KRB5_KDB_NOMASTERKEY: i32 = -1780008432;
+pub const KRB5_KDB_NOTLOCKED: i32 = -1780008437;
+pub const KRB5_KDB_NO_MATCHING_KEY: i32 = -1780008417;
+pub const KRB5_KDB_NO_PERMITTED_KEY: i32 = -1780008418;
+pub const KRB5_KDB_POLICY_REF: i32 = -1780008404;
+pub const KRB5_KDB_RCSID: i32 = -1780008448;
+pub const KRB5_KDB_RECURSIVELOCK: i32 = -1780008438;
+pub const KRB5_KDB_SERVER_INTERNAL_ERR: i32 = -1780008413;
+pub const KRB5_KDB_STORED_MKEY_NOTCURRENT: i32 = -1780008425;
+pub const KRB5_KDB_STRINGS_TOOLONG: i32 = -1780008403;
+pub const KRB5_KDB_TRUNCATED_RECORD: i32 = -1780008439;
+pub const KRB5_KDB_UK_RERROR: i32 = -1780008445;
+pub const KRB5_KDB_UK_SERROR: i32 = -1780008446;
+pub const KRB5_KDB_UNAUTH: i32 = -1780008444;
+pub const KRB5_KDCREP_MODIFIED: i32 = -1765328237;
+pub const KRB5_KDCREP_SKEW: i32 = -1765328236;
+pub const KRB5_KDC_UNREACH: i32 = -1765328228;
+pub const KRB5_KEYTAB_BADVNO: i32 = -1765328171;
+pub const KRB5_KEYUSAGE_AD_ITE: u32 = 21;
+pub const KRB5_KEYUSAGE_AD_KDCISSUED_CKSUM: u32 = 19;
+pub const KRB5_KEYUSAGE_AD_MTE: u32 = 20;
+pub const KRB5_KEYUSAGE_AD_SIGNEDPATH: i32 = -21;
+pub const KRB5_KEYUSAGE_APP_DATA_CKSUM: u32 = 17;
+pub const KRB5_KEYUSAGE_APP_DATA_ENCRYPT: u32 = 16;
+pub const KRB5_KEYUSAGE_AP_REP_ENCPART: u32 = 12;
+pub const KRB5_KEYUSAGE_AP_REQ_AUTH: u32 = 11;
+pub const KRB5_KEYUSAGE_AP_REQ_AUTH_CKSUM: u32 = 10;
+pub const KRB5_KEYUSAGE_AS_REP_ENCPART: u32 = 3;
+pub const KRB5_KEYUSAGE_AS_REQ: u32 = 56;
+pub const KRB5_KEYUSAGE_AS_REQ_PA_ENC_TS: u32 = 1;
+pub const KRB5_KEYUSAGE_CAMMAC: u32 = 64;
+pub const KRB5_KEYUSAGE_ENC_CHALLENGE_CLIENT: u32 = 54;
+pub const KRB5_KEYUSAGE_ENC_CHALLENGE_KDC: u32 = 55;
+pub const KRB5_KEYUSAGE_FAST_ENC: u32 = 51;
+pub const KRB5_KEYUSAGE_FAST_FINISHED: u32 = 53;
+pub const KRB5_KEYUSAGE_FAST_REP: u32 = 52;
+pub const KRB5_KEYUSAGE_FAST_REQ_CHKSUM: u32 = 50;
+pub const KRB5_KEYUSAGE_GSS_TOK_MIC: u32 = 22;
+pub const KRB5_KEYUSAGE_GSS_TOK_WRAP_INTEG: u32 = 23;
+pub const KRB5_KEYUSAGE_GSS_TOK_WRAP_PRIV: u32 = 24;
+pub const KRB5_KEYUSAGE_IAKERB_FINISHED: u32 = 42;
+pub const KRB5_KEYUSAGE_KDC_REP_TICKET: u32 = 2;
+pub const KRB5_KEYUSAGE_KRB_CRED_ENCPART: u32 = 14;
+pub const KRB5_KEYUSAGE_KRB_ERROR_CKSUM: u32 = 18;
+pub const KRB5_KEYUSAGE_KRB_PRIV_ENCPART: u32 = 13;
+pub const KRB5_KEYUSAGE_KRB_SAFE_CKSUM: u32 = 15;
+pub const KRB5_KEYUSAGE_PA_AS_FRESHNESS: u32 = 514;
+pub const KRB5_KEYUSAGE_PA_FX_COOKIE: u32 = 513;
+pub const KRB5_KEYUSAGE_PA_OTP_REQUEST: u32 = 45;
+pub const KRB5_KEYUSAGE_PA_PKINIT_KX: u32 = 44;
+pub const KRB5_KEYUSAGE_PA_REFERRAL: u32 = 26;
+pub const KRB5_KEYUSAGE_PA_S4U_X509_USER_REPLY: u32 = 27;
+pub const KRB5_KEYUSAGE_PA_S4U_X509_USER_REQUEST: u32 = 26;
+pub const KRB5_KEYUSAGE_PA_SAM_CHALLENGE_CKSUM: u32 = 25;
+pub const KRB5_KEYUSAGE_PA_SAM_CHALLENGE_TRACKID: u32 = 26;
+pub const KRB5_KEYUSAGE_PA_SAM_RESPONSE: u32 = 27;
+pub const KRB5_KEYUSAGE_SPAKE: u32 = 65;
+pub const KRB5_KEYUSAGE_TGS_REP_ENCPART_SESSKEY: u32 = 8;
+pub const KRB5_KEYUSAGE_TGS_REP_ENCPART_SUBKEY: u32 = 9;
+pub const KRB5_KEYUSAGE_TGS_REQ_AD_SESSKEY: u32 = 4;
+pub const KRB5_KEYUSAGE_TGS_REQ_AD_SUBKEY: u32 = 5;
+pub const KRB5_KEYUSAGE_TGS_REQ_AUTH: u32 = 7;
+pub const KRB5_KEYUSAGE_TGS_REQ_AUTH_CKSUM: u32 = 6;
+pub const KRB5_KPASSWD_ACCESSDENIED: u32 = 5;
+pub const KRB5_KPASSWD_AUTHERROR: u32 = 3;
+pub const KRB5_KPASSWD_BAD_VERSION: u32 = 6;
+pub const KRB5_KPASSWD_HARDERROR: u32 = 2;
+pub const KRB5_KPASSWD_INITIAL_FLAG_NEEDED: u32 = 7;
+pub const KRB5_KPASSWD_MALFORMED: u32 = 1;
+pub const KRB5_KPASSWD_SOFTERROR: u32 = 4;
+pub const KRB5_KPASSWD_SUCCESS: u32 = 0;
+pub const KRB5_KT_BADNAME: i32 = -1765328205;
+pub const KRB5_KT_END: i32 = -1765328202;
+pub const KRB5_KT_FORMAT: i32 = -1765328149;
+pub const KRB5_KT_IOERR: i32 = -1765328200;
+pub const KRB5_KT_KVNONOTFOUND: i32 = -1765328154;
+pub const KRB5_KT_NAME_TOOLONG: i32 = -1765328155;
+pub const KRB5_KT_NOTFOUND: i32 = -1765328203;
+pub const KRB5_KT_NOWRITE: i32 = -1765328201;
+pub const KRB5_KT_TYPE_EXISTS: i32 = -1765328192;
+pub const KRB5_KT_UNKNOWN_TYPE: i32 = -1765328204;
+pub const KRB5_LIBOS_BADLOCKFLAG: i32 = -1765328255;
+pub const KRB5_LIBOS_BADPWDMATCH: i32 = -1765328253;
+pub const KRB5_LIBOS_CANTREADPWD: i32 = -1765328254;
+pub const KRB5_LIBOS_PWDINTR: i32 = -1765328252;
+pub const KRB5_LIB_EXPIRED: i32 = -1765328152;
+pub const KRB5_LNAME_BADFORMAT: i32 = -1765328207;
+pub const KRB5_LNAME_CANTOPEN: i32 = -1765328209;
+pub const KRB5_LNAME_NOTRANS: i32 = -1765328208;
+pub const KRB5_LOCAL_ADDR_REQUIRED: i32 = -1765328131;
+pub const KRB5_LOG_CONV: i32 = -1780008409;
+pub const KRB5_LOG_CORRUPT: i32 = -1780008407;
+pub const KRB5_LOG_ERROR: i32 = -1780008406;
+pub const KRB5_LOG_UNSTABLE: i32 = -1780008408;
+pub const KRB5_LRQ_ALL_ACCT_EXPTIME: u32 = 7;
+pub const KRB5_LRQ_ALL_LAST_INITIAL: u32 = 2;
+pub const KRB5_LRQ_ALL_LAST_RENEWAL: u32 = 4;
+pub const KRB5_LRQ_ALL_LAST_REQ: u32 = 5;
+pub const KRB5_LRQ_ALL_LAST_TGT: u32 = 1;
+pub const KRB5_LRQ_ALL_LAST_TGT_ISSUED: u32 = 3;
+pub const KRB5_LRQ_ALL_PW_EXPTIME: u32 = 6;
+pub const KRB5_LRQ_NONE: u32 = 0;
+pub const KRB5_LRQ_ONE_ACCT_EXPTIME: i32 = -7;
+pub const KRB5_LRQ_ONE_LAST_INITIAL: i32 = -2;
+pub const KRB5_LRQ_ONE_LAST_RENEWAL: i32 = -4;
+pub const KRB5_LRQ_ONE_LAST_REQ: i32 = -5;
+pub const KRB5_LRQ_ONE_LAST_TGT: i32 = -1;
+pub const KRB5_LRQ_ONE_LAST_TGT_ISSUED: i32 = -3;
+pub const KRB5_LRQ_ONE_PW_EXPTIME: i32 = -6;
+pub const KRB5_MUTUAL_FAILED: i32 = -1765328226;
+pub const KRB5_NOCREDS_SUPPLIED: i32 = -1765328181;
+pub const KRB5_NOPERM_ETYPE: i32 = -1765328148;
+pub const KRB5_NO_2ND_TKT: i32 = -1765328182;
+pub const KRB5_NO_LOCALNAME: i32 = -1765328227;
+pub const KRB5_NO_TKT_IN_RLM: i32 = -1765328199;
+pub const KRB5_NO_TKT_SUPPLIED: i32 = -1765328241;
+pub const KRB5_NT_ENTERPRISE_PRINCIPAL: u32 = 10;
+pub const KRB5_NT_ENT_PRINCIPAL_AND_ID: i32 = -130;
+pub const KRB5_NT_MS_PRINCIPAL: i32 = -128;
+pub const KRB5_NT_MS_PRINCIPAL_AND_ID: i32 = -129;
+pub const KRB5_NT_PRINCIPAL: u32 = 1;
+pub const KRB5_NT_SMTP_NAME: u32 = 7;
+pub const KRB5_NT_SRV_HST: u32 = 3;
+pub const KRB5_NT_SRV_INST: u32 = 2;
+pub const KRB5_NT_SRV_XHST: u32 = 4;
+pub const KRB5_NT_UID: u32 = 5;
+pub const KRB5_NT_UNKNOWN: u32 = 0;
+pub const KRB5_NT_WELLKNOWN: u32 = 11;
+pub const KRB5_NT_X500_PRINCIPAL: u32 = 6;
+pub const KRB5_OBSOLETE_FN: i32 = -1765328146;
+pub const KRB5_PAC_CLIENT_INFO: u32 = 10;
+pub const KRB5_PAC_CREDENTIALS_INFO: u32 = 2;
+pub const KRB5_PAC_DELEGATION_INFO: u32 = 11;
+pub const KRB5_PAC_LOGON_INFO: u32 = 1;
+pub const KRB5_PAC_PRIVSVR_CHECKSUM: u32 = 7;
+pub const KRB5_PAC_SERVER_CHECKSUM: u32 = 6;
+pub const KRB5_PAC_UPN_DNS_INFO: u32 = 12;
+pub const KRB5_PADATA_AFS3_SALT: u32 = 10;
+pub const KRB5_PADATA_AP_REQ: u32 = 1;
+pub const KRB5_PADATA_AS_CHECKSUM: u32 = 132;
+pub const KRB5_PADATA_AS_FRESHNESS: u32 = 150;
+pub const KRB5_PADATA_ENCRYPTED_CHALLENGE: u32 = 138;
+pub const KRB5_PADATA_ENC_SANDIA_SECURID: u32 = 6;
+pub const KRB5_PADATA_ENC_TIMESTAMP: u32 = 2;
+pub const KRB5_PADATA_ENC_UNIX_TIME: u32 = 5;
+pub const KRB5_PADATA_ETYPE_INFO: u32 = 11;
+pub const KRB5_PADATA_ETYPE_INFO2: u32 = 19;
+pub const KRB5_PADATA_FOR_USER: u32 = 129;
+pub const KRB5_PADATA_FX_COOKIE: u32 = 133;
+pub const KRB5_PADATA_FX_ERROR: u32 = 137;
+pub const KRB5_PADATA_FX_FAST: u32 = 136;
+pub const KRB5_PADATA_GET_FROM_TYPED_DATA: u32 = 22;
+pub const KRB5_PADATA_NONE: u32 = 0;
+pub const KRB5_PADATA_OSF_DCE: u32 = 8;
+pub const KRB5_PADATA_OTP_CHALLENGE: u32 = 141;
+pub const KRB5_PADATA_OTP_PIN_CHANGE: u32 = 144;
+pub const KRB5_PADATA_OTP_REQUEST: u32 = 142;
+pub const KRB5_PADATA_PAC_OPTIONS: u32 = 167;
+pub const KRB5_PADATA_PAC_REQUEST: u32 = 128;
+pub const KRB5_PADATA_PKINIT_KX: u32 = 147;
+pub const KRB5_PADATA_PK_AS_REP: u32 = 17;
+pub const KRB5_PADATA_PK_AS_REP_OLD: u32 = 15;
+pub const KRB5_PADATA_PK_AS_REQ: u32 = 16;
+pub const KRB5_PADATA_PK_AS_REQ_OLD: u32 = 14;
+pub const KRB5_PADATA_PW_SALT: u32 = 3;
+pub const KRB5_PADATA_REFERRAL: u32 = 25;
+pub const KRB5_PADATA_S4U_X509_USER: u32 = 130;
+pub const KRB5_PADATA_SAM_CHALLENGE: u32 = 12;
+pub const KRB5_PADATA_SAM_CHALLENGE_2: u32 = 30;
+pub const KRB5_PADATA_SAM_REDIRECT: u32 = 21;
+pub const KRB5_PADATA_SAM_RESPONSE: u32 = 13;
+pub const KRB5_PADATA_SAM_RESPONSE_2: u32 = 31;
+pub const KRB5_PADATA_SESAME: u32 = 7;
+pub const KRB5_PADATA_SPAKE: u32 = 151;
+pub const KRB5_PADATA_SVR_REFERRAL_INFO: u32 = 20;
+pub const KRB5_PADATA_TGS_REQ: u32 = 1;
+pub const KRB5_PADATA_USE_SPECIFIED_KVNO: u32 = 20;
+pub const KRB5_PARSE_ILLCHAR: i32 = -1765328251;
+pub const KRB5_PARSE_MALFORMED: i32 = -1765328250;
+pub const KRB5_PLUGIN_BAD_MODULE_SPEC: i32 = -1750600191;
+pub const KRB5_PLUGIN_NAME_NOTFOUND: i32 = -1750600190;
+pub const KRB5_PLUGIN_NO_HANDLE: i32 = -1765328135;
+pub const KRB5_PLUGIN_OP_NOTSUPP: i32 = -1765328134;
+pub const KRB5_PLUGIN_VER_NOTSUPP: i32 = -1750600192;
+pub const KRB5_PREAUTH_BAD_TYPE: i32 = -1765328176;
+pub const KRB5_PREAUTH_FAILED: i32 = -1765328174;
+pub const KRB5_PREAUTH_NO_KEY: i32 = -1765328175;
+pub const KRB5_PRINCIPAL_COMPARE_CASEFOLD: u32 = 4;
+pub const KRB5_PRINCIPAL_COMPARE_ENTERPRISE: u32 = 2;
+pub const KRB5_PRINCIPAL_COMPARE_IGNORE_REALM: u32 = 1;
+pub const KRB5_PRINCIPAL_COMPARE_UTF8: u32 = 8;
+pub const KRB5_PRINCIPAL_PARSE_ENTERPRISE: u32 = 4;
+pub const KRB5_PRINCIPAL_PARSE_IGNORE_REALM: u32 = 8;
+pub const KRB5_PRINCIPAL_PARSE_NO_DEF_REALM: u32 = 16;
+pub const KRB5_PRINCIPAL_PARSE_NO_REALM: u32 = 1;
+pub const KRB5_PRINCIPAL_PARSE_REQUIRE_REALM: u32 = 2;
+pub const KRB5_PRINCIPAL_UNPARSE_DISPLAY: u32 = 4;
+pub const KRB5_PRINCIPAL_UNPARSE_NO_REALM: u32 = 2;
+pub const KRB5_PRINCIPAL_UNPARSE_SHORT: u32 = 1;
+pub const KRB5_PRINC_NOMATCH: i32 = -1765328238;
+pub const KRB5_PROG_ATYPE_NOSUPP: i32 = -1765328170;
+pub const KRB5_PROG_ETYPE_NOSUPP: i32 = -1765328234;
+pub const KRB5_PROG_KEYTYPE_NOSUPP: i32 = -1765328233;
+pub const KRB5_PROG_SUMTYPE_NOSUPP: i32 = -1765328231;
+pub const KRB5_PROMPT_TYPE_NEW_PASSWORD: u32 = 2;
+pub const KRB5_PROMPT_TYPE_NEW_PASSWORD_AGAIN: u32 = 3;
+pub const KRB5_PROMPT_TYPE_PASSWORD: u32 = 1;
+pub const KRB5_PROMPT_TYPE_PREAUTH: u32 = 4;
+pub const KRB5_PVNO: u32 = 5;
+pub const KRB5_RCACHE_BADVNO: i32 = -1765328173;
+pub const KRB5_RC_IO: i32 = -1765328220;
+pub const KRB5_RC_IO_EOF: i32 = -1765328217;
+pub const KRB5_RC_IO_IO: i32 = -1765328214;
+pub const KRB5_RC_IO_MALLOC: i32 = -1765328216;
+pub const KRB5_RC_IO_PERM: i32 = -1765328215;
+pub const KRB5_RC_IO_SPACE: i32 = -1765328212;
+pub const KRB5_RC_IO_UNKNOWN: i32 = -1765328213;
+pub const KRB5_RC_MALLOC: i32 = -1765328224;
+pub const KRB5_RC_NOIO: i32 = -1765328219;
+pub const KRB5_RC_PARSE: i32 = -1765328218;
+pub const KRB5_RC_REPLAY: i32 = -1765328221;
+pub const KRB5_RC_REQUIRED: i32 = -1765328169;
+pub const KRB5_RC_TYPE_EXISTS: i32 = -1765328225;
+pub const KRB5_RC_TYPE_NOTFOUND: i32 = -1765328223;
+pub const KRB5_RC_UNKNOWN: i32 = -1765328222;
+pub const KRB5_REALM_CANT_RESOLVE: i32 = -1765328164;
+pub const KRB5_REALM_UNKNOWN: i32 = -1765328230;
+pub const KRB5_RECVAUTH_BADAUTHVERS: u32 = 2;
+pub const KRB5_RECVAUTH_SKIP_VERSION: u32 = 1;
+pub const KRB5_REFERRAL_REALM: &'static [u8; 1] = b"\0";
+pub const KRB5_REMOTE_ADDR_REQUIRED: i32 = -1765328130;
+pub const KRB5_RESPONDER_OTP_FLAGS_COLLECT_PIN: u32 = 2;
+pub const KRB5_RESPONDER_OTP_FLAGS_COLLECT_TOKEN: u32 = 1;
+pub const KRB5_RESPONDER_OTP_FLAGS_NEXTOTP: u32 = 4;
+pub const KRB5_RESPONDER_OTP_FLAGS_SEPARATE_PIN: u32 = 8;
+pub const KRB5_RESPONDER_OTP_FORMAT_ALPHANUMERIC: u32 = 2;
+pub const KRB5_RESPONDER_OTP_FORMAT_DECIMAL: u32 = 0;
+pub const KRB5_RESPONDER_OTP_FORMAT_HEXADECIMAL: u32 = 1;
+pub const KRB5_RESPONDER_PKINIT_FLAGS_TOKEN_USER_PIN_COUNT_LOW: u32 = 1;
+pub const KRB5_RESPONDER_PKINIT_FLAGS_TOKEN_USER_PIN_FINAL_TRY: u32 = 2;
+pub const KRB5_RESPONDER_PKINIT_FLAGS_TOKEN_USER_PIN_LOCKED: u32 = 4;
+pub const KRB5_RESPONDER_QUESTION_OTP: &'static [u8; 4] = b"otp\0";
+pub const KRB5_RESPONDER_QUESTION_PASSWORD: &'static [u8; 9] = b"password\0";
+pub const KRB5_RESPONDER_QUESTION_PKINIT: &'static [u8; 7] = b"pkinit\0";
+pub const KRB5_SAM_BAD_CHECKSUM: i32 = -1765328156;
+pub const KRB5_SAM_INVALID_ETYPE: i32 = -1765328158;
+pub const KRB5_SAM_MUST_PK_ENCRYPT_SAD: u32 = 536870912;
+pub const KRB5_SAM_NO_CHECKSUM: i32 = -1765328157;
+pub const KRB5_SAM_SEND_ENCRYPTED_SAD: u32 = 1073741824;
+pub const KRB5_SAM_UNSUPPORTED: i32 = -1765328159;
+pub const KRB5_SAM_USE_SAD_AS_KEY: u32 = 2147483648;
+pub const KRB5_SENDAUTH_BADAPPLVERS: i32 = -1765328179;
+pub const KRB5_SENDAUTH_BADAUTHVERS: i32 = -1765328180;
+pub const KRB5_SENDAUTH_BADRESPONSE: i32 = -1765328178;
+pub const KRB5_SENDAUTH_REJECTED: i32 = -1765328177;
+pub const KRB5_SERVICE_UNKNOWN: i32 = -1765328229;
+pub const KRB5_SNAME_UNSUPP_NAMETYPE: i32 = -1765328166;
+pub const KRB5_TC_MATCH_2ND_TKT: u32 = 128;
+pub const KRB5_TC_MATCH_AUTHDATA: u32 = 32;
+pub const KRB5_TC_MATCH_FLAGS: u32 = 4;
+pub const KRB5_TC_MATCH_FLAGS_EXACT: u32 = 16;
+pub const KRB5_TC_MATCH_IS_SKEY: u32 = 2;
+pub const KRB5_TC_MATCH_KTYPE: u32 = 256;
+pub const KRB5_TC_MATCH_SRV_NAMEONLY: u32 = 64;
+pub const KRB5_TC_MATCH_TIMES: u32 = 1;
+pub const KRB5_TC_MATCH_TIMES_EXACT: u32 = 8;
+pub const KRB5_TC_NOTICKET: u32 = 2;
+pub const KRB5_TC_OPENCLOSE: u32 = 1;
+pub const KRB5_TC_SUPPORTED_KTYPES: u32 = 512;
+pub const KRB5_TGS_NAME: &'static [u8; 7] = b"krbtgt\0";
+pub const KRB5_TGS_NAME_SIZE: u32 = 6;
+pub const KRB5_TKT_CREDS_STEP_FLAG_CONTINUE: u32 = 1;
+pub const KRB5_TKT_NOT_FORWARDABLE: i32 = -1765328163;
+pub const KRB5_TRACE_NOSUPP: i32 = -1765328129;
+pub const KRB5_TRANS_BADFORMAT: i32 = -1765328210;
+pub const KRB5_TRANS_CANTOPEN: i32 = -1765328211;
+pub const KRB5_VERIFY_INIT_CREDS_OPT_AP_REQ_NOFAIL: u32 = 1;
+pub const KRB5_WELLKNOWN_NAMESTR: &'static [u8; 10] = b"WELLKNOWN\0";
+pub const KRB5_WRONG_ETYPE: i32 = -1765328232;
+pub const LR_TYPE_INTERPRETATION_MASK: u32 = 32767;
+pub const LR_TYPE_THIS_SERVER_ONLY: u32 = 32768;
+pub const MAX_KEYTAB_NAME_LEN: u32 = 1100;
+pub const MSEC_DIRBIT: u32 = 32768;
+pub const MSEC_VAL_MASK: u32 = 32767;
+pub const PROF_ADD_NOT_SECTION: i32 = -1429577724;
+pub const PROF_BAD_BOOLEAN: i32 = -1429577700;
+pub const PROF_BAD_GROUP_LVL: i32 = -1429577721;
+pub const PROF_BAD_INTEGER: i32 = -1429577699;
+pub const PROF_BAD_LINK_LIST: i32 = -1429577722;
+pub const PROF_BAD_NAMESET: i32 = -1429577705;
+pub const PROF_BAD_PARENT_PTR: i32 = -1429577720;
+pub const PROF_EINVAL: i32 = -1429577717;
+pub const PROF_END_OF_SECTIONS: i32 = -1429577706;
+pub const PROF_EXISTS: i32 = -1429577701;
+pub const PROF_EXTRA_CBRACE: i32 = -1429577712;
+pub const PROF_FAIL_INCLUDE_DIR: i32 = -1429577696;
+pub const PROF_FAIL_INCLUDE_FILE: i32 = -1429577697;
+pub const PROF_FAIL_OPEN: i32 = -1429577702;
+pub const PROF_INVALID_SECTION: i32 = -1429577707;
+pub const PROF_MAGIC_FILE: i32 = -1429577703;
+pub const PROF_MAGIC_FILE_DATA: i32 = -1429577698;
+pub const PROF_MAGIC_ITERATOR: i32 = -1429577719;
+pub const PROF_MAGIC_NODE: i32 = -1429577727;
+pub const PROF_MAGIC_NODE_ITERATOR: i32 = -1429577694;
+pub const PROF_MAGIC_PROFILE: i32 = -1429577710;
+pub const PROF_MAGIC_SECTION: i32 = -1429577709;
+pub const PROF_MISSING_OBRACE: i32 = -1429577711;
+pub const PROF_MODULE: i32 = -1429577693;
+pub const PROF_MODULE_INVALID: i32 = -1429577691;
+pub const PROF_MODULE_SYNTAX: i32 = -1429577692;
+pub const PROF_NO_PROFILE: i32 = -1429577704;
+pub const PROF_NO_RELATION: i32 = -1429577725;
+pub const PROF_NO_SECTION: i32 = -1429577726;
+pub const PROF_READ_ONLY: i32 = -1429577716;
+pub const PROF_RELATION_SYNTAX: i32 = -1429577713;
+pub const PROF_SECTION_NOTOP: i32 = -1429577715;
+pub const PROF_SECTION_SYNTAX: i32 = -1429577714;
+pub const PROF_SECTION_WITH_VALUE: i32 = -1429577723;
+pub const PROF_SET_SECTION_VALUE: i32 = -1429577718;
+pub const PROF_TOPSECTION_ITER_NOSUPP: i32 = -1429577708;
+pub const PROF_UNSUPPORTED: i32 = -1429577695;
+pub const PROF_VERSION: i32 = -1429577728;
+pub const TKT_FLG_ANONYMOUS: u32 = 32768;
+pub const TKT_FLG_ENC_PA_REP: u32 = 65536;
+pub const TKT_FLG_FORWARDABLE: u32 = 1073741824;
+pub const TKT_FLG_FORWARDED: u32 = 536870912;
+pub const TKT_FLG_HW_AUTH: u32 = 1048576;
+pub const TKT_FLG_INITIAL: u32 = 4194304;
+pub const TKT_FLG_INVALID: u32 = 16777216;
+pub const TKT_FLG_MAY_POSTDATE: u32 = 67108864;
+pub const TKT_FLG_OK_AS_DELEGATE: u32 = 262144;
+pub const TKT_FLG_POSTDATED: u32 = 33554432;
+pub const TKT_FLG_PRE_AUTH: u32 = 2097152;
+pub const TKT_FLG_PROXIABLE: u32 = 268435456;
+pub const TKT_FLG_PROXY: u32 = 134217728;
+pub const TKT_FLG_RENEWABLE: u32 = 8388608;
+pub const TKT_FLG_TRANSIT_POLICY_CHECKED: u32 = 524288;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct __va_list_tag {
+  pub gp_offset: ::std::os::raw::c_uint,
+  pub fp_offset: ::std::os::raw::c_uint,
+  pub overflow_arg_area: *mut ::std::os::raw::c_void,
+  pub reg_save_area: *mut ::std::os::raw::c_void,
+}
+#[test]
+fn bindgen_test_layout___va_list_tag() {
+  const UNINIT: ::std::mem::MaybeUninit<__va_list_tag> = ::std::mem::MaybeUninit::uninit();
+  let ptr = UNINIT.as_ptr();
+  assert_eq!(
+    ::std::mem::size_of::<__va_list_tag>(),
+    24usize,
+    concat!("Size of: ", stringify!(__va_list_tag))
+  );
+  assert_eq!(
+    ::std::mem::align_of::<__va_list_tag>(),
+    8usize,
+    concat!("Alignment of ", stringify!(__va_list_tag))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).gp_offset) as usize - ptr as usize },
+    0usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(__va_list_tag),
+      "::",
+      stringify!(gp_offset)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).fp_offset) as usize - ptr as usize },
+    4usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(__va_list_tag),
+      "::",
+      stringify!(fp_offset)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).overflow_arg_area) as usize - ptr as usize },
+    8usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(__va_list_tag),
+      "::",
+      stringify!(overflow_arg_area)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).reg_save_area) as usize - ptr as usize },
+    16usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(__va_list_tag),
+      "::",
+      stringify!(reg_save_area)
+    )
+  );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _krb5_address {
+  pub magic: krb5_magic,
+  pub addrtype: krb5_addrtype,
+  pub length: ::std::os::raw::c_uint,
+  pub contents: *mut krb5_octet,
+}
+#[test]
+fn bindgen_test_layout__krb5_address() {
+  const UNINIT: ::std::mem::MaybeUninit<_krb5_address> = ::std::mem::MaybeUninit::uninit();
+  let ptr = UNINIT.as_ptr();
+  assert_eq!(
+    ::std::mem::size_of::<_krb5_address>(),
+    24usize,
+    concat!("Size of: ", stringify!(_krb5_address))
+  );
+  assert_eq!(
+    ::std::mem::align_of::<_krb5_address>(),
+    8usize,
+    concat!("Alignment of ", stringify!(_krb5_address))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).magic) as usize - ptr as usize },
+    0usize,
+    concat!("Offset of field: ", stringify!(_krb5_address), "::", stringify!(magic))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).addrtype) as usize - ptr as usize },
+    4usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_address),
+      "::",
+      stringify!(addrtype)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).length) as usize - ptr as usize },
+    8usize,
+    concat!("Offset of field: ", stringify!(_krb5_address), "::", stringify!(length))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).contents) as usize - ptr as usize },
+    16usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_address),
+      "::",
+      stringify!(contents)
+    )
+  );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _krb5_ap_rep {
+  pub magic: krb5_magic,
+  pub enc_part: krb5_enc_data,
+}
+#[test]
+fn bindgen_test_layout__krb5_ap_rep() {
+  const UNINIT: ::std::mem::MaybeUninit<_krb5_ap_rep> = ::std::mem::MaybeUninit::uninit();
+  let ptr = UNINIT.as_ptr();
+  assert_eq!(
+    ::std::mem::size_of::<_krb5_ap_rep>(),
+    40usize,
+    concat!("Size of: ", stringify!(_krb5_ap_rep))
+  );
+  assert_eq!(
+    ::std::mem::align_of::<_krb5_ap_rep>(),
+    8usize,
+    concat!("Alignment of ", stringify!(_krb5_ap_rep))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).magic) as usize - ptr as usize },
+    0usize,
+    concat!("Offset of field: ", stringify!(_krb5_ap_rep), "::", stringify!(magic))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).enc_part) as usize - ptr as usize },
+    8usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_ap_rep),
+      "::",
+      stringify!(enc_part)
+    )
+  );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _krb5_ap_rep_enc_part {
+  pub magic: krb5_magic,
+  pub ctime: krb5_timestamp,
+  pub cusec: krb5_int32,
+  pub subkey: *mut krb5_keyblock,
+  pub seq_number: krb5_ui_4,
+}
+#[test]
+fn bindgen_test_layout__krb5_ap_rep_enc_part() {
+  const UNINIT: ::std::mem::MaybeUninit<_krb5_ap_rep_enc_part> = ::std::mem::MaybeUninit::uninit();
+  let ptr = UNINIT.as_ptr();
+  assert_eq!(
+    ::std::mem::size_of::<_krb5_ap_rep_enc_part>(),
+    32usize,
+    concat!("Size of: ", stringify!(_krb5_ap_rep_enc_part))
+  );
+  assert_eq!(
+    ::std::mem::align_of::<_krb5_ap_rep_enc_part>(),
+    8usize,
+    concat!("Alignment of ", stringify!(_krb5_ap_rep_enc_part))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).magic) as usize - ptr as usize },
+    0usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_ap_rep_enc_part),
+      "::",
+      stringify!(magic)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).ctime) as usize - ptr as usize },
+    4usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_ap_rep_enc_part),
+      "::",
+      stringify!(ctime)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).cusec) as usize - ptr as usize },
+    8usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_ap_rep_enc_part),
+      "::",
+      stringify!(cusec)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).subkey) as usize - ptr as usize },
+    16usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_ap_rep_enc_part),
+      "::",
+      stringify!(subkey)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).seq_number) as usize - ptr as usize },
+    24usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_ap_rep_enc_part),
+      "::",
+      stringify!(seq_number)
+    )
+  );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _krb5_ap_req {
+  pub magic: krb5_magic,
+  pub ap_options: krb5_flags,
+  pub ticket: *mut krb5_ticket,
+  pub authenticator: krb5_enc_data,
+}
+#[test]
+fn bindgen_test_layout__krb5_ap_req() {
+  const UNINIT: ::std::mem::MaybeUninit<_krb5_ap_req> = ::std::mem::MaybeUninit::uninit();
+  let ptr = UNINIT.as_ptr();
+  assert_eq!(
+    ::std::mem::size_of::<_krb5_ap_req>(),
+    48usize,
+    concat!("Size of: ", stringify!(_krb5_ap_req))
+  );
+  assert_eq!(
+    ::std::mem::align_of::<_krb5_ap_req>(),
+    8usize,
+    concat!("Alignment of ", stringify!(_krb5_ap_req))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).magic) as usize - ptr as usize },
+    0usize,
+    concat!("Offset of field: ", stringify!(_krb5_ap_req), "::", stringify!(magic))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).ap_options) as usize - ptr as usize },
+    4usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_ap_req),
+      "::",
+      stringify!(ap_options)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).ticket) as usize - ptr as usize },
+    8usize,
+    concat!("Offset of field: ", stringify!(_krb5_ap_req), "::", stringify!(ticket))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).authenticator) as usize - ptr as usize },
+    16usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_ap_req),
+      "::",
+      stringify!(authenticator)
+    )
+  );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _krb5_auth_context {
+  _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _krb5_authdata {
+  pub magic: krb5_magic,
+  pub ad_type: krb5_authdatatype,
+  pub length: ::std::os::raw::c_uint,
+  pub contents: *mut krb5_octet,
+}
+#[test]
+fn bindgen_test_layout__krb5_authdata() {
+  const UNINIT: ::std::mem::MaybeUninit<_krb5_authdata> = ::std::mem::MaybeUninit::uninit();
+  let ptr = UNINIT.as_ptr();
+  assert_eq!(
+    ::std::mem::size_of::<_krb5_authdata>(),
+    24usize,
+    concat!("Size of: ", stringify!(_krb5_authdata))
+  );
+  assert_eq!(
+    ::std::mem::align_of::<_krb5_authdata>(),
+    8usize,
+    concat!("Alignment of ", stringify!(_krb5_authdata))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).magic) as usize - ptr as usize },
+    0usize,
+    concat!("Offset of field: ", stringify!(_krb5_authdata), "::", stringify!(magic))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).ad_type) as usize - ptr as usize },
+    4usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_authdata),
+      "::",
+      stringify!(ad_type)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).length) as usize - ptr as usize },
+    8usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_authdata),
+      "::",
+      stringify!(length)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).contents) as usize - ptr as usize },
+    16usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_authdata),
+      "::",
+      stringify!(contents)
+    )
+  );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _krb5_authenticator {
+  pub magic: krb5_magic,
+  pub client: krb5_principal,
+  pub checksum: *mut krb5_checksum,
+  pub cusec: krb5_int32,
+  pub ctime: krb5_timestamp,
+  pub subkey: *mut krb5_keyblock,
+  pub seq_number: krb5_ui_4,
+  pub authorization_data: *mut *mut krb5_authdata,
+}
+#[test]
+fn bindgen_test_layout__krb5_authenticator() {
+  const UNINIT: ::std::mem::MaybeUninit<_krb5_authenticator> = ::std::mem::MaybeUninit::uninit();
+  let ptr = UNINIT.as_ptr();
+  assert_eq!(
+    ::std::mem::size_of::<_krb5_authenticator>(),
+    56usize,
+    concat!("Size of: ", stringify!(_krb5_authenticator))
+  );
+  assert_eq!(
+    ::std::mem::align_of::<_krb5_authenticator>(),
+    8usize,
+    concat!("Alignment of ", stringify!(_krb5_authenticator))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).magic) as usize - ptr as usize },
+    0usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_authenticator),
+      "::",
+      stringify!(magic)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).client) as usize - ptr as usize },
+    8usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_authenticator),
+      "::",
+      stringify!(client)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).checksum) as usize - ptr as usize },
+    16usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_authenticator),
+      "::",
+      stringify!(checksum)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).cusec) as usize - ptr as usize },
+    24usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_authenticator),
+      "::",
+      stringify!(cusec)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).ctime) as usize - ptr as usize },
+    28usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_authenticator),
+      "::",
+      stringify!(ctime)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).subkey) as usize - ptr as usize },
+    32usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_authenticator),
+      "::",
+      stringify!(subkey)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).seq_number) as usize - ptr as usize },
+    40usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_authenticator),
+      "::",
+      stringify!(seq_number)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).authorization_data) as usize - ptr as usize },
+    48usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_authenticator),
+      "::",
+      stringify!(authorization_data)
+    )
+  );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _krb5_cc_ops {
+  _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _krb5_ccache {
+  _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _krb5_cccol_cursor {
+  _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _krb5_checksum {
+  pub magic: krb5_magic,
+  pub checksum_type: krb5_cksumtype,
+  pub length: ::std::os::raw::c_uint,
+  pub contents: *mut krb5_octet,
+}
+#[test]
+fn bindgen_test_layout__krb5_checksum() {
+  const UNINIT: ::std::mem::MaybeUninit<_krb5_checksum> = ::std::mem::MaybeUninit::uninit();
+  let ptr = UNINIT.as_ptr();
+  assert_eq!(
+    ::std::mem::size_of::<_krb5_checksum>(),
+    24usize,
+    concat!("Size of: ", stringify!(_krb5_checksum))
+  );
+  assert_eq!(
+    ::std::mem::align_of::<_krb5_checksum>(),
+    8usize,
+    concat!("Alignment of ", stringify!(_krb5_checksum))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).magic) as usize - ptr as usize },
+    0usize,
+    concat!("Offset of field: ", stringify!(_krb5_checksum), "::", stringify!(magic))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).checksum_type) as usize - ptr as usize },
+    4usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_checksum),
+      "::",
+      stringify!(checksum_type)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).length) as usize - ptr as usize },
+    8usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_checksum),
+      "::",
+      stringify!(length)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).contents) as usize - ptr as usize },
+    16usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_checksum),
+      "::",
+      stringify!(contents)
+    )
+  );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _krb5_context {
+  _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _krb5_cred {
+  pub magic: krb5_magic,
+  pub tickets: *mut *mut krb5_ticket,
+  pub enc_part: krb5_enc_data,
+  pub enc_part2: *mut krb5_cred_enc_part,
+}
+#[test]
+fn bindgen_test_layout__krb5_cred() {
+  const UNINIT: ::std::mem::MaybeUninit<_krb5_cred> = ::std::mem::MaybeUninit::uninit();
+  let ptr = UNINIT.as_ptr();
+  assert_eq!(
+    ::std::mem::size_of::<_krb5_cred>(),
+    56usize,
+    concat!("Size of: ", stringify!(_krb5_cred))
+  );
+  assert_eq!(
+    ::std::mem::align_of::<_krb5_cred>(),
+    8usize,
+    concat!("Alignment of ", stringify!(_krb5_cred))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).magic) as usize - ptr as usize },
+    0usize,
+    concat!("Offset of field: ", stringify!(_krb5_cred), "::", stringify!(magic))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).tickets) as usize - ptr as usize },
+    8usize,
+    concat!("Offset of field: ", stringify!(_krb5_cred), "::", stringify!(tickets))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).enc_part) as usize - ptr as usize },
+    16usize,
+    concat!("Offset of field: ", stringify!(_krb5_cred), "::", stringify!(enc_part))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).enc_part2) as usize - ptr as usize },
+    48usize,
+    concat!("Offset of field: ", stringify!(_krb5_cred), "::", stringify!(enc_part2))
+  );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _krb5_cred_enc_part {
+  pub magic: krb5_magic,
+  pub nonce: krb5_int32,
+  pub timestamp: krb5_timestamp,
+  pub usec: krb5_int32,
+  pub s_address: *mut krb5_address,
+  pub r_address: *mut krb5_address,
+  pub ticket_info: *mut *mut krb5_cred_info,
+}
+#[test]
+fn bindgen_test_layout__krb5_cred_enc_part() {
+  const UNINIT: ::std::mem::MaybeUninit<_krb5_cred_enc_part> = ::std::mem::MaybeUninit::uninit();
+  let ptr = UNINIT.as_ptr();
+  assert_eq!(
+    ::std::mem::size_of::<_krb5_cred_enc_part>(),
+    40usize,
+    concat!("Size of: ", stringify!(_krb5_cred_enc_part))
+  );
+  assert_eq!(
+    ::std::mem::align_of::<_krb5_cred_enc_part>(),
+    8usize,
+    concat!("Alignment of ", stringify!(_krb5_cred_enc_part))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).magic) as usize - ptr as usize },
+    0usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_cred_enc_part),
+      "::",
+      stringify!(magic)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).nonce) as usize - ptr as usize },
+    4usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_cred_enc_part),
+      "::",
+      stringify!(nonce)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).timestamp) as usize - ptr as usize },
+    8usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_cred_enc_part),
+      "::",
+      stringify!(timestamp)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).usec) as usize - ptr as usize },
+    12usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_cred_enc_part),
+      "::",
+      stringify!(usec)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).s_address) as usize - ptr as usize },
+    16usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_cred_enc_part),
+      "::",
+      stringify!(s_address)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).r_address) as usize - ptr as usize },
+    24usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_cred_enc_part),
+      "::",
+      stringify!(r_address)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).ticket_info) as usize - ptr as usize },
+    32usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_cred_enc_part),
+      "::",
+      stringify!(ticket_info)
+    )
+  );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _krb5_cred_info {
+  pub magic: krb5_magic,
+  pub session: *mut krb5_keyblock,
+  pub client: krb5_principal,
+  pub server: krb5_principal,
+  pub flags: krb5_flags,
+  pub times: krb5_ticket_times,
+  pub caddrs: *mut *mut krb5_address,
+}
+#[test]
+fn bindgen_test_layout__krb5_cred_info() {
+  const UNINIT: ::std::mem::MaybeUninit<_krb5_cred_info> = ::std::mem::MaybeUninit::uninit();
+  let ptr = UNINIT.as_ptr();
+  assert_eq!(
+    ::std::mem::size_of::<_krb5_cred_info>(),
+    64usize,
+    concat!("Size of: ", stringify!(_krb5_cred_info))
+  );
+  assert_eq!(
+    ::std::mem::align_of::<_krb5_cred_info>(),
+    8usize,
+    concat!("Alignment of ", stringify!(_krb5_cred_info))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).magic) as usize - ptr as usize },
+    0usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_cred_info),
+      "::",
+      stringify!(magic)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).session) as usize - ptr as usize },
+    8usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_cred_info),
+      "::",
+      stringify!(session)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).client) as usize - ptr as usize },
+    16usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_cred_info),
+      "::",
+      stringify!(client)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).server) as usize - ptr as usize },
+    24usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_cred_info),
+      "::",
+      stringify!(server)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).flags) as usize - ptr as usize },
+    32usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_cred_info),
+      "::",
+      stringify!(flags)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).times) as usize - ptr as usize },
+    36usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_cred_info),
+      "::",
+      stringify!(times)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).caddrs) as usize - ptr as usize },
+    56usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_cred_info),
+      "::",
+      stringify!(caddrs)
+    )
+  );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _krb5_creds {
+  pub magic: krb5_magic,
+  pub client: krb5_principal,
+  pub server: krb5_principal,
+  pub keyblock: krb5_keyblock,
+  pub times: krb5_ticket_times,
+  pub is_skey: krb5_boolean,
+  pub ticket_flags: krb5_flags,
+  pub addresses: *mut *mut krb5_address,
+  pub ticket: krb5_data,
+  pub second_ticket: krb5_data,
+  pub authdata: *mut *mut krb5_authdata,
+}
+#[test]
+fn bindgen_test_layout__krb5_creds() {
+  const UNINIT: ::std::mem::MaybeUninit<_krb5_creds> = ::std::mem::MaybeUninit::uninit();
+  let ptr = UNINIT.as_ptr();
+  assert_eq!(
+    ::std::mem::size_of::<_krb5_creds>(),
+    120usize,
+    concat!("Size of: ", stringify!(_krb5_creds))
+  );
+  assert_eq!(
+    ::std::mem::align_of::<_krb5_creds>(),
+    8usize,
+    concat!("Alignment of ", stringify!(_krb5_creds))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).magic) as usize - ptr as usize },
+    0usize,
+    concat!("Offset of field: ", stringify!(_krb5_creds), "::", stringify!(magic))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).client) as usize - ptr as usize },
+    8usize,
+    concat!("Offset of field: ", stringify!(_krb5_creds), "::", stringify!(client))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).server) as usize - ptr as usize },
+    16usize,
+    concat!("Offset of field: ", stringify!(_krb5_creds), "::", stringify!(server))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).keyblock) as usize - ptr as usize },
+    24usize,
+    concat!("Offset of field: ", stringify!(_krb5_creds), "::", stringify!(keyblock))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).times) as usize - ptr as usize },
+    48usize,
+    concat!("Offset of field: ", stringify!(_krb5_creds), "::", stringify!(times))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).is_skey) as usize - ptr as usize },
+    64usize,
+    concat!("Offset of field: ", stringify!(_krb5_creds), "::", stringify!(is_skey))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).ticket_flags) as usize - ptr as usize },
+    68usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_creds),
+      "::",
+      stringify!(ticket_flags)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).addresses) as usize - ptr as usize },
+    72usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_creds),
+      "::",
+      stringify!(addresses)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).ticket) as usize - ptr as usize },
+    80usize,
+    concat!("Offset of field: ", stringify!(_krb5_creds), "::", stringify!(ticket))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).second_ticket) as usize - ptr as usize },
+    96usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_creds),
+      "::",
+      stringify!(second_ticket)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).authdata) as usize - ptr as usize },
+    112usize,
+    concat!("Offset of field: ", stringify!(_krb5_creds), "::", stringify!(authdata))
+  );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _krb5_crypto_iov {
+  pub flags: krb5_cryptotype,
+  pub data: krb5_data,
+}
+#[test]
+fn bindgen_test_layout__krb5_crypto_iov() {
+  const UNINIT: ::std::mem::MaybeUninit<_krb5_crypto_iov> = ::std::mem::MaybeUninit::uninit();
+  let ptr = UNINIT.as_ptr();
+  assert_eq!(
+    ::std::mem::size_of::<_krb5_crypto_iov>(),
+    24usize,
+    concat!("Size of: ", stringify!(_krb5_crypto_iov))
+  );
+  assert_eq!(
+    ::std::mem::align_of::<_krb5_crypto_iov>(),
+    8usize,
+    concat!("Alignment of ", stringify!(_krb5_crypto_iov))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).flags) as usize - ptr as usize },
+    0usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_crypto_iov),
+      "::",
+      stringify!(flags)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).data) as usize - ptr as usize },
+    8usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_crypto_iov),
+      "::",
+      stringify!(data)
+    )
+  );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _krb5_cryptosystem_entry {
+  _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _krb5_data {
+  pub magic: krb5_magic,
+  pub length: ::std::os::raw::c_uint,
+  pub data: *mut ::std::os::raw::c_char,
+}
+#[test]
+fn bindgen_test_layout__krb5_data() {
+  const UNINIT: ::std::mem::MaybeUninit<_krb5_data> = ::std::mem::MaybeUninit::uninit();
+  let ptr = UNINIT.as_ptr();
+  assert_eq!(
+    ::std::mem::size_of::<_krb5_data>(),
+    16usize,
+    concat!("Size of: ", stringify!(_krb5_data))
+  );
+  assert_eq!(
+    ::std::mem::align_of::<_krb5_data>(),
+    8usize,
+    concat!("Alignment of ", stringify!(_krb5_data))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).magic) as usize - ptr as usize },
+    0usize,
+    concat!("Offset of field: ", stringify!(_krb5_data), "::", stringify!(magic))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).length) as usize - ptr as usize },
+    4usize,
+    concat!("Offset of field: ", stringify!(_krb5_data), "::", stringify!(length))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).data) as usize - ptr as usize },
+    8usize,
+    concat!("Offset of field: ", stringify!(_krb5_data), "::", stringify!(data))
+  );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _krb5_enc_data {
+  pub magic: krb5_magic,
+  pub enctype: krb5_enctype,
+  pub kvno: krb5_kvno,
+  pub ciphertext: krb5_data,
+}
+#[test]
+fn bindgen_test_layout__krb5_enc_data() {
+  const UNINIT: ::std::mem::MaybeUninit<_krb5_enc_data> = ::std::mem::MaybeUninit::uninit();
+  let ptr = UNINIT.as_ptr();
+  assert_eq!(
+    ::std::mem::size_of::<_krb5_enc_data>(),
+    32usize,
+    concat!("Size of: ", stringify!(_krb5_enc_data))
+  );
+  assert_eq!(
+    ::std::mem::align_of::<_krb5_enc_data>(),
+    8usize,
+    concat!("Alignment of ", stringify!(_krb5_enc_data))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).magic) as usize - ptr as usize },
+    0usize,
+    concat!("Offset of field: ", stringify!(_krb5_enc_data), "::", stringify!(magic))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).enctype) as usize - ptr as usize },
+    4usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_enc_data),
+      "::",
+      stringify!(enctype)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).kvno) as usize - ptr as usize },
+    8usize,
+    concat!("Offset of field: ", stringify!(_krb5_enc_data), "::", stringify!(kvno))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).ciphertext) as usize - ptr as usize },
+    16usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_enc_data),
+      "::",
+      stringify!(ciphertext)
+    )
+  );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _krb5_enc_kdc_rep_part {
+  pub magic: krb5_magic,
+  pub msg_type: krb5_msgtype,
+  pub session: *mut krb5_keyblock,
+  pub last_req: *mut *mut krb5_last_req_entry,
+  pub nonce: krb5_int32,
+  pub key_exp: krb5_timestamp,
+  pub flags: krb5_flags,
+  pub times: krb5_ticket_times,
+  pub server: krb5_principal,
+  pub caddrs: *mut *mut krb5_address,
+  pub enc_padata: *mut *mut krb5_pa_data,
+}
+#[test]
+fn bindgen_test_layout__krb5_enc_kdc_rep_part() {
+  const UNINIT: ::std::mem::MaybeUninit<_krb5_enc_kdc_rep_part> = ::std::mem::MaybeUninit::uninit();
+  let ptr = UNINIT.as_ptr();
+  assert_eq!(
+    ::std::mem::size_of::<_krb5_enc_kdc_rep_part>(),
+    80usize,
+    concat!("Size of: ", stringify!(_krb5_enc_kdc_rep_part))
+  );
+  assert_eq!(
+    ::std::mem::align_of::<_krb5_enc_kdc_rep_part>(),
+    8usize,
+    concat!("Alignment of ", stringify!(_krb5_enc_kdc_rep_part))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).magic) as usize - ptr as usize },
+    0usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_enc_kdc_rep_part),
+      "::",
+      stringify!(magic)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).msg_type) as usize - ptr as usize },
+    4usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_enc_kdc_rep_part),
+      "::",
+      stringify!(msg_type)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).session) as usize - ptr as usize },
+    8usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_enc_kdc_rep_part),
+      "::",
+      stringify!(session)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).last_req) as usize - ptr as usize },
+    16usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_enc_kdc_rep_part),
+      "::",
+      stringify!(last_req)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).nonce) as usize - ptr as usize },
+    24usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_enc_kdc_rep_part),
+      "::",
+      stringify!(nonce)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).key_exp) as usize - ptr as usize },
+    28usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_enc_kdc_rep_part),
+      "::",
+      stringify!(key_exp)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).flags) as usize - ptr as usize },
+    32usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_enc_kdc_rep_part),
+      "::",
+      stringify!(flags)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).times) as usize - ptr as usize },
+    36usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_enc_kdc_rep_part),
+      "::",
+      stringify!(times)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).server) as usize - ptr as usize },
+    56usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_enc_kdc_rep_part),
+      "::",
+      stringify!(server)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).caddrs) as usize - ptr as usize },
+    64usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_enc_kdc_rep_part),
+      "::",
+      stringify!(caddrs)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).enc_padata) as usize - ptr as usize },
+    72usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_enc_kdc_rep_part),
+      "::",
+      stringify!(enc_padata)
+    )
+  );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _krb5_enc_tkt_part {
+  pub magic: krb5_magic,
+  pub flags: krb5_flags,
+  pub session: *mut krb5_keyblock,
+  pub client: krb5_principal,
+  pub transited: krb5_transited,
+  pub times: krb5_ticket_times,
+  pub caddrs: *mut *mut krb5_address,
+  pub authorization_data: *mut *mut krb5_authdata,
+}
+#[test]
+fn bindgen_test_layout__krb5_enc_tkt_part() {
+  const UNINIT: ::std::mem::MaybeUninit<_krb5_enc_tkt_part> = ::std::mem::MaybeUninit::uninit();
+  let ptr = UNINIT.as_ptr();
+  assert_eq!(
+    ::std::mem::size_of::<_krb5_enc_tkt_part>(),
+    80usize,
+    concat!("Size of: ", stringify!(_krb5_enc_tkt_part))
+  );
+  assert_eq!(
+    ::std::mem::align_of::<_krb5_enc_tkt_part>(),
+    8usize,
+    concat!("Alignment of ", stringify!(_krb5_enc_tkt_part))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).magic) as usize - ptr as usize },
+    0usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_enc_tkt_part),
+      "::",
+      stringify!(magic)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).flags) as usize - ptr as usize },
+    4usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_enc_tkt_part),
+      "::",
+      stringify!(flags)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).session) as usize - ptr as usize },
+    8usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_enc_tkt_part),
+      "::",
+      stringify!(session)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).client) as usize - ptr as usize },
+    16usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_enc_tkt_part),
+      "::",
+      stringify!(client)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).transited) as usize - ptr as usize },
+    24usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_enc_tkt_part),
+      "::",
+      stringify!(transited)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).times) as usize - ptr as usize },
+    48usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_enc_tkt_part),
+      "::",
+      stringify!(times)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).caddrs) as usize - ptr as usize },
+    64usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_enc_tkt_part),
+      "::",
+      stringify!(caddrs)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).authorization_data) as usize - ptr as usize },
+    72usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_enc_tkt_part),
+      "::",
+      stringify!(authorization_data)
+    )
+  );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _krb5_encrypt_block {
+  pub magic: krb5_magic,
+  pub crypto_entry: krb5_enctype,
+  pub key: *mut krb5_keyblock,
+}
+#[test]
+fn bindgen_test_layout__krb5_encrypt_block() {
+  const UNINIT: ::std::mem::MaybeUninit<_krb5_encrypt_block> = ::std::mem::MaybeUninit::uninit();
+  let ptr = UNINIT.as_ptr();
+  assert_eq!(
+    ::std::mem::size_of::<_krb5_encrypt_block>(),
+    16usize,
+    concat!("Size of: ", stringify!(_krb5_encrypt_block))
+  );
+  assert_eq!(
+    ::std::mem::align_of::<_krb5_encrypt_block>(),
+    8usize,
+    concat!("Alignment of ", stringify!(_krb5_encrypt_block))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).magic) as usize - ptr as usize },
+    0usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_encrypt_block),
+      "::",
+      stringify!(magic)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).crypto_entry) as usize - ptr as usize },
+    4usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_encrypt_block),
+      "::",
+      stringify!(crypto_entry)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).key) as usize - ptr as usize },
+    8usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_encrypt_block),
+      "::",
+      stringify!(key)
+    )
+  );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _krb5_error {
+  pub magic: krb5_magic,
+  pub ctime: krb5_timestamp,
+  pub cusec: krb5_int32,
+  pub susec: krb5_int32,
+  pub stime: krb5_timestamp,
+  pub error: krb5_ui_4,
+  pub client: krb5_principal,
+  pub server: krb5_principal,
+  pub text: krb5_data,
+  pub e_data: krb5_data,
+}
+#[test]
+fn bindgen_test_layout__krb5_error() {
+  const UNINIT: ::std::mem::MaybeUninit<_krb5_error> = ::std::mem::MaybeUninit::uninit();
+  let ptr = UNINIT.as_ptr();
+  assert_eq!(
+    ::std::mem::size_of::<_krb5_error>(),
+    72usize,
+    concat!("Size of: ", stringify!(_krb5_error))
+  );
+  assert_eq!(
+    ::std::mem::align_of::<_krb5_error>(),
+    8usize,
+    concat!("Alignment of ", stringify!(_krb5_error))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).magic) as usize - ptr as usize },
+    0usize,
+    concat!("Offset of field: ", stringify!(_krb5_error), "::", stringify!(magic))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).ctime) as usize - ptr as usize },
+    4usize,
+    concat!("Offset of field: ", stringify!(_krb5_error), "::", stringify!(ctime))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).cusec) as usize - ptr as usize },
+    8usize,
+    concat!("Offset of field: ", stringify!(_krb5_error), "::", stringify!(cusec))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).susec) as usize - ptr as usize },
+    12usize,
+    concat!("Offset of field: ", stringify!(_krb5_error), "::", stringify!(susec))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).stime) as usize - ptr as usize },
+    16usize,
+    concat!("Offset of field: ", stringify!(_krb5_error), "::", stringify!(stime))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).error) as usize - ptr as usize },
+    20usize,
+    concat!("Offset of field: ", stringify!(_krb5_error), "::", stringify!(error))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).client) as usize - ptr as usize },
+    24usize,
+    concat!("Offset of field: ", stringify!(_krb5_error), "::", stringify!(client))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).server) as usize - ptr as usize },
+    32usize,
+    concat!("Offset of field: ", stringify!(_krb5_error), "::", stringify!(server))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).text) as usize - ptr as usize },
+    40usize,
+    concat!("Offset of field: ", stringify!(_krb5_error), "::", stringify!(text))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).e_data) as usize - ptr as usize },
+    56usize,
+    concat!("Offset of field: ", stringify!(_krb5_error), "::", stringify!(e_data))
+  );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _krb5_get_init_creds_opt {
+  pub flags: krb5_flags,
+  pub tkt_life: krb5_deltat,
+  pub renew_life: krb5_deltat,
+  pub forwardable: ::std::os::raw::c_int,
+  pub proxiable: ::std::os::raw::c_int,
+  pub etype_list: *mut krb5_enctype,
+  pub etype_list_length: ::std::os::raw::c_int,
+  pub address_list: *mut *mut krb5_address,
+  pub preauth_list: *mut krb5_preauthtype,
+  pub preauth_list_length: ::std::os::raw::c_int,
+  pub salt: *mut krb5_data,
+}
+#[test]
+fn bindgen_test_layout__krb5_get_init_creds_opt() {
+  const UNINIT: ::std::mem::MaybeUninit<_krb5_get_init_creds_opt> = ::std::mem::MaybeUninit::uninit();
+  let ptr = UNINIT.as_ptr();
+  assert_eq!(
+    ::std::mem::size_of::<_krb5_get_init_creds_opt>(),
+    72usize,
+    concat!("Size of: ", stringify!(_krb5_get_init_creds_opt))
+  );
+  assert_eq!(
+    ::std::mem::align_of::<_krb5_get_init_creds_opt>(),
+    8usize,
+    concat!("Alignment of ", stringify!(_krb5_get_init_creds_opt))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).flags) as usize - ptr as usize },
+    0usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_get_init_creds_opt),
+      "::",
+      stringify!(flags)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).tkt_life) as usize - ptr as usize },
+    4usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_get_init_creds_opt),
+      "::",
+      stringify!(tkt_life)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).renew_life) as usize - ptr as usize },
+    8usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_get_init_creds_opt),
+      "::",
+      stringify!(renew_life)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).forwardable) as usize - ptr as usize },
+    12usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_get_init_creds_opt),
+      "::",
+      stringify!(forwardable)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).proxiable) as usize - ptr as usize },
+    16usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_get_init_creds_opt),
+      "::",
+      stringify!(proxiable)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).etype_list) as usize - ptr as usize },
+    24usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_get_init_creds_opt),
+      "::",
+      stringify!(etype_list)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).etype_list_length) as usize - ptr as usize },
+    32usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_get_init_creds_opt),
+      "::",
+      stringify!(etype_list_length)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).address_list) as usize - ptr as usize },
+    40usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_get_init_creds_opt),
+      "::",
+      stringify!(address_list)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).preauth_list) as usize - ptr as usize },
+    48usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_get_init_creds_opt),
+      "::",
+      stringify!(preauth_list)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).preauth_list_length) as usize - ptr as usize },
+    56usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_get_init_creds_opt),
+      "::",
+      stringify!(preauth_list_length)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).salt) as usize - ptr as usize },
+    64usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_get_init_creds_opt),
+      "::",
+      stringify!(salt)
+    )
+  );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _krb5_gic_opt_pa_data {
+  pub attr: *mut ::std::os::raw::c_char,
+  pub value: *mut ::std::os::raw::c_char,
+}
+#[test]
+fn bindgen_test_layout__krb5_gic_opt_pa_data() {
+  const UNINIT: ::std::mem::MaybeUninit<_krb5_gic_opt_pa_data> = ::std::mem::MaybeUninit::uninit();
+  let ptr = UNINIT.as_ptr();
+  assert_eq!(
+    ::std::mem::size_of::<_krb5_gic_opt_pa_data>(),
+    16usize,
+    concat!("Size of: ", stringify!(_krb5_gic_opt_pa_data))
+  );
+  assert_eq!(
+    ::std::mem::align_of::<_krb5_gic_opt_pa_data>(),
+    8usize,
+    concat!("Alignment of ", stringify!(_krb5_gic_opt_pa_data))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).attr) as usize - ptr as usize },
+    0usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_gic_opt_pa_data),
+      "::",
+      stringify!(attr)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).value) as usize - ptr as usize },
+    8usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_gic_opt_pa_data),
+      "::",
+      stringify!(value)
+    )
+  );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _krb5_init_creds_context {
+  _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _krb5_kdc_rep {
+  pub magic: krb5_magic,
+  pub msg_type: krb5_msgtype,
+  pub padata: *mut *mut krb5_pa_data,
+  pub client: krb5_principal,
+  pub ticket: *mut krb5_ticket,
+  pub enc_part: krb5_enc_data,
+  pub enc_part2: *mut krb5_enc_kdc_rep_part,
+}
+#[test]
+fn bindgen_test_layout__krb5_kdc_rep() {
+  const UNINIT: ::std::mem::MaybeUninit<_krb5_kdc_rep> = ::std::mem::MaybeUninit::uninit();
+  let ptr = UNINIT.as_ptr();
+  assert_eq!(
+    ::std::mem::size_of::<_krb5_kdc_rep>(),
+    72usize,
+    concat!("Size of: ", stringify!(_krb5_kdc_rep))
+  );
+  assert_eq!(
+    ::std::mem::align_of::<_krb5_kdc_rep>(),
+    8usize,
+    concat!("Alignment of ", stringify!(_krb5_kdc_rep))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).magic) as usize - ptr as usize },
+    0usize,
+    concat!("Offset of field: ", stringify!(_krb5_kdc_rep), "::", stringify!(magic))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).msg_type) as usize - ptr as usize },
+    4usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_kdc_rep),
+      "::",
+      stringify!(msg_type)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).padata) as usize - ptr as usize },
+    8usize,
+    concat!("Offset of field: ", stringify!(_krb5_kdc_rep), "::", stringify!(padata))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).client) as usize - ptr as usize },
+    16usize,
+    concat!("Offset of field: ", stringify!(_krb5_kdc_rep), "::", stringify!(client))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).ticket) as usize - ptr as usize },
+    24usize,
+    concat!("Offset of field: ", stringify!(_krb5_kdc_rep), "::", stringify!(ticket))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).enc_part) as usize - ptr as usize },
+    32usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_kdc_rep),
+      "::",
+      stringify!(enc_part)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).enc_part2) as usize - ptr as usize },
+    64usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_kdc_rep),
+      "::",
+      stringify!(enc_part2)
+    )
+  );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _krb5_kdc_req {
+  pub magic: krb5_magic,
+  pub msg_type: krb5_msgtype,
+  pub padata: *mut *mut krb5_pa_data,
+  pub kdc_options: krb5_flags,
+  pub client: krb5_principal,
+  pub server: krb5_principal,
+  pub from: krb5_timestamp,
+  pub till: krb5_timestamp,
+  pub rtime: krb5_timestamp,
+  pub nonce: krb5_int32,
+  pub nktypes: ::std::os::raw::c_int,
+  pub ktype: *mut krb5_enctype,
+  pub addresses: *mut *mut krb5_address,
+  pub authorization_data: krb5_enc_data,
+  pub unenc_authdata: *mut *mut krb5_authdata,
+  pub second_ticket: *mut *mut krb5_ticket,
+}
+#[test]
+fn bindgen_test_layout__krb5_kdc_req() {
+  const UNINIT: ::std::mem::MaybeUninit<_krb5_kdc_req> = ::std::mem::MaybeUninit::uninit();
+  let ptr = UNINIT.as_ptr();
+  assert_eq!(
+    ::std::mem::size_of::<_krb5_kdc_req>(),
+    128usize,
+    concat!("Size of: ", stringify!(_krb5_kdc_req))
+  );
+  assert_eq!(
+    ::std::mem::align_of::<_krb5_kdc_req>(),
+    8usize,
+    concat!("Alignment of ", stringify!(_krb5_kdc_req))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).magic) as usize - ptr as usize },
+    0usize,
+    concat!("Offset of field: ", stringify!(_krb5_kdc_req), "::", stringify!(magic))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).msg_type) as usize - ptr as usize },
+    4usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_kdc_req),
+      "::",
+      stringify!(msg_type)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).padata) as usize - ptr as usize },
+    8usize,
+    concat!("Offset of field: ", stringify!(_krb5_kdc_req), "::", stringify!(padata))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).kdc_options) as usize - ptr as usize },
+    16usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_kdc_req),
+      "::",
+      stringify!(kdc_options)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).client) as usize - ptr as usize },
+    24usize,
+    concat!("Offset of field: ", stringify!(_krb5_kdc_req), "::", stringify!(client))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).server) as usize - ptr as usize },
+    32usize,
+    concat!("Offset of field: ", stringify!(_krb5_kdc_req), "::", stringify!(server))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).from) as usize - ptr as usize },
+    40usize,
+    concat!("Offset of field: ", stringify!(_krb5_kdc_req), "::", stringify!(from))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).till) as usize - ptr as usize },
+    44usize,
+    concat!("Offset of field: ", stringify!(_krb5_kdc_req), "::", stringify!(till))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).rtime) as usize - ptr as usize },
+    48usize,
+    concat!("Offset of field: ", stringify!(_krb5_kdc_req), "::", stringify!(rtime))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).nonce) as usize - ptr as usize },
+    52usize,
+    concat!("Offset of field: ", stringify!(_krb5_kdc_req), "::", stringify!(nonce))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).nktypes) as usize - ptr as usize },
+    56usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_kdc_req),
+      "::",
+      stringify!(nktypes)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).ktype) as usize - ptr as usize },
+    64usize,
+    concat!("Offset of field: ", stringify!(_krb5_kdc_req), "::", stringify!(ktype))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).addresses) as usize - ptr as usize },
+    72usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_kdc_req),
+      "::",
+      stringify!(addresses)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).authorization_data) as usize - ptr as usize },
+    80usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_kdc_req),
+      "::",
+      stringify!(authorization_data)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).unenc_authdata) as usize - ptr as usize },
+    112usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_kdc_req),
+      "::",
+      stringify!(unenc_authdata)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).second_ticket) as usize - ptr as usize },
+    120usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_kdc_req),
+      "::",
+      stringify!(second_ticket)
+    )
+  );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _krb5_keyblock {
+  pub magic: krb5_magic,
+  pub enctype: krb5_enctype,
+  pub length: ::std::os::raw::c_uint,
+  pub contents: *mut krb5_octet,
+}
+#[test]
+fn bindgen_test_layout__krb5_keyblock() {
+  const UNINIT: ::std::mem::MaybeUninit<_krb5_keyblock> = ::std::mem::MaybeUninit::uninit();
+  let ptr = UNINIT.as_ptr();
+  assert_eq!(
+    ::std::mem::size_of::<_krb5_keyblock>(),
+    24usize,
+    concat!("Size of: ", stringify!(_krb5_keyblock))
+  );
+  assert_eq!(
+    ::std::mem::align_of::<_krb5_keyblock>(),
+    8usize,
+    concat!("Alignment of ", stringify!(_krb5_keyblock))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).magic) as usize - ptr as usize },
+    0usize,
+    concat!("Offset of field: ", stringify!(_krb5_keyblock), "::", stringify!(magic))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).enctype) as usize - ptr as usize },
+    4usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_keyblock),
+      "::",
+      stringify!(enctype)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).length) as usize - ptr as usize },
+    8usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_keyblock),
+      "::",
+      stringify!(length)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).contents) as usize - ptr as usize },
+    16usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_keyblock),
+      "::",
+      stringify!(contents)
+    )
+  );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _krb5_kt {
+  _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _krb5_last_req_entry {
+  pub magic: krb5_magic,
+  pub lr_type: krb5_int32,
+  pub value: krb5_timestamp,
+}
+#[test]
+fn bindgen_test_layout__krb5_last_req_entry() {
+  const UNINIT: ::std::mem::MaybeUninit<_krb5_last_req_entry> = ::std::mem::MaybeUninit::uninit();
+  let ptr = UNINIT.as_ptr();
+  assert_eq!(
+    ::std::mem::size_of::<_krb5_last_req_entry>(),
+    12usize,
+    concat!("Size of: ", stringify!(_krb5_last_req_entry))
+  );
+  assert_eq!(
+    ::std::mem::align_of::<_krb5_last_req_entry>(),
+    4usize,
+    concat!("Alignment of ", stringify!(_krb5_last_req_entry))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).magic) as usize - ptr as usize },
+    0usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_last_req_entry),
+      "::",
+      stringify!(magic)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).lr_type) as usize - ptr as usize },
+    4usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_last_req_entry),
+      "::",
+      stringify!(lr_type)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).value) as usize - ptr as usize },
+    8usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_last_req_entry),
+      "::",
+      stringify!(value)
+    )
+  );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _krb5_octet_data {
+  pub magic: krb5_magic,
+  pub length: ::std::os::raw::c_uint,
+  pub data: *mut krb5_octet,
+}
+#[test]
+fn bindgen_test_layout__krb5_octet_data() {
+  const UNINIT: ::std::mem::MaybeUninit<_krb5_octet_data> = ::std::mem::MaybeUninit::uninit();
+  let ptr = UNINIT.as_ptr();
+  assert_eq!(
+    ::std::mem::size_of::<_krb5_octet_data>(),
+    16usize,
+    concat!("Size of: ", stringify!(_krb5_octet_data))
+  );
+  assert_eq!(
+    ::std::mem::align_of::<_krb5_octet_data>(),
+    8usize,
+    concat!("Alignment of ", stringify!(_krb5_octet_data))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).magic) as usize - ptr as usize },
+    0usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_octet_data),
+      "::",
+      stringify!(magic)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).length) as usize - ptr as usize },
+    4usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_octet_data),
+      "::",
+      stringify!(length)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).data) as usize - ptr as usize },
+    8usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_octet_data),
+      "::",
+      stringify!(data)
+    )
+  );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _krb5_pa_data {
+  pub magic: krb5_magic,
+  pub pa_type: krb5_preauthtype,
+  pub length: ::std::os::raw::c_uint,
+  pub contents: *mut krb5_octet,
+}
+#[test]
+fn bindgen_test_layout__krb5_pa_data() {
+  const UNINIT: ::std::mem::MaybeUninit<_krb5_pa_data> = ::std::mem::MaybeUninit::uninit();
+  let ptr = UNINIT.as_ptr();
+  assert_eq!(
+    ::std::mem::size_of::<_krb5_pa_data>(),
+    24usize,
+    concat!("Size of: ", stringify!(_krb5_pa_data))
+  );
+  assert_eq!(
+    ::std::mem::align_of::<_krb5_pa_data>(),
+    8usize,
+    concat!("Alignment of ", stringify!(_krb5_pa_data))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).magic) as usize - ptr as usize },
+    0usize,
+    concat!("Offset of field: ", stringify!(_krb5_pa_data), "::", stringify!(magic))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).pa_type) as usize - ptr as usize },
+    4usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_pa_data),
+      "::",
+      stringify!(pa_type)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).length) as usize - ptr as usize },
+    8usize,
+    concat!("Offset of field: ", stringify!(_krb5_pa_data), "::", stringify!(length))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).contents) as usize - ptr as usize },
+    16usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_pa_data),
+      "::",
+      stringify!(contents)
+    )
+  );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _krb5_pa_pac_req {
+  pub include_pac: krb5_boolean,
+}
+#[test]
+fn bindgen_test_layout__krb5_pa_pac_req() {
+  const UNINIT: ::std::mem::MaybeUninit<_krb5_pa_pac_req> = ::std::mem::MaybeUninit::uninit();
+  let ptr = UNINIT.as_ptr();
+  assert_eq!(
+    ::std::mem::size_of::<_krb5_pa_pac_req>(),
+    4usize,
+    concat!("Size of: ", stringify!(_krb5_pa_pac_req))
+  );
+  assert_eq!(
+    ::std::mem::align_of::<_krb5_pa_pac_req>(),
+    4usize,
+    concat!("Alignment of ", stringify!(_krb5_pa_pac_req))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).include_pac) as usize - ptr as usize },
+    0usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_pa_pac_req),
+      "::",
+      stringify!(include_pac)
+    )
+  );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _krb5_pa_server_referral_data {
+  pub referred_realm: *mut krb5_data,
+  pub true_principal_name: krb5_principal,
+  pub requested_principal_name: krb5_principal,
+  pub referral_valid_until: krb5_timestamp,
+  pub rep_cksum: krb5_checksum,
+}
+#[test]
+fn bindgen_test_layout__krb5_pa_server_referral_data() {
+  const UNINIT: ::std::mem::MaybeUninit<_krb5_pa_server_referral_data> = ::std::mem::MaybeUninit::uninit();
+  let ptr = UNINIT.as_ptr();
+  assert_eq!(
+    ::std::mem::size_of::<_krb5_pa_server_referral_data>(),
+    56usize,
+    concat!("Size of: ", stringify!(_krb5_pa_server_referral_data))
+  );
+  assert_eq!(
+    ::std::mem::align_of::<_krb5_pa_server_referral_data>(),
+    8usize,
+    concat!("Alignment of ", stringify!(_krb5_pa_server_referral_data))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).referred_realm) as usize - ptr as usize },
+    0usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_pa_server_referral_data),
+      "::",
+      stringify!(referred_realm)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).true_principal_name) as usize - ptr as usize },
+    8usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_pa_server_referral_data),
+      "::",
+      stringify!(true_principal_name)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).requested_principal_name) as usize - ptr as usize },
+    16usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_pa_server_referral_data),
+      "::",
+      stringify!(requested_principal_name)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).referral_valid_until) as usize - ptr as usize },
+    24usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_pa_server_referral_data),
+      "::",
+      stringify!(referral_valid_until)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).rep_cksum) as usize - ptr as usize },
+    32usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_pa_server_referral_data),
+      "::",
+      stringify!(rep_cksum)
+    )
+  );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _krb5_pa_svr_referral_data {
+  pub principal: krb5_principal,
+}
+#[test]
+fn bindgen_test_layout__krb5_pa_svr_referral_data() {
+  const UNINIT: ::std::mem::MaybeUninit<_krb5_pa_svr_referral_data> = ::std::mem::MaybeUninit::uninit();
+  let ptr = UNINIT.as_ptr();
+  assert_eq!(
+    ::std::mem::size_of::<_krb5_pa_svr_referral_data>(),
+    8usize,
+    concat!("Size of: ", stringify!(_krb5_pa_svr_referral_data))
+  );
+  assert_eq!(
+    ::std::mem::align_of::<_krb5_pa_svr_referral_data>(),
+    8usize,
+    concat!("Alignment of ", stringify!(_krb5_pa_svr_referral_data))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).principal) as usize - ptr as usize },
+    0usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_pa_svr_referral_data),
+      "::",
+      stringify!(principal)
+    )
+  );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _krb5_prompt {
+  pub prompt: *mut ::std::os::raw::c_char,
+  pub hidden: ::std::os::raw::c_int,
+  pub reply: *mut krb5_data,
+}
+#[test]
+fn bindgen_test_layout__krb5_prompt() {
+  const UNINIT: ::std::mem::MaybeUninit<_krb5_prompt> = ::std::mem::MaybeUninit::uninit();
+  let ptr = UNINIT.as_ptr();
+  assert_eq!(
+    ::std::mem::size_of::<_krb5_prompt>(),
+    24usize,
+    concat!("Size of: ", stringify!(_krb5_prompt))
+  );
+  assert_eq!(
+    ::std::mem::align_of::<_krb5_prompt>(),
+    8usize,
+    concat!("Alignment of ", stringify!(_krb5_prompt))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).prompt) as usize - ptr as usize },
+    0usize,
+    concat!("Offset of field: ", stringify!(_krb5_prompt), "::", stringify!(prompt))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).hidden) as usize - ptr as usize },
+    8usize,
+    concat!("Offset of field: ", stringify!(_krb5_prompt), "::", stringify!(hidden))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).reply) as usize - ptr as usize },
+    16usize,
+    concat!("Offset of field: ", stringify!(_krb5_prompt), "::", stringify!(reply))
+  );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _krb5_pwd_data {
+  pub magic: krb5_magic,
+  pub sequence_count: ::std::os::raw::c_int,
+  pub element: *mut *mut passwd_phrase_element,
+}
+#[test]
+fn bindgen_test_layout__krb5_pwd_data() {
+  const UNINIT: ::std::mem::MaybeUninit<_krb5_pwd_data> = ::std::mem::MaybeUninit::uninit();
+  let ptr = UNINIT.as_ptr();
+  assert_eq!(
+    ::std::mem::size_of::<_krb5_pwd_data>(),
+    16usize,
+    concat!("Size of: ", stringify!(_krb5_pwd_data))
+  );
+  assert_eq!(
+    ::std::mem::align_of::<_krb5_pwd_data>(),
+    8usize,
+    concat!("Alignment of ", stringify!(_krb5_pwd_data))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).magic) as usize - ptr as usize },
+    0usize,
+    concat!("Offset of field: ", stringify!(_krb5_pwd_data), "::", stringify!(magic))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).sequence_count) as usize - ptr as usize },
+    4usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_pwd_data),
+      "::",
+      stringify!(sequence_count)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).element) as usize - ptr as usize },
+    8usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_pwd_data),
+      "::",
+      stringify!(element)
+    )
+  );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _krb5_responder_otp_challenge {
+  pub service: *mut ::std::os::raw::c_char,
+  pub tokeninfo: *mut *mut krb5_responder_otp_tokeninfo,
+}
+#[test]
+fn bindgen_test_layout__krb5_responder_otp_challenge() {
+  const UNINIT: ::std::mem::MaybeUninit<_krb5_responder_otp_challenge> = ::std::mem::MaybeUninit::uninit();
+  let ptr = UNINIT.as_ptr();
+  assert_eq!(
+    ::std::mem::size_of::<_krb5_responder_otp_challenge>(),
+    16usize,
+    concat!("Size of: ", stringify!(_krb5_responder_otp_challenge))
+  );
+  assert_eq!(
+    ::std::mem::align_of::<_krb5_responder_otp_challenge>(),
+    8usize,
+    concat!("Alignment of ", stringify!(_krb5_responder_otp_challenge))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).service) as usize - ptr as usize },
+    0usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_responder_otp_challenge),
+      "::",
+      stringify!(service)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).tokeninfo) as usize - ptr as usize },
+    8usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_responder_otp_challenge),
+      "::",
+      stringify!(tokeninfo)
+    )
+  );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _krb5_responder_otp_tokeninfo {
+  pub flags: krb5_flags,
+  pub format: krb5_int32,
+  pub length: krb5_int32,
+  pub vendor: *mut ::std::os::raw::c_char,
+  pub challenge: *mut ::std::os::raw::c_char,
+  pub token_id: *mut ::std::os::raw::c_char,
+  pub alg_id: *mut ::std::os::raw::c_char,
+}
+#[test]
+fn bindgen_test_layout__krb5_responder_otp_tokeninfo() {
+  const UNINIT: ::std::mem::MaybeUninit<_krb5_responder_otp_tokeninfo> = ::std::mem::MaybeUninit::uninit();
+  let ptr = UNINIT.as_ptr();
+  assert_eq!(
+    ::std::mem::size_of::<_krb5_responder_otp_tokeninfo>(),
+    48usize,
+    concat!("Size of: ", stringify!(_krb5_responder_otp_tokeninfo))
+  );
+  assert_eq!(
+    ::std::mem::align_of::<_krb5_responder_otp_tokeninfo>(),
+    8usize,
+    concat!("Alignment of ", stringify!(_krb5_responder_otp_tokeninfo))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).flags) as usize - ptr as usize },
+    0usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_responder_otp_tokeninfo),
+      "::",
+      stringify!(flags)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).format) as usize - ptr as usize },
+    4usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_responder_otp_tokeninfo),
+      "::",
+      stringify!(format)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).length) as usize - ptr as usize },
+    8usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_responder_otp_tokeninfo),
+      "::",
+      stringify!(length)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).vendor) as usize - ptr as usize },
+    16usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_responder_otp_tokeninfo),
+      "::",
+      stringify!(vendor)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).challenge) as usize - ptr as usize },
+    24usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_responder_otp_tokeninfo),
+      "::",
+      stringify!(challenge)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).token_id) as usize - ptr as usize },
+    32usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_responder_otp_tokeninfo),
+      "::",
+      stringify!(token_id)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).alg_id) as usize - ptr as usize },
+    40usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_responder_otp_tokeninfo),
+      "::",
+      stringify!(alg_id)
+    )
+  );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _krb5_responder_pkinit_challenge {
+  pub identities: *mut *mut krb5_responder_pkinit_identity,
+}
+#[test]
+fn bindgen_test_layout__krb5_responder_pkinit_challenge() {
+  const UNINIT: ::std::mem::MaybeUninit<_krb5_responder_pkinit_challenge> = ::std::mem::MaybeUninit::uninit();
+  let ptr = UNINIT.as_ptr();
+  assert_eq!(
+    ::std::mem::size_of::<_krb5_responder_pkinit_challenge>(),
+    8usize,
+    concat!("Size of: ", stringify!(_krb5_responder_pkinit_challenge))
+  );
+  assert_eq!(
+    ::std::mem::align_of::<_krb5_responder_pkinit_challenge>(),
+    8usize,
+    concat!("Alignment of ", stringify!(_krb5_responder_pkinit_challenge))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).identities) as usize - ptr as usize },
+    0usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_responder_pkinit_challenge),
+      "::",
+      stringify!(identities)
+    )
+  );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _krb5_responder_pkinit_identity {
+  pub identity: *mut ::std::os::raw::c_char,
+  pub token_flags: krb5_int32,
+}
+#[test]
+fn bindgen_test_layout__krb5_responder_pkinit_identity() {
+  const UNINIT: ::std::mem::MaybeUninit<_krb5_responder_pkinit_identity> = ::std::mem::MaybeUninit::uninit();
+  let ptr = UNINIT.as_ptr();
+  assert_eq!(
+    ::std::mem::size_of::<_krb5_responder_pkinit_identity>(),
+    16usize,
+    concat!("Size of: ", stringify!(_krb5_responder_pkinit_identity))
+  );
+  assert_eq!(
+    ::std::mem::align_of::<_krb5_responder_pkinit_identity>(),
+    8usize,
+    concat!("Alignment of ", stringify!(_krb5_responder_pkinit_identity))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).identity) as usize - ptr as usize },
+    0usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_responder_pkinit_identity),
+      "::",
+      stringify!(identity)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).token_flags) as usize - ptr as usize },
+    8usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_responder_pkinit_identity),
+      "::",
+      stringify!(token_flags)
+    )
+  );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _krb5_response {
+  pub magic: krb5_magic,
+  pub message_type: krb5_octet,
+  pub response: krb5_data,
+  pub expected_nonce: krb5_int32,
+  pub request_time: krb5_timestamp,
+}
+#[test]
+fn bindgen_test_layout__krb5_response() {
+  const UNINIT: ::std::mem::MaybeUninit<_krb5_response> = ::std::mem::MaybeUninit::uninit();
+  let ptr = UNINIT.as_ptr();
+  assert_eq!(
+    ::std::mem::size_of::<_krb5_response>(),
+    32usize,
+    concat!("Size of: ", stringify!(_krb5_response))
+  );
+  assert_eq!(
+    ::std::mem::align_of::<_krb5_response>(),
+    8usize,
+    concat!("Alignment of ", stringify!(_krb5_response))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).magic) as usize - ptr as usize },
+    0usize,
+    concat!("Offset of field: ", stringify!(_krb5_response), "::", stringify!(magic))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).message_type) as usize - ptr as usize },
+    4usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_response),
+      "::",
+      stringify!(message_type)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).response) as usize - ptr as usize },
+    8usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_response),
+      "::",
+      stringify!(response)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).expected_nonce) as usize - ptr as usize },
+    24usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_response),
+      "::",
+      stringify!(expected_nonce)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).request_time) as usize - ptr as usize },
+    28usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_response),
+      "::",
+      stringify!(request_time)
+    )
+  );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _krb5_ticket {
+  pub magic: krb5_magic,
+  pub server: krb5_principal,
+  pub enc_part: krb5_enc_data,
+  pub enc_part2: *mut krb5_enc_tkt_part,
+}
+#[test]
+fn bindgen_test_layout__krb5_ticket() {
+  const UNINIT: ::std::mem::MaybeUninit<_krb5_ticket> = ::std::mem::MaybeUninit::uninit();
+  let ptr = UNINIT.as_ptr();
+  assert_eq!(
+    ::std::mem::size_of::<_krb5_ticket>(),
+    56usize,
+    concat!("Size of: ", stringify!(_krb5_ticket))
+  );
+  assert_eq!(
+    ::std::mem::align_of::<_krb5_ticket>(),
+    8usize,
+    concat!("Alignment of ", stringify!(_krb5_ticket))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).magic) as usize - ptr as usize },
+    0usize,
+    concat!("Offset of field: ", stringify!(_krb5_ticket), "::", stringify!(magic))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).server) as usize - ptr as usize },
+    8usize,
+    concat!("Offset of field: ", stringify!(_krb5_ticket), "::", stringify!(server))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).enc_part) as usize - ptr as usize },
+    16usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_ticket),
+      "::",
+      stringify!(enc_part)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).enc_part2) as usize - ptr as usize },
+    48usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_ticket),
+      "::",
+      stringify!(enc_part2)
+    )
+  );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _krb5_ticket_times {
+  pub authtime: krb5_timestamp,
+  pub starttime: krb5_timestamp,
+  pub endtime: krb5_timestamp,
+  pub renew_till: krb5_timestamp,
+}
+#[test]
+fn bindgen_test_layout__krb5_ticket_times() {
+  const UNINIT: ::std::mem::MaybeUninit<_krb5_ticket_times> = ::std::mem::MaybeUninit::uninit();
+  let ptr = UNINIT.as_ptr();
+  assert_eq!(
+    ::std::mem::size_of::<_krb5_ticket_times>(),
+    16usize,
+    concat!("Size of: ", stringify!(_krb5_ticket_times))
+  );
+  assert_eq!(
+    ::std::mem::align_of::<_krb5_ticket_times>(),
+    4usize,
+    concat!("Alignment of ", stringify!(_krb5_ticket_times))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).authtime) as usize - ptr as usize },
+    0usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_ticket_times),
+      "::",
+      stringify!(authtime)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).starttime) as usize - ptr as usize },
+    4usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_ticket_times),
+      "::",
+      stringify!(starttime)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).endtime) as usize - ptr as usize },
+    8usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_ticket_times),
+      "::",
+      stringify!(endtime)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).renew_till) as usize - ptr as usize },
+    12usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_ticket_times),
+      "::",
+      stringify!(renew_till)
+    )
+  );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _krb5_tkt_authent {
+  pub magic: krb5_magic,
+  pub ticket: *mut krb5_ticket,
+  pub authenticator: *mut krb5_authenticator,
+  pub ap_options: krb5_flags,
+}
+#[test]
+fn bindgen_test_layout__krb5_tkt_authent() {
+  const UNINIT: ::std::mem::MaybeUninit<_krb5_tkt_authent> = ::std::mem::MaybeUninit::uninit();
+  let ptr = UNINIT.as_ptr();
+  assert_eq!(
+    ::std::mem::size_of::<_krb5_tkt_authent>(),
+    32usize,
+    concat!("Size of: ", stringify!(_krb5_tkt_authent))
+  );
+  assert_eq!(
+    ::std::mem::align_of::<_krb5_tkt_authent>(),
+    8usize,
+    concat!("Alignment of ", stringify!(_krb5_tkt_authent))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).magic) as usize - ptr as usize },
+    0usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_tkt_authent),
+      "::",
+      stringify!(magic)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).ticket) as usize - ptr as usize },
+    8usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_tkt_authent),
+      "::",
+      stringify!(ticket)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).authenticator) as usize - ptr as usize },
+    16usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_tkt_authent),
+      "::",
+      stringify!(authenticator)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).ap_options) as usize - ptr as usize },
+    24usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_tkt_authent),
+      "::",
+      stringify!(ap_options)
+    )
+  );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _krb5_tkt_creds_context {
+  _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _krb5_trace_info {
+  pub message: *const ::std::os::raw::c_char,
+}
+#[test]
+fn bindgen_test_layout__krb5_trace_info() {
+  const UNINIT: ::std::mem::MaybeUninit<_krb5_trace_info> = ::std::mem::MaybeUninit::uninit();
+  let ptr = UNINIT.as_ptr();
+  assert_eq!(
+    ::std::mem::size_of::<_krb5_trace_info>(),
+    8usize,
+    concat!("Size of: ", stringify!(_krb5_trace_info))
+  );
+  assert_eq!(
+    ::std::mem::align_of::<_krb5_trace_info>(),
+    8usize,
+    concat!("Alignment of ", stringify!(_krb5_trace_info))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).message) as usize - ptr as usize },
+    0usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_trace_info),
+      "::",
+      stringify!(message)
+    )
+  );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _krb5_transited {
+  pub magic: krb5_magic,
+  pub tr_type: krb5_octet,
+  pub tr_contents: krb5_data,
+}
+#[test]
+fn bindgen_test_layout__krb5_transited() {
+  const UNINIT: ::std::mem::MaybeUninit<_krb5_transited> = ::std::mem::MaybeUninit::uninit();
+  let ptr = UNINIT.as_ptr();
+  assert_eq!(
+    ::std::mem::size_of::<_krb5_transited>(),
+    24usize,
+    concat!("Size of: ", stringify!(_krb5_transited))
+  );
+  assert_eq!(
+    ::std::mem::align_of::<_krb5_transited>(),
+    8usize,
+    concat!("Alignment of ", stringify!(_krb5_transited))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).magic) as usize - ptr as usize },
+    0usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_transited),
+      "::",
+      stringify!(magic)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).tr_type) as usize - ptr as usize },
+    4usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_transited),
+      "::",
+      stringify!(tr_type)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).tr_contents) as usize - ptr as usize },
+    8usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_transited),
+      "::",
+      stringify!(tr_contents)
+    )
+  );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _krb5_typed_data {
+  pub magic: krb5_magic,
+  pub type_: krb5_int32,
+  pub length: ::std::os::raw::c_uint,
+  pub data: *mut krb5_octet,
+}
+#[test]
+fn bindgen_test_layout__krb5_typed_data() {
+  const UNINIT: ::std::mem::MaybeUninit<_krb5_typed_data> = ::std::mem::MaybeUninit::uninit();
+  let ptr = UNINIT.as_ptr();
+  assert_eq!(
+    ::std::mem::size_of::<_krb5_typed_data>(),
+    24usize,
+    concat!("Size of: ", stringify!(_krb5_typed_data))
+  );
+  assert_eq!(
+    ::std::mem::align_of::<_krb5_typed_data>(),
+    8usize,
+    concat!("Alignment of ", stringify!(_krb5_typed_data))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).magic) as usize - ptr as usize },
+    0usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_typed_data),
+      "::",
+      stringify!(magic)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).type_) as usize - ptr as usize },
+    4usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_typed_data),
+      "::",
+      stringify!(type_)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).length) as usize - ptr as usize },
+    8usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_typed_data),
+      "::",
+      stringify!(length)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).data) as usize - ptr as usize },
+    16usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_typed_data),
+      "::",
+      stringify!(data)
+    )
+  );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _krb5_verify_init_creds_opt {
+  pub flags: krb5_flags,
+  pub ap_req_nofail: ::std::os::raw::c_int,
+}
+#[test]
+fn bindgen_test_layout__krb5_verify_init_creds_opt() {
+  const UNINIT: ::std::mem::MaybeUninit<_krb5_verify_init_creds_opt> = ::std::mem::MaybeUninit::uninit();
+  let ptr = UNINIT.as_ptr();
+  assert_eq!(
+    ::std::mem::size_of::<_krb5_verify_init_creds_opt>(),
+    8usize,
+    concat!("Size of: ", stringify!(_krb5_verify_init_creds_opt))
+  );
+  assert_eq!(
+    ::std::mem::align_of::<_krb5_verify_init_creds_opt>(),
+    4usize,
+    concat!("Alignment of ", stringify!(_krb5_verify_init_creds_opt))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).flags) as usize - ptr as usize },
+    0usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_verify_init_creds_opt),
+      "::",
+      stringify!(flags)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).ap_req_nofail) as usize - ptr as usize },
+    4usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_krb5_verify_init_creds_opt),
+      "::",
+      stringify!(ap_req_nofail)
+    )
+  );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _passwd_phrase_element {
+  pub magic: krb5_magic,
+  pub passwd: *mut krb5_data,
+  pub phrase: *mut krb5_data,
+}
+#[test]
+fn bindgen_test_layout__passwd_phrase_element() {
+  const UNINIT: ::std::mem::MaybeUninit<_passwd_phrase_element> = ::std::mem::MaybeUninit::uninit();
+  let ptr = UNINIT.as_ptr();
+  assert_eq!(
+    ::std::mem::size_of::<_passwd_phrase_element>(),
+    24usize,
+    concat!("Size of: ", stringify!(_passwd_phrase_element))
+  );
+  assert_eq!(
+    ::std::mem::align_of::<_passwd_phrase_element>(),
+    8usize,
+    concat!("Alignment of ", stringify!(_passwd_phrase_element))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).magic) as usize - ptr as usize },
+    0usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_passwd_phrase_element),
+      "::",
+      stringify!(magic)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).passwd) as usize - ptr as usize },
+    8usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_passwd_phrase_element),
+      "::",
+      stringify!(passwd)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).phrase) as usize - ptr as usize },
+    16usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(_passwd_phrase_element),
+      "::",
+      stringify!(phrase)
+    )
+  );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _profile_t {
+  _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct credentials {
+  _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct krb5_key_st {
+  _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct krb5_keytab_entry_st {
+  pub magic: krb5_magic,
+  pub principal: krb5_principal,
+  pub timestamp: krb5_timestamp,
+  pub vno: krb5_kvno,
+  pub key: krb5_keyblock,
+}
+#[test]
+fn bindgen_test_layout_krb5_keytab_entry_st() {
+  const UNINIT: ::std::mem::MaybeUninit<krb5_keytab_entry_st> = ::std::mem::MaybeUninit::uninit();
+  let ptr = UNINIT.as_ptr();
+  assert_eq!(
+    ::std::mem::size_of::<krb5_keytab_entry_st>(),
+    48usize,
+    concat!("Size of: ", stringify!(krb5_keytab_entry_st))
+  );
+  assert_eq!(
+    ::std::mem::align_of::<krb5_keytab_entry_st>(),
+    8usize,
+    concat!("Alignment of ", stringify!(krb5_keytab_entry_st))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).magic) as usize - ptr as usize },
+    0usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(krb5_keytab_entry_st),
+      "::",
+      stringify!(magic)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).principal) as usize - ptr as usize },
+    8usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(krb5_keytab_entry_st),
+      "::",
+      stringify!(principal)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).timestamp) as usize - ptr as usize },
+    16usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(krb5_keytab_entry_st),
+      "::",
+      stringify!(timestamp)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).vno) as usize - ptr as usize },
+    20usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(krb5_keytab_entry_st),
+      "::",
+      stringify!(vno)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).key) as usize - ptr as usize },
+    24usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(krb5_keytab_entry_st),
+      "::",
+      stringify!(key)
+    )
+  );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct krb5_pac_data {
+  _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct krb5_principal_data {
+  pub magic: krb5_magic,
+  pub realm: krb5_data,
+  pub data: *mut krb5_data,
+  pub length: krb5_int32,
+  pub type_: krb5_int32,
+}
+#[test]
+fn bindgen_test_layout_krb5_principal_data() {
+  const UNINIT: ::std::mem::MaybeUninit<krb5_principal_data> = ::std::mem::MaybeUninit::uninit();
+  let ptr = UNINIT.as_ptr();
+  assert_eq!(
+    ::std::mem::size_of::<krb5_principal_data>(),
+    40usize,
+    concat!("Size of: ", stringify!(krb5_principal_data))
+  );
+  assert_eq!(
+    ::std::mem::align_of::<krb5_principal_data>(),
+    8usize,
+    concat!("Alignment of ", stringify!(krb5_principal_data))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).magic) as usize - ptr as usize },
+    0usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(krb5_principal_data),
+      "::",
+      stringify!(magic)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).realm) as usize - ptr as usize },
+    8usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(krb5_principal_data),
+      "::",
+      stringify!(realm)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).data) as usize - ptr as usize },
+    24usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(krb5_principal_data),
+      "::",
+      stringify!(data)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).length) as usize - ptr as usize },
+    32usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(krb5_principal_data),
+      "::",
+      stringify!(length)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).type_) as usize - ptr as usize },
+    36usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(krb5_principal_data),
+      "::",
+      stringify!(type_)
+    )
+  );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct krb5_rc_st {
+  _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct krb5_replay_data {
+  pub timestamp: krb5_timestamp,
+  pub usec: krb5_int32,
+  pub seq: krb5_ui_4,
+}
+#[test]
+fn bindgen_test_layout_krb5_replay_data() {
+  const UNINIT: ::std::mem::MaybeUninit<krb5_replay_data> = ::std::mem::MaybeUninit::uninit();
+  let ptr = UNINIT.as_ptr();
+  assert_eq!(
+    ::std::mem::size_of::<krb5_replay_data>(),
+    12usize,
+    concat!("Size of: ", stringify!(krb5_replay_data))
+  );
+  assert_eq!(
+    ::std::mem::align_of::<krb5_replay_data>(),
+    4usize,
+    concat!("Alignment of ", stringify!(krb5_replay_data))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).timestamp) as usize - ptr as usize },
+    0usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(krb5_replay_data),
+      "::",
+      stringify!(timestamp)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).usec) as usize - ptr as usize },
+    4usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(krb5_replay_data),
+      "::",
+      stringify!(usec)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).seq) as usize - ptr as usize },
+    8usize,
+    concat!("Offset of field: ", stringify!(krb5_replay_data), "::", stringify!(seq))
+  );
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct krb5_responder_context_st {
+  _unused: [u8; 0],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct profile_vtable {
+  pub minor_ver: ::std::os::raw::c_int,
+  pub get_values: profile_get_values_fn,
+  pub free_values: profile_free_values_fn,
+  pub cleanup: profile_cleanup_fn,
+  pub copy: profile_copy_fn,
+  pub iterator_create: profile_iterator_create_fn,
+  pub iterator: profile_iterator_fn,
+  pub iterator_free: profile_iterator_free_fn,
+  pub free_string: profile_free_string_fn,
+  pub writable: profile_writable_fn,
+  pub modified: profile_modified_fn,
+  pub update_relation: profile_update_relation_fn,
+  pub rename_section: profile_rename_section_fn,
+  pub add_relation: profile_add_relation_fn,
+  pub flush: profile_flush_fn,
+}
+#[test]
+fn bindgen_test_layout_profile_vtable() {
+  const UNINIT: ::std::mem::MaybeUninit<profile_vtable> = ::std::mem::MaybeUninit::uninit();
+  let ptr = UNINIT.as_ptr();
+  assert_eq!(
+    ::std::mem::size_of::<profile_vtable>(),
+    120usize,
+    concat!("Size of: ", stringify!(profile_vtable))
+  );
+  assert_eq!(
+    ::std::mem::align_of::<profile_vtable>(),
+    8usize,
+    concat!("Alignment of ", stringify!(profile_vtable))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).minor_ver) as usize - ptr as usize },
+    0usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(profile_vtable),
+      "::",
+      stringify!(minor_ver)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).get_values) as usize - ptr as usize },
+    8usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(profile_vtable),
+      "::",
+      stringify!(get_values)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).free_values) as usize - ptr as usize },
+    16usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(profile_vtable),
+      "::",
+      stringify!(free_values)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).cleanup) as usize - ptr as usize },
+    24usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(profile_vtable),
+      "::",
+      stringify!(cleanup)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).copy) as usize - ptr as usize },
+    32usize,
+    concat!("Offset of field: ", stringify!(profile_vtable), "::", stringify!(copy))
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).iterator_create) as usize - ptr as usize },
+    40usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(profile_vtable),
+      "::",
+      stringify!(iterator_create)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).iterator) as usize - ptr as usize },
+    48usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(profile_vtable),
+      "::",
+      stringify!(iterator)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).iterator_free) as usize - ptr as usize },
+    56usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(profile_vtable),
+      "::",
+      stringify!(iterator_free)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).free_string) as usize - ptr as usize },
+    64usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(profile_vtable),
+      "::",
+      stringify!(free_string)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).writable) as usize - ptr as usize },
+    72usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(profile_vtable),
+      "::",
+      stringify!(writable)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).modified) as usize - ptr as usize },
+    80usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(profile_vtable),
+      "::",
+      stringify!(modified)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).update_relation) as usize - ptr as usize },
+    88usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(profile_vtable),
+      "::",
+      stringify!(update_relation)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).rename_section) as usize - ptr as usize },
+    96usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(profile_vtable),
+      "::",
+      stringify!(rename_section)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).add_relation) as usize - ptr as usize },
+    104usize,
+    concat!(
+      "Offset of field: ",
+      stringify!(profile_vtable),
+      "::",
+      stringify!(add_relation)
+    )
+  );
+  assert_eq!(
+    unsafe { ::std::ptr::addr_of!((*ptr).flush) as usize - ptr as usize },
+    112usize,
+    concat!("Offset of field: ", stringify!(profile_vtable), "::", stringify!(flush))
+  );
+}
+pub type __builtin_va_list = [__va_list_tag; 1usize];
+pub type __gnuc_va_list = __builtin_va_list;
+pub type __int16_t = ::std::os::raw::c_short;
+pub type __int32_t = ::std::os::raw::c_int;
+pub type __uint16_t = ::std::os::raw::c_ushort;
+pub type __uint32_t = ::std::os::raw::c_uint;
+pub type __uint8_t = ::std::os::raw::c_uchar;
+pub type const_profile_filespec_list_t = *const ::std::os::raw::c_char;
+pub type const_profile_filespec_t = *const ::std::os::raw::c_char;
+pub type errcode_t = ::std::os::raw::c_long;
+pub type int16_t = __int16_t;
+pub type int32_t = __int32_t;
+pub type krb5_address = _krb5_address;
+pub type krb5_addrtype = krb5_int32;
+pub type krb5_ap_rep = _krb5_ap_rep;
+pub type krb5_ap_rep_enc_part = _krb5_ap_rep_enc_part;
+pub type krb5_ap_req = _krb5_ap_req;
+pub type krb5_auth_context = *mut _krb5_auth_context;
+pub type krb5_authdata = _krb5_authdata;
+pub type krb5_authdatatype = krb5_int32;
+pub type krb5_authenticator = _krb5_authenticator;
+pub type krb5_boolean = ::std::os::raw::c_uint;
+pub type krb5_cc_cursor = krb5_pointer;
+pub type krb5_cc_ops = _krb5_cc_ops;
+pub type krb5_ccache = *mut _krb5_ccache;
+pub type krb5_cccol_cursor = *mut _krb5_cccol_cursor;
+pub type krb5_checksum = _krb5_checksum;
+pub type krb5_cksumtype = krb5_int32;
+pub type krb5_const_pointer = *const ::std::os::raw::c_void;
+pub type krb5_const_principal = *const krb5_principal_data;
+pub type krb5_context = *mut _krb5_context;
+pub type krb5_cred = _krb5_cred;
+pub type krb5_cred_enc_part = _krb5_cred_enc_part;
+pub type krb5_cred_info = _krb5_cred_info;
+pub type krb5_creds = _krb5_creds;
+pub type krb5_crypto_iov = _krb5_crypto_iov;
+pub type krb5_cryptotype = krb5_int32;
+pub type krb5_data = _krb5_data;
+pub type krb5_deltat = krb5_int32;
+pub type krb5_enc_data = _krb5_enc_data;
+pub type krb5_enc_kdc_rep_part = _krb5_enc_kdc_rep_part;
+pub type krb5_enc_tkt_part = _krb5_enc_tkt_part;
+pub type krb5_encrypt_block = _krb5_encrypt_block;
+pub type krb5_enctype = krb5_int32;
+pub type krb5_error = _krb5_error;
+pub type krb5_error_code = krb5_int32;
+pub type krb5_expire_callback_func = ::std::option::Option<
+  unsafe extern "C" fn(
+    context: krb5_context,
+    data: *mut ::std::os::raw::c_void,
+    password_expiration: krb5_timestamp,
+    account_expiration: krb5_timestamp,
+    is_last_req: krb5_boolean,
+  ),
+>;
+pub type krb5_flags = krb5_int32;
+pub type krb5_get_init_creds_opt = _krb5_get_init_creds_opt;
+pub type krb5_gic_opt_pa_data = _krb5_gic_opt_pa_data;
+pub type krb5_init_creds_context = *mut _krb5_init_creds_context;
+pub type krb5_int16 = int16_t;
+pub type krb5_int32 = int32_t;
+pub type krb5_kdc_rep = _krb5_kdc_rep;
+pub type krb5_kdc_req = _krb5_kdc_req;
+pub type krb5_key = *mut krb5_key_st;
+pub type krb5_keyblock = _krb5_keyblock;
+pub type krb5_keytab = *mut _krb5_kt;
+pub type krb5_keytab_entry = krb5_keytab_entry_st;
+pub type krb5_keyusage = krb5_int32;
+pub type krb5_kt_cursor = krb5_pointer;
+pub type krb5_kvno = ::std::os::raw::c_uint;
+pub type krb5_last_req_entry = _krb5_last_req_entry;
+pub type krb5_magic = krb5_error_code;
+pub type krb5_mk_req_checksum_func = ::std::option::Option<
+  unsafe extern "C" fn(
+    arg1: krb5_context,
+    arg2: krb5_auth_context,
+    arg3: *mut ::std::os::raw::c_void,
+    arg4: *mut *mut krb5_data,
+  ) -> krb5_error_code,
+>;
+pub type krb5_msgtype = ::std::os::raw::c_uint;
+pub type krb5_octet = uint8_t;
+pub type krb5_octet_data = _krb5_octet_data;
+pub type krb5_pa_data = _krb5_pa_data;
+pub type krb5_pa_pac_req = _krb5_pa_pac_req;
+pub type krb5_pa_server_referral_data = _krb5_pa_server_referral_data;
+pub type krb5_pa_svr_referral_data = _krb5_pa_svr_referral_data;
+pub type krb5_pac = *mut krb5_pac_data;
+pub type krb5_pointer = *mut ::std::os::raw::c_void;
+pub type krb5_post_recv_fn = ::std::option::Option<
+  unsafe extern "C" fn(
+    context: krb5_context,
+    data: *mut ::std::os::raw::c_void,
+    code: krb5_error_code,
+    realm: *const krb5_data,
+    message: *const krb5_data,
+    reply: *const krb5_data,
+    new_reply_out: *mut *mut krb5_data,
+  ) -> krb5_error_code,
+>;
+pub type krb5_pre_send_fn = ::std::option::Option<
+  unsafe extern "C" fn(
+    context: krb5_context,
+    data: *mut ::std::os::raw::c_void,
+    realm: *const krb5_data,
+    message: *const krb5_data,
+    new_message_out: *mut *mut krb5_data,
+    new_reply_out: *mut *mut krb5_data,
+  ) -> krb5_error_code,
+>;
+pub type krb5_preauthtype = krb5_int32;
+pub type krb5_principal = *mut krb5_principal_data;
+pub type krb5_prompt = _krb5_prompt;
+pub type krb5_prompt_type = krb5_int32;
+pub type krb5_prompter_fct = ::std::option::Option<
+  unsafe extern "C" fn(
+    context: krb5_context,
+    data: *mut ::std::os::raw::c_void,
+    name: *const ::std::os::raw::c_char,
+    banner: *const ::std::os::raw::c_char,
+    num_prompts: ::std::os::raw::c_int,
+    prompts: *mut krb5_prompt,
+  ) -> krb5_error_code,
+>;
+pub type krb5_pwd_data = _krb5_pwd_data;
+pub type krb5_rcache = *mut krb5_rc_st;
+pub type krb5_responder_context = *mut krb5_responder_context_st;
+pub type krb5_responder_fn = ::std::option::Option<
+  unsafe extern "C" fn(
+    ctx: krb5_context,
+    data: *mut ::std::os::raw::c_void,
+    rctx: krb5_responder_context,
+  ) -> krb5_error_code,
+>;
+pub type krb5_responder_otp_challenge = _krb5_responder_otp_challenge;
+pub type krb5_responder_otp_tokeninfo = _krb5_responder_otp_tokeninfo;
+pub type krb5_responder_pkinit_challenge = _krb5_responder_pkinit_challenge;
+pub type krb5_responder_pkinit_identity = _krb5_responder_pkinit_identity;
+pub type krb5_response = _krb5_response;
+pub type krb5_ticket = _krb5_ticket;
+pub type krb5_ticket_times = _krb5_ticket_times;
+pub type krb5_timestamp = krb5_int32;
+pub type krb5_tkt_authent = _krb5_tkt_authent;
+pub type krb5_tkt_creds_context = *mut _krb5_tkt_creds_context;
+pub type krb5_trace_callback = ::std::option::Option<
+  unsafe extern "C" fn(context: krb5_context, info: *const krb5_trace_info, cb_data: *mut ::std::os::raw::c_void),
+>;
+pub type krb5_trace_info = _krb5_trace_info;
+pub type krb5_transited = _krb5_transited;
+pub type krb5_typed_data = _krb5_typed_data;
+pub type krb5_ui_2 = uint16_t;
+pub type krb5_ui_4 = uint32_t;
+pub type krb5_verify_init_creds_opt = _krb5_verify_init_creds_opt;
+pub type passwd_phrase_element = _passwd_phrase_element;
+pub type profile_add_relation_fn = ::std::option::Option<
+  unsafe extern "C" fn(
+    cbdata: *mut ::std::os::raw::c_void,
+    names: *mut *const ::std::os::raw::c_char,
+    new_value: *const ::std::os::raw::c_char,
+  ) -> ::std::os::raw::c_long,
+>;
+pub type profile_cleanup_fn = ::std::option::Option<unsafe extern "C" fn(cbdata: *mut ::std::os::raw::c_void)>;
+pub type profile_copy_fn = ::std::option::Option<
+  unsafe extern "C" fn(
+    cbdata: *mut ::std::os::raw::c_void,
+    ret_cbdata: *mut *mut ::std::os::raw::c_void,
+  ) -> ::std::os::raw::c_long,
+>;
+pub type profile_filespec_list_t = *mut ::std::os::raw::c_char;
+pub type profile_filespec_t = *mut ::std::os::raw::c_char;
+pub type profile_flush_fn =
+  ::std::option::Option<unsafe extern "C" fn(cbdata: *mut ::std::os::raw::c_void) -> ::std::os::raw::c_long>;
+pub type profile_free_string_fn =
+  ::std::option::Option<unsafe extern "C" fn(cbdata: *mut ::std::os::raw::c_void, string: *mut ::std::os::raw::c_char)>;
+pub type profile_free_values_fn = ::std::option::Option<
+  unsafe extern "C" fn(cbdata: *mut ::std::os::raw::c_void, values: *mut *mut ::std::os::raw::c_char),
+>;
+pub type profile_get_values_fn = ::std::option::Option<
+  unsafe extern "C" fn(
+    cbdata: *mut ::std::os::raw::c_void,
+    names: *const *const ::std::os::raw::c_char,
+    ret_values: *mut *mut *mut ::std::os::raw::c_char,
+  ) -> ::std::os::raw::c_long,
+>;
+pub type profile_iterator_create_fn = ::std::option::Option<
+  unsafe extern "C" fn(
+    cbdata: *mut ::std::os::raw::c_void,
+    names: *const *const ::std::os::raw::c_char,
+    flags: ::std::os::raw::c_int,
+    ret_iter: *mut *mut ::std::os::raw::c_void,
+  ) -> ::std::os::raw::c_long,
+>;
+pub type profile_iterator_fn = ::std::option::Option<
+  unsafe extern "C" fn(
+    cbdata: *mut ::std::os::raw::c_void,
+    iter: *mut ::std::os::raw::c_void,
+    ret_name: *mut *mut ::std::os::raw::c_char,
+    ret_value: *mut *mut ::std::os::raw::c_char,
+  ) -> ::std::os::raw::c_long,
+>;
+pub type profile_iterator_free_fn =
+  ::std::option::Option<unsafe extern "C" fn(cbdata: *mut ::std::os::raw::c_void, iter: *mut ::std::os::raw::c_void)>;
+pub type profile_modified_fn = ::std::option::Option<
+  unsafe extern "C" fn(
+    cbdata: *mut ::std::os::raw::c_void,
+    modified: *mut ::std::os::raw::c_int,
+  ) -> ::std::os::raw::c_long,
+>;
+pub type profile_module_init_fn = ::std::option::Option<
+  unsafe extern "C" fn(
+    residual: *const ::std::os::raw::c_char,
+    vtable: *mut profile_vtable,
+    cb_ret: *mut *mut ::std::os::raw::c_void,
+  ) -> ::std::os::raw::c_long,
+>;
+pub type profile_rename_section_fn = ::std::option::Option<
+  unsafe extern "C" fn(
+    cbdata: *mut ::std::os::raw::c_void,
+    names: *mut *const ::std::os::raw::c_char,
+    new_name: *const ::std::os::raw::c_char,
+  ) -> ::std::os::raw::c_long,
+>;
+pub type profile_t = *mut _profile_t;
+pub type profile_update_relation_fn = ::std::option::Option<
+  unsafe extern "C" fn(
+    cbdata: *mut ::std::os::raw::c_void,
+    names: *mut *const ::std::os::raw::c_char,
+    old_value: *const ::std::os::raw::c_char,
+    new_value: *const ::std::os::raw::c_char,
+  ) -> ::std::os::raw::c_long,
+>;
+pub type profile_writable_fn = ::std::option::Option<
+  unsafe extern "C" fn(
+    cbdata: *mut ::std::os::raw::c_void,
+    writable: *mut ::std::os::raw::c_int,
+  ) -> ::std::os::raw::c_long,
+>;
+pub type size_t = ::std::os::raw::c_ulong;
+pub type uint16_t = __uint16_t;
+pub type uint32_t = __uint32_t;
+pub type uint8_t = __uint8_t;
+pub type va_list = __gnuc_va_list;
+pub const KRB5_C_RANDSOURCE_OLDAPI: _bindgen_ty_1 = 0;
+pub const KRB5_C_RANDSOURCE_OSRAND: _bindgen_ty_1 = 1;
+pub const KRB5_C_RANDSOURCE_TRUSTEDPARTY: _bindgen_ty_1 = 2;
+pub const KRB5_C_RANDSOURCE_TIMING: _bindgen_ty_1 = 3;
+pub const KRB5_C_RANDSOURCE_EXTERNAL_PROTOCOL: _bindgen_ty_1 = 4;
+pub const KRB5_C_RANDSOURCE_MAX: _bindgen_ty_1 = 5;
+pub type _bindgen_ty_1 = ::std::os::raw::c_uint;
+extern "C" {
+  pub fn krb5_is_referral_realm(r: *const krb5_data) -> krb5_boolean;
+}
+extern "C" {
+  pub fn krb5_anonymous_realm() -> *const krb5_data;
+}
+extern "C" {
+  pub fn krb5_anonymous_principal() -> krb5_const_principal;
+}
+extern "C" {
+  pub fn krb5_c_encrypt(
+    context: krb5_context,
+    key: *const krb5_keyblock,
+    usage: krb5_keyusage,
+    cipher_state: *const krb5_data,
+    input: *const krb5_data,
+    output: *mut krb5_enc_data,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_c_decrypt(
+    context: krb5_context,
+    key: *const krb5_keyblock,
+    usage: krb5_keyusage,
+    cipher_state: *const krb5_data,
+    input: *const krb5_enc_data,
+    output: *mut krb5_data,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_c_encrypt_length(
+    context: krb5_context,
+    enctype: krb5_enctype,
+    inputlen: size_t,
+    length: *mut size_t,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_c_block_size(context: krb5_context, enctype: krb5_enctype, blocksize: *mut size_t) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_c_keylengths(
+    context: krb5_context,
+    enctype: krb5_enctype,
+    keybytes: *mut size_t,
+    keylength: *mut size_t,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_c_init_state(
+    context: krb5_context,
+    key: *const krb5_keyblock,
+    usage: krb5_keyusage,
+    new_state: *mut krb5_data,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_c_free_state(context: krb5_context, key: *const krb5_keyblock, state: *mut krb5_data) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_c_prf(
+    context: krb5_context,
+    keyblock: *const krb5_keyblock,
+    input: *mut krb5_data,
+    output: *mut krb5_data,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_c_prf_length(context: krb5_context, enctype: krb5_enctype, len: *mut size_t) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_c_prfplus(
+    context: krb5_context,
+    k: *const krb5_keyblock,
+    input: *const krb5_data,
+    output: *mut krb5_data,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_c_derive_prfplus(
+    context: krb5_context,
+    k: *const krb5_keyblock,
+    input: *const krb5_data,
+    enctype: krb5_enctype,
+    out: *mut *mut krb5_keyblock,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_c_fx_cf2_simple(
+    context: krb5_context,
+    k1: *const krb5_keyblock,
+    pepper1: *const ::std::os::raw::c_char,
+    k2: *const krb5_keyblock,
+    pepper2: *const ::std::os::raw::c_char,
+    out: *mut *mut krb5_keyblock,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_c_make_random_key(
+    context: krb5_context,
+    enctype: krb5_enctype,
+    k5_random_key: *mut krb5_keyblock,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_c_random_to_key(
+    context: krb5_context,
+    enctype: krb5_enctype,
+    random_data: *mut krb5_data,
+    k5_random_key: *mut krb5_keyblock,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_c_random_add_entropy(
+    context: krb5_context,
+    randsource: ::std::os::raw::c_uint,
+    data: *const krb5_data,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_c_random_make_octets(context: krb5_context, data: *mut krb5_data) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_c_random_os_entropy(
+    context: krb5_context,
+    strong: ::std::os::raw::c_int,
+    success: *mut ::std::os::raw::c_int,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_c_random_seed(context: krb5_context, data: *mut krb5_data) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_c_string_to_key(
+    context: krb5_context,
+    enctype: krb5_enctype,
+    string: *const krb5_data,
+    salt: *const krb5_data,
+    key: *mut krb5_keyblock,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_c_string_to_key_with_params(
+    context: krb5_context,
+    enctype: krb5_enctype,
+    string: *const krb5_data,
+    salt: *const krb5_data,
+    params: *const krb5_data,
+    key: *mut krb5_keyblock,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_c_enctype_compare(
+    context: krb5_context,
+    e1: krb5_enctype,
+    e2: krb5_enctype,
+    similar: *mut krb5_boolean,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_c_make_checksum(
+    context: krb5_context,
+    cksumtype: krb5_cksumtype,
+    key: *const krb5_keyblock,
+    usage: krb5_keyusage,
+    input: *const krb5_data,
+    cksum: *mut krb5_checksum,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_c_verify_checksum(
+    context: krb5_context,
+    key: *const krb5_keyblock,
+    usage: krb5_keyusage,
+    data: *const krb5_data,
+    cksum: *const krb5_checksum,
+    valid: *mut krb5_boolean,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_c_checksum_length(
+    context: krb5_context,
+    cksumtype: krb5_cksumtype,
+    length: *mut size_t,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_c_keyed_checksum_types(
+    context: krb5_context,
+    enctype: krb5_enctype,
+    count: *mut ::std::os::raw::c_uint,
+    cksumtypes: *mut *mut krb5_cksumtype,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_c_valid_enctype(ktype: krb5_enctype) -> krb5_boolean;
+}
+extern "C" {
+  pub fn krb5_c_valid_cksumtype(ctype: krb5_cksumtype) -> krb5_boolean;
+}
+extern "C" {
+  pub fn krb5_c_is_coll_proof_cksum(ctype: krb5_cksumtype) -> krb5_boolean;
+}
+extern "C" {
+  pub fn krb5_c_is_keyed_cksum(ctype: krb5_cksumtype) -> krb5_boolean;
+}
+extern "C" {
+  pub fn krb5_c_make_checksum_iov(
+    context: krb5_context,
+    cksumtype: krb5_cksumtype,
+    key: *const krb5_keyblock,
+    usage: krb5_keyusage,
+    data: *mut krb5_crypto_iov,
+    num_data: size_t,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_c_verify_checksum_iov(
+    context: krb5_context,
+    cksumtype: krb5_cksumtype,
+    key: *const krb5_keyblock,
+    usage: krb5_keyusage,
+    data: *const krb5_crypto_iov,
+    num_data: size_t,
+    valid: *mut krb5_boolean,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_c_encrypt_iov(
+    context: krb5_context,
+    keyblock: *const krb5_keyblock,
+    usage: krb5_keyusage,
+    cipher_state: *const krb5_data,
+    data: *mut krb5_crypto_iov,
+    num_data: size_t,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_c_decrypt_iov(
+    context: krb5_context,
+    keyblock: *const krb5_keyblock,
+    usage: krb5_keyusage,
+    cipher_state: *const krb5_data,
+    data: *mut krb5_crypto_iov,
+    num_data: size_t,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_c_crypto_length(
+    context: krb5_context,
+    enctype: krb5_enctype,
+    type_: krb5_cryptotype,
+    size: *mut ::std::os::raw::c_uint,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_c_crypto_length_iov(
+    context: krb5_context,
+    enctype: krb5_enctype,
+    data: *mut krb5_crypto_iov,
+    num_data: size_t,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_c_padding_length(
+    context: krb5_context,
+    enctype: krb5_enctype,
+    data_length: size_t,
+    size: *mut ::std::os::raw::c_uint,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_k_create_key(
+    context: krb5_context,
+    key_data: *const krb5_keyblock,
+    out: *mut krb5_key,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_k_reference_key(context: krb5_context, key: krb5_key);
+}
+extern "C" {
+  pub fn krb5_k_free_key(context: krb5_context, key: krb5_key);
+}
+extern "C" {
+  pub fn krb5_k_key_keyblock(
+    context: krb5_context,
+    key: krb5_key,
+    key_data: *mut *mut krb5_keyblock,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_k_key_enctype(context: krb5_context, key: krb5_key) -> krb5_enctype;
+}
+extern "C" {
+  pub fn krb5_k_encrypt(
+    context: krb5_context,
+    key: krb5_key,
+    usage: krb5_keyusage,
+    cipher_state: *const krb5_data,
+    input: *const krb5_data,
+    output: *mut krb5_enc_data,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_k_encrypt_iov(
+    context: krb5_context,
+    key: krb5_key,
+    usage: krb5_keyusage,
+    cipher_state: *const krb5_data,
+    data: *mut krb5_crypto_iov,
+    num_data: size_t,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_k_decrypt(
+    context: krb5_context,
+    key: krb5_key,
+    usage: krb5_keyusage,
+    cipher_state: *const krb5_data,
+    input: *const krb5_enc_data,
+    output: *mut krb5_data,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_k_decrypt_iov(
+    context: krb5_context,
+    key: krb5_key,
+    usage: krb5_keyusage,
+    cipher_state: *const krb5_data,
+    data: *mut krb5_crypto_iov,
+    num_data: size_t,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_k_make_checksum(
+    context: krb5_context,
+    cksumtype: krb5_cksumtype,
+    key: krb5_key,
+    usage: krb5_keyusage,
+    input: *const krb5_data,
+    cksum: *mut krb5_checksum,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_k_make_checksum_iov(
+    context: krb5_context,
+    cksumtype: krb5_cksumtype,
+    key: krb5_key,
+    usage: krb5_keyusage,
+    data: *mut krb5_crypto_iov,
+    num_data: size_t,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_k_verify_checksum(
+    context: krb5_context,
+    key: krb5_key,
+    usage: krb5_keyusage,
+    data: *const krb5_data,
+    cksum: *const krb5_checksum,
+    valid: *mut krb5_boolean,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_k_verify_checksum_iov(
+    context: krb5_context,
+    cksumtype: krb5_cksumtype,
+    key: krb5_key,
+    usage: krb5_keyusage,
+    data: *const krb5_crypto_iov,
+    num_data: size_t,
+    valid: *mut krb5_boolean,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_k_prf(
+    context: krb5_context,
+    key: krb5_key,
+    input: *mut krb5_data,
+    output: *mut krb5_data,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_encrypt(
+    context: krb5_context,
+    inptr: krb5_const_pointer,
+    outptr: krb5_pointer,
+    size: size_t,
+    eblock: *mut krb5_encrypt_block,
+    ivec: krb5_pointer,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_decrypt(
+    context: krb5_context,
+    inptr: krb5_const_pointer,
+    outptr: krb5_pointer,
+    size: size_t,
+    eblock: *mut krb5_encrypt_block,
+    ivec: krb5_pointer,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_process_key(
+    context: krb5_context,
+    eblock: *mut krb5_encrypt_block,
+    key: *const krb5_keyblock,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_finish_key(context: krb5_context, eblock: *mut krb5_encrypt_block) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_string_to_key(
+    context: krb5_context,
+    eblock: *const krb5_encrypt_block,
+    keyblock: *mut krb5_keyblock,
+    data: *const krb5_data,
+    salt: *const krb5_data,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_init_random_key(
+    context: krb5_context,
+    eblock: *const krb5_encrypt_block,
+    keyblock: *const krb5_keyblock,
+    ptr: *mut krb5_pointer,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_finish_random_key(
+    context: krb5_context,
+    eblock: *const krb5_encrypt_block,
+    ptr: *mut krb5_pointer,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_random_key(
+    context: krb5_context,
+    eblock: *const krb5_encrypt_block,
+    ptr: krb5_pointer,
+    keyblock: *mut *mut krb5_keyblock,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_eblock_enctype(context: krb5_context, eblock: *const krb5_encrypt_block) -> krb5_enctype;
+}
+extern "C" {
+  pub fn krb5_use_enctype(
+    context: krb5_context,
+    eblock: *mut krb5_encrypt_block,
+    enctype: krb5_enctype,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_encrypt_size(length: size_t, crypto: krb5_enctype) -> size_t;
+}
+extern "C" {
+  pub fn krb5_checksum_size(context: krb5_context, ctype: krb5_cksumtype) -> size_t;
+}
+extern "C" {
+  pub fn krb5_calculate_checksum(
+    context: krb5_context,
+    ctype: krb5_cksumtype,
+    in_: krb5_const_pointer,
+    in_length: size_t,
+    seed: krb5_const_pointer,
+    seed_length: size_t,
+    outcksum: *mut krb5_checksum,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_verify_checksum(
+    context: krb5_context,
+    ctype: krb5_cksumtype,
+    cksum: *const krb5_checksum,
+    in_: krb5_const_pointer,
+    in_length: size_t,
+    seed: krb5_const_pointer,
+    seed_length: size_t,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_cc_get_name(context: krb5_context, cache: krb5_ccache) -> *const ::std::os::raw::c_char;
+}
+extern "C" {
+  pub fn krb5_cc_get_full_name(
+    context: krb5_context,
+    cache: krb5_ccache,
+    fullname_out: *mut *mut ::std::os::raw::c_char,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_cc_initialize(context: krb5_context, cache: krb5_ccache, principal: krb5_principal) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_cc_destroy(context: krb5_context, cache: krb5_ccache) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_cc_close(context: krb5_context, cache: krb5_ccache) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_cc_store_cred(context: krb5_context, cache: krb5_ccache, creds: *mut krb5_creds) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_cc_retrieve_cred(
+    context: krb5_context,
+    cache: krb5_ccache,
+    flags: krb5_flags,
+    mcreds: *mut krb5_creds,
+    creds: *mut krb5_creds,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_cc_get_principal(
+    context: krb5_context,
+    cache: krb5_ccache,
+    principal: *mut krb5_principal,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_cc_start_seq_get(
+    context: krb5_context,
+    cache: krb5_ccache,
+    cursor: *mut krb5_cc_cursor,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_cc_next_cred(
+    context: krb5_context,
+    cache: krb5_ccache,
+    cursor: *mut krb5_cc_cursor,
+    creds: *mut krb5_creds,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_cc_end_seq_get(context: krb5_context, cache: krb5_ccache, cursor: *mut krb5_cc_cursor)
+    -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_cc_remove_cred(
+    context: krb5_context,
+    cache: krb5_ccache,
+    flags: krb5_flags,
+    creds: *mut krb5_creds,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_cc_set_flags(context: krb5_context, cache: krb5_ccache, flags: krb5_flags) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_cc_get_flags(context: krb5_context, cache: krb5_ccache, flags: *mut krb5_flags) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_cc_get_type(context: krb5_context, cache: krb5_ccache) -> *const ::std::os::raw::c_char;
+}
+extern "C" {
+  pub fn krb5_cc_move(context: krb5_context, src: krb5_ccache, dst: krb5_ccache) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_cccol_cursor_new(context: krb5_context, cursor: *mut krb5_cccol_cursor) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_cccol_cursor_next(
+    context: krb5_context,
+    cursor: krb5_cccol_cursor,
+    ccache: *mut krb5_ccache,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_cccol_cursor_free(context: krb5_context, cursor: *mut krb5_cccol_cursor) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_cccol_have_content(context: krb5_context) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_cc_new_unique(
+    context: krb5_context,
+    type_: *const ::std::os::raw::c_char,
+    hint: *const ::std::os::raw::c_char,
+    id: *mut krb5_ccache,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_kt_get_type(context: krb5_context, keytab: krb5_keytab) -> *const ::std::os::raw::c_char;
+}
+extern "C" {
+  pub fn krb5_kt_get_name(
+    context: krb5_context,
+    keytab: krb5_keytab,
+    name: *mut ::std::os::raw::c_char,
+    namelen: ::std::os::raw::c_uint,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_kt_close(context: krb5_context, keytab: krb5_keytab) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_kt_get_entry(
+    context: krb5_context,
+    keytab: krb5_keytab,
+    principal: krb5_const_principal,
+    vno: krb5_kvno,
+    enctype: krb5_enctype,
+    entry: *mut krb5_keytab_entry,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_kt_start_seq_get(
+    context: krb5_context,
+    keytab: krb5_keytab,
+    cursor: *mut krb5_kt_cursor,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_kt_next_entry(
+    context: krb5_context,
+    keytab: krb5_keytab,
+    entry: *mut krb5_keytab_entry,
+    cursor: *mut krb5_kt_cursor,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_kt_end_seq_get(
+    context: krb5_context,
+    keytab: krb5_keytab,
+    cursor: *mut krb5_kt_cursor,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_kt_have_content(context: krb5_context, keytab: krb5_keytab) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_init_context(context: *mut krb5_context) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_init_secure_context(context: *mut krb5_context) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_init_context_profile(
+    profile: *mut _profile_t,
+    flags: krb5_flags,
+    context: *mut krb5_context,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_free_context(context: krb5_context);
+}
+extern "C" {
+  pub fn krb5_copy_context(ctx: krb5_context, nctx_out: *mut krb5_context) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_set_default_tgs_enctypes(context: krb5_context, etypes: *const krb5_enctype) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_get_permitted_enctypes(context: krb5_context, ktypes: *mut *mut krb5_enctype) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_is_thread_safe() -> krb5_boolean;
+}
+extern "C" {
+  pub fn krb5_server_decrypt_ticket_keytab(
+    context: krb5_context,
+    kt: krb5_keytab,
+    ticket: *mut krb5_ticket,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_free_tgt_creds(context: krb5_context, tgts: *mut *mut krb5_creds);
+}
+extern "C" {
+  pub fn krb5_get_credentials(
+    context: krb5_context,
+    options: krb5_flags,
+    ccache: krb5_ccache,
+    in_creds: *mut krb5_creds,
+    out_creds: *mut *mut krb5_creds,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_get_credentials_validate(
+    context: krb5_context,
+    options: krb5_flags,
+    ccache: krb5_ccache,
+    in_creds: *mut krb5_creds,
+    out_creds: *mut *mut krb5_creds,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_get_credentials_renew(
+    context: krb5_context,
+    options: krb5_flags,
+    ccache: krb5_ccache,
+    in_creds: *mut krb5_creds,
+    out_creds: *mut *mut krb5_creds,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_mk_req(
+    context: krb5_context,
+    auth_context: *mut krb5_auth_context,
+    ap_req_options: krb5_flags,
+    service: *const ::std::os::raw::c_char,
+    hostname: *const ::std::os::raw::c_char,
+    in_data: *mut krb5_data,
+    ccache: krb5_ccache,
+    outbuf: *mut krb5_data,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_mk_req_extended(
+    context: krb5_context,
+    auth_context: *mut krb5_auth_context,
+    ap_req_options: krb5_flags,
+    in_data: *mut krb5_data,
+    in_creds: *mut krb5_creds,
+    outbuf: *mut krb5_data,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_mk_rep(context: krb5_context, auth_context: krb5_auth_context, outbuf: *mut krb5_data)
+    -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_mk_rep_dce(
+    context: krb5_context,
+    auth_context: krb5_auth_context,
+    outbuf: *mut krb5_data,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_rd_rep(
+    context: krb5_context,
+    auth_context: krb5_auth_context,
+    inbuf: *const krb5_data,
+    repl: *mut *mut krb5_ap_rep_enc_part,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_rd_rep_dce(
+    context: krb5_context,
+    auth_context: krb5_auth_context,
+    inbuf: *const krb5_data,
+    nonce: *mut krb5_ui_4,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_mk_error(context: krb5_context, dec_err: *const krb5_error, enc_err: *mut krb5_data) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_rd_error(
+    context: krb5_context,
+    enc_errbuf: *const krb5_data,
+    dec_error: *mut *mut krb5_error,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_rd_safe(
+    context: krb5_context,
+    auth_context: krb5_auth_context,
+    inbuf: *const krb5_data,
+    userdata_out: *mut krb5_data,
+    rdata_out: *mut krb5_replay_data,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_rd_priv(
+    context: krb5_context,
+    auth_context: krb5_auth_context,
+    inbuf: *const krb5_data,
+    userdata_out: *mut krb5_data,
+    rdata_out: *mut krb5_replay_data,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_parse_name(
+    context: krb5_context,
+    name: *const ::std::os::raw::c_char,
+    principal_out: *mut krb5_principal,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_parse_name_flags(
+    context: krb5_context,
+    name: *const ::std::os::raw::c_char,
+    flags: ::std::os::raw::c_int,
+    principal_out: *mut krb5_principal,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_unparse_name(
+    context: krb5_context,
+    principal: krb5_const_principal,
+    name: *mut *mut ::std::os::raw::c_char,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_unparse_name_ext(
+    context: krb5_context,
+    principal: krb5_const_principal,
+    name: *mut *mut ::std::os::raw::c_char,
+    size: *mut ::std::os::raw::c_uint,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_unparse_name_flags(
+    context: krb5_context,
+    principal: krb5_const_principal,
+    flags: ::std::os::raw::c_int,
+    name: *mut *mut ::std::os::raw::c_char,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_unparse_name_flags_ext(
+    context: krb5_context,
+    principal: krb5_const_principal,
+    flags: ::std::os::raw::c_int,
+    name: *mut *mut ::std::os::raw::c_char,
+    size: *mut ::std::os::raw::c_uint,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_set_principal_realm(
+    context: krb5_context,
+    principal: krb5_principal,
+    realm: *const ::std::os::raw::c_char,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_address_search(
+    context: krb5_context,
+    addr: *const krb5_address,
+    addrlist: *const *mut krb5_address,
+  ) -> krb5_boolean;
+}
+extern "C" {
+  pub fn krb5_address_compare(
+    context: krb5_context,
+    addr1: *const krb5_address,
+    addr2: *const krb5_address,
+  ) -> krb5_boolean;
+}
+extern "C" {
+  pub fn krb5_address_order(
+    context: krb5_context,
+    addr1: *const krb5_address,
+    addr2: *const krb5_address,
+  ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+  pub fn krb5_realm_compare(
+    context: krb5_context,
+    princ1: krb5_const_principal,
+    princ2: krb5_const_principal,
+  ) -> krb5_boolean;
+}
+extern "C" {
+  pub fn krb5_principal_compare(
+    context: krb5_context,
+    princ1: krb5_const_principal,
+    princ2: krb5_const_principal,
+  ) -> krb5_boolean;
+}
+extern "C" {
+  pub fn krb5_principal_compare_any_realm(
+    context: krb5_context,
+    princ1: krb5_const_principal,
+    princ2: krb5_const_principal,
+  ) -> krb5_boolean;
+}
+extern "C" {
+  pub fn krb5_principal_compare_flags(
+    context: krb5_context,
+    princ1: krb5_const_principal,
+    princ2: krb5_const_principal,
+    flags: ::std::os::raw::c_int,
+  ) -> krb5_boolean;
+}
+extern "C" {
+  pub fn krb5_init_keyblock(
+    context: krb5_context,
+    enctype: krb5_enctype,
+    length: size_t,
+    out: *mut *mut krb5_keyblock,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_copy_keyblock(
+    context: krb5_context,
+    from: *const krb5_keyblock,
+    to: *mut *mut krb5_keyblock,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_copy_keyblock_contents(
+    context: krb5_context,
+    from: *const krb5_keyblock,
+    to: *mut krb5_keyblock,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_copy_creds(
+    context: krb5_context,
+    incred: *const krb5_creds,
+    outcred: *mut *mut krb5_creds,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_copy_data(
+    context: krb5_context,
+    indata: *const krb5_data,
+    outdata: *mut *mut krb5_data,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_copy_principal(
+    context: krb5_context,
+    inprinc: krb5_const_principal,
+    outprinc: *mut krb5_principal,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_copy_addresses(
+    context: krb5_context,
+    inaddr: *const *mut krb5_address,
+    outaddr: *mut *mut *mut krb5_address,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_copy_ticket(
+    context: krb5_context,
+    from: *const krb5_ticket,
+    pto: *mut *mut krb5_ticket,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_copy_authdata(
+    context: krb5_context,
+    in_authdat: *const *mut krb5_authdata,
+    out: *mut *mut *mut krb5_authdata,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_find_authdata(
+    context: krb5_context,
+    ticket_authdata: *const *mut krb5_authdata,
+    ap_req_authdata: *const *mut krb5_authdata,
+    ad_type: krb5_authdatatype,
+    results: *mut *mut *mut krb5_authdata,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_merge_authdata(
+    context: krb5_context,
+    inauthdat1: *const *mut krb5_authdata,
+    inauthdat2: *const *mut krb5_authdata,
+    outauthdat: *mut *mut *mut krb5_authdata,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_copy_authenticator(
+    context: krb5_context,
+    authfrom: *const krb5_authenticator,
+    authto: *mut *mut krb5_authenticator,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_copy_checksum(
+    context: krb5_context,
+    ckfrom: *const krb5_checksum,
+    ckto: *mut *mut krb5_checksum,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_get_server_rcache(
+    context: krb5_context,
+    piece: *const krb5_data,
+    rcptr: *mut krb5_rcache,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_build_principal_ext(
+    context: krb5_context,
+    princ: *mut krb5_principal,
+    rlen: ::std::os::raw::c_uint,
+    realm: *const ::std::os::raw::c_char,
+    ...
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_build_principal(
+    context: krb5_context,
+    princ: *mut krb5_principal,
+    rlen: ::std::os::raw::c_uint,
+    realm: *const ::std::os::raw::c_char,
+    ...
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_build_principal_alloc_va(
+    context: krb5_context,
+    princ: *mut krb5_principal,
+    rlen: ::std::os::raw::c_uint,
+    realm: *const ::std::os::raw::c_char,
+    ap: *mut __va_list_tag,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_425_conv_principal(
+    context: krb5_context,
+    name: *const ::std::os::raw::c_char,
+    instance: *const ::std::os::raw::c_char,
+    realm: *const ::std::os::raw::c_char,
+    princ: *mut krb5_principal,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_524_conv_principal(
+    context: krb5_context,
+    princ: krb5_const_principal,
+    name: *mut ::std::os::raw::c_char,
+    inst: *mut ::std::os::raw::c_char,
+    realm: *mut ::std::os::raw::c_char,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_524_convert_creds(
+    context: krb5_context,
+    v5creds: *mut krb5_creds,
+    v4creds: *mut credentials,
+  ) -> ::std::os::raw::c_int;
+}
+extern "C" {
+  pub fn krb5_kt_resolve(
+    context: krb5_context,
+    name: *const ::std::os::raw::c_char,
+    ktid: *mut krb5_keytab,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_kt_dup(context: krb5_context, in_: krb5_keytab, out: *mut krb5_keytab) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_kt_default_name(
+    context: krb5_context,
+    name: *mut ::std::os::raw::c_char,
+    name_size: ::std::os::raw::c_int,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_kt_default(context: krb5_context, id: *mut krb5_keytab) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_kt_client_default(context: krb5_context, keytab_out: *mut krb5_keytab) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_free_keytab_entry_contents(context: krb5_context, entry: *mut krb5_keytab_entry) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_kt_free_entry(context: krb5_context, entry: *mut krb5_keytab_entry) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_kt_remove_entry(context: krb5_context, id: krb5_keytab, entry: *mut krb5_keytab_entry)
+    -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_kt_add_entry(context: krb5_context, id: krb5_keytab, entry: *mut krb5_keytab_entry) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_principal2salt(context: krb5_context, pr: krb5_const_principal, ret: *mut krb5_data) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_cc_resolve(
+    context: krb5_context,
+    name: *const ::std::os::raw::c_char,
+    cache: *mut krb5_ccache,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_cc_dup(context: krb5_context, in_: krb5_ccache, out: *mut krb5_ccache) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_cc_default_name(context: krb5_context) -> *const ::std::os::raw::c_char;
+}
+extern "C" {
+  pub fn krb5_cc_set_default_name(context: krb5_context, name: *const ::std::os::raw::c_char) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_cc_default(context: krb5_context, ccache: *mut krb5_ccache) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_cc_copy_creds(context: krb5_context, incc: krb5_ccache, outcc: krb5_ccache) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_cc_get_config(
+    context: krb5_context,
+    id: krb5_ccache,
+    principal: krb5_const_principal,
+    key: *const ::std::os::raw::c_char,
+    data: *mut krb5_data,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_cc_set_config(
+    context: krb5_context,
+    id: krb5_ccache,
+    principal: krb5_const_principal,
+    key: *const ::std::os::raw::c_char,
+    data: *mut krb5_data,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_is_config_principal(context: krb5_context, principal: krb5_const_principal) -> krb5_boolean;
+}
+extern "C" {
+  pub fn krb5_cc_switch(context: krb5_context, cache: krb5_ccache) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_cc_support_switch(context: krb5_context, type_: *const ::std::os::raw::c_char) -> krb5_boolean;
+}
+extern "C" {
+  pub fn krb5_cc_cache_match(
+    context: krb5_context,
+    client: krb5_principal,
+    cache_out: *mut krb5_ccache,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_cc_select(
+    context: krb5_context,
+    server: krb5_principal,
+    cache_out: *mut krb5_ccache,
+    princ_out: *mut krb5_principal,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_free_principal(context: krb5_context, val: krb5_principal);
+}
+extern "C" {
+  pub fn krb5_free_authenticator(context: krb5_context, val: *mut krb5_authenticator);
+}
+extern "C" {
+  pub fn krb5_free_addresses(context: krb5_context, val: *mut *mut krb5_address);
+}
+extern "C" {
+  pub fn krb5_free_authdata(context: krb5_context, val: *mut *mut krb5_authdata);
+}
+extern "C" {
+  pub fn krb5_free_ticket(context: krb5_context, val: *mut krb5_ticket);
+}
+extern "C" {
+  pub fn krb5_free_error(context: krb5_context, val: *mut krb5_error);
+}
+extern "C" {
+  pub fn krb5_free_creds(context: krb5_context, val: *mut krb5_creds);
+}
+extern "C" {
+  pub fn krb5_free_cred_contents(context: krb5_context, val: *mut krb5_creds);
+}
+extern "C" {
+  pub fn krb5_free_checksum(context: krb5_context, val: *mut krb5_checksum);
+}
+extern "C" {
+  pub fn krb5_free_checksum_contents(context: krb5_context, val: *mut krb5_checksum);
+}
+extern "C" {
+  pub fn krb5_free_keyblock(context: krb5_context, val: *mut krb5_keyblock);
+}
+extern "C" {
+  pub fn krb5_free_keyblock_contents(context: krb5_context, key: *mut krb5_keyblock);
+}
+extern "C" {
+  pub fn krb5_free_ap_rep_enc_part(context: krb5_context, val: *mut krb5_ap_rep_enc_part);
+}
+extern "C" {
+  pub fn krb5_free_data(context: krb5_context, val: *mut krb5_data);
+}
+extern "C" {
+  pub fn krb5_free_octet_data(context: krb5_context, val: *mut krb5_octet_data);
+}
+extern "C" {
+  pub fn krb5_free_data_contents(context: krb5_context, val: *mut krb5_data);
+}
+extern "C" {
+  pub fn krb5_free_unparsed_name(context: krb5_context, val: *mut ::std::os::raw::c_char);
+}
+extern "C" {
+  pub fn krb5_free_string(context: krb5_context, val: *mut ::std::os::raw::c_char);
+}
+extern "C" {
+  pub fn krb5_free_enctypes(context: krb5_context, val: *mut krb5_enctype);
+}
+extern "C" {
+  pub fn krb5_free_cksumtypes(context: krb5_context, val: *mut krb5_cksumtype);
+}
+extern "C" {
+  pub fn krb5_us_timeofday(
+    context: krb5_context,
+    seconds: *mut krb5_timestamp,
+    microseconds: *mut krb5_int32,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_timeofday(context: krb5_context, timeret: *mut krb5_timestamp) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_check_clockskew(context: krb5_context, date: krb5_timestamp) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_os_localaddr(context: krb5_context, addr: *mut *mut *mut krb5_address) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_get_default_realm(context: krb5_context, lrealm: *mut *mut ::std::os::raw::c_char) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_set_default_realm(context: krb5_context, lrealm: *const ::std::os::raw::c_char) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_free_default_realm(context: krb5_context, lrealm: *mut ::std::os::raw::c_char);
+}
+extern "C" {
+  pub fn krb5_expand_hostname(
+    context: krb5_context,
+    host: *const ::std::os::raw::c_char,
+    canonhost_out: *mut *mut ::std::os::raw::c_char,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_sname_to_principal(
+    context: krb5_context,
+    hostname: *const ::std::os::raw::c_char,
+    sname: *const ::std::os::raw::c_char,
+    type_: krb5_int32,
+    ret_princ: *mut krb5_principal,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_sname_match(
+    context: krb5_context,
+    matching: krb5_const_principal,
+    princ: krb5_const_principal,
+  ) -> krb5_boolean;
+}
+extern "C" {
+  pub fn krb5_change_password(
+    context: krb5_context,
+    creds: *mut krb5_creds,
+    newpw: *const ::std::os::raw::c_char,
+    result_code: *mut ::std::os::raw::c_int,
+    result_code_string: *mut krb5_data,
+    result_string: *mut krb5_data,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_set_password(
+    context: krb5_context,
+    creds: *mut krb5_creds,
+    newpw: *const ::std::os::raw::c_char,
+    change_password_for: krb5_principal,
+    result_code: *mut ::std::os::raw::c_int,
+    result_code_string: *mut krb5_data,
+    result_string: *mut krb5_data,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_set_password_using_ccache(
+    context: krb5_context,
+    ccache: krb5_ccache,
+    newpw: *const ::std::os::raw::c_char,
+    change_password_for: krb5_principal,
+    result_code: *mut ::std::os::raw::c_int,
+    result_code_string: *mut krb5_data,
+    result_string: *mut krb5_data,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_chpw_message(
+    context: krb5_context,
+    server_string: *const krb5_data,
+    message_out: *mut *mut ::std::os::raw::c_char,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_get_profile(context: krb5_context, profile: *mut *mut _profile_t) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_rd_req(
+    context: krb5_context,
+    auth_context: *mut krb5_auth_context,
+    inbuf: *const krb5_data,
+    server: krb5_const_principal,
+    keytab: krb5_keytab,
+    ap_req_options: *mut krb5_flags,
+    ticket: *mut *mut krb5_ticket,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_kt_read_service_key(
+    context: krb5_context,
+    keyprocarg: krb5_pointer,
+    principal: krb5_principal,
+    vno: krb5_kvno,
+    enctype: krb5_enctype,
+    key: *mut *mut krb5_keyblock,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_mk_safe(
+    context: krb5_context,
+    auth_context: krb5_auth_context,
+    userdata: *const krb5_data,
+    der_out: *mut krb5_data,
+    rdata_out: *mut krb5_replay_data,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_mk_priv(
+    context: krb5_context,
+    auth_context: krb5_auth_context,
+    userdata: *const krb5_data,
+    der_out: *mut krb5_data,
+    rdata_out: *mut krb5_replay_data,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_sendauth(
+    context: krb5_context,
+    auth_context: *mut krb5_auth_context,
+    fd: krb5_pointer,
+    appl_version: *mut ::std::os::raw::c_char,
+    client: krb5_principal,
+    server: krb5_principal,
+    ap_req_options: krb5_flags,
+    in_data: *mut krb5_data,
+    in_creds: *mut krb5_creds,
+    ccache: krb5_ccache,
+    error: *mut *mut krb5_error,
+    rep_result: *mut *mut krb5_ap_rep_enc_part,
+    out_creds: *mut *mut krb5_creds,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_recvauth(
+    context: krb5_context,
+    auth_context: *mut krb5_auth_context,
+    fd: krb5_pointer,
+    appl_version: *mut ::std::os::raw::c_char,
+    server: krb5_principal,
+    flags: krb5_int32,
+    keytab: krb5_keytab,
+    ticket: *mut *mut krb5_ticket,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_recvauth_version(
+    context: krb5_context,
+    auth_context: *mut krb5_auth_context,
+    fd: krb5_pointer,
+    server: krb5_principal,
+    flags: krb5_int32,
+    keytab: krb5_keytab,
+    ticket: *mut *mut krb5_ticket,
+    version: *mut krb5_data,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_mk_ncred(
+    context: krb5_context,
+    auth_context: krb5_auth_context,
+    creds: *mut *mut krb5_creds,
+    der_out: *mut *mut krb5_data,
+    rdata_out: *mut krb5_replay_data,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_mk_1cred(
+    context: krb5_context,
+    auth_context: krb5_auth_context,
+    creds: *mut krb5_creds,
+    der_out: *mut *mut krb5_data,
+    rdata_out: *mut krb5_replay_data,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_rd_cred(
+    context: krb5_context,
+    auth_context: krb5_auth_context,
+    creddata: *mut krb5_data,
+    creds_out: *mut *mut *mut krb5_creds,
+    rdata_out: *mut krb5_replay_data,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_fwd_tgt_creds(
+    context: krb5_context,
+    auth_context: krb5_auth_context,
+    rhost: *const ::std::os::raw::c_char,
+    client: krb5_principal,
+    server: krb5_principal,
+    cc: krb5_ccache,
+    forwardable: ::std::os::raw::c_int,
+    outbuf: *mut krb5_data,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_auth_con_init(context: krb5_context, auth_context: *mut krb5_auth_context) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_auth_con_free(context: krb5_context, auth_context: krb5_auth_context) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_auth_con_setflags(
+    context: krb5_context,
+    auth_context: krb5_auth_context,
+    flags: krb5_int32,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_auth_con_getflags(
+    context: krb5_context,
+    auth_context: krb5_auth_context,
+    flags: *mut krb5_int32,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_auth_con_set_checksum_func(
+    context: krb5_context,
+    auth_context: krb5_auth_context,
+    func: krb5_mk_req_checksum_func,
+    data: *mut ::std::os::raw::c_void,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_auth_con_get_checksum_func(
+    context: krb5_context,
+    auth_context: krb5_auth_context,
+    func: *mut krb5_mk_req_checksum_func,
+    data: *mut *mut ::std::os::raw::c_void,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_auth_con_setaddrs(
+    context: krb5_context,
+    auth_context: krb5_auth_context,
+    local_addr: *mut krb5_address,
+    remote_addr: *mut krb5_address,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_auth_con_getaddrs(
+    context: krb5_context,
+    auth_context: krb5_auth_context,
+    local_addr: *mut *mut krb5_address,
+    remote_addr: *mut *mut krb5_address,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_auth_con_setports(
+    context: krb5_context,
+    auth_context: krb5_auth_context,
+    local_port: *mut krb5_address,
+    remote_port: *mut krb5_address,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_auth_con_setuseruserkey(
+    context: krb5_context,
+    auth_context: krb5_auth_context,
+    keyblock: *mut krb5_keyblock,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_auth_con_getkey(
+    context: krb5_context,
+    auth_context: krb5_auth_context,
+    keyblock: *mut *mut krb5_keyblock,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_auth_con_getkey_k(
+    context: krb5_context,
+    auth_context: krb5_auth_context,
+    key: *mut krb5_key,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_auth_con_getsendsubkey(
+    ctx: krb5_context,
+    ac: krb5_auth_context,
+    keyblock: *mut *mut krb5_keyblock,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_auth_con_getsendsubkey_k(ctx: krb5_context, ac: krb5_auth_context, key: *mut krb5_key)
+    -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_auth_con_getrecvsubkey(
+    ctx: krb5_context,
+    ac: krb5_auth_context,
+    keyblock: *mut *mut krb5_keyblock,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_auth_con_getrecvsubkey_k(ctx: krb5_context, ac: krb5_auth_context, key: *mut krb5_key)
+    -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_auth_con_setsendsubkey(
+    ctx: krb5_context,
+    ac: krb5_auth_context,
+    keyblock: *mut krb5_keyblock,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_auth_con_setsendsubkey_k(ctx: krb5_context, ac: krb5_auth_context, key: krb5_key) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_auth_con_setrecvsubkey(
+    ctx: krb5_context,
+    ac: krb5_auth_context,
+    keyblock: *mut krb5_keyblock,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_auth_con_setrecvsubkey_k(ctx: krb5_context, ac: krb5_auth_context, key: krb5_key) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_auth_con_getlocalseqnumber(
+    context: krb5_context,
+    auth_context: krb5_auth_context,
+    seqnumber: *mut krb5_int32,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_auth_con_getremoteseqnumber(
+    context: krb5_context,
+    auth_context: krb5_auth_context,
+    seqnumber: *mut krb5_int32,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_auth_con_initivector(context: krb5_context, auth_context: krb5_auth_context) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_auth_con_setrcache(
+    context: krb5_context,
+    auth_context: krb5_auth_context,
+    rcache: krb5_rcache,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_auth_con_getrcache(
+    context: krb5_context,
+    auth_context: krb5_auth_context,
+    rcache: *mut krb5_rcache,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_auth_con_getauthenticator(
+    context: krb5_context,
+    auth_context: krb5_auth_context,
+    authenticator: *mut *mut krb5_authenticator,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_auth_con_set_req_cksumtype(
+    context: krb5_context,
+    auth_context: krb5_auth_context,
+    cksumtype: krb5_cksumtype,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_read_password(
+    context: krb5_context,
+    prompt: *const ::std::os::raw::c_char,
+    prompt2: *const ::std::os::raw::c_char,
+    return_pwd: *mut ::std::os::raw::c_char,
+    size_return: *mut ::std::os::raw::c_uint,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_aname_to_localname(
+    context: krb5_context,
+    aname: krb5_const_principal,
+    lnsize_in: ::std::os::raw::c_int,
+    lname: *mut ::std::os::raw::c_char,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_get_host_realm(
+    context: krb5_context,
+    host: *const ::std::os::raw::c_char,
+    realmsp: *mut *mut *mut ::std::os::raw::c_char,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_get_fallback_host_realm(
+    context: krb5_context,
+    hdata: *mut krb5_data,
+    realmsp: *mut *mut *mut ::std::os::raw::c_char,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_free_host_realm(context: krb5_context, realmlist: *const *mut ::std::os::raw::c_char) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_kuserok(
+    context: krb5_context,
+    principal: krb5_principal,
+    luser: *const ::std::os::raw::c_char,
+  ) -> krb5_boolean;
+}
+extern "C" {
+  pub fn krb5_auth_con_genaddrs(
+    context: krb5_context,
+    auth_context: krb5_auth_context,
+    infd: ::std::os::raw::c_int,
+    flags: ::std::os::raw::c_int,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_set_real_time(
+    context: krb5_context,
+    seconds: krb5_timestamp,
+    microseconds: krb5_int32,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_get_time_offsets(
+    context: krb5_context,
+    seconds: *mut krb5_timestamp,
+    microseconds: *mut krb5_int32,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_string_to_enctype(string: *mut ::std::os::raw::c_char, enctypep: *mut krb5_enctype) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_string_to_salttype(string: *mut ::std::os::raw::c_char, salttypep: *mut krb5_int32) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_string_to_cksumtype(
+    string: *mut ::std::os::raw::c_char,
+    cksumtypep: *mut krb5_cksumtype,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_string_to_timestamp(
+    string: *mut ::std::os::raw::c_char,
+    timestampp: *mut krb5_timestamp,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_string_to_deltat(string: *mut ::std::os::raw::c_char, deltatp: *mut krb5_deltat) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_enctype_to_string(
+    enctype: krb5_enctype,
+    buffer: *mut ::std::os::raw::c_char,
+    buflen: size_t,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_enctype_to_name(
+    enctype: krb5_enctype,
+    shortest: krb5_boolean,
+    buffer: *mut ::std::os::raw::c_char,
+    buflen: size_t,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_salttype_to_string(
+    salttype: krb5_int32,
+    buffer: *mut ::std::os::raw::c_char,
+    buflen: size_t,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_cksumtype_to_string(
+    cksumtype: krb5_cksumtype,
+    buffer: *mut ::std::os::raw::c_char,
+    buflen: size_t,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_timestamp_to_string(
+    timestamp: krb5_timestamp,
+    buffer: *mut ::std::os::raw::c_char,
+    buflen: size_t,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_timestamp_to_sfstring(
+    timestamp: krb5_timestamp,
+    buffer: *mut ::std::os::raw::c_char,
+    buflen: size_t,
+    pad: *mut ::std::os::raw::c_char,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_deltat_to_string(
+    deltat: krb5_deltat,
+    buffer: *mut ::std::os::raw::c_char,
+    buflen: size_t,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_prompter_posix(
+    context: krb5_context,
+    data: *mut ::std::os::raw::c_void,
+    name: *const ::std::os::raw::c_char,
+    banner: *const ::std::os::raw::c_char,
+    num_prompts: ::std::os::raw::c_int,
+    prompts: *mut krb5_prompt,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_responder_list_questions(
+    ctx: krb5_context,
+    rctx: krb5_responder_context,
+  ) -> *const *const ::std::os::raw::c_char;
+}
+extern "C" {
+  pub fn krb5_responder_get_challenge(
+    ctx: krb5_context,
+    rctx: krb5_responder_context,
+    question: *const ::std::os::raw::c_char,
+  ) -> *const ::std::os::raw::c_char;
+}
+extern "C" {
+  pub fn krb5_responder_set_answer(
+    ctx: krb5_context,
+    rctx: krb5_responder_context,
+    question: *const ::std::os::raw::c_char,
+    answer: *const ::std::os::raw::c_char,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_responder_otp_get_challenge(
+    ctx: krb5_context,
+    rctx: krb5_responder_context,
+    chl: *mut *mut krb5_responder_otp_challenge,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_responder_otp_set_answer(
+    ctx: krb5_context,
+    rctx: krb5_responder_context,
+    ti: size_t,
+    value: *const ::std::os::raw::c_char,
+    pin: *const ::std::os::raw::c_char,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_responder_otp_challenge_free(
+    ctx: krb5_context,
+    rctx: krb5_responder_context,
+    chl: *mut krb5_responder_otp_challenge,
+  );
+}
+extern "C" {
+  pub fn krb5_responder_pkinit_get_challenge(
+    ctx: krb5_context,
+    rctx: krb5_responder_context,
+    chl_out: *mut *mut krb5_responder_pkinit_challenge,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_responder_pkinit_set_answer(
+    ctx: krb5_context,
+    rctx: krb5_responder_context,
+    identity: *const ::std::os::raw::c_char,
+    pin: *const ::std::os::raw::c_char,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_responder_pkinit_challenge_free(
+    ctx: krb5_context,
+    rctx: krb5_responder_context,
+    chl: *mut krb5_responder_pkinit_challenge,
+  );
+}
+extern "C" {
+  pub fn krb5_get_init_creds_opt_alloc(
+    context: krb5_context,
+    opt: *mut *mut krb5_get_init_creds_opt,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_get_init_creds_opt_free(context: krb5_context, opt: *mut krb5_get_init_creds_opt);
+}
+extern "C" {
+  pub fn krb5_get_init_creds_opt_init(opt: *mut krb5_get_init_creds_opt);
+}
+extern "C" {
+  pub fn krb5_get_init_creds_opt_set_tkt_life(opt: *mut krb5_get_init_creds_opt, tkt_life: krb5_deltat);
+}
+extern "C" {
+  pub fn krb5_get_init_creds_opt_set_renew_life(opt: *mut krb5_get_init_creds_opt, renew_life: krb5_deltat);
+}
+extern "C" {
+  pub fn krb5_get_init_creds_opt_set_forwardable(opt: *mut krb5_get_init_creds_opt, forwardable: ::std::os::raw::c_int);
+}
+extern "C" {
+  pub fn krb5_get_init_creds_opt_set_proxiable(opt: *mut krb5_get_init_creds_opt, proxiable: ::std::os::raw::c_int);
+}
+extern "C" {
+  pub fn krb5_get_init_creds_opt_set_canonicalize(
+    opt: *mut krb5_get_init_creds_opt,
+    canonicalize: ::std::os::raw::c_int,
+  );
+}
+extern "C" {
+  pub fn krb5_get_init_creds_opt_set_anonymous(opt: *mut krb5_get_init_creds_opt, anonymous: ::std::os::raw::c_int);
+}
+extern "C" {
+  pub fn krb5_get_init_creds_opt_set_etype_list(
+    opt: *mut krb5_get_init_creds_opt,
+    etype_list: *mut krb5_enctype,
+    etype_list_length: ::std::os::raw::c_int,
+  );
+}
+extern "C" {
+  pub fn krb5_get_init_creds_opt_set_address_list(opt: *mut krb5_get_init_creds_opt, addresses: *mut *mut krb5_address);
+}
+extern "C" {
+  pub fn krb5_get_init_creds_opt_set_preauth_list(
+    opt: *mut krb5_get_init_creds_opt,
+    preauth_list: *mut krb5_preauthtype,
+    preauth_list_length: ::std::os::raw::c_int,
+  );
+}
+extern "C" {
+  pub fn krb5_get_init_creds_opt_set_salt(opt: *mut krb5_get_init_creds_opt, salt: *mut krb5_data);
+}
+extern "C" {
+  pub fn krb5_get_init_creds_opt_set_change_password_prompt(
+    opt: *mut krb5_get_init_creds_opt,
+    prompt: ::std::os::raw::c_int,
+  );
+}
+extern "C" {
+  pub fn krb5_get_init_creds_opt_set_pa(
+    context: krb5_context,
+    opt: *mut krb5_get_init_creds_opt,
+    attr: *const ::std::os::raw::c_char,
+    value: *const ::std::os::raw::c_char,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_get_init_creds_opt_set_fast_ccache_name(
+    context: krb5_context,
+    opt: *mut krb5_get_init_creds_opt,
+    fast_ccache_name: *const ::std::os::raw::c_char,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_get_init_creds_opt_set_fast_ccache(
+    context: krb5_context,
+    opt: *mut krb5_get_init_creds_opt,
+    ccache: krb5_ccache,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_get_init_creds_opt_set_in_ccache(
+    context: krb5_context,
+    opt: *mut krb5_get_init_creds_opt,
+    ccache: krb5_ccache,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_get_init_creds_opt_set_out_ccache(
+    context: krb5_context,
+    opt: *mut krb5_get_init_creds_opt,
+    ccache: krb5_ccache,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_get_init_creds_opt_set_pac_request(
+    context: krb5_context,
+    opt: *mut krb5_get_init_creds_opt,
+    req_pac: krb5_boolean,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_get_init_creds_opt_set_fast_flags(
+    context: krb5_context,
+    opt: *mut krb5_get_init_creds_opt,
+    flags: krb5_flags,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_get_init_creds_opt_get_fast_flags(
+    context: krb5_context,
+    opt: *mut krb5_get_init_creds_opt,
+    out_flags: *mut krb5_flags,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_get_init_creds_opt_set_expire_callback(
+    context: krb5_context,
+    opt: *mut krb5_get_init_creds_opt,
+    cb: krb5_expire_callback_func,
+    data: *mut ::std::os::raw::c_void,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_get_init_creds_opt_set_responder(
+    context: krb5_context,
+    opt: *mut krb5_get_init_creds_opt,
+    responder: krb5_responder_fn,
+    data: *mut ::std::os::raw::c_void,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_get_init_creds_password(
+    context: krb5_context,
+    creds: *mut krb5_creds,
+    client: krb5_principal,
+    password: *const ::std::os::raw::c_char,
+    prompter: krb5_prompter_fct,
+    data: *mut ::std::os::raw::c_void,
+    start_time: krb5_deltat,
+    in_tkt_service: *const ::std::os::raw::c_char,
+    k5_gic_options: *mut krb5_get_init_creds_opt,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_get_etype_info(
+    context: krb5_context,
+    principal: krb5_principal,
+    opt: *mut krb5_get_init_creds_opt,
+    enctype_out: *mut krb5_enctype,
+    salt_out: *mut krb5_data,
+    s2kparams_out: *mut krb5_data,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_init_creds_free(context: krb5_context, ctx: krb5_init_creds_context);
+}
+extern "C" {
+  pub fn krb5_init_creds_get(context: krb5_context, ctx: krb5_init_creds_context) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_init_creds_get_creds(
+    context: krb5_context,
+    ctx: krb5_init_creds_context,
+    creds: *mut krb5_creds,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_init_creds_get_error(
+    context: krb5_context,
+    ctx: krb5_init_creds_context,
+    error: *mut *mut krb5_error,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_init_creds_init(
+    context: krb5_context,
+    client: krb5_principal,
+    prompter: krb5_prompter_fct,
+    data: *mut ::std::os::raw::c_void,
+    start_time: krb5_deltat,
+    options: *mut krb5_get_init_creds_opt,
+    ctx: *mut krb5_init_creds_context,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_init_creds_set_keytab(
+    context: krb5_context,
+    ctx: krb5_init_creds_context,
+    keytab: krb5_keytab,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_init_creds_step(
+    context: krb5_context,
+    ctx: krb5_init_creds_context,
+    in_: *mut krb5_data,
+    out: *mut krb5_data,
+    realm: *mut krb5_data,
+    flags: *mut ::std::os::raw::c_uint,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_init_creds_set_password(
+    context: krb5_context,
+    ctx: krb5_init_creds_context,
+    password: *const ::std::os::raw::c_char,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_init_creds_set_service(
+    context: krb5_context,
+    ctx: krb5_init_creds_context,
+    service: *const ::std::os::raw::c_char,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_init_creds_get_times(
+    context: krb5_context,
+    ctx: krb5_init_creds_context,
+    times: *mut krb5_ticket_times,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_tkt_creds_init(
+    context: krb5_context,
+    ccache: krb5_ccache,
+    creds: *mut krb5_creds,
+    options: krb5_flags,
+    ctx: *mut krb5_tkt_creds_context,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_tkt_creds_get(context: krb5_context, ctx: krb5_tkt_creds_context) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_tkt_creds_get_creds(
+    context: krb5_context,
+    ctx: krb5_tkt_creds_context,
+    creds: *mut krb5_creds,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_tkt_creds_free(context: krb5_context, ctx: krb5_tkt_creds_context);
+}
+extern "C" {
+  pub fn krb5_tkt_creds_step(
+    context: krb5_context,
+    ctx: krb5_tkt_creds_context,
+    in_: *mut krb5_data,
+    out: *mut krb5_data,
+    realm: *mut krb5_data,
+    flags: *mut ::std::os::raw::c_uint,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_tkt_creds_get_times(
+    context: krb5_context,
+    ctx: krb5_tkt_creds_context,
+    times: *mut krb5_ticket_times,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_get_init_creds_keytab(
+    context: krb5_context,
+    creds: *mut krb5_creds,
+    client: krb5_principal,
+    arg_keytab: krb5_keytab,
+    start_time: krb5_deltat,
+    in_tkt_service: *const ::std::os::raw::c_char,
+    k5_gic_options: *mut krb5_get_init_creds_opt,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_verify_init_creds_opt_init(k5_vic_options: *mut krb5_verify_init_creds_opt);
+}
+extern "C" {
+  pub fn krb5_verify_init_creds_opt_set_ap_req_nofail(
+    k5_vic_options: *mut krb5_verify_init_creds_opt,
+    ap_req_nofail: ::std::os::raw::c_int,
+  );
+}
+extern "C" {
+  pub fn krb5_verify_init_creds(
+    context: krb5_context,
+    creds: *mut krb5_creds,
+    server: krb5_principal,
+    keytab: krb5_keytab,
+    ccache: *mut krb5_ccache,
+    options: *mut krb5_verify_init_creds_opt,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_get_validated_creds(
+    context: krb5_context,
+    creds: *mut krb5_creds,
+    client: krb5_principal,
+    ccache: krb5_ccache,
+    in_tkt_service: *const ::std::os::raw::c_char,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_get_renewed_creds(
+    context: krb5_context,
+    creds: *mut krb5_creds,
+    client: krb5_principal,
+    ccache: krb5_ccache,
+    in_tkt_service: *const ::std::os::raw::c_char,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_decode_ticket(code: *const krb5_data, rep: *mut *mut krb5_ticket) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_appdefault_string(
+    context: krb5_context,
+    appname: *const ::std::os::raw::c_char,
+    realm: *const krb5_data,
+    option: *const ::std::os::raw::c_char,
+    default_value: *const ::std::os::raw::c_char,
+    ret_value: *mut *mut ::std::os::raw::c_char,
+  );
+}
+extern "C" {
+  pub fn krb5_appdefault_boolean(
+    context: krb5_context,
+    appname: *const ::std::os::raw::c_char,
+    realm: *const krb5_data,
+    option: *const ::std::os::raw::c_char,
+    default_value: ::std::os::raw::c_int,
+    ret_value: *mut ::std::os::raw::c_int,
+  );
+}
+extern "C" {
+  pub fn krb5_get_prompt_types(context: krb5_context) -> *mut krb5_prompt_type;
+}
+extern "C" {
+  pub fn krb5_set_error_message(ctx: krb5_context, code: krb5_error_code, fmt: *const ::std::os::raw::c_char, ...);
+}
+extern "C" {
+  pub fn krb5_vset_error_message(
+    ctx: krb5_context,
+    code: krb5_error_code,
+    fmt: *const ::std::os::raw::c_char,
+    args: *mut __va_list_tag,
+  );
+}
+extern "C" {
+  pub fn krb5_prepend_error_message(ctx: krb5_context, code: krb5_error_code, fmt: *const ::std::os::raw::c_char, ...);
+}
+extern "C" {
+  pub fn krb5_vprepend_error_message(
+    ctx: krb5_context,
+    code: krb5_error_code,
+    fmt: *const ::std::os::raw::c_char,
+    args: *mut __va_list_tag,
+  );
+}
+extern "C" {
+  pub fn krb5_wrap_error_message(
+    ctx: krb5_context,
+    old_code: krb5_error_code,
+    code: krb5_error_code,
+    fmt: *const ::std::os::raw::c_char,
+    ...
+  );
+}
+extern "C" {
+  pub fn krb5_vwrap_error_message(
+    ctx: krb5_context,
+    old_code: krb5_error_code,
+    code: krb5_error_code,
+    fmt: *const ::std::os::raw::c_char,
+    args: *mut __va_list_tag,
+  );
+}
+extern "C" {
+  pub fn krb5_copy_error_message(dest_ctx: krb5_context, src_ctx: krb5_context);
+}
+extern "C" {
+  pub fn krb5_get_error_message(ctx: krb5_context, code: krb5_error_code) -> *const ::std::os::raw::c_char;
+}
+extern "C" {
+  pub fn krb5_free_error_message(ctx: krb5_context, msg: *const ::std::os::raw::c_char);
+}
+extern "C" {
+  pub fn krb5_clear_error_message(ctx: krb5_context);
+}
+extern "C" {
+  pub fn krb5_decode_authdata_container(
+    context: krb5_context,
+    type_: krb5_authdatatype,
+    container: *const krb5_authdata,
+    authdata: *mut *mut *mut krb5_authdata,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_encode_authdata_container(
+    context: krb5_context,
+    type_: krb5_authdatatype,
+    authdata: *const *mut krb5_authdata,
+    container: *mut *mut *mut krb5_authdata,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_make_authdata_kdc_issued(
+    context: krb5_context,
+    key: *const krb5_keyblock,
+    issuer: krb5_const_principal,
+    authdata: *const *mut krb5_authdata,
+    ad_kdcissued: *mut *mut *mut krb5_authdata,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_verify_authdata_kdc_issued(
+    context: krb5_context,
+    key: *const krb5_keyblock,
+    ad_kdcissued: *const krb5_authdata,
+    issuer: *mut krb5_principal,
+    authdata: *mut *mut *mut krb5_authdata,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_pac_add_buffer(
+    context: krb5_context,
+    pac: krb5_pac,
+    type_: krb5_ui_4,
+    data: *const krb5_data,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_pac_free(context: krb5_context, pac: krb5_pac);
+}
+extern "C" {
+  pub fn krb5_pac_get_buffer(
+    context: krb5_context,
+    pac: krb5_pac,
+    type_: krb5_ui_4,
+    data: *mut krb5_data,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_pac_get_types(
+    context: krb5_context,
+    pac: krb5_pac,
+    len: *mut size_t,
+    types: *mut *mut krb5_ui_4,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_pac_init(context: krb5_context, pac: *mut krb5_pac) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_pac_parse(
+    context: krb5_context,
+    ptr: *const ::std::os::raw::c_void,
+    len: size_t,
+    pac: *mut krb5_pac,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_pac_verify(
+    context: krb5_context,
+    pac: krb5_pac,
+    authtime: krb5_timestamp,
+    principal: krb5_const_principal,
+    server: *const krb5_keyblock,
+    privsvr: *const krb5_keyblock,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_pac_verify_ext(
+    context: krb5_context,
+    pac: krb5_pac,
+    authtime: krb5_timestamp,
+    principal: krb5_const_principal,
+    server: *const krb5_keyblock,
+    privsvr: *const krb5_keyblock,
+    with_realm: krb5_boolean,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_pac_sign(
+    context: krb5_context,
+    pac: krb5_pac,
+    authtime: krb5_timestamp,
+    principal: krb5_const_principal,
+    server_key: *const krb5_keyblock,
+    privsvr_key: *const krb5_keyblock,
+    data: *mut krb5_data,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_pac_sign_ext(
+    context: krb5_context,
+    pac: krb5_pac,
+    authtime: krb5_timestamp,
+    principal: krb5_const_principal,
+    server_key: *const krb5_keyblock,
+    privsvr_key: *const krb5_keyblock,
+    with_realm: krb5_boolean,
+    data: *mut krb5_data,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_pac_get_client_info(
+    context: krb5_context,
+    pac: krb5_pac,
+    authtime_out: *mut krb5_timestamp,
+    princname_out: *mut *mut ::std::os::raw::c_char,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_allow_weak_crypto(context: krb5_context, enable: krb5_boolean) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_set_trace_callback(
+    context: krb5_context,
+    fn_: krb5_trace_callback,
+    cb_data: *mut ::std::os::raw::c_void,
+  ) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_set_trace_filename(context: krb5_context, filename: *const ::std::os::raw::c_char) -> krb5_error_code;
+}
+extern "C" {
+  pub fn krb5_set_kdc_send_hook(context: krb5_context, send_hook: krb5_pre_send_fn, data: *mut ::std::os::raw::c_void);
+}
+extern "C" {
+  pub fn krb5_set_kdc_recv_hook(context: krb5_context, recv_hook: krb5_post_recv_fn, data: *mut ::std::os::raw::c_void);
+}
+extern "C" {
+  pub fn com_err(arg1: *const ::std::os::raw::c_char, arg2: errcode_t, arg3: *const ::std::os::raw::c_char, ...);
+}
+extern "C" {
+  pub fn error_message(arg1: errcode_t) -> *const ::std::os::raw::c_char;
+}
+extern "C" {
+  pub fn profile_init(files: *mut const_profile_filespec_t, ret_profile: *mut profile_t) -> ::std::os::raw::c_long;
+}
+extern "C" {
+  pub fn profile_init_flags(
+    files: *mut const_profile_filespec_t,
+    flags: ::std::os::raw::c_int,
+    ret_profile: *mut profile_t,
+  ) -> ::std::os::raw::c_long;
+}
+extern "C" {
+  pub fn profile_init_path(
+    filelist: const_profile_filespec_list_t,
+    ret_profile: *mut profile_t,
+  ) -> ::std::os::raw::c_long;
+}
+extern "C" {
+  pub fn profile_flush(profile: profile_t) -> ::std::os::raw::c_long;
+}
+extern "C" {
+  pub fn profile_flush_to_file(profile: profile_t, outfile: const_profile_filespec_t) -> ::std::os::raw::c_long;
+}
+extern "C" {
+  pub fn profile_flush_to_buffer(profile: profile_t, bufp: *mut *mut ::std::os::raw::c_char) -> ::std::os::raw::c_long;
+}
+extern "C" {
+  pub fn profile_free_buffer(profile: profile_t, buf: *mut ::std::os::raw::c_char);
+}
+extern "C" {
+  pub fn profile_is_writable(profile: profile_t, writable: *mut ::std::os::raw::c_int) -> ::std::os::raw::c_long;
+}
+extern "C" {
+  pub fn profile_is_modified(profile: profile_t, modified: *mut ::std::os::raw::c_int) -> ::std::os::raw::c_long;
+}
+extern "C" {
+  pub fn profile_abandon(profile: profile_t);
+}
+extern "C" {
+  pub fn profile_release(profile: profile_t);
+}
+extern "C" {
+  pub fn profile_get_values(
+    profile: profile_t,
+    names: *const *const ::std::os::raw::c_char,
+    ret_values: *mut *mut *mut ::std::os::raw::c_char,
+  ) -> ::std::os::raw::c_long;
+}
+extern "C" {
+  pub fn profile_free_list(list: *mut *mut ::std::os::raw::c_char);
+}
+extern "C" {
+  pub fn profile_get_string(
+    profile: profile_t,
+    name: *const ::std::os::raw::c_char,
+    subname: *const ::std::os::raw::c_char,
+    subsubname: *const ::std::os::raw::c_char,
+    def_val: *const ::std::os::raw::c_char,
+    ret_string: *mut *mut ::std::os::raw::c_char,
+  ) -> ::std::os::raw::c_long;
+}
+extern "C" {
+  pub fn profile_get_integer(
+    profile: profile_t,
+    name: *const ::std::os::raw::c_char,
+    subname: *const ::std::os::raw::c_char,
+    subsubname: *const ::std::os::raw::c_char,
+    def_val: ::std::os::raw::c_int,
+    ret_default: *mut ::std::os::raw::c_int,
+  ) -> ::std::os::raw::c_long;
+}
+extern "C" {
+  pub fn profile_get_boolean(
+    profile: profile_t,
+    name: *const ::std::os::raw::c_char,
+    subname: *const ::std::os::raw::c_char,
+    subsubname: *const ::std::os::raw::c_char,
+    def_val: ::std::os::raw::c_int,
+    ret_default: *mut ::std::os::raw::c_int,
+  ) -> ::std::os::raw::c_long;
+}
+extern "C" {
+  pub fn profile_get_relation_names(
+    profile: profile_t,
+    names: *mut *const ::std::os::raw::c_char,
+    ret_names: *mut *mut *mut ::std::os::raw::c_char,
+  ) -> ::std::os::raw::c_long;
+}
+extern "C" {
+  pub fn profile_get_subsection_names(
+    profile: profile_t,
+    names: *mut *const ::std::os::raw::c_char,
+    ret_names: *mut *mut *mut ::std::os::raw::c_char,
+  ) -> ::std::os::raw::c_long;
+}
+extern "C" {
+  pub fn profile_iterator_create(
+    profile: profile_t,
+    names: *const *const ::std::os::raw::c_char,
+    flags: ::std::os::raw::c_int,
+    ret_iter: *mut *mut ::std::os::raw::c_void,
+  ) -> ::std::os::raw::c_long;
+}
+extern "C" {
+  pub fn profile_iterator_free(iter_p: *mut *mut ::std::os::raw::c_void);
+}
+extern "C" {
+  pub fn profile_iterator(
+    iter_p: *mut *mut ::std::os::raw::c_void,
+    ret_name: *mut *mut ::std::os::raw::c_char,
+    ret_value: *mut *mut ::std::os::raw::c_char,
+  ) -> ::std::os::raw::c_long;
+}
+extern "C" {
+  pub fn profile_release_string(str: *mut ::std::os::raw::c_char);
+}
+extern "C" {
+  pub fn profile_update_relation(
+    profile: profile_t,
+    names: *mut *const ::std::os::raw::c_char,
+    old_value: *const ::std::os::raw::c_char,
+    new_value: *const ::std::os::raw::c_char,
+  ) -> ::std::os::raw::c_long;
+}
+extern "C" {
+  pub fn profile_clear_relation(
+    profile: profile_t,
+    names: *mut *const ::std::os::raw::c_char,
+  ) -> ::std::os::raw::c_long;
+}
+extern "C" {
+  pub fn profile_rename_section(
+    profile: profile_t,
+    names: *mut *const ::std::os::raw::c_char,
+    new_name: *const ::std::os::raw::c_char,
+  ) -> ::std::os::raw::c_long;
+}
+extern "C" {
+  pub fn profile_add_relation(
+    profile: profile_t,
+    names: *mut *const ::std::os::raw::c_char,
+    new_value: *const ::std::os::raw::c_char,
+  ) -> ::std::os::raw::c_long;
+}
+extern "C" {
+  pub fn profile_init_vtable(
+    vtable: *mut profile_vtable,
+    cbdata: *mut ::std::os::raw::c_void,
+    ret_profile: *mut profile_t,
+  ) -> ::std::os::raw::c_long;
+}
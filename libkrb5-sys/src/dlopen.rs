@@ -0,0 +1,77 @@
+/*!
+ * Loading libkrb5 at run time, with the `dlopen` feature.
+ *
+ * The library is loaded on first use. Binaries built with this feature start on systems without
+ * Kerberos, and [load] tells whether it is available before any function is called. The
+ * functions of the bindings panic if the library could not be loaded. Functions missing from an
+ * older library return `KRB5_PLUGIN_OP_NOTSUPP`, or null or zero if they return no error code.
+ *
+ * Only MIT krb5 is loaded, and the bindings are built as MIT bindings: Heimdal exports the same
+ * names with different types and structures, so its libkrb5 can not be called through them, and
+ * it is not searched for. GSSAPI is not covered either, as the bindings only contain the
+ * functions of libkrb5, so libgssapi_krb5 is not loaded.
+ */
+use lazy_static::lazy_static;
+use libloading::Library;
+
+use crate::Krb5Functions;
+
+/**
+ * The names of MIT krb5 libraries, in the order they are tried.
+ */
+#[cfg(target_os = "macos")]
+const LIBRARY_NAMES: &[&str] = &[
+  "libkrb5.3.3.dylib",
+  "/opt/homebrew/opt/krb5/lib/libkrb5.3.3.dylib",
+  "/usr/local/opt/krb5/lib/libkrb5.3.3.dylib",
+];
+#[cfg(windows)]
+const LIBRARY_NAMES: &[&str] = &["krb5_64.dll", "krb5_32.dll"];
+#[cfg(not(any(target_os = "macos", windows)))]
+const LIBRARY_NAMES: &[&str] = &["libkrb5.so.3", "libkrb5.so"];
+
+struct LoadedLibrary {
+  // must outlive the functions looked up in it
+  _library: Library,
+  functions: Krb5Functions,
+}
+
+lazy_static! {
+  static ref LIBRARY: Result<LoadedLibrary, String> = open();
+}
+
+fn open() -> Result<LoadedLibrary, String> {
+  let mut errors = Vec::new();
+
+  for name in LIBRARY_NAMES {
+    match unsafe { Library::new(name) } {
+      Ok(library) => {
+        let functions = unsafe { Krb5Functions::load(&library) };
+        return Ok(LoadedLibrary {
+          _library: library,
+          functions,
+        });
+      },
+      Err(error) => errors.push(error.to_string()),
+    }
+  }
+
+  Err(format!("Kerberos is not installed ({})", errors.join("; ")))
+}
+
+/**
+ * Load libkrb5 if it is not loaded yet, and return why it could not be loaded.
+ */
+pub fn load() -> Result<(), String> {
+  LIBRARY.as_ref().map(|_| ()).map_err(Clone::clone)
+}
+
+/**
+ * Return the functions of the loaded library.
+ */
+pub(crate) fn functions() -> &'static Krb5Functions {
+  match &*LIBRARY {
+    Ok(library) => &library.functions,
+    Err(message) => panic!("{}", message),
+  }
+}
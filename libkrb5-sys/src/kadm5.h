@@ -0,0 +1 @@
+#include <kadm5/admin.h>
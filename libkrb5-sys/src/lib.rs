@@ -3,5 +3,27 @@
 #![allow(non_snake_case)]
 #![allow(clippy::redundant_static_lifetimes)]
 #![allow(clippy::unreadable_literal)]
+#![cfg_attr(
+  feature = "dlopen",
+  allow(
+    clippy::missing_safety_doc,
+    clippy::single_match,
+    clippy::too_many_arguments,
+    clippy::type_complexity
+  )
+)]
 
+// generated by bindgen, or copied from src/bindings by the build script
+#[cfg(not(feature = "dlopen"))]
 include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
+
+#[cfg(feature = "dlopen")]
+include!(concat!(env!("OUT_DIR"), "/dlopen_bindings.rs"));
+
+#[cfg(feature = "dlopen")]
+mod dlopen;
+#[cfg(feature = "dlopen")]
+pub use dlopen::load;
+
+#[cfg(not(any(feature = "pregenerated-bindings", feature = "buildtime-bindgen", feature = "dlopen")))]
+compile_error!("one of the `pregenerated-bindings`, `buildtime-bindgen` or `dlopen` features must be enabled");
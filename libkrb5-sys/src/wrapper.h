@@ -1 +1,13 @@
 #include <krb5.h>
+/* profile.h is MIT-only, so include it and com_err.h only where they exist (e.g. not with the Kerberos framework of macOS) */
+#if defined(__has_include)
+#if __has_include(<profile.h>)
+#include <profile.h>
+#endif
+#if __has_include(<com_err.h>)
+#include <com_err.h>
+#endif
+#else
+#include <profile.h>
+#include <com_err.h>
+#endif
@@ -0,0 +1,59 @@
+/*!
+ * Build script for libkrb5.
+ *
+ * Emits `cfg(krb5_mit)` or `cfg(krb5_heimdal)` for the Kerberos implementation libkrb5-sys
+ * linked, so wrappers of functions only one of them has can be gated.
+ *
+ * Emits `cfg(krb5_1_11)`, `cfg(krb5_1_15)`, ... for the releases of MIT krb5 that added
+ * functions this crate wraps, up to the release libkrb5-sys linked, so the crate builds against
+ * older libraries. They are all emitted if the release of MIT krb5 is not known, and none for
+ * Heimdal, so they imply `cfg(krb5_mit)`.
+ */
+
+use std::env;
+
+/* releases of MIT krb5 that added functions wrapped by this crate */
+const MIT_RELEASES: &[(u32, u32)] = &[(1, 11), (1, 15), (1, 18)];
+
+fn main() {
+  println!("cargo:rustc-check-cfg=cfg(krb5_mit)");
+  println!("cargo:rustc-check-cfg=cfg(krb5_heimdal)");
+  for (major, minor) in MIT_RELEASES {
+    println!("cargo:rustc-check-cfg=cfg(krb5_{}_{})", major, minor);
+  }
+
+  /* libkrb5-sys links MIT krb5 unless it reports otherwise */
+  let flavor = env::var("DEP_KRB5_FLAVOR").unwrap_or_else(|_| String::from("mit"));
+  println!("cargo:rustc-cfg=krb5_{}", flavor);
+
+  let version = env::var("DEP_KRB5_VERSION").ok();
+  if let Some(version) = &version {
+    println!("cargo:rustc-env=KRB5_LIBRARY_VERSION={}", version);
+  }
+
+  if flavor != "mit" {
+    return;
+  }
+
+  let release = version
+    .as_deref()
+    .and_then(parse_release)
+    .unwrap_or((u32::MAX, u32::MAX));
+  for &(major, minor) in MIT_RELEASES {
+    if (major, minor) <= release {
+      println!("cargo:rustc-cfg=krb5_{}_{}", major, minor);
+    }
+  }
+}
+
+/**
+ * Parse the major and minor release from a version like `1.19.2` or `1.21-beta1`.
+ */
+fn parse_release(version: &str) -> Option<(u32, u32)> {
+  let mut parts = version.split('.').map(|part| {
+    let digits: String = part.chars().take_while(char::is_ascii_digit).collect();
+    digits.parse::<u32>().ok()
+  });
+
+  Some((parts.next()??, parts.next()??))
+}
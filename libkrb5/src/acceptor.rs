@@ -0,0 +1,110 @@
+/*!
+ * Accepting AP requests for a service.
+ */
+use libkrb5_sys::*;
+
+use crate::auth_context::{Krb5ApOptions, Krb5AuthContext};
+use crate::checksum::Krb5Checksum;
+use crate::context::Krb5Context;
+use crate::error::{krb5_error_code_escape_hatch, Krb5Error};
+use crate::keytab::Krb5Keytab;
+use crate::principal::Krb5Principal;
+use crate::rcache::Krb5RCache;
+use crate::ticket::Krb5Ticket;
+
+/**
+ * Verifies AP requests for a service.
+ *
+ * The key table, service principal and replay cache are set up once and reused for every
+ * request, so accepting a request only creates a new authentication context.
+ */
+#[derive(Debug)]
+pub struct ServiceAcceptor<'a> {
+  context: &'a Krb5Context,
+  keytab: Krb5Keytab<'a>,
+  server: Option<Krb5Principal<'a>>,
+  rcache: Option<Krb5RCache<'a>>,
+}
+
+impl<'a> ServiceAcceptor<'a> {
+  /**
+   * Create an acceptor.
+   *
+   * # Arguments
+   *
+   *  * context: the Krb5Context instance
+   *  * keytab: the key table holding the service keys
+   *  * server: the expected server principal, or `None` to accept any principal in the key
+   *    table
+   *  * rcache: the replay cache shared by all requests, or `None` to open the default replay
+   *    cache for every request
+   */
+  pub fn new(
+    context: &'a Krb5Context,
+    keytab: Krb5Keytab<'a>,
+    server: Option<Krb5Principal<'a>>,
+    rcache: Option<Krb5RCache<'a>>,
+  ) -> Self {
+    ServiceAcceptor {
+      context,
+      keytab,
+      server,
+      rcache,
+    }
+  }
+
+  /**
+   * Verify an AP request.
+   *
+   * # Arguments
+   *
+   *  * token: the AP request token sent by the client
+   */
+  pub fn accept(&self, token: &[u8]) -> Result<AuthenticatedPeer<'a>, Krb5Error> {
+    let mut auth_context = Krb5AuthContext::init(self.context)?;
+
+    if let Some(rcache) = &self.rcache {
+      let code: krb5_error_code =
+        unsafe { krb5_auth_con_setrcache(self.context.context, auth_context.auth_context, rcache.rcache) };
+
+      krb5_error_code_escape_hatch(self.context, code)?;
+    }
+
+    let result = auth_context.rd_req(token, self.server.as_ref(), Some(&self.keytab));
+
+    if self.rcache.is_some() {
+      // detach the shared replay cache again, so that it is not closed with the authentication
+      // context
+      unsafe { krb5_auth_con_setrcache(self.context.context, auth_context.auth_context, std::ptr::null_mut()) };
+    }
+
+    let request = result?;
+
+    Ok(AuthenticatedPeer {
+      client: request.client,
+      ticket: request.ticket,
+      options: request.options,
+      checksum: request.checksum,
+      auth_context,
+    })
+  }
+}
+
+/**
+ * A client authenticated by [ServiceAcceptor::accept].
+ */
+#[derive(Debug)]
+pub struct AuthenticatedPeer<'a> {
+  /// the authenticated client principal
+  pub client: Krb5Principal<'a>,
+  /// the decrypted service ticket
+  pub ticket: Krb5Ticket<'a>,
+  /// the AP options requested by the client
+  pub options: Krb5ApOptions,
+  /// the checksum of the authenticator, if the client included one, see
+  /// [Krb5AuthContext::verify_authenticator_checksum]
+  pub checksum: Option<Krb5Checksum>,
+  /// the authentication context, e.g. to create the AP reply if `options` contains
+  /// `MUTUAL_REQUIRED`, or to exchange KRB-PRIV and KRB-SAFE messages
+  pub auth_context: Krb5AuthContext<'a>,
+}
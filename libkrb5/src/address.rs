@@ -0,0 +1,141 @@
+/*!
+ * Conversion between Rust network addresses and `krb5_address`.
+ */
+use std::convert::TryFrom;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::os::raw::c_uint;
+
+use libkrb5_sys::*;
+
+/**
+ * Owned contents of a `krb5_address`.
+ */
+#[derive(Debug)]
+pub(crate) struct AddressBuffer {
+  addrtype: krb5_addrtype,
+  contents: Vec<u8>,
+}
+
+impl AddressBuffer {
+  /**
+   * Create an `ADDRTYPE_INET` or `ADDRTYPE_INET6` address.
+   */
+  pub(crate) fn from_ip(ip: &IpAddr) -> AddressBuffer {
+    match ip {
+      IpAddr::V4(ip) => AddressBuffer {
+        addrtype: ADDRTYPE_INET as krb5_addrtype,
+        contents: ip.octets().to_vec(),
+      },
+      IpAddr::V6(ip) => AddressBuffer {
+        addrtype: ADDRTYPE_INET6 as krb5_addrtype,
+        contents: ip.octets().to_vec(),
+      },
+    }
+  }
+
+  /**
+   * Create an `ADDRTYPE_IPPORT` address (port in network byte order).
+   */
+  pub(crate) fn from_port(port: u16) -> AddressBuffer {
+    AddressBuffer {
+      addrtype: ADDRTYPE_IPPORT as krb5_addrtype,
+      contents: port.to_be_bytes().to_vec(),
+    }
+  }
+
+  /**
+   * Create a `krb5_address` that borrows the contents of this buffer.
+   */
+  pub(crate) fn as_krb5_address(&mut self) -> krb5_address {
+    krb5_address {
+      magic: 0,
+      addrtype: self.addrtype,
+      length: self.contents.len() as c_uint,
+      contents: self.contents.as_mut_ptr(),
+    }
+  }
+}
+
+/**
+ * Owned NULL-terminated `krb5_address` array, for functions that take an address list.
+ */
+#[derive(Debug)]
+pub(crate) struct AddressList {
+  // the addresses point into the buffers, and the pointers point into the addresses
+  _buffers: Vec<AddressBuffer>,
+  _addresses: Vec<krb5_address>,
+  pointers: Vec<*mut krb5_address>,
+}
+
+impl AddressList {
+  /**
+   * Create a list of `ADDRTYPE_INET` and `ADDRTYPE_INET6` addresses.
+   */
+  pub(crate) fn from_ips(ips: &[IpAddr]) -> AddressList {
+    let mut buffers: Vec<AddressBuffer> = ips.iter().map(AddressBuffer::from_ip).collect();
+    let mut addresses: Vec<krb5_address> = buffers.iter_mut().map(AddressBuffer::as_krb5_address).collect();
+
+    let mut pointers: Vec<*mut krb5_address> = addresses.iter_mut().map(|address| address as *mut _).collect();
+    pointers.push(std::ptr::null_mut());
+
+    AddressList {
+      _buffers: buffers,
+      _addresses: addresses,
+      pointers,
+    }
+  }
+
+  /**
+   * Return a pointer to the NULL-terminated array, which is valid as long as the list is alive.
+   */
+  pub(crate) fn as_mut_ptr(&mut self) -> *mut *mut krb5_address {
+    self.pointers.as_mut_ptr()
+  }
+}
+
+/**
+ * Convert an `ADDRTYPE_INET` or `ADDRTYPE_INET6` address, or return `None` for other address
+ * types (e.g. NetBIOS names or ports) and malformed addresses.
+ */
+pub(crate) fn address_to_ip(address: &krb5_address) -> Option<IpAddr> {
+  if address.contents.is_null() {
+    return None;
+  }
+
+  let contents = unsafe { std::slice::from_raw_parts(address.contents, address.length as usize) };
+
+  match address.addrtype as u32 {
+    ADDRTYPE_INET => Some(IpAddr::V4(Ipv4Addr::from(<[u8; 4]>::try_from(contents).ok()?))),
+    ADDRTYPE_INET6 => Some(IpAddr::V6(Ipv6Addr::from(<[u8; 16]>::try_from(contents).ok()?))),
+    _ => None,
+  }
+}
+
+/**
+ * Convert the IP addresses of a NULL-terminated `krb5_address` array (which may be NULL
+ * itself), skipping addresses of other types.
+ */
+pub(crate) fn addresses_to_ips(addresses: *const *mut krb5_address) -> Vec<IpAddr> {
+  let mut ips = Vec::new();
+
+  if addresses.is_null() {
+    return ips;
+  }
+
+  let mut index: isize = 0;
+  loop {
+    let address = unsafe { *addresses.offset(index) };
+
+    if address.is_null() {
+      break;
+    }
+
+    if let Some(ip) = address_to_ip(unsafe { &*address }) {
+      ips.push(ip);
+    }
+
+    index += 1;
+  }
+
+  ips
+}
@@ -0,0 +1,1438 @@
+/*!
+ * Rustic wrapper for krb5 authentication contexts.
+ */
+use std::io::{Read, Write};
+use std::mem::MaybeUninit;
+use std::net::SocketAddr;
+use std::os::raw::{c_char, c_int};
+#[cfg(unix)]
+use std::os::unix::io::{AsRawFd, RawFd};
+
+use bitflags::bitflags;
+use libkrb5_sys::*;
+
+use crate::address::AddressBuffer;
+use crate::ccache::Krb5CCache;
+use crate::checksum::{Krb5Checksum, Krb5ChecksumType, GSS_CHECKSUM_TYPE};
+use crate::context::Krb5Context;
+use crate::creds::Krb5Creds;
+use crate::data::{slice_to_krb5_data, Krb5Data};
+use crate::error::{krb5_error_code_escape_hatch, Krb5Error};
+use crate::error_message::Krb5ErrorMessage;
+use crate::handshake::{read_frame, write_frame};
+use crate::keyblock::Krb5Keyblock;
+use crate::keytab::Krb5Keytab;
+use crate::keyusage::Krb5KeyUsage;
+use crate::principal::{copy_principal, parse_name, Krb5Principal};
+use crate::rcache::Krb5RCache;
+use crate::secret::constant_time_eq;
+use crate::strconv::string_to_c_string;
+use crate::ticket::Krb5Ticket;
+use crate::timestamp::Krb5Timestamp;
+
+/* version of the sendauth protocol, sent with its terminating NUL like krb5_sendauth does */
+const SENDAUTH_VERSION: &[u8] = b"KRB5_SENDAUTH_V1.0\0";
+
+bitflags! {
+  /**
+   * Options of an AP request.
+   */
+  pub struct Krb5ApOptions: krb5_flags {
+    /// request mutual authentication, i.e. an AP reply from the server
+    const MUTUAL_REQUIRED = AP_OPTS_MUTUAL_REQUIRED as krb5_flags;
+    /// the ticket is encrypted in the session key of a TGT (user-to-user authentication)
+    const USE_SESSION_KEY = AP_OPTS_USE_SESSION_KEY as krb5_flags;
+    /// generate a subkey for the session
+    const USE_SUBKEY = AP_OPTS_USE_SUBKEY as krb5_flags;
+  }
+}
+
+bitflags! {
+  /**
+   * Flags of an authentication context.
+   */
+  pub struct Krb5AuthContextFlags: krb5_int32 {
+    /// use timestamps in KRB-SAFE and KRB-PRIV messages, and check them against the replay cache
+    const DO_TIME = KRB5_AUTH_CONTEXT_DO_TIME as krb5_int32;
+    /// return the timestamp of received messages
+    const RET_TIME = KRB5_AUTH_CONTEXT_RET_TIME as krb5_int32;
+    /// use sequence numbers in KRB-SAFE and KRB-PRIV messages, and check their order
+    const DO_SEQUENCE = KRB5_AUTH_CONTEXT_DO_SEQUENCE as krb5_int32;
+    /// return the sequence number of received messages
+    const RET_SEQUENCE = KRB5_AUTH_CONTEXT_RET_SEQUENCE as krb5_int32;
+    /// accept any enctype, not only the permitted ones
+    const PERMIT_ALL = KRB5_AUTH_CONTEXT_PERMIT_ALL as krb5_int32;
+    /// generate a subkey when creating AP requests
+    const USE_SUBKEY = KRB5_AUTH_CONTEXT_USE_SUBKEY as krb5_int32;
+  }
+}
+
+bitflags! {
+  /**
+   * Selects which addresses [Krb5AuthContext::genaddrs] generates from a socket.
+   */
+  pub struct Krb5GenAddrsFlags: i32 {
+    /// set the local address (without port)
+    const LOCAL_ADDR = KRB5_AUTH_CONTEXT_GENERATE_LOCAL_ADDR as i32;
+    /// set the remote address (without port)
+    const REMOTE_ADDR = KRB5_AUTH_CONTEXT_GENERATE_REMOTE_ADDR as i32;
+    /// set the local address and port
+    const LOCAL_FULL_ADDR = KRB5_AUTH_CONTEXT_GENERATE_LOCAL_FULL_ADDR as i32;
+    /// set the remote address and port
+    const REMOTE_FULL_ADDR = KRB5_AUTH_CONTEXT_GENERATE_REMOTE_FULL_ADDR as i32;
+  }
+}
+
+/**
+ * Wrapper struct for a krb5 authentication context.
+ *
+ * An authentication context holds the state of one AP exchange (keys, addresses, sequence
+ * numbers, replay cache) and is needed by the AP-REQ/AP-REP, KRB-SAFE, KRB-PRIV and KRB-CRED
+ * functions.
+ */
+#[derive(Debug)]
+pub struct Krb5AuthContext<'a> {
+  pub(crate) context: &'a Krb5Context,
+  pub(crate) auth_context: krb5_auth_context,
+  /// whether an AP request has been verified, so the authenticator is complete
+  pub(crate) received_authenticator: bool,
+  /// the replay cache attached with [Krb5AuthContext::set_rcache], closed by its wrapper
+  rcache: Option<Krb5RCache<'a>>,
+}
+
+/**
+ * Free an authentication context.
+ *
+ * [krb5_auth_con_free](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_auth_con_free.html)
+ */
+impl<'a> Drop for Krb5AuthContext<'a> {
+  fn drop(&mut self) {
+    unsafe {
+      // the wrapper of an attached replay cache closes it
+      if self.rcache.is_some() {
+        krb5_auth_con_setrcache(self.context.context, self.auth_context, std::ptr::null_mut());
+      }
+      krb5_auth_con_free(self.context.context, self.auth_context);
+    }
+  }
+}
+
+impl<'a> Krb5AuthContext<'a> {
+  /**
+   * Create a new authentication context.
+   *
+   * [krb5_auth_con_init](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_auth_con_init.html)
+   *
+   * # Arguments
+   *
+   *  * context: the Krb5Context instance
+   */
+  pub fn init(context: &'a Krb5Context) -> Result<Krb5AuthContext<'a>, Krb5Error> {
+    let mut auth_context_ptr: MaybeUninit<krb5_auth_context> = MaybeUninit::zeroed();
+
+    let code: krb5_error_code = unsafe { krb5_auth_con_init(context.context, auth_context_ptr.as_mut_ptr()) };
+
+    krb5_error_code_escape_hatch(context, code)?;
+
+    let auth_context = Krb5AuthContext {
+      context,
+      auth_context: unsafe { auth_context_ptr.assume_init() },
+      received_authenticator: false,
+      rcache: None,
+    };
+
+    Ok(auth_context)
+  }
+
+  /**
+   * Set the local and remote addresses of the authentication context from the local and peer
+   * address of a socket.
+   *
+   * KRB-SAFE and KRB-PRIV messages include the sender address, and require the local address to
+   * be set.
+   *
+   * [krb5_auth_con_genaddrs](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_auth_con_genaddrs.html)
+   *
+   * # Arguments
+   *
+   *  * fd: the file descriptor of a connected socket, e.g. from `TcpStream::as_raw_fd`
+   *  * flags: the addresses to set
+   */
+  #[cfg(unix)]
+  pub fn genaddrs(&mut self, fd: RawFd, flags: Krb5GenAddrsFlags) -> Result<(), Krb5Error> {
+    let code: krb5_error_code =
+      unsafe { krb5_auth_con_genaddrs(self.context.context, self.auth_context, fd, flags.bits()) };
+
+    krb5_error_code_escape_hatch(self.context, code)
+  }
+
+  /**
+   * Obtain a forwardable copy of the client's TGT for another host and return it as a KRB-CRED
+   * message, protected with the key of the authentication context.
+   *
+   * [krb5_fwd_tgt_creds](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_fwd_tgt_creds.html)
+   *
+   * # Arguments
+   *
+   *  * rhost: the host the credentials are forwarded to, or `None` to use the host of `server`
+   *  * client: the client principal, or `None` to use the principal of the credential cache
+   *  * server: the server principal the credentials are forwarded to
+   *  * ccache: the credential cache holding the client's TGT
+   *  * forwardable: whether the forwarded TGT can be forwarded again
+   */
+  pub fn fwd_tgt_creds(
+    &mut self,
+    rhost: Option<&str>,
+    client: Option<&Krb5Principal>,
+    server: &Krb5Principal,
+    ccache: &Krb5CCache,
+    forwardable: bool,
+  ) -> Result<Krb5Data<'a>, Krb5Error> {
+    let rhost_c = match rhost {
+      Some(rhost) => Some(string_to_c_string(rhost)?),
+      None => None,
+    };
+    let rhost_ptr = match &rhost_c {
+      Some(rhost) => rhost.as_ptr(),
+      None => std::ptr::null(),
+    };
+    let client_ptr = match client {
+      Some(client) => client.principal,
+      None => std::ptr::null_mut(),
+    };
+    let mut outbuf: MaybeUninit<krb5_data> = MaybeUninit::zeroed();
+
+    let code: krb5_error_code = unsafe {
+      krb5_fwd_tgt_creds(
+        self.context.context,
+        self.auth_context,
+        rhost_ptr,
+        client_ptr,
+        server.principal,
+        ccache.ccache,
+        forwardable as c_int,
+        outbuf.as_mut_ptr(),
+      )
+    };
+
+    krb5_error_code_escape_hatch(self.context, code)?;
+
+    let message = Krb5Data::from_raw(self.context, unsafe { outbuf.assume_init() });
+
+    Ok(message)
+  }
+
+  /**
+   * Retrieve the flags of the authentication context.
+   *
+   * [krb5_auth_con_getflags](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_auth_con_getflags.html)
+   */
+  pub fn get_flags(&self) -> Result<Krb5AuthContextFlags, Krb5Error> {
+    let mut flags: krb5_int32 = 0;
+
+    let code: krb5_error_code = unsafe { krb5_auth_con_getflags(self.context.context, self.auth_context, &mut flags) };
+
+    krb5_error_code_escape_hatch(self.context, code)?;
+
+    Ok(Krb5AuthContextFlags::from_bits_truncate(flags))
+  }
+
+  /**
+   * Run the client side of the classic `sendauth` / `recvauth` handshake on a connected socket:
+   * send an AP request for the server, and verify the server's AP reply if mutual
+   * authentication is requested.
+   *
+   * A service ticket is obtained (and stored in the credential cache) if the cache does not
+   * contain one yet. If the server rejects the request, its error code is returned.
+   *
+   * [krb5_sendauth](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_sendauth.html)
+   *
+   * # Arguments
+   *
+   *  * socket: the connected socket, e.g. a `TcpStream`
+   *  * appl_version: the application protocol version, which must match the server's
+   *  * client: the client principal, or `None` to use the principal of the credential cache
+   *  * server: the server principal
+   *  * options: AP request options, e.g. `Krb5ApOptions::MUTUAL_REQUIRED`
+   *  * ccache: the credential cache holding the client's credentials
+   */
+  #[cfg(unix)]
+  pub fn sendauth<S: AsRawFd>(
+    &mut self,
+    socket: &S,
+    appl_version: &str,
+    client: Option<&Krb5Principal>,
+    server: &Krb5Principal,
+    options: Krb5ApOptions,
+    ccache: &Krb5CCache,
+  ) -> Result<(), Krb5Error> {
+    let mut fd: RawFd = socket.as_raw_fd();
+    let appl_version_c = string_to_c_string(appl_version)?;
+    let client_ptr = match client {
+      Some(client) => client.principal,
+      None => std::ptr::null_mut(),
+    };
+    let mut error: *mut krb5_error = std::ptr::null_mut();
+    let mut rep_result: *mut krb5_ap_rep_enc_part = std::ptr::null_mut();
+
+    let mut code: krb5_error_code = unsafe {
+      krb5_sendauth(
+        self.context.context,
+        &mut self.auth_context,
+        &mut fd as *mut RawFd as krb5_pointer,
+        appl_version_c.as_ptr() as *mut c_char,
+        client_ptr,
+        server.principal,
+        options.bits(),
+        std::ptr::null_mut(),
+        std::ptr::null_mut(),
+        ccache.ccache,
+        &mut error,
+        &mut rep_result,
+        std::ptr::null_mut(),
+      )
+    };
+
+    if !error.is_null() {
+      // report why the server rejected the request instead of KRB5_SENDAUTH_REJECTED
+      code = KRB5KDC_ERR_NONE + unsafe { (*error).error } as krb5_error_code;
+      unsafe { krb5_free_error(self.context.context, error) };
+    }
+
+    if !rep_result.is_null() {
+      unsafe { krb5_free_ap_rep_enc_part(self.context.context, rep_result) };
+    }
+
+    krb5_error_code_escape_hatch(self.context, code)
+  }
+
+  /**
+   * Run the client side of the `sendauth` / `recvauth` handshake over any stream, e.g. a TLS
+   * stream, see [Krb5AuthContext::sendauth].
+   *
+   * The messages are the ones `krb5_sendauth` exchanges, so the server can use either
+   * [Krb5AuthContext::recvauth] or [Krb5AuthContext::recvauth_stream].
+   *
+   * # Arguments
+   *
+   *  * stream: the connected stream
+   *  * appl_version: the application protocol version, which must match the server's
+   *  * client: the client principal, or `None` to use the principal of the credential cache
+   *  * server: the server principal
+   *  * options: AP request options, e.g. `Krb5ApOptions::MUTUAL_REQUIRED`
+   *  * ccache: the credential cache holding the client's credentials
+   */
+  pub fn sendauth_stream<S: Read + Write>(
+    &mut self,
+    stream: &mut S,
+    appl_version: &str,
+    client: Option<&Krb5Principal>,
+    server: &Krb5Principal,
+    options: Krb5ApOptions,
+    ccache: &Krb5CCache,
+  ) -> Result<(), Krb5Error> {
+    let appl_version_c = string_to_c_string(appl_version)?;
+
+    write_frame(stream, SENDAUTH_VERSION)?;
+    write_frame(stream, appl_version_c.as_bytes_with_nul())?;
+
+    let mut response = [0u8; 1];
+    stream.read_exact(&mut response)?;
+    match response[0] {
+      0 => {},
+      1 => return krb5_error_code_escape_hatch(self.context, KRB5_SENDAUTH_BADAUTHVERS),
+      2 => return krb5_error_code_escape_hatch(self.context, KRB5_SENDAUTH_BADAPPLVERS),
+      _ => return krb5_error_code_escape_hatch(self.context, KRB5_SENDAUTH_BADRESPONSE),
+    }
+
+    let creds = match client {
+      Some(client) => ccache.get_credentials_for(client, server)?,
+      None => ccache.get_credentials(server)?,
+    };
+    let request = self.mk_req_extended(&creds, options, None)?;
+    write_frame(stream, &request)?;
+
+    // an empty message accepts the request, anything else is a KRB-ERROR
+    let error = read_frame(stream)?;
+    if !error.is_empty() {
+      // report why the server rejected the request instead of KRB5_SENDAUTH_REJECTED
+      let error = Krb5ErrorMessage::rd_error(self.context, &error)?;
+      return krb5_error_code_escape_hatch(self.context, error.library_code());
+    }
+
+    if options.contains(Krb5ApOptions::MUTUAL_REQUIRED) {
+      let reply = read_frame(stream)?;
+      self.rd_rep(&reply)?;
+    }
+
+    Ok(())
+  }
+
+  /**
+   * Set the local and remote addresses (including ports) of the authentication context.
+   *
+   * KRB-SAFE and KRB-PRIV messages include the sender address, and require the local address to
+   * be set.
+   *
+   * [krb5_auth_con_setaddrs](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_auth_con_setaddrs.html)
+   * and [krb5_auth_con_setports](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_auth_con_setports.html)
+   *
+   * # Arguments
+   *
+   *  * local: the local address, or `None` to clear it
+   *  * remote: the remote address, or `None` to clear it
+   */
+  pub fn set_addrs(&mut self, local: Option<&SocketAddr>, remote: Option<&SocketAddr>) -> Result<(), Krb5Error> {
+    let mut local_addr = local.map(|addr| AddressBuffer::from_ip(&addr.ip()));
+    let mut remote_addr = remote.map(|addr| AddressBuffer::from_ip(&addr.ip()));
+    let mut local_port = local.map(|addr| AddressBuffer::from_port(addr.port()));
+    let mut remote_port = remote.map(|addr| AddressBuffer::from_port(addr.port()));
+
+    let mut local_addr = local_addr.as_mut().map(AddressBuffer::as_krb5_address);
+    let mut remote_addr = remote_addr.as_mut().map(AddressBuffer::as_krb5_address);
+    let mut local_port = local_port.as_mut().map(AddressBuffer::as_krb5_address);
+    let mut remote_port = remote_port.as_mut().map(AddressBuffer::as_krb5_address);
+
+    let code: krb5_error_code = unsafe {
+      krb5_auth_con_setaddrs(
+        self.context.context,
+        self.auth_context,
+        optional_address_ptr(&mut local_addr),
+        optional_address_ptr(&mut remote_addr),
+      )
+    };
+
+    krb5_error_code_escape_hatch(self.context, code)?;
+
+    let code: krb5_error_code = unsafe {
+      krb5_auth_con_setports(
+        self.context.context,
+        self.auth_context,
+        optional_address_ptr(&mut local_port),
+        optional_address_ptr(&mut remote_port),
+      )
+    };
+
+    krb5_error_code_escape_hatch(self.context, code)
+  }
+
+  /**
+   * Set the flags of the authentication context.
+   *
+   * [krb5_auth_con_setflags](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_auth_con_setflags.html)
+   *
+   * # Arguments
+   *
+   *  * flags: the new flags, replacing the current ones
+   */
+  pub fn set_flags(&mut self, flags: Krb5AuthContextFlags) -> Result<(), Krb5Error> {
+    let code: krb5_error_code =
+      unsafe { krb5_auth_con_setflags(self.context.context, self.auth_context, flags.bits()) };
+
+    krb5_error_code_escape_hatch(self.context, code)
+  }
+
+  /**
+   * Open the default server replay cache and attach it to the authentication context.
+   *
+   * Without an explicit replay cache, [Krb5AuthContext::rd_req] opens the default replay cache
+   * itself if `DO_TIME` is set in the flags of the authentication context, and skips replay
+   * detection otherwise. The default replay cache is shared between processes through the
+   * file system, so calling this on every authentication context of a server shares replay
+   * detection between them. The replay cache is closed together with the authentication
+   * context. Nothing is done if the authentication context already has a replay cache.
+   *
+   * See [Krb5RCache::server_default] and [Krb5AuthContext::set_rcache].
+   */
+  pub fn use_server_rcache(&mut self) -> Result<(), Krb5Error> {
+    if self.has_rcache()? {
+      return Ok(());
+    }
+
+    self.set_rcache(Krb5RCache::server_default(self.context)?)
+  }
+
+  /**
+   * Attach a replay cache to the authentication context.
+   *
+   * The authentication context takes ownership of the replay cache and closes it when it is
+   * freed. A replay cache that was attached before is replaced. The replay cache can be
+   * borrowed back with [Krb5AuthContext::rcache].
+   *
+   * [krb5_auth_con_setrcache](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_auth_con_setrcache.html)
+   *
+   * # Arguments
+   *
+   *  * rcache: the replay cache
+   */
+  pub fn set_rcache(&mut self, rcache: Krb5RCache<'a>) -> Result<(), Krb5Error> {
+    let mut previous: MaybeUninit<krb5_rcache> = MaybeUninit::zeroed();
+
+    let code: krb5_error_code =
+      unsafe { krb5_auth_con_getrcache(self.context.context, self.auth_context, previous.as_mut_ptr()) };
+
+    krb5_error_code_escape_hatch(self.context, code)?;
+
+    let code: krb5_error_code =
+      unsafe { krb5_auth_con_setrcache(self.context.context, self.auth_context, rcache.rcache) };
+
+    krb5_error_code_escape_hatch(self.context, code)?;
+
+    // the wrapper of a replaced replay cache closes it, one opened by libkrb5 is wrapped first
+    let previous = unsafe { previous.assume_init() };
+    if self.rcache.replace(rcache).is_none() && !previous.is_null() {
+      drop(Krb5RCache {
+        context: self.context,
+        rcache: previous,
+      });
+    }
+
+    Ok(())
+  }
+
+  /**
+   * Set the subkey used to verify or decrypt received messages.
+   *
+   * [krb5_auth_con_setrecvsubkey](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_auth_con_setrecvsubkey.html)
+   *
+   * # Arguments
+   *
+   *  * key: the subkey (which is copied), or `None` to use the session key
+   */
+  pub fn set_recv_subkey(&mut self, key: Option<&Krb5Keyblock>) -> Result<(), Krb5Error> {
+    let key_ptr = match key {
+      Some(key) => key.keyblock,
+      None => std::ptr::null_mut(),
+    };
+
+    let code: krb5_error_code =
+      unsafe { krb5_auth_con_setrecvsubkey(self.context.context, self.auth_context, key_ptr) };
+
+    krb5_error_code_escape_hatch(self.context, code)
+  }
+
+  /**
+   * Set the checksum type used for the authenticator checksum of AP requests.
+   *
+   * [krb5_auth_con_set_req_cksumtype](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_auth_con_set_req_cksumtype.html)
+   *
+   * # Arguments
+   *
+   *  * cksumtype: the checksum type (`CKSUMTYPE_*`)
+   */
+  pub fn set_req_cksumtype(&mut self, checksum_type: Krb5ChecksumType) -> Result<(), Krb5Error> {
+    let code: krb5_error_code =
+      unsafe { krb5_auth_con_set_req_cksumtype(self.context.context, self.auth_context, checksum_type.as_raw()) };
+
+    krb5_error_code_escape_hatch(self.context, code)
+  }
+
+  /**
+   * Return a copy of the session key of the authentication context, if it has one.
+   *
+   * [krb5_auth_con_getkey](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_auth_con_getkey.html)
+   */
+  pub fn get_key(&self) -> Result<Option<Krb5Keyblock<'a>>, Krb5Error> {
+    self.get_keyblock(krb5_auth_con_getkey)
+  }
+
+  /**
+   * Return the sequence number of the next message sent with this authentication context.
+   *
+   * libkrb5 has no function to set sequence numbers; they are negotiated in the AP exchange and
+   * increased for every KRB-SAFE, KRB-PRIV and KRB-CRED message while `DO_SEQUENCE` or
+   * `RET_SEQUENCE` is set.
+   *
+   * [krb5_auth_con_getlocalseqnumber](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_auth_con_getlocalseqnumber.html)
+   */
+  pub fn get_local_seq_number(&self) -> Result<krb5_ui_4, Krb5Error> {
+    let mut seqnumber: krb5_int32 = 0;
+
+    let code: krb5_error_code =
+      unsafe { krb5_auth_con_getlocalseqnumber(self.context.context, self.auth_context, &mut seqnumber) };
+
+    krb5_error_code_escape_hatch(self.context, code)?;
+
+    Ok(seqnumber as krb5_ui_4)
+  }
+
+  /**
+   * Return a copy of the subkey used to verify or decrypt received messages, if there is one.
+   *
+   * [krb5_auth_con_getrecvsubkey](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_auth_con_getrecvsubkey.html)
+   */
+  pub fn get_recv_subkey(&self) -> Result<Option<Krb5Keyblock<'a>>, Krb5Error> {
+    self.get_keyblock(krb5_auth_con_getrecvsubkey)
+  }
+
+  /**
+   * Return the sequence number expected for the next message received from the peer.
+   *
+   * [krb5_auth_con_getremoteseqnumber](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_auth_con_getremoteseqnumber.html)
+   */
+  pub fn get_remote_seq_number(&self) -> Result<krb5_ui_4, Krb5Error> {
+    let mut seqnumber: krb5_int32 = 0;
+
+    let code: krb5_error_code =
+      unsafe { krb5_auth_con_getremoteseqnumber(self.context.context, self.auth_context, &mut seqnumber) };
+
+    krb5_error_code_escape_hatch(self.context, code)?;
+
+    Ok(seqnumber as krb5_ui_4)
+  }
+
+  /**
+   * Return a copy of the subkey used to protect sent messages, if there is one.
+   *
+   * [krb5_auth_con_getsendsubkey](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_auth_con_getsendsubkey.html)
+   */
+  pub fn get_send_subkey(&self) -> Result<Option<Krb5Keyblock<'a>>, Krb5Error> {
+    self.get_keyblock(krb5_auth_con_getsendsubkey)
+  }
+
+  /**
+   * Return whether the authentication context has a replay cache.
+   *
+   * [krb5_auth_con_getrcache](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_auth_con_getrcache.html)
+   */
+  pub fn has_rcache(&self) -> Result<bool, Krb5Error> {
+    let mut rcache: MaybeUninit<krb5_rcache> = MaybeUninit::zeroed();
+
+    let code: krb5_error_code =
+      unsafe { krb5_auth_con_getrcache(self.context.context, self.auth_context, rcache.as_mut_ptr()) };
+
+    krb5_error_code_escape_hatch(self.context, code)?;
+
+    Ok(!unsafe { rcache.assume_init() }.is_null())
+  }
+
+  /**
+   * Return the replay cache attached with [Krb5AuthContext::set_rcache], e.g. to check its
+   * name.
+   *
+   * A replay cache that libkrb5 opened itself in [Krb5AuthContext::rd_req] is not returned, but
+   * reported by [Krb5AuthContext::has_rcache].
+   */
+  pub fn rcache(&self) -> Option<&Krb5RCache<'a>> {
+    self.rcache.as_ref()
+  }
+
+  /**
+   * Create an AP request from existing service credentials.
+   *
+   * A checksum of the application data is included in the authenticator, binding the data (e.g.
+   * channel binding information) to the authentication.
+   *
+   * [krb5_mk_req_extended](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_mk_req_extended.html)
+   *
+   * # Arguments
+   *
+   *  * creds: the service ticket and session key, e.g. from a credential cache
+   *  * options: AP request options
+   *  * data: application data to checksum, or `None` to omit the checksum
+   */
+  pub fn mk_req_extended(
+    &mut self,
+    creds: &Krb5Creds,
+    options: Krb5ApOptions,
+    data: Option<&[u8]>,
+  ) -> Result<Krb5Data<'a>, Krb5Error> {
+    let mut in_data = data.map(slice_to_krb5_data);
+    let in_data_ptr = match &mut in_data {
+      Some(in_data) => in_data as *mut krb5_data,
+      None => std::ptr::null_mut(),
+    };
+    let mut outbuf: MaybeUninit<krb5_data> = MaybeUninit::zeroed();
+
+    // libkrb5 does not modify the credentials, the parameter just lacks the const qualifier
+    let code: krb5_error_code = unsafe {
+      krb5_mk_req_extended(
+        self.context.context,
+        &mut self.auth_context,
+        options.bits(),
+        in_data_ptr,
+        &creds.creds as *const krb5_creds as *mut krb5_creds,
+        outbuf.as_mut_ptr(),
+      )
+    };
+
+    krb5_error_code_escape_hatch(self.context, code)?;
+
+    let token = Krb5Data::from_raw(self.context, unsafe { outbuf.assume_init() });
+
+    Ok(token)
+  }
+
+  /**
+   * Create a KRB-PRIV message, which encrypts and integrity protects application data with the
+   * session key (or subkey) of the authentication context.
+   *
+   * The local address of the authentication context must be set.
+   *
+   * [krb5_mk_priv](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_mk_priv.html)
+   *
+   * # Arguments
+   *
+   *  * data: the application data to protect
+   */
+  pub fn mk_priv(&mut self, data: &[u8]) -> Result<Krb5Data<'a>, Krb5Error> {
+    self.require_key()?;
+
+    let userdata = slice_to_krb5_data(data);
+    let mut der_out: MaybeUninit<krb5_data> = MaybeUninit::zeroed();
+    let mut rdata_out: MaybeUninit<krb5_replay_data> = MaybeUninit::zeroed();
+
+    let code: krb5_error_code = unsafe {
+      krb5_mk_priv(
+        self.context.context,
+        self.auth_context,
+        &userdata,
+        der_out.as_mut_ptr(),
+        rdata_out.as_mut_ptr(),
+      )
+    };
+
+    krb5_error_code_escape_hatch(self.context, code)?;
+
+    let message = Krb5Data::from_raw(self.context, unsafe { der_out.assume_init() });
+
+    Ok(message)
+  }
+
+  /**
+   * Create a KRB-CRED message that transports credentials to the peer, protected with the send
+   * subkey or session key of the authentication context (or unencrypted if it has no key).
+   *
+   * [krb5_mk_1cred](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_mk_1cred.html)
+   *
+   * # Arguments
+   *
+   *  * creds: the credentials to send
+   */
+  pub fn mk_1cred(&mut self, creds: &Krb5Creds) -> Result<Krb5Data<'a>, Krb5Error> {
+    let mut der_out: MaybeUninit<*mut krb5_data> = MaybeUninit::zeroed();
+    let mut rdata_out: MaybeUninit<krb5_replay_data> = MaybeUninit::zeroed();
+
+    // libkrb5 does not modify the credentials, the parameter just lacks the const qualifier
+    let code: krb5_error_code = unsafe {
+      krb5_mk_1cred(
+        self.context.context,
+        self.auth_context,
+        &creds.creds as *const krb5_creds as *mut krb5_creds,
+        der_out.as_mut_ptr(),
+        rdata_out.as_mut_ptr(),
+      )
+    };
+
+    krb5_error_code_escape_hatch(self.context, code)?;
+
+    // take over the contents and only free the krb5_data structure itself
+    let der_out = unsafe { der_out.assume_init() };
+    let message = Krb5Data::from_raw(self.context, unsafe { *der_out });
+    unsafe {
+      (*der_out).data = std::ptr::null_mut();
+      krb5_free_data(self.context.context, der_out);
+    }
+
+    Ok(message)
+  }
+
+  /**
+   * Create an AP reply for mutual authentication, after an AP request has been verified with
+   * [Krb5AuthContext::rd_req].
+   *
+   * [krb5_mk_rep](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_mk_rep.html)
+   */
+  pub fn mk_rep(&mut self) -> Result<Krb5Data<'a>, Krb5Error> {
+    self.require_key()?;
+
+    let mut outbuf: MaybeUninit<krb5_data> = MaybeUninit::zeroed();
+
+    let code: krb5_error_code = unsafe { krb5_mk_rep(self.context.context, self.auth_context, outbuf.as_mut_ptr()) };
+
+    krb5_error_code_escape_hatch(self.context, code)?;
+
+    let token = Krb5Data::from_raw(self.context, unsafe { outbuf.assume_init() });
+
+    Ok(token)
+  }
+
+  /**
+   * Create an AP request for a service, using credentials from a credential cache.
+   *
+   * A service ticket is obtained (and stored in the credential cache) if the cache does not
+   * contain one yet. The returned token can be sent to the service over any transport.
+   *
+   * [krb5_mk_req](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_mk_req.html)
+   *
+   * # Arguments
+   *
+   *  * service: the service name, e.g. `host`
+   *  * hostname: the host name of the service
+   *  * ccache: the credential cache holding the client's credentials
+   *  * options: AP request options
+   */
+  pub fn mk_req(
+    &mut self,
+    service: &str,
+    hostname: &str,
+    ccache: &Krb5CCache,
+    options: Krb5ApOptions,
+  ) -> Result<Krb5Data<'a>, Krb5Error> {
+    let service_c = string_to_c_string(service)?;
+    let hostname_c = string_to_c_string(hostname)?;
+    let mut outbuf: MaybeUninit<krb5_data> = MaybeUninit::zeroed();
+
+    let code: krb5_error_code = unsafe {
+      krb5_mk_req(
+        self.context.context,
+        &mut self.auth_context,
+        options.bits(),
+        service_c.as_ptr(),
+        hostname_c.as_ptr(),
+        std::ptr::null_mut(),
+        ccache.ccache,
+        outbuf.as_mut_ptr(),
+      )
+    };
+
+    krb5_error_code_escape_hatch(self.context, code)?;
+
+    let token = Krb5Data::from_raw(self.context, unsafe { outbuf.assume_init() });
+
+    Ok(token)
+  }
+
+  /**
+   * Read the credentials from a KRB-CRED message created by the peer, e.g. with
+   * [Krb5AuthContext::mk_1cred] or [Krb5AuthContext::fwd_tgt_creds].
+   *
+   * The credentials can be stored in a credential cache with [Krb5CCache::store_cred].
+   *
+   * [krb5_rd_cred](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_rd_cred.html)
+   *
+   * # Arguments
+   *
+   *  * message: the KRB-CRED message
+   */
+  pub fn rd_cred(&mut self, message: &[u8]) -> Result<Vec<Krb5Creds<'a>>, Krb5Error> {
+    let mut creddata = slice_to_krb5_data(message);
+    let mut creds_out: MaybeUninit<*mut *mut krb5_creds> = MaybeUninit::zeroed();
+    let mut rdata_out: MaybeUninit<krb5_replay_data> = MaybeUninit::zeroed();
+
+    let code: krb5_error_code = unsafe {
+      krb5_rd_cred(
+        self.context.context,
+        self.auth_context,
+        &mut creddata,
+        creds_out.as_mut_ptr(),
+        rdata_out.as_mut_ptr(),
+      )
+    };
+
+    krb5_error_code_escape_hatch(self.context, code)?;
+
+    let creds_out = unsafe { creds_out.assume_init() };
+
+    let mut creds = Vec::new();
+    let mut index: isize = 0;
+    loop {
+      let ptr = unsafe { *creds_out.offset(index) };
+
+      if ptr.is_null() {
+        break;
+      }
+
+      // move the contents out, leaving an empty structure for krb5_free_tgt_creds
+      let contents: krb5_creds = unsafe { std::ptr::replace(ptr, std::mem::zeroed()) };
+      creds.push(Krb5Creds {
+        context: self.context,
+        creds: contents,
+      });
+
+      index += 1;
+    }
+
+    unsafe { krb5_free_tgt_creds(self.context.context, creds_out) };
+
+    Ok(creds)
+  }
+
+  /**
+   * Decrypt and verify a KRB-PRIV message created by the peer with [Krb5AuthContext::mk_priv].
+   *
+   * [krb5_rd_priv](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_rd_priv.html)
+   *
+   * # Arguments
+   *
+   *  * message: the KRB-PRIV message
+   */
+  pub fn rd_priv(&mut self, message: &[u8]) -> Result<Krb5Data<'a>, Krb5Error> {
+    self.require_key()?;
+
+    let inbuf = slice_to_krb5_data(message);
+    let mut userdata_out: MaybeUninit<krb5_data> = MaybeUninit::zeroed();
+    let mut rdata_out: MaybeUninit<krb5_replay_data> = MaybeUninit::zeroed();
+
+    let code: krb5_error_code = unsafe {
+      krb5_rd_priv(
+        self.context.context,
+        self.auth_context,
+        &inbuf,
+        userdata_out.as_mut_ptr(),
+        rdata_out.as_mut_ptr(),
+      )
+    };
+
+    krb5_error_code_escape_hatch(self.context, code)?;
+
+    let data = Krb5Data::from_raw(self.context, unsafe { userdata_out.assume_init() });
+
+    Ok(data)
+  }
+
+  /**
+   * Run the server side of the classic `sendauth` / `recvauth` handshake on a connected socket:
+   * receive and verify the client's AP request, and send an AP reply if the client requested
+   * mutual authentication.
+   *
+   * [krb5_recvauth](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_recvauth.html)
+   *
+   * # Arguments
+   *
+   *  * socket: the connected socket, e.g. a `TcpStream`
+   *  * appl_version: the application protocol version, which must match the client's
+   *  * server: the expected server principal, or `None` to accept any principal in the key
+   *    table
+   *  * keytab: the key table holding the service keys, or `None` to use the default key table
+   */
+  #[cfg(unix)]
+  pub fn recvauth<S: AsRawFd>(
+    &mut self,
+    socket: &S,
+    appl_version: &str,
+    server: Option<&Krb5Principal>,
+    keytab: Option<&Krb5Keytab>,
+  ) -> Result<Krb5Ticket<'a>, Krb5Error> {
+    let mut fd: RawFd = socket.as_raw_fd();
+    let appl_version_c = string_to_c_string(appl_version)?;
+    let server_ptr = match server {
+      Some(server) => server.principal,
+      None => std::ptr::null_mut(),
+    };
+    let keytab_ptr = match keytab {
+      Some(keytab) => keytab.keytab,
+      None => std::ptr::null_mut(),
+    };
+    let mut ticket_ptr: MaybeUninit<*mut krb5_ticket> = MaybeUninit::zeroed();
+
+    let code: krb5_error_code = unsafe {
+      krb5_recvauth(
+        self.context.context,
+        &mut self.auth_context,
+        &mut fd as *mut RawFd as krb5_pointer,
+        appl_version_c.as_ptr() as *mut c_char,
+        server_ptr,
+        0,
+        keytab_ptr,
+        ticket_ptr.as_mut_ptr(),
+      )
+    };
+
+    krb5_error_code_escape_hatch(self.context, code)?;
+    self.received_authenticator = true;
+
+    let ticket = Krb5Ticket {
+      context: self.context,
+      ticket: unsafe { ticket_ptr.assume_init() },
+    };
+
+    Ok(ticket)
+  }
+
+  /**
+   * Run the server side of the `sendauth` / `recvauth` handshake over any stream, e.g. a TLS
+   * stream, see [Krb5AuthContext::recvauth].
+   *
+   * The messages are the ones `krb5_recvauth` exchanges, so the client can use either
+   * [Krb5AuthContext::sendauth] or [Krb5AuthContext::sendauth_stream]. A rejected request is
+   * answered with a KRB-ERROR before the error is returned.
+   *
+   * # Arguments
+   *
+   *  * stream: the connected stream
+   *  * appl_version: the application protocol version, which must match the client's
+   *  * server: the expected server principal, or `None` to accept any principal in the key
+   *    table
+   *  * keytab: the key table holding the service keys, or `None` to use the default key table
+   */
+  pub fn recvauth_stream<S: Read + Write>(
+    &mut self,
+    stream: &mut S,
+    appl_version: &str,
+    server: Option<&Krb5Principal>,
+    keytab: Option<&Krb5Keytab>,
+  ) -> Result<Krb5Ticket<'a>, Krb5Error> {
+    let appl_version_c = string_to_c_string(appl_version)?;
+
+    // both versions are read before answering, like krb5_recvauth does
+    let sendauth_version = read_frame(stream)?;
+    let client_appl_version = read_frame(stream)?;
+    let (response, problem) = if sendauth_version != SENDAUTH_VERSION {
+      (1, KRB5_SENDAUTH_BADAUTHVERS)
+    } else if client_appl_version != appl_version_c.as_bytes_with_nul() {
+      (2, KRB5_SENDAUTH_BADAPPLVERS)
+    } else {
+      (0, 0)
+    };
+
+    stream.write_all(&[response])?;
+    stream.flush()?;
+    krb5_error_code_escape_hatch(self.context, problem)?;
+
+    let request = read_frame(stream)?;
+    let request = match self.rd_req(&request, server, keytab) {
+      Ok(request) => request,
+      Err(error) => {
+        // the rejection is more useful to the caller than a failure to report it
+        let _ = self.send_error(stream, server, &error);
+        return Err(error);
+      },
+    };
+
+    // an empty message accepts the request
+    write_frame(stream, &[])?;
+
+    if request.options.contains(Krb5ApOptions::MUTUAL_REQUIRED) {
+      let reply = self.mk_rep()?;
+      write_frame(stream, &reply)?;
+    }
+
+    Ok(request.ticket)
+  }
+
+  /**
+   * Verify the AP reply of a server for mutual authentication, after sending an AP request
+   * created with [Krb5AuthContext::mk_req].
+   *
+   * The subkey and initial sequence number chosen by the server are stored in the
+   * authentication context.
+   *
+   * [krb5_rd_rep](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_rd_rep.html)
+   *
+   * # Arguments
+   *
+   *  * reply: the AP reply token sent by the server
+   */
+  pub fn rd_rep(&mut self, reply: &[u8]) -> Result<(), Krb5Error> {
+    self.require_key()?;
+
+    let inbuf = slice_to_krb5_data(reply);
+    let mut repl: MaybeUninit<*mut krb5_ap_rep_enc_part> = MaybeUninit::zeroed();
+
+    let code: krb5_error_code =
+      unsafe { krb5_rd_rep(self.context.context, self.auth_context, &inbuf, repl.as_mut_ptr()) };
+
+    krb5_error_code_escape_hatch(self.context, code)?;
+
+    // everything needed from the decrypted reply has been copied into the authentication context
+    unsafe { krb5_free_ap_rep_enc_part(self.context.context, repl.assume_init()) };
+
+    Ok(())
+  }
+
+  /**
+   * Set the subkey used to protect sent messages.
+   *
+   * [krb5_auth_con_setsendsubkey](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_auth_con_setsendsubkey.html)
+   *
+   * # Arguments
+   *
+   *  * key: the subkey (which is copied), or `None` to use the session key
+   */
+  pub fn set_send_subkey(&mut self, key: Option<&Krb5Keyblock>) -> Result<(), Krb5Error> {
+    let key_ptr = match key {
+      Some(key) => key.keyblock,
+      None => std::ptr::null_mut(),
+    };
+
+    let code: krb5_error_code =
+      unsafe { krb5_auth_con_setsendsubkey(self.context.context, self.auth_context, key_ptr) };
+
+    krb5_error_code_escape_hatch(self.context, code)
+  }
+
+  /**
+   * Set the session key of the authentication context, e.g. for user-to-user authentication or
+   * when the key has been negotiated by other means.
+   *
+   * [krb5_auth_con_setuseruserkey](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_auth_con_setuseruserkey.html)
+   *
+   * # Arguments
+   *
+   *  * key: the session key (which is copied)
+   */
+  pub fn set_user_user_key(&mut self, key: &Krb5Keyblock) -> Result<(), Krb5Error> {
+    let code: krb5_error_code =
+      unsafe { krb5_auth_con_setuseruserkey(self.context.context, self.auth_context, key.keyblock) };
+
+    krb5_error_code_escape_hatch(self.context, code)
+  }
+
+  /**
+   * Create a KRB-SAFE message, which integrity protects (but does not encrypt) application data
+   * with the session key (or subkey) of the authentication context.
+   *
+   * The local address of the authentication context must be set.
+   *
+   * [krb5_mk_safe](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_mk_safe.html)
+   *
+   * # Arguments
+   *
+   *  * data: the application data to protect
+   */
+  pub fn mk_safe(&mut self, data: &[u8]) -> Result<Krb5Data<'a>, Krb5Error> {
+    self.require_key()?;
+
+    let userdata = slice_to_krb5_data(data);
+    let mut der_out: MaybeUninit<krb5_data> = MaybeUninit::zeroed();
+    let mut rdata_out: MaybeUninit<krb5_replay_data> = MaybeUninit::zeroed();
+
+    let code: krb5_error_code = unsafe {
+      krb5_mk_safe(
+        self.context.context,
+        self.auth_context,
+        &userdata,
+        der_out.as_mut_ptr(),
+        rdata_out.as_mut_ptr(),
+      )
+    };
+
+    krb5_error_code_escape_hatch(self.context, code)?;
+
+    let message = Krb5Data::from_raw(self.context, unsafe { der_out.assume_init() });
+
+    Ok(message)
+  }
+
+  /**
+   * Verify an AP request received from a client.
+   *
+   * The ticket in the request is decrypted with a key from the key table, and the authenticator
+   * is checked against the ticket and the replay cache.
+   *
+   * [krb5_rd_req](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_rd_req.html)
+   *
+   * # Arguments
+   *
+   *  * request: the AP request token sent by the client
+   *  * server: the expected server principal, or `None` to accept any principal in the key
+   *    table
+   *  * keytab: the key table holding the service keys, or `None` to use the default key table
+   */
+  pub fn rd_req(
+    &mut self,
+    request: &[u8],
+    server: Option<&Krb5Principal>,
+    keytab: Option<&Krb5Keytab>,
+  ) -> Result<Krb5ApRequest<'a>, Krb5Error> {
+    let inbuf = slice_to_krb5_data(request);
+    let server_ptr = match server {
+      Some(server) => server.principal as krb5_const_principal,
+      None => std::ptr::null(),
+    };
+    let keytab_ptr = match keytab {
+      Some(keytab) => keytab.keytab,
+      None => std::ptr::null_mut(),
+    };
+    let mut options: krb5_flags = 0;
+    let mut ticket_ptr: MaybeUninit<*mut krb5_ticket> = MaybeUninit::zeroed();
+
+    let code: krb5_error_code = unsafe {
+      krb5_rd_req(
+        self.context.context,
+        &mut self.auth_context,
+        &inbuf,
+        server_ptr,
+        keytab_ptr,
+        &mut options,
+        ticket_ptr.as_mut_ptr(),
+      )
+    };
+
+    krb5_error_code_escape_hatch(self.context, code)?;
+    self.received_authenticator = true;
+
+    let ticket = Krb5Ticket {
+      context: self.context,
+      ticket: unsafe { ticket_ptr.assume_init() },
+    };
+
+    // the ticket has been decrypted, so the encrypted part is always present
+    let client = copy_principal(self.context, unsafe { (*(*ticket.ticket).enc_part2).client })?;
+    let checksum = self.get_authenticator()?.checksum;
+
+    Ok(Krb5ApRequest {
+      client,
+      ticket,
+      options: Krb5ApOptions::from_bits_truncate(options),
+      checksum,
+    })
+  }
+
+  /**
+   * Verify a KRB-SAFE message created by the peer with [Krb5AuthContext::mk_safe], and return
+   * the application data.
+   *
+   * [krb5_rd_safe](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_rd_safe.html)
+   *
+   * # Arguments
+   *
+   *  * message: the KRB-SAFE message
+   */
+  pub fn rd_safe(&mut self, message: &[u8]) -> Result<Krb5Data<'a>, Krb5Error> {
+    self.require_key()?;
+
+    let inbuf = slice_to_krb5_data(message);
+    let mut userdata_out: MaybeUninit<krb5_data> = MaybeUninit::zeroed();
+    let mut rdata_out: MaybeUninit<krb5_replay_data> = MaybeUninit::zeroed();
+
+    let code: krb5_error_code = unsafe {
+      krb5_rd_safe(
+        self.context.context,
+        self.auth_context,
+        &inbuf,
+        userdata_out.as_mut_ptr(),
+        rdata_out.as_mut_ptr(),
+      )
+    };
+
+    krb5_error_code_escape_hatch(self.context, code)?;
+
+    let data = Krb5Data::from_raw(self.context, unsafe { userdata_out.assume_init() });
+
+    Ok(data)
+  }
+
+  /**
+   * Verify the authenticator checksum of an AP request against the application data the client
+   * passed to [Krb5AuthContext::mk_req_extended], e.g. channel binding data.
+   *
+   * Keyed checksums are verified with the session key of the authentication context. Checksums
+   * of the GSS-API type 0x8003 are not keyed and are compared with `data` directly.
+   *
+   * [krb5_c_verify_checksum](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_c_verify_checksum.html)
+   *
+   * # Arguments
+   *
+   *  * checksum: the authenticator checksum, see [Krb5ApRequest::checksum]
+   *  * data: the expected application data
+   */
+  pub fn verify_authenticator_checksum(&self, checksum: &Krb5Checksum, data: &[u8]) -> Result<bool, Krb5Error> {
+    if checksum.checksum_type.as_raw() == GSS_CHECKSUM_TYPE {
+      return Ok(constant_time_eq(&checksum.contents, data));
+    }
+
+    let key = match self.get_key()? {
+      Some(key) => key,
+      None => {
+        return Err(Krb5Error::LibraryError {
+          code: KRB5KRB_AP_ERR_NOKEY,
+          message: String::from("Authentication context has no key, no AP request has been exchanged"),
+        })
+      },
+    };
+
+    key.verify_checksum(Krb5KeyUsage::ApReqAuthChecksum, data, checksum)
+  }
+
+  /**
+   * Return a copy of the authenticator of the last AP request verified with this authentication
+   * context, by [Krb5AuthContext::rd_req] or [Krb5AuthContext::recvauth].
+   *
+   * libkrb5 keeps an incomplete authenticator on the client side, so this fails for AP requests
+   * created with this authentication context.
+   *
+   * [krb5_auth_con_getauthenticator](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_auth_con_getauthenticator.html)
+   */
+  pub fn get_authenticator(&self) -> Result<Krb5Authenticator<'a>, Krb5Error> {
+    if !self.received_authenticator {
+      return Err(Krb5Error::LibraryError {
+        code: KRB5KRB_AP_ERR_NOKEY,
+        message: String::from("Authentication context has no authenticator, no AP request has been verified"),
+      });
+    }
+
+    let mut authenticator: MaybeUninit<*mut krb5_authenticator> = MaybeUninit::zeroed();
+
+    let code: krb5_error_code =
+      unsafe { krb5_auth_con_getauthenticator(self.context.context, self.auth_context, authenticator.as_mut_ptr()) };
+
+    krb5_error_code_escape_hatch(self.context, code)?;
+
+    let authenticator = unsafe { authenticator.assume_init() };
+    let raw = unsafe { &*authenticator };
+
+    let result = copy_principal(self.context, raw.client).and_then(|client| {
+      let subkey = if raw.subkey.is_null() {
+        None
+      } else {
+        Some(Krb5Keyblock::copy_from(self.context, raw.subkey)?)
+      };
+
+      Ok(Krb5Authenticator {
+        client,
+        checksum: unsafe { raw.checksum.as_ref() }.map(Krb5Checksum::from_raw),
+        time: Krb5Timestamp::from_raw(raw.ctime),
+        usec: raw.cusec,
+        subkey,
+        seq_number: raw.seq_number,
+      })
+    });
+
+    unsafe { krb5_free_authenticator(self.context.context, authenticator) };
+
+    result
+  }
+
+  /**
+   * Answer a rejected AP request with a KRB-ERROR, like `krb5_recvauth` does.
+   */
+  fn send_error<S: Write>(
+    &self,
+    stream: &mut S,
+    server: Option<&Krb5Principal>,
+    error: &Krb5Error,
+  ) -> Result<(), Krb5Error> {
+    let code = match error {
+      Krb5Error::LibraryError { code, .. } => *code,
+      _ => KRB5KRB_ERR_GENERIC,
+    };
+
+    // library codes beyond the protocol error codes are reported as generic errors
+    let mut error_number = code.wrapping_sub(KRB5KDC_ERR_NONE) as krb5_ui_4;
+    if error_number > 127 {
+      error_number = (KRB5KRB_ERR_GENERIC - KRB5KDC_ERR_NONE) as krb5_ui_4;
+    }
+
+    let mut stime: krb5_timestamp = 0;
+    let mut susec: krb5_int32 = 0;
+    let code: krb5_error_code = unsafe { krb5_us_timeofday(self.context.context, &mut stime, &mut susec) };
+    krb5_error_code_escape_hatch(self.context, code)?;
+
+    let server = match server {
+      Some(server) => copy_principal(self.context, server.principal)?,
+      None => parse_name(self.context, "????")?,
+    };
+
+    let message = Krb5ErrorMessage {
+      ctime: 0,
+      cusec: 0,
+      stime,
+      susec,
+      error: error_number,
+      client: None,
+      server,
+      text: Some(error.to_string()),
+      e_data: Vec::new(),
+    };
+
+    write_frame(stream, &message.mk_error(self.context)?)
+  }
+
+  /**
+   * Retrieve a copy of one of the keys of the authentication context.
+   */
+  fn get_keyblock(
+    &self,
+    getter: unsafe extern "C" fn(krb5_context, krb5_auth_context, *mut *mut krb5_keyblock) -> krb5_error_code,
+  ) -> Result<Option<Krb5Keyblock<'a>>, Krb5Error> {
+    let mut keyblock: MaybeUninit<*mut krb5_keyblock> = MaybeUninit::zeroed();
+
+    let code: krb5_error_code = unsafe { getter(self.context.context, self.auth_context, keyblock.as_mut_ptr()) };
+
+    krb5_error_code_escape_hatch(self.context, code)?;
+
+    let keyblock = unsafe { keyblock.assume_init() };
+    if keyblock.is_null() {
+      return Ok(None);
+    }
+
+    Ok(Some(Krb5Keyblock {
+      context: self.context,
+      keyblock,
+    }))
+  }
+
+  /**
+   * Fail unless the authentication context has a key, i.e. an AP request has been created or
+   * verified.
+   *
+   * libkrb5 does not check this itself and dereferences the missing state in that case.
+   */
+  fn require_key(&self) -> Result<(), Krb5Error> {
+    let mut key: MaybeUninit<krb5_key> = MaybeUninit::zeroed();
+
+    let code: krb5_error_code =
+      unsafe { krb5_auth_con_getkey_k(self.context.context, self.auth_context, key.as_mut_ptr()) };
+
+    krb5_error_code_escape_hatch(self.context, code)?;
+
+    let key = unsafe { key.assume_init() };
+    if key.is_null() {
+      return Err(Krb5Error::LibraryError {
+        code: KRB5KRB_AP_ERR_NOKEY,
+        message: String::from("Authentication context has no key, no AP request has been exchanged"),
+      });
+    }
+
+    unsafe { krb5_k_free_key(self.context.context, key) };
+
+    Ok(())
+  }
+}
+
+/**
+ * Return a pointer to an optional address, or NULL.
+ */
+fn optional_address_ptr(address: &mut Option<krb5_address>) -> *mut krb5_address {
+  match address {
+    Some(address) => address,
+    None => std::ptr::null_mut(),
+  }
+}
+
+/**
+ * The authenticator of an AP request, see [Krb5AuthContext::get_authenticator].
+ */
+#[derive(Debug)]
+pub struct Krb5Authenticator<'a> {
+  /// the client principal
+  pub client: Krb5Principal<'a>,
+  /// the checksum of the application data, if the client included one
+  pub checksum: Option<Krb5Checksum>,
+  /// the time of the client when it created the authenticator
+  pub time: Krb5Timestamp,
+  /// the microseconds part of the client time
+  pub usec: krb5_int32,
+  /// the subkey proposed by the client, if any
+  pub subkey: Option<Krb5Keyblock<'a>>,
+  /// the initial sequence number of the client, if sequence numbers are used
+  pub seq_number: krb5_ui_4,
+}
+
+/**
+ * A verified AP request, see [Krb5AuthContext::rd_req].
+ */
+#[derive(Debug)]
+pub struct Krb5ApRequest<'a> {
+  /// the authenticated client principal
+  pub client: Krb5Principal<'a>,
+  /// the decrypted service ticket
+  pub ticket: Krb5Ticket<'a>,
+  /// the AP options requested by the client
+  pub options: Krb5ApOptions,
+  /// the checksum of the authenticator, if the client included one, see
+  /// [Krb5AuthContext::verify_authenticator_checksum]
+  pub checksum: Option<Krb5Checksum>,
+}
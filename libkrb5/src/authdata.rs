@@ -0,0 +1,384 @@
+/*!
+ * Authorization data of tickets and authenticators.
+ */
+use std::hash::{Hash, Hasher};
+use std::mem::MaybeUninit;
+
+use libkrb5_sys::*;
+
+use crate::context::Krb5Context;
+use crate::error::{krb5_error_code_escape_hatch, Krb5Error};
+use crate::keyblock::Krb5Keyblock;
+use crate::principal::Krb5Principal;
+
+/**
+ * A krb5 authorization data type (`KRB5_AUTHDATA_*`, RFC 4120 section 5.2.6 and the IANA
+ * registry), which determines the structure of the contents.
+ *
+ * New variants may be added for further types, so matches need a wildcard arm. Types are
+ * compared by their number, so `Unknown(128)` equals `Win2kPac`.
+ */
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub enum Krb5AuthdataType {
+  /// AD-IF-RELEVANT, a container of elements that may be ignored if not understood (1)
+  IfRelevant,
+  /// AD-KDCIssued, a container of elements signed by the KDC (4)
+  KdcIssued,
+  /// AD-AND-OR, a container of alternative elements (5)
+  AndOr,
+  /// AD-MANDATORY-FOR-KDC, a container of elements the KDC has to understand (8)
+  MandatoryForKdc,
+  /// the certification authorities that verified a PKINIT client (9)
+  InitialVerifiedCas,
+  /// OSF DCE privilege attributes (64)
+  OsfDce,
+  /// SESAME privilege attributes (65)
+  Sesame,
+  /// the FAST armor key of the request (71)
+  FxArmor,
+  /// AD-CAMMAC, a container of elements verifiable by the KDC (96)
+  Cammac,
+  /// the authentication indicators of the initial authentication (97)
+  AuthIndicator,
+  /// the PAC of an Active Directory ticket, see [crate::Krb5Pac] (128)
+  Win2kPac,
+  /// the encryption types the client supports, in an authenticator (129)
+  EtypeNegotiation,
+  /// the AP options of an authenticator, e.g. for channel binding (143)
+  ApOptions,
+  /// a signature of the ticket by the KDC (512)
+  SignTicket,
+  /// any other authorization data type
+  Unknown(krb5_authdatatype),
+}
+
+impl Krb5AuthdataType {
+  /**
+   * Convert an authorization data type number.
+   */
+  pub fn from_raw(ad_type: krb5_authdatatype) -> Self {
+    use Krb5AuthdataType::*;
+
+    match ad_type as u32 {
+      KRB5_AUTHDATA_IF_RELEVANT => IfRelevant,
+      KRB5_AUTHDATA_KDC_ISSUED => KdcIssued,
+      KRB5_AUTHDATA_AND_OR => AndOr,
+      KRB5_AUTHDATA_MANDATORY_FOR_KDC => MandatoryForKdc,
+      KRB5_AUTHDATA_INITIAL_VERIFIED_CAS => InitialVerifiedCas,
+      KRB5_AUTHDATA_OSF_DCE => OsfDce,
+      KRB5_AUTHDATA_SESAME => Sesame,
+      KRB5_AUTHDATA_FX_ARMOR => FxArmor,
+      KRB5_AUTHDATA_CAMMAC => Cammac,
+      KRB5_AUTHDATA_AUTH_INDICATOR => AuthIndicator,
+      KRB5_AUTHDATA_WIN2K_PAC => Win2kPac,
+      KRB5_AUTHDATA_ETYPE_NEGOTIATION => EtypeNegotiation,
+      KRB5_AUTHDATA_AP_OPTIONS => ApOptions,
+      KRB5_AUTHDATA_SIGNTICKET => SignTicket,
+      _ => Unknown(ad_type),
+    }
+  }
+
+  /**
+   * Return the authorization data type number.
+   */
+  pub fn as_raw(self) -> krb5_authdatatype {
+    use Krb5AuthdataType::*;
+
+    let ad_type = match self {
+      IfRelevant => KRB5_AUTHDATA_IF_RELEVANT,
+      KdcIssued => KRB5_AUTHDATA_KDC_ISSUED,
+      AndOr => KRB5_AUTHDATA_AND_OR,
+      MandatoryForKdc => KRB5_AUTHDATA_MANDATORY_FOR_KDC,
+      InitialVerifiedCas => KRB5_AUTHDATA_INITIAL_VERIFIED_CAS,
+      OsfDce => KRB5_AUTHDATA_OSF_DCE,
+      Sesame => KRB5_AUTHDATA_SESAME,
+      FxArmor => KRB5_AUTHDATA_FX_ARMOR,
+      Cammac => KRB5_AUTHDATA_CAMMAC,
+      AuthIndicator => KRB5_AUTHDATA_AUTH_INDICATOR,
+      Win2kPac => KRB5_AUTHDATA_WIN2K_PAC,
+      EtypeNegotiation => KRB5_AUTHDATA_ETYPE_NEGOTIATION,
+      ApOptions => KRB5_AUTHDATA_AP_OPTIONS,
+      SignTicket => KRB5_AUTHDATA_SIGNTICKET,
+      Unknown(ad_type) => return ad_type,
+    };
+
+    ad_type as krb5_authdatatype
+  }
+}
+
+impl PartialEq for Krb5AuthdataType {
+  fn eq(&self, other: &Self) -> bool {
+    self.as_raw() == other.as_raw()
+  }
+}
+
+impl Eq for Krb5AuthdataType {}
+
+impl Hash for Krb5AuthdataType {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    self.as_raw().hash(state);
+  }
+}
+
+impl From<krb5_authdatatype> for Krb5AuthdataType {
+  fn from(ad_type: krb5_authdatatype) -> Self {
+    Krb5AuthdataType::from_raw(ad_type)
+  }
+}
+
+impl From<Krb5AuthdataType> for krb5_authdatatype {
+  fn from(ad_type: Krb5AuthdataType) -> Self {
+    ad_type.as_raw()
+  }
+}
+
+/**
+ * An owned copy of a krb5 authorization data element.
+ *
+ * https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/types/krb5_authdata.html
+ */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Krb5Authdata {
+  /// the authorization data type
+  pub ad_type: Krb5AuthdataType,
+  /// the contents, to be decoded according to the type
+  pub contents: Vec<u8>,
+}
+
+impl Krb5Authdata {
+  /**
+   * Copy a libkrb5 authorization data element.
+   */
+  pub(crate) fn from_raw(authdata: &krb5_authdata) -> Self {
+    let contents = if authdata.contents.is_null() || authdata.length == 0 {
+      Vec::new()
+    } else {
+      unsafe { std::slice::from_raw_parts(authdata.contents, authdata.length as usize) }.to_vec()
+    };
+
+    Krb5Authdata {
+      ad_type: Krb5AuthdataType::from_raw(authdata.ad_type),
+      contents,
+    }
+  }
+
+  /**
+   * Return a libkrb5 authorization data element borrowing the contents of this element.
+   *
+   * The returned structure must not be freed and must not outlive `self`.
+   */
+  pub(crate) fn as_krb5_authdata(&self) -> krb5_authdata {
+    krb5_authdata {
+      magic: 0,
+      ad_type: self.ad_type.as_raw(),
+      length: self.contents.len() as u32,
+      contents: self.contents.as_ptr() as *mut krb5_octet,
+    }
+  }
+
+  /**
+   * Decode the elements of a container element whose contents are encoded authorization data,
+   * i.e. AD-IF-RELEVANT or AD-MANDATORY-FOR-KDC.
+   *
+   * [krb5_decode_authdata_container](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_decode_authdata_container.html)
+   *
+   * # Arguments
+   *
+   *  * context: the Krb5Context instance
+   */
+  pub fn decode_container(&self, context: &Krb5Context) -> Result<Vec<Krb5Authdata>, Krb5Error> {
+    let container = self.as_krb5_authdata();
+    let mut authdata: MaybeUninit<*mut *mut krb5_authdata> = MaybeUninit::zeroed();
+
+    let code: krb5_error_code =
+      unsafe { krb5_decode_authdata_container(context.context, container.ad_type, &container, authdata.as_mut_ptr()) };
+
+    krb5_error_code_escape_hatch(context, code)?;
+
+    let authdata = unsafe { authdata.assume_init() };
+    let elements = unsafe { authdata_list_from_raw(authdata) };
+    unsafe { krb5_free_authdata(context.context, authdata) };
+
+    Ok(elements)
+  }
+
+  /**
+   * Encode elements into a container element whose contents are encoded authorization data,
+   * i.e. AD-IF-RELEVANT or AD-MANDATORY-FOR-KDC.
+   *
+   * [krb5_encode_authdata_container](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_encode_authdata_container.html)
+   *
+   * # Arguments
+   *
+   *  * context: the Krb5Context instance
+   *  * ad_type: the type of the container
+   *  * elements: the elements to put into the container
+   */
+  pub fn encode_container(
+    context: &Krb5Context,
+    ad_type: Krb5AuthdataType,
+    elements: &[Krb5Authdata],
+  ) -> Result<Krb5Authdata, Krb5Error> {
+    let (_raw, list) = authdata_list(elements);
+    let mut container: MaybeUninit<*mut *mut krb5_authdata> = MaybeUninit::zeroed();
+
+    let code: krb5_error_code = unsafe {
+      krb5_encode_authdata_container(context.context, ad_type.as_raw(), list.as_ptr(), container.as_mut_ptr())
+    };
+
+    krb5_error_code_escape_hatch(context, code)?;
+
+    Ok(unsafe { take_single_authdata(context, container.assume_init()) })
+  }
+
+  /**
+   * Wrap elements into an AD-KDCIssued element, signed with the given key, usually the session
+   * key of the ticket the element is put into.
+   *
+   * [krb5_make_authdata_kdc_issued](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_make_authdata_kdc_issued.html)
+   *
+   * # Arguments
+   *
+   *  * context: the Krb5Context instance
+   *  * key: the key to sign the elements with
+   *  * issuer: the principal that issued the elements, or `None` for the KDC
+   *  * elements: the elements to wrap
+   */
+  pub fn make_kdc_issued(
+    context: &Krb5Context,
+    key: &Krb5Keyblock,
+    issuer: Option<&Krb5Principal>,
+    elements: &[Krb5Authdata],
+  ) -> Result<Krb5Authdata, Krb5Error> {
+    let (_raw, list) = authdata_list(elements);
+    let issuer_ptr = match issuer {
+      Some(issuer) => issuer.principal as krb5_const_principal,
+      None => std::ptr::null(),
+    };
+    let mut kdc_issued: MaybeUninit<*mut *mut krb5_authdata> = MaybeUninit::zeroed();
+
+    let code: krb5_error_code = unsafe {
+      krb5_make_authdata_kdc_issued(
+        context.context,
+        key.keyblock,
+        issuer_ptr,
+        list.as_ptr(),
+        kdc_issued.as_mut_ptr(),
+      )
+    };
+
+    krb5_error_code_escape_hatch(context, code)?;
+
+    Ok(unsafe { take_single_authdata(context, kdc_issued.assume_init()) })
+  }
+
+  /**
+   * Verify the signature of an AD-KDCIssued element, and return the issuer, if it is given, and
+   * the wrapped elements.
+   *
+   * [krb5_verify_authdata_kdc_issued](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_verify_authdata_kdc_issued.html)
+   *
+   * # Arguments
+   *
+   *  * context: the Krb5Context instance
+   *  * key: the key the element was signed with
+   */
+  pub fn verify_kdc_issued<'a>(
+    &self,
+    context: &'a Krb5Context,
+    key: &Krb5Keyblock,
+  ) -> Result<(Option<Krb5Principal<'a>>, Vec<Krb5Authdata>), Krb5Error> {
+    let kdc_issued = self.as_krb5_authdata();
+    let mut issuer: krb5_principal = std::ptr::null_mut();
+    let mut authdata: MaybeUninit<*mut *mut krb5_authdata> = MaybeUninit::zeroed();
+
+    let code: krb5_error_code = unsafe {
+      krb5_verify_authdata_kdc_issued(
+        context.context,
+        key.keyblock,
+        &kdc_issued,
+        &mut issuer,
+        authdata.as_mut_ptr(),
+      )
+    };
+
+    krb5_error_code_escape_hatch(context, code)?;
+
+    let authdata = unsafe { authdata.assume_init() };
+    let elements = unsafe { authdata_list_from_raw(authdata) };
+    unsafe { krb5_free_authdata(context.context, authdata) };
+
+    let issuer = if issuer.is_null() {
+      None
+    } else {
+      Some(Krb5Principal {
+        context,
+        principal: issuer,
+      })
+    };
+
+    Ok((issuer, elements))
+  }
+}
+
+/**
+ * Build a NULL-terminated list of libkrb5 authorization data elements borrowing the given
+ * elements. The list points into the returned elements, so both have to be kept alive.
+ */
+fn authdata_list(elements: &[Krb5Authdata]) -> (Vec<krb5_authdata>, Vec<*mut krb5_authdata>) {
+  let mut raw: Vec<krb5_authdata> = elements.iter().map(Krb5Authdata::as_krb5_authdata).collect();
+  let mut list: Vec<*mut krb5_authdata> = raw.iter_mut().map(|element| element as *mut krb5_authdata).collect();
+  list.push(std::ptr::null_mut());
+
+  (raw, list)
+}
+
+/**
+ * Copy the single element of a list returned by libkrb5, and free the list.
+ */
+unsafe fn take_single_authdata(context: &Krb5Context, list: *mut *mut krb5_authdata) -> Krb5Authdata {
+  let element = Krb5Authdata::from_raw(&**list);
+  krb5_free_authdata(context.context, list);
+
+  element
+}
+
+/**
+ * Copy a NULL-terminated list of authorization data elements, which may be NULL itself.
+ */
+pub(crate) unsafe fn authdata_list_from_raw(list: *const *mut krb5_authdata) -> Vec<Krb5Authdata> {
+  let mut elements = Vec::new();
+
+  if list.is_null() {
+    return elements;
+  }
+
+  let mut i = 0;
+  while !(*list.add(i)).is_null() {
+    elements.push(Krb5Authdata::from_raw(&**list.add(i)));
+    i += 1;
+  }
+
+  elements
+}
+
+/**
+ * Replace AD-IF-RELEVANT containers by their elements, recursively.
+ */
+pub(crate) fn unwrap_if_relevant(
+  context: &Krb5Context,
+  authdata: Vec<Krb5Authdata>,
+) -> Result<Vec<Krb5Authdata>, Krb5Error> {
+  let mut elements = Vec::with_capacity(authdata.len());
+
+  for element in authdata {
+    if element.ad_type == Krb5AuthdataType::IfRelevant {
+      elements.extend(unwrap_if_relevant(context, element.decode_container(context)?)?);
+    } else {
+      elements.push(element);
+    }
+  }
+
+  Ok(elements)
+}
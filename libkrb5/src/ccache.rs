@@ -1,15 +1,24 @@
 /*!
  * Rustic wrapper for krb5 credential caches.
  */
+use std::ffi::{OsStr, OsString};
 use std::mem::MaybeUninit;
 use std::os::raw::c_char;
+#[cfg(feature = "async")]
+use std::pin::Pin;
+#[cfg(feature = "async")]
+use std::task::Poll;
 
 use libkrb5_sys::*;
 
 use crate::context::Krb5Context;
+use crate::creds::Krb5Creds;
+use crate::enctype::Krb5Enctype;
 use crate::error::{krb5_error_code_escape_hatch, Krb5Error};
 use crate::principal::Krb5Principal;
-use crate::strconv::{c_string_to_string, string_to_c_string};
+use crate::strconv::{c_string_to_os_string, os_str_to_c_string};
+use crate::ticket::Krb5Ticket;
+use crate::timestamp::Krb5Timestamp;
 
 /**
  * Wrapper struct for a krb5 credential cache.
@@ -22,6 +31,49 @@ pub struct Krb5CCache<'a> {
   pub(crate) ccache: krb5_ccache,
 }
 
+/**
+ * The service ticket information of [Krb5CCache::get_service_ticket].
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Krb5ServiceTicketInfo {
+  /// the version number of the service key the ticket is encrypted with
+  pub kvno: krb5_kvno,
+  /// the encryption type of the service key the ticket is encrypted with
+  pub enctype: Krb5Enctype,
+  /// the encryption type of the session key
+  pub session_enctype: Krb5Enctype,
+  /// the ticket flags (`TKT_FLG_*`)
+  pub ticket_flags: krb5_flags,
+  /// the time of the initial authentication
+  pub auth_time: Krb5Timestamp,
+  /// the time the ticket becomes valid
+  pub start_time: Krb5Timestamp,
+  /// the time the ticket expires
+  pub end_time: Krb5Timestamp,
+  /// the latest time the ticket can be renewed until, unset if it is not renewable
+  pub renew_till: Krb5Timestamp,
+}
+
+impl Krb5ServiceTicketInfo {
+  /**
+   * Collect the ticket information of credentials.
+   */
+  pub(crate) fn from_creds(context: &Krb5Context, creds: &Krb5Creds) -> Result<Krb5ServiceTicketInfo, Krb5Error> {
+    let ticket = Krb5Ticket::decode(context, creds.ticket())?;
+
+    Ok(Krb5ServiceTicketInfo {
+      kvno: ticket.kvno(),
+      enctype: ticket.enctype(),
+      session_enctype: Krb5Enctype::from_raw(creds.creds.keyblock.enctype),
+      ticket_flags: creds.ticket_flags(),
+      auth_time: creds.auth_time(),
+      start_time: creds.start_time(),
+      end_time: creds.end_time(),
+      renew_till: creds.renew_till(),
+    })
+  }
+}
+
 /**
  * Free a credential cache instance.
  *
@@ -36,6 +88,74 @@ impl<'a> Drop for Krb5CCache<'a> {
 }
 
 impl<'a> Krb5CCache<'a> {
+  /**
+   * Return the ticket granting ticket for the realm of the default principal from the
+   * credential cache, even if it has expired, or `None` if the credential cache has none. The
+   * KDC is not contacted.
+   *
+   * [krb5_cc_retrieve_cred](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_cc_retrieve_cred.html)
+   */
+  #[cfg(feature = "async")]
+  pub(crate) fn cached_tgt(&self) -> Result<Option<Krb5Creds<'a>>, Krb5Error> {
+    let client = match self.get_principal()? {
+      Some(client) => client,
+      None => return Ok(None),
+    };
+
+    let realm = client.data().realm()?;
+    let components = [String::from("krbtgt"), realm.clone()];
+    let server = self.context.build_principal(&realm, &components)?;
+
+    // the principals are borrowed and not freed
+    let mut mcreds: krb5_creds = unsafe { std::mem::zeroed() };
+    mcreds.client = client.principal;
+    mcreds.server = server.principal;
+
+    let mut creds: krb5_creds = unsafe { std::mem::zeroed() };
+
+    let code: krb5_error_code =
+      unsafe { krb5_cc_retrieve_cred(self.context.context, self.ccache, 0, &mut mcreds, &mut creds) };
+
+    if code == KRB5_CC_NOTFOUND {
+      return Ok(None);
+    }
+
+    krb5_error_code_escape_hatch(self.context, code)?;
+
+    Ok(Some(Krb5Creds {
+      context: self.context,
+      creds,
+    }))
+  }
+
+  /**
+   * Iterate over the credentials in the credential cache.
+   *
+   * The entries include configuration entries libkrb5 stores in the credential cache, whose
+   * server principal is in the `X-CACHECONF:` realm.
+   *
+   * [krb5_cc_start_seq_get](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_cc_start_seq_get.html)
+   */
+  pub fn credentials<'c>(&'c self) -> Result<Krb5CCacheCursor<'a, 'c>, Krb5Error> {
+    let mut cursor_ptr: MaybeUninit<krb5_cc_cursor> = MaybeUninit::zeroed();
+
+    let code: krb5_error_code =
+      unsafe { krb5_cc_start_seq_get(self.context.context, self.ccache, cursor_ptr.as_mut_ptr()) };
+
+    krb5_error_code_escape_hatch(self.context, code).map_err(|error| {
+      let cctype = self.get_type().unwrap_or_default();
+      let name = self.get_name().unwrap_or_default().to_string_lossy().into_owned();
+      error.context(format!("while reading ccache {}:{}", cctype, name))
+    })?;
+
+    let cursor = Krb5CCacheCursor {
+      ccache: self,
+      cursor: unsafe { cursor_ptr.assume_init() },
+    };
+
+    Ok(cursor)
+  }
+
   /**
    * Initialize using the default credential cache name.
    *
@@ -45,7 +165,7 @@ impl<'a> Krb5CCache<'a> {
    *
    *  * context: the Krb5Context instance
    */
-  pub fn default(context: &Krb5Context) -> Result<Krb5CCache, Krb5Error> {
+  pub fn default(context: &Krb5Context) -> Result<Krb5CCache<'_>, Krb5Error> {
     let mut ccache_ptr: MaybeUninit<krb5_ccache> = MaybeUninit::zeroed();
 
     let code: krb5_error_code = unsafe { krb5_cc_default(context.context, ccache_ptr.as_mut_ptr()) };
@@ -72,7 +192,7 @@ impl<'a> Krb5CCache<'a> {
   pub fn default_name(context: &Krb5Context) -> Result<String, Krb5Error> {
     let name: *const c_char = unsafe { krb5_cc_default_name(context.context) };
 
-    c_string_to_string(name)
+    context.c_string_to_string(name)
   }
 
   /**
@@ -81,41 +201,104 @@ impl<'a> Krb5CCache<'a> {
    * [krb5_cc_destroy](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_cc_destroy.html)
    */
   pub fn destroy(self) -> Result<(), Krb5Error> {
-    let code = unsafe { krb5_cc_destroy(self.context.context, self.ccache) };
+    let context = self.context;
+    let code = unsafe { krb5_cc_destroy(context.context, self.ccache) };
 
-    krb5_error_code_escape_hatch(self.context, code)?;
+    // krb5_cc_destroy closes the handle even if it fails
+    std::mem::forget(self);
+
+    krb5_error_code_escape_hatch(context, code)?;
 
     Ok(())
   }
 
   /**
-   * Duplicate a credential cache handle.
+   * Duplicate a credential cache handle. The new handle refers to the same credential cache,
+   * and can be closed independently. Only available with MIT krb5.
    *
-   * This is commented out since it is not available in Heimdal Kerberos.
+   * [krb5_cc_dup](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_cc_dup.html)
    */
-  // pub fn dup(&self) -> Result<Krb5CCache, Krb5Error> {
-  //     let mut ccache_ptr: MaybeUninit<krb5_ccache> = MaybeUninit::zeroed();
+  #[cfg(krb5_mit)]
+  pub fn dup(&self) -> Result<Krb5CCache<'a>, Krb5Error> {
+    let mut ccache_ptr: MaybeUninit<krb5_ccache> = MaybeUninit::zeroed();
 
-  //     let code: krb5_error_code = unsafe { krb5_cc_dup(self.context.context, self.ccache, ccache_ptr.as_mut_ptr()) };
+    let code: krb5_error_code = unsafe { krb5_cc_dup(self.context.context, self.ccache, ccache_ptr.as_mut_ptr()) };
 
-  //     krb5_error_code_escape_hatch(self.context, code)?;
+    krb5_error_code_escape_hatch(self.context, code)?;
 
-  //     let ccache = Krb5CCache {
-  //         context: self.context,
-  //         ccache: unsafe { ccache_ptr.assume_init() },
-  //     };
+    Ok(Krb5CCache {
+      context: self.context,
+      ccache: unsafe { ccache_ptr.assume_init() },
+    })
+  }
 
-  //     Ok(ccache)
-  // }
+  /**
+   * Get credentials for a service, from the credential cache or from the KDC with the ticket
+   * granting ticket of the credential cache. New credentials are stored in the credential
+   * cache.
+   *
+   * [krb5_get_credentials](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_get_credentials.html)
+   *
+   * # Arguments
+   *
+   *  * server: the principal of the service
+   */
+  pub fn get_credentials(&self, server: &Krb5Principal) -> Result<Krb5Creds<'a>, Krb5Error> {
+    let client = self.get_principal()?.ok_or_else(|| Krb5Error::LibraryError {
+      code: KRB5_CC_NOTFOUND,
+      message: String::from("Credential cache is not initialized"),
+    })?;
+
+    self.get_credentials_for(&client, server)
+  }
+
+  /**
+   * Get credentials of a client other than the default principal of the credential cache, see
+   * [Krb5CCache::get_credentials].
+   */
+  pub(crate) fn get_credentials_for(
+    &self,
+    client: &Krb5Principal,
+    server: &Krb5Principal,
+  ) -> Result<Krb5Creds<'a>, Krb5Error> {
+    // the principals are borrowed and not freed
+    let mut in_creds: krb5_creds = unsafe { std::mem::zeroed() };
+    in_creds.client = client.principal;
+    in_creds.server = server.principal;
+
+    let mut out_creds: MaybeUninit<*mut krb5_creds> = MaybeUninit::zeroed();
+
+    let code: krb5_error_code = unsafe {
+      krb5_get_credentials(
+        self.context.context,
+        0,
+        self.ccache,
+        &mut in_creds,
+        out_creds.as_mut_ptr(),
+      )
+    };
+
+    krb5_error_code_escape_hatch(self.context, code)?;
+
+    // move the contents out, leaving an empty structure for krb5_free_creds
+    let out_creds = unsafe { out_creds.assume_init() };
+    let contents: krb5_creds = unsafe { std::ptr::replace(out_creds, std::mem::zeroed()) };
+    unsafe { krb5_free_creds(self.context.context, out_creds) };
+
+    Ok(Krb5Creds {
+      context: self.context,
+      creds: contents,
+    })
+  }
 
   /**
    * Return the name of the credential cache.
    *
    * [krb5_cc_get_name](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_cc_get_name.html)
    */
-  pub fn get_name(&self) -> Result<String, Krb5Error> {
+  pub fn get_name(&self) -> Result<OsString, Krb5Error> {
     let name: *const c_char = unsafe { krb5_cc_get_name(self.context.context, self.ccache) };
-    c_string_to_string(name)
+    c_string_to_os_string(name)
   }
 
   /**
@@ -123,13 +306,17 @@ impl<'a> Krb5CCache<'a> {
    *
    * [krb5_cc_get_principal](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_cc_get_principal.html)
    */
-  pub fn get_principal(&self) -> Result<Option<Krb5Principal>, Krb5Error> {
+  pub fn get_principal(&self) -> Result<Option<Krb5Principal<'a>>, Krb5Error> {
     let mut principal_ptr: MaybeUninit<krb5_principal> = MaybeUninit::zeroed();
 
     let code: krb5_error_code =
       unsafe { krb5_cc_get_principal(self.context.context, self.ccache, principal_ptr.as_mut_ptr()) };
 
-    krb5_error_code_escape_hatch(self.context, code)?;
+    krb5_error_code_escape_hatch(self.context, code).map_err(|error| {
+      let cctype = self.get_type().unwrap_or_default();
+      let name = self.get_name().unwrap_or_default().to_string_lossy().into_owned();
+      error.context(format!("while reading ccache {}:{}", cctype, name))
+    })?;
 
     let principal_ptr = unsafe { principal_ptr.assume_init() };
 
@@ -138,13 +325,27 @@ impl<'a> Krb5CCache<'a> {
     }
 
     let principal = Krb5Principal {
-      context: &self.context,
+      context: self.context,
       principal: principal_ptr,
     };
 
     Ok(Some(principal))
   }
 
+  /**
+   * Get a ticket for a service like the `kvno` tool, and return the key version number and
+   * encryption type of the service key it is encrypted with, e.g. to check that a key table
+   * agrees with the KDC.
+   *
+   * # Arguments
+   *
+   *  * service: the principal of the service
+   */
+  pub fn get_service_ticket(&self, service: &Krb5Principal) -> Result<Krb5ServiceTicketInfo, Krb5Error> {
+    let creds = self.get_credentials(service)?;
+    Krb5ServiceTicketInfo::from_creds(self.context, &creds)
+  }
+
   /**
    * Get the type of the credential cache.
    *
@@ -153,7 +354,7 @@ impl<'a> Krb5CCache<'a> {
   pub fn get_type(&self) -> Result<String, Krb5Error> {
     let cctype: *const c_char = unsafe { krb5_cc_get_type(self.context.context, self.ccache) };
 
-    c_string_to_string(cctype)
+    self.context.c_string_to_string(cctype)
   }
 
   /**
@@ -180,13 +381,19 @@ impl<'a> Krb5CCache<'a> {
    *  * cctype: the credential cache [type name](https://web.mit.edu/kerberos/krb5-1.12/doc/basic/ccache_def.html#ccache-types)
    *
    */
-  pub fn new_unique(context: &'a Krb5Context, cctype: &str) -> Result<Krb5CCache<'a>, Krb5Error> {
-    let cctype = string_to_c_string(cctype)?;
+  pub fn new_unique<T: AsRef<OsStr>>(context: &'a Krb5Context, cctype: T) -> Result<Krb5CCache<'a>, Krb5Error> {
+    let cctype = os_str_to_c_string(cctype.as_ref())?;
 
     let mut ccache_ptr: MaybeUninit<krb5_ccache> = MaybeUninit::zeroed();
 
-    let code: krb5_error_code =
-      unsafe { krb5_cc_new_unique(context.context, cctype, std::ptr::null(), ccache_ptr.as_mut_ptr()) };
+    let code: krb5_error_code = unsafe {
+      krb5_cc_new_unique(
+        context.context,
+        cctype.as_ptr(),
+        std::ptr::null(),
+        ccache_ptr.as_mut_ptr(),
+      )
+    };
 
     krb5_error_code_escape_hatch(context, code)?;
 
@@ -198,6 +405,45 @@ impl<'a> Krb5CCache<'a> {
     Ok(cursor)
   }
 
+  /**
+   * Renew the ticket granting ticket of the credential cache with the KDC, like `kinit -R`,
+   * and replace the contents of the credential cache with the renewed ticket.
+   *
+   * The ticket has to be renewable, and its renewable lifetime must not have ended.
+   *
+   * [krb5_get_renewed_creds](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_get_renewed_creds.html)
+   */
+  pub fn renew(&mut self) -> Result<Krb5Creds<'a>, Krb5Error> {
+    let client = self.get_principal()?.ok_or_else(|| Krb5Error::LibraryError {
+      code: KRB5_CC_NOTFOUND,
+      message: String::from("Credential cache is not initialized"),
+    })?;
+
+    let mut creds: MaybeUninit<krb5_creds> = MaybeUninit::zeroed();
+
+    let code: krb5_error_code = unsafe {
+      krb5_get_renewed_creds(
+        self.context.context,
+        creds.as_mut_ptr(),
+        client.principal,
+        self.ccache,
+        std::ptr::null(),
+      )
+    };
+
+    krb5_error_code_escape_hatch(self.context, code)?;
+
+    let creds = Krb5Creds {
+      context: self.context,
+      creds: unsafe { creds.assume_init() },
+    };
+
+    self.initialize(&client)?;
+    self.store_cred(&creds)?;
+
+    Ok(creds)
+  }
+
   /**
    * Resolve a credential cache name.
    *
@@ -206,17 +452,20 @@ impl<'a> Krb5CCache<'a> {
    * # Arguments
    *
    *  * context: the Krb5Context instance
-   *  * name: the credential cache name to be resolved
+   *  * name: the credential cache name to be resolved, which may contain a file path that is
+   *    not valid UTF-8
    *
    */
-  pub fn resolve(context: &'a Krb5Context, name: &str) -> Result<Krb5CCache<'a>, Krb5Error> {
-    let name = string_to_c_string(name)?;
+  pub fn resolve<N: AsRef<OsStr>>(context: &'a Krb5Context, name: N) -> Result<Krb5CCache<'a>, Krb5Error> {
+    let name = name.as_ref();
+    let name_c = os_str_to_c_string(name)?;
 
     let mut ccache_ptr: MaybeUninit<krb5_ccache> = MaybeUninit::zeroed();
 
-    let code: krb5_error_code = unsafe { krb5_cc_resolve(context.context, name, ccache_ptr.as_mut_ptr()) };
+    let code: krb5_error_code = unsafe { krb5_cc_resolve(context.context, name_c.as_ptr(), ccache_ptr.as_mut_ptr()) };
 
-    krb5_error_code_escape_hatch(context, code)?;
+    krb5_error_code_escape_hatch(context, code)
+      .map_err(|error| error.context(format!("while resolving ccache {}", name.to_string_lossy())))?;
 
     let cursor = Krb5CCache {
       context,
@@ -225,4 +474,103 @@ impl<'a> Krb5CCache<'a> {
 
     Ok(cursor)
   }
+
+  /**
+   * Store credentials in the credential cache.
+   *
+   * [krb5_cc_store_cred](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_cc_store_cred.html)
+   *
+   * # Arguments
+   *
+   *  * creds: the credentials to store (which are copied)
+   */
+  pub fn store_cred(&mut self, creds: &Krb5Creds) -> Result<(), Krb5Error> {
+    // libkrb5 does not modify the credentials, the parameter just lacks the const qualifier
+    let code: krb5_error_code = unsafe {
+      krb5_cc_store_cred(
+        self.context.context,
+        self.ccache,
+        &creds.creds as *const krb5_creds as *mut krb5_creds,
+      )
+    };
+
+    krb5_error_code_escape_hatch(self.context, code)
+  }
+}
+
+/**
+ * Wrapper struct for a credential cache cursor.
+ *
+ * Yields copies of the credentials; the cursor is released when it is dropped.
+ */
+#[derive(Debug)]
+pub struct Krb5CCacheCursor<'a, 'c> {
+  pub(crate) ccache: &'c Krb5CCache<'a>,
+  pub(crate) cursor: krb5_cc_cursor,
+}
+
+/**
+ * Release a credential cache cursor.
+ *
+ * [krb5_cc_end_seq_get](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_cc_end_seq_get.html)
+ */
+impl<'a, 'c> Drop for Krb5CCacheCursor<'a, 'c> {
+  fn drop(&mut self) {
+    unsafe {
+      krb5_cc_end_seq_get(self.ccache.context.context, self.ccache.ccache, &mut self.cursor);
+    }
+  }
+}
+
+/**
+ * Implement Rustic iterator for credential cache entries.
+ *
+ * [krb5_cc_next_cred](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_cc_next_cred.html)
+ */
+impl<'a, 'c> Iterator for Krb5CCacheCursor<'a, 'c> {
+  type Item = Result<Krb5Creds<'a>, Krb5Error>;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    let context = self.ccache.context;
+    let mut creds_ptr: MaybeUninit<krb5_creds> = MaybeUninit::zeroed();
+
+    let code: krb5_error_code = unsafe {
+      krb5_cc_next_cred(
+        context.context,
+        self.ccache.ccache,
+        &mut self.cursor,
+        creds_ptr.as_mut_ptr(),
+      )
+    };
+
+    if code == KRB5_CC_END {
+      return None;
+    }
+
+    if let Err(error) = krb5_error_code_escape_hatch(context, code) {
+      return Some(Err(error));
+    }
+
+    let creds = Krb5Creds {
+      context,
+      creds: unsafe { creds_ptr.assume_init() },
+    };
+
+    Some(Ok(creds))
+  }
+}
+
+/**
+ * Stream the credentials of a credential cache, available with the `async` feature.
+ *
+ * Reading the next entry is a quick local operation, so every item is ready right away. The
+ * entries are only read when they are polled, so consumers control the pace.
+ */
+#[cfg(feature = "async")]
+impl<'a, 'c> futures_core::Stream for Krb5CCacheCursor<'a, 'c> {
+  type Item = Result<Krb5Creds<'a>, Krb5Error>;
+
+  fn poll_next(self: Pin<&mut Self>, _cx: &mut std::task::Context<'_>) -> Poll<Option<Self::Item>> {
+    Poll::Ready(self.get_mut().next())
+  }
 }
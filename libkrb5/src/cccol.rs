@@ -2,6 +2,10 @@
  * Rustic wrapper for krb5 credential cache collections.
  */
 use std::mem::MaybeUninit;
+#[cfg(feature = "async")]
+use std::pin::Pin;
+#[cfg(feature = "async")]
+use std::task::Poll;
 
 use libkrb5_sys::*;
 
@@ -28,7 +32,7 @@ impl<'a> Krb5CCCol<'a> {
    * Creates krb5 credential cache collection cursor that can be iterated over later.
    * [krb5_cccol_cursor_new](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_cccol_cursor_new.html)
    */
-  pub fn new(context: &Krb5Context) -> Result<Krb5CCCol, Krb5Error> {
+  pub fn new(context: &Krb5Context) -> Result<Krb5CCCol<'_>, Krb5Error> {
     let mut cursor_ptr: MaybeUninit<krb5_cccol_cursor> = MaybeUninit::zeroed();
 
     let code: krb5_error_code = unsafe { krb5_cccol_cursor_new(context.context, cursor_ptr.as_mut_ptr()) };
@@ -36,7 +40,7 @@ impl<'a> Krb5CCCol<'a> {
     krb5_error_code_escape_hatch(context, code)?;
 
     let cursor = Krb5CCCol {
-      context: &context,
+      context,
       cursor: unsafe { cursor_ptr.assume_init() },
     };
 
@@ -79,10 +83,25 @@ impl<'a> Iterator for Krb5CCCol<'a> {
     }
 
     let ccache = Krb5CCache {
-      context: &self.context,
+      context: self.context,
       ccache: ccache_ptr,
     };
 
     Some(Ok(ccache))
   }
 }
+
+/**
+ * Stream the credential caches of the collection, available with the `async` feature.
+ *
+ * Finding the next credential cache is a quick local operation, so every item is ready right
+ * away. The caches are only looked up when they are polled, so consumers control the pace.
+ */
+#[cfg(feature = "async")]
+impl<'a> futures_core::Stream for Krb5CCCol<'a> {
+  type Item = Result<Krb5CCache<'a>, Krb5Error>;
+
+  fn poll_next(self: Pin<&mut Self>, _cx: &mut std::task::Context<'_>) -> Poll<Option<Self::Item>> {
+    Poll::Ready(self.get_mut().next())
+  }
+}
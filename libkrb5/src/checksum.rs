@@ -0,0 +1,276 @@
+/*!
+ * Rustic wrapper for krb5 checksums.
+ */
+use std::fmt::{Display, Formatter};
+use std::mem::MaybeUninit;
+use std::os::raw::c_char;
+use std::str::FromStr;
+
+use libkrb5_sys::*;
+
+use crate::context::Krb5Context;
+use crate::error::{krb5_error_code_escape_hatch, Krb5Error};
+use crate::strconv::{c_string_to_string, string_to_c_string};
+
+/**
+ * Checksum type used by the GSS-API krb5 mechanism (RFC 4121) for the authenticator checksum.
+ *
+ * The contents of such a checksum are not a cryptographic checksum but carry the channel
+ * binding hash and the GSS flags.
+ */
+pub(crate) const GSS_CHECKSUM_TYPE: krb5_cksumtype = 0x8003;
+
+/**
+ * An owned copy of a krb5 checksum.
+ *
+ * https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/types/krb5_checksum.html
+ */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Krb5Checksum {
+  /// the checksum type
+  pub checksum_type: Krb5ChecksumType,
+  /// the checksum value
+  pub contents: Vec<u8>,
+}
+
+impl Krb5Checksum {
+  /**
+   * Copy a libkrb5 checksum.
+   */
+  pub(crate) fn from_raw(checksum: &krb5_checksum) -> Self {
+    let contents = if checksum.contents.is_null() || checksum.length == 0 {
+      Vec::new()
+    } else {
+      unsafe { std::slice::from_raw_parts(checksum.contents, checksum.length as usize) }.to_vec()
+    };
+
+    Krb5Checksum {
+      checksum_type: Krb5ChecksumType::from_raw(checksum.checksum_type),
+      contents,
+    }
+  }
+
+  /**
+   * Return a libkrb5 checksum borrowing the contents of this checksum.
+   *
+   * The returned structure must not be freed and must not outlive `self`.
+   */
+  pub(crate) fn as_krb5_checksum(&self) -> krb5_checksum {
+    krb5_checksum {
+      magic: 0,
+      checksum_type: self.checksum_type.as_raw(),
+      length: self.contents.len() as u32,
+      contents: self.contents.as_ptr() as *mut krb5_octet,
+    }
+  }
+}
+
+/**
+ * A krb5 checksum type (`CKSUMTYPE_*`).
+ *
+ * https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/types/krb5_cksumtype.html
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Krb5ChecksumType {
+  /// CRC-32 (unkeyed, weak)
+  Crc32,
+  /// RSA MD4 (unkeyed, weak)
+  RsaMd4,
+  /// RSA MD4 with DES (weak)
+  RsaMd4Des,
+  /// DES CBC MAC (weak)
+  DesCbc,
+  /// RSA MD5 (unkeyed, weak)
+  RsaMd5,
+  /// RSA MD5 with DES (weak)
+  RsaMd5Des,
+  /// SHA-1 (unkeyed)
+  NistSha,
+  /// HMAC SHA-1 with a DES3 key
+  HmacSha1Des3,
+  /// HMAC SHA-1 truncated to 96 bits with an AES128 key
+  HmacSha1_96Aes128,
+  /// HMAC SHA-1 truncated to 96 bits with an AES256 key
+  HmacSha1_96Aes256,
+  /// CMAC with a Camellia128 key
+  CmacCamellia128,
+  /// CMAC with a Camellia256 key
+  CmacCamellia256,
+  /// HMAC SHA-256 truncated to 128 bits with an AES128 key
+  HmacSha256_128Aes128,
+  /// HMAC SHA-384 truncated to 192 bits with an AES256 key
+  HmacSha384_192Aes256,
+  /// HMAC MD5 with an RC4 key (RFC 4757)
+  HmacMd5Arcfour,
+  /// MD5 HMAC with an RC4 key (Microsoft netlogon)
+  Md5HmacArcfour,
+  /// any other checksum type number
+  Unknown(krb5_cksumtype),
+}
+
+/// The checksum types known to [Krb5ChecksumType] and their numbers.
+const CHECKSUM_TYPES: [(Krb5ChecksumType, krb5_cksumtype); 16] = [
+  (Krb5ChecksumType::Crc32, CKSUMTYPE_CRC32 as krb5_cksumtype),
+  (Krb5ChecksumType::RsaMd4, CKSUMTYPE_RSA_MD4 as krb5_cksumtype),
+  (Krb5ChecksumType::RsaMd4Des, CKSUMTYPE_RSA_MD4_DES as krb5_cksumtype),
+  (Krb5ChecksumType::DesCbc, CKSUMTYPE_DESCBC as krb5_cksumtype),
+  (Krb5ChecksumType::RsaMd5, CKSUMTYPE_RSA_MD5 as krb5_cksumtype),
+  (Krb5ChecksumType::RsaMd5Des, CKSUMTYPE_RSA_MD5_DES as krb5_cksumtype),
+  (Krb5ChecksumType::NistSha, CKSUMTYPE_NIST_SHA as krb5_cksumtype),
+  (
+    Krb5ChecksumType::HmacSha1Des3,
+    CKSUMTYPE_HMAC_SHA1_DES3 as krb5_cksumtype,
+  ),
+  (
+    Krb5ChecksumType::HmacSha1_96Aes128,
+    CKSUMTYPE_HMAC_SHA1_96_AES128 as krb5_cksumtype,
+  ),
+  (
+    Krb5ChecksumType::HmacSha1_96Aes256,
+    CKSUMTYPE_HMAC_SHA1_96_AES256 as krb5_cksumtype,
+  ),
+  (
+    Krb5ChecksumType::CmacCamellia128,
+    CKSUMTYPE_CMAC_CAMELLIA128 as krb5_cksumtype,
+  ),
+  (
+    Krb5ChecksumType::CmacCamellia256,
+    CKSUMTYPE_CMAC_CAMELLIA256 as krb5_cksumtype,
+  ),
+  (
+    Krb5ChecksumType::HmacSha256_128Aes128,
+    CKSUMTYPE_HMAC_SHA256_128_AES128 as krb5_cksumtype,
+  ),
+  (
+    Krb5ChecksumType::HmacSha384_192Aes256,
+    CKSUMTYPE_HMAC_SHA384_192_AES256 as krb5_cksumtype,
+  ),
+  (Krb5ChecksumType::HmacMd5Arcfour, CKSUMTYPE_HMAC_MD5_ARCFOUR),
+  (Krb5ChecksumType::Md5HmacArcfour, CKSUMTYPE_MD5_HMAC_ARCFOUR),
+];
+
+impl Krb5ChecksumType {
+  /**
+   * Convert a checksum type number.
+   */
+  pub fn from_raw(cksumtype: krb5_cksumtype) -> Self {
+    CHECKSUM_TYPES
+      .iter()
+      .find(|(_, raw)| *raw == cksumtype)
+      .map(|(checksum_type, _)| *checksum_type)
+      .unwrap_or(Krb5ChecksumType::Unknown(cksumtype))
+  }
+
+  /**
+   * Return the checksum type number.
+   */
+  pub fn as_raw(self) -> krb5_cksumtype {
+    if let Krb5ChecksumType::Unknown(cksumtype) = self {
+      return cksumtype;
+    }
+
+    CHECKSUM_TYPES
+      .iter()
+      .find(|(checksum_type, _)| *checksum_type == self)
+      .map(|(_, raw)| *raw)
+      .expect("all known checksum types are listed in CHECKSUM_TYPES")
+  }
+
+  /**
+   * Return a human readable description, e.g. "HMAC-SHA1 AES256 key".
+   *
+   * [krb5_cksumtype_to_string](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_cksumtype_to_string.html)
+   */
+  pub fn description(self) -> Result<String, Krb5Error> {
+    let mut buffer: [c_char; 128] = [0; 128];
+
+    let code: krb5_error_code =
+      unsafe { krb5_cksumtype_to_string(self.as_raw(), buffer.as_mut_ptr(), buffer.len() as size_t) };
+
+    if code != 0 {
+      return Err(Krb5Error::LibraryError {
+        code,
+        message: format!("Unknown checksum type: {}", self.as_raw()),
+      });
+    }
+
+    c_string_to_string(buffer.as_ptr())
+  }
+
+  /**
+   * Parse a checksum type name, e.g. "hmac-sha1-96-aes256".
+   *
+   * [krb5_string_to_cksumtype](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_string_to_cksumtype.html)
+   *
+   * # Arguments
+   *
+   *  * name: the checksum type name
+   */
+  pub fn from_name(name: &str) -> Result<Self, Krb5Error> {
+    let name_c = string_to_c_string(name)?;
+    let mut cksumtype: krb5_cksumtype = 0;
+
+    // libkrb5 does not modify the string, the parameter just lacks the const qualifier
+    let code: krb5_error_code = unsafe { krb5_string_to_cksumtype(name_c.as_ptr() as *mut c_char, &mut cksumtype) };
+
+    if code != 0 {
+      return Err(Krb5Error::LibraryError {
+        code,
+        message: format!("Unknown checksum type name: {}", name),
+      });
+    }
+
+    Ok(Krb5ChecksumType::from_raw(cksumtype))
+  }
+
+  /**
+   * Return the length of checksums of this type in bytes.
+   *
+   * [krb5_c_checksum_length](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_c_checksum_length.html)
+   *
+   * # Arguments
+   *
+   *  * context: the Krb5Context instance
+   */
+  pub fn length(self, context: &Krb5Context) -> Result<usize, Krb5Error> {
+    let mut length: MaybeUninit<size_t> = MaybeUninit::zeroed();
+
+    let code: krb5_error_code = unsafe { krb5_c_checksum_length(context.context, self.as_raw(), length.as_mut_ptr()) };
+
+    krb5_error_code_escape_hatch(context, code)?;
+
+    Ok(unsafe { length.assume_init() } as usize)
+  }
+}
+
+impl From<krb5_cksumtype> for Krb5ChecksumType {
+  fn from(cksumtype: krb5_cksumtype) -> Self {
+    Krb5ChecksumType::from_raw(cksumtype)
+  }
+}
+
+impl From<Krb5ChecksumType> for krb5_cksumtype {
+  fn from(checksum_type: Krb5ChecksumType) -> Self {
+    checksum_type.as_raw()
+  }
+}
+
+/**
+ * Display the description, or the number for unknown checksum types.
+ */
+impl Display for Krb5ChecksumType {
+  fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+    match self.description() {
+      Ok(description) => write!(f, "{}", description),
+      Err(_) => write!(f, "cksumtype {}", self.as_raw()),
+    }
+  }
+}
+
+impl FromStr for Krb5ChecksumType {
+  type Err = Krb5Error;
+
+  fn from_str(name: &str) -> Result<Self, Self::Err> {
+    Krb5ChecksumType::from_name(name)
+  }
+}
@@ -2,16 +2,39 @@
  * "Safe" Rust wrapper for krb5 library context.
  *
  */
+use std::ffi::OsStr;
+#[cfg(krb5_1_15)]
+use std::ffi::OsString;
 use std::mem::MaybeUninit;
-use std::os::raw::c_char;
+use std::net::IpAddr;
+#[cfg(all(krb5_mit, krb5_1_15))]
+use std::os::raw::c_void;
+use std::os::raw::{c_char, c_int};
 use std::sync::Mutex;
 
 use lazy_static::lazy_static;
 use libkrb5_sys::*;
 
+use crate::address::addresses_to_ips;
+use crate::creds::{InitCredsContext, Krb5Creds, Krb5InitCredsOptions};
+use crate::data::buffer_to_krb5_data;
 use crate::error::{krb5_error_code_escape_hatch, Krb5Error};
+#[cfg(all(krb5_mit, krb5_1_15))]
+use crate::kdc_hook::{kdc_send_hook, KdcSendHook};
+use crate::password::{Krb5PasswordAuth, Krb5PasswordChangeResult, Krb5PasswordChangeStatus};
 use crate::principal::Krb5Principal;
-use crate::strconv::{c_string_to_string, string_to_c_string};
+#[cfg(krb5_mit)]
+use crate::profile::overlay_profile;
+use crate::secret::{zeroize_c_string, zeroize_string};
+#[cfg(krb5_1_15)]
+use crate::strconv::c_string_to_os_string;
+use crate::strconv::{
+  c_string_to_string_lossy,
+  c_string_to_string_with_policy,
+  os_str_to_c_string,
+  string_to_c_string,
+  Krb5StringPolicy,
+};
 
 lazy_static! {
     /**
@@ -21,21 +44,54 @@ lazy_static! {
     static ref CONTEXT_INIT_LOCK: Mutex<()> = Mutex::new(());
 }
 
+/**
+ * Load libkrb5 with the `dlopen` feature, so a missing library is returned as an error by the
+ * context initialization instead of panicking.
+ */
+fn load_library() -> Result<(), Krb5Error> {
+  #[cfg(feature = "dlopen")]
+  libkrb5_sys::load().map_err(|message| Krb5Error::Io {
+    error: std::io::Error::new(std::io::ErrorKind::NotFound, message),
+  })?;
+
+  Ok(())
+}
+
+/**
+ * The service of password changing tickets.
+ */
+const PASSWORD_CHANGE_SERVICE: &str = "kadmin/changepw";
+
+/**
+ * How often the user gets to choose a new password if the server rejects it, like libkrb5.
+ */
+const PASSWORD_CHANGE_TRIES: usize = 3;
+
 /**
  * Wrapper struct for `krb5_context_data`.
  */
 #[derive(Debug)]
 pub struct Krb5Context {
   pub(crate) context: krb5_context,
+  string_policy: Krb5StringPolicy,
+  #[cfg(krb5_mit)]
+  pub(crate) secure: bool,
+  #[cfg(all(krb5_mit, krb5_1_15))]
+  kdc_send_hook: Option<Box<KdcSendHook>>,
 }
 
 impl Krb5Context {
   /**
    * Initialize krb5 context.
    *
+   * With the `dlopen` feature, an [std::io::ErrorKind::NotFound] error is returned if libkrb5
+   * is not installed.
+   *
    * Wraps [krb5_init_context](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_init_context.html)
    */
   pub fn init() -> Result<Krb5Context, Krb5Error> {
+    load_library()?;
+
     let _guard = CONTEXT_INIT_LOCK
       .lock()
       .expect("Failed to lock context initialization.");
@@ -46,6 +102,11 @@ impl Krb5Context {
 
     let context = Krb5Context {
       context: unsafe { context_ptr.assume_init() },
+      string_policy: Krb5StringPolicy::default(),
+      #[cfg(krb5_mit)]
+      secure: false,
+      #[cfg(all(krb5_mit, krb5_1_15))]
+      kdc_send_hook: None,
     };
 
     krb5_error_code_escape_hatch(&context, code)?;
@@ -59,6 +120,8 @@ impl Krb5Context {
    * Wraps [krb5_init_secure_context](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_init_secure_context.html)
    */
   pub fn init_secure() -> Result<Krb5Context, Krb5Error> {
+    load_library()?;
+
     let _guard = CONTEXT_INIT_LOCK
       .lock()
       .expect("Failed to lock context initialization.");
@@ -69,6 +132,11 @@ impl Krb5Context {
 
     let context = Krb5Context {
       context: unsafe { context_ptr.assume_init() },
+      string_policy: Krb5StringPolicy::default(),
+      #[cfg(krb5_mit)]
+      secure: true,
+      #[cfg(all(krb5_mit, krb5_1_15))]
+      kdc_send_hook: None,
     };
 
     krb5_error_code_escape_hatch(&context, code)?;
@@ -76,28 +144,123 @@ impl Krb5Context {
     Ok(context)
   }
 
+  /**
+   * Create a context with the configuration of this one, except for the values of one relation
+   * of krb5.conf, e.g. `&["libdefaults", "dns_lookup_kdc"]` or
+   * `&["realms", "EXAMPLE.COM", "kdc"]`. An empty list of values removes the relation.
+   *
+   * The configuration is not modified on disk, and the override is only visible to lookups of
+   * the relation, not when iterating over the configuration. Calls can be chained to override
+   * several relations. The new context uses the string policy of this one.
+   *
+   * Wraps [krb5_init_context_profile](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_init_context_profile.html)
+   *
+   * # Arguments
+   *
+   *  * names: the section, subsections and name of the relation
+   *  * values: the values of the relation (use e.g. `&[] as &[&str]` to remove it)
+   */
+  #[cfg(krb5_mit)]
+  pub fn with_profile_values<N: AsRef<OsStr>, V: AsRef<OsStr>>(
+    &self,
+    names: &[N],
+    values: &[V],
+  ) -> Result<Krb5Context, Krb5Error> {
+    let mut profile: profile_t = std::ptr::null_mut();
+
+    let code: krb5_error_code = unsafe { krb5_get_profile(self.context, &mut profile) };
+
+    krb5_error_code_escape_hatch(self, code)?;
+
+    let profile = overlay_profile(profile, names, values)?;
+
+    let flags = if self.secure { KRB5_INIT_CONTEXT_SECURE } else { 0 };
+    let mut context_ptr: MaybeUninit<krb5_context> = MaybeUninit::zeroed();
+
+    let code: krb5_error_code = {
+      let _guard = CONTEXT_INIT_LOCK
+        .lock()
+        .expect("Failed to lock context initialization.");
+
+      // the context keeps its own copy of the profile
+      let code = unsafe { krb5_init_context_profile(profile, flags as krb5_flags, context_ptr.as_mut_ptr()) };
+      unsafe { profile_release(profile) };
+      code
+    };
+
+    let context = Krb5Context {
+      context: unsafe { context_ptr.assume_init() },
+      string_policy: self.string_policy,
+      secure: self.secure,
+      #[cfg(all(krb5_mit, krb5_1_15))]
+      kdc_send_hook: None,
+    };
+
+    krb5_error_code_escape_hatch(&context, code)?;
+
+    Ok(context)
+  }
+
+  /**
+   * Create a context with the configuration of this one, except that password changes for a
+   * realm are sent to the given kpasswd servers, e.g. if there are no DNS records for the
+   * kpasswd service of the realm, or the admin server in krb5.conf does not serve kpasswd.
+   *
+   * This sets the `kpasswd_server` relation of the realm, see
+   * [Krb5Context::with_profile_values]. The other servers of the realm are still looked up in
+   * krb5.conf or DNS.
+   *
+   * # Arguments
+   *
+   *  * realm: the realm of the principals whose passwords are changed
+   *  * servers: the kpasswd servers as `host` or `host:port` (the default port is 464)
+   */
+  #[cfg(krb5_mit)]
+  pub fn with_kpasswd_server<R: AsRef<OsStr>, S: AsRef<OsStr>>(
+    &self,
+    realm: R,
+    servers: &[S],
+  ) -> Result<Krb5Context, Krb5Error> {
+    self.with_profile_values(
+      &[OsStr::new("realms"), realm.as_ref(), OsStr::new("kpasswd_server")],
+      servers,
+    )
+  }
+
   /**
    * Build a principal name using a realm and 1 or more strings.
    *
    * Wraps [krb5_build_principal](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_build_principal.html)
    */
-
   pub fn build_principal<'a>(&'a self, realm: &'a str, args: &'a [String]) -> Result<Krb5Principal<'a>, Krb5Error> {
-    let crealm = string_to_c_string(realm)?;
+    let realm_c = string_to_c_string(realm)?;
+    let crealm = realm_c.as_ptr();
     let realml = realm.len() as u32;
 
-    let mut varargs = Vec::new();
+    let mut args_c = Vec::new();
     for arg in args {
-      varargs.push(string_to_c_string(arg)?);
+      args_c.push(string_to_c_string(arg)?);
     }
+    let varargs: Vec<*const c_char> = args_c.iter().map(|arg| arg.as_ptr()).collect();
+    // the list of components has to be terminated with a NULL pointer
+    let end: *const c_char = std::ptr::null();
 
     let mut principal_ptr: MaybeUninit<krb5_principal> = MaybeUninit::zeroed();
 
     // TODO: write a macro to generate this match block
     let code: krb5_error_code = match args.len() {
       // varargs support in Rust is lacking, so only support a limited number of arguments for now
-      0 => unsafe { krb5_build_principal(self.context, principal_ptr.as_mut_ptr(), realml, crealm) },
-      1 => unsafe { krb5_build_principal(self.context, principal_ptr.as_mut_ptr(), realml, crealm, varargs[0]) },
+      0 => unsafe { krb5_build_principal(self.context, principal_ptr.as_mut_ptr(), realml, crealm, end) },
+      1 => unsafe {
+        krb5_build_principal(
+          self.context,
+          principal_ptr.as_mut_ptr(),
+          realml,
+          crealm,
+          varargs[0],
+          end,
+        )
+      },
       2 => unsafe {
         krb5_build_principal(
           self.context,
@@ -106,6 +269,7 @@ impl Krb5Context {
           crealm,
           varargs[0],
           varargs[1],
+          end,
         )
       },
       3 => unsafe {
@@ -117,6 +281,7 @@ impl Krb5Context {
           varargs[0],
           varargs[1],
           varargs[2],
+          end,
         )
       },
       4 => unsafe {
@@ -129,6 +294,7 @@ impl Krb5Context {
           varargs[1],
           varargs[2],
           varargs[3],
+          end,
         )
       },
       _ => return Err(Krb5Error::MaxVarArgsExceeded),
@@ -162,10 +328,10 @@ impl Krb5Context {
 
     let realm = unsafe { realm.assume_init() };
 
-    let string = c_string_to_string(realm)?;
+    let string = self.c_string_to_string(realm);
     unsafe { krb5_free_default_realm(self.context, realm) };
 
-    Ok(Some(string))
+    Ok(Some(string?))
   }
 
   /**
@@ -173,9 +339,13 @@ impl Krb5Context {
    *
    * Wraps [krb5_get_host_realm](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_get_host_realm.html)
    */
-  pub fn get_host_realms(&self, host: Option<&str>) -> Result<Vec<String>, Krb5Error> {
-    let c_host = match host {
-      Some(host) => string_to_c_string(host)?,
+  pub fn get_host_realms(&self, host: Option<&OsStr>) -> Result<Vec<String>, Krb5Error> {
+    let host_c = match host {
+      Some(host) => Some(os_str_to_c_string(host)?),
+      None => None,
+    };
+    let c_host = match &host_c {
+      Some(host) => host.as_ptr(),
       None => std::ptr::null(),
     };
 
@@ -195,7 +365,13 @@ impl Krb5Context {
         break;
       }
 
-      realms.push(c_string_to_string(ptr)?);
+      match self.c_string_to_string(ptr) {
+        Ok(realm) => realms.push(realm),
+        Err(error) => {
+          unsafe { krb5_free_host_realm(self.context, c_realms) };
+          return Err(error);
+        },
+      }
 
       index += 1;
     }
@@ -205,27 +381,448 @@ impl Krb5Context {
     Ok(realms)
   }
 
+  /**
+   * Return the IP addresses of the local host, as libkrb5 would put them into address
+   * restricted tickets. Addresses of other types (e.g. configured via `extra_addresses`) are
+   * skipped.
+   *
+   * Wraps [krb5_os_localaddr](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_os_localaddr.html)
+   */
+  pub fn local_addresses(&self) -> Result<Vec<IpAddr>, Krb5Error> {
+    let mut addresses: MaybeUninit<*mut *mut krb5_address> = MaybeUninit::zeroed();
+
+    let code: krb5_error_code = unsafe { krb5_os_localaddr(self.context, addresses.as_mut_ptr()) };
+
+    krb5_error_code_escape_hatch(self, code)?;
+
+    let addresses = unsafe { addresses.assume_init() };
+    let ips = addresses_to_ips(addresses);
+    unsafe { krb5_free_addresses(self.context, addresses) };
+
+    Ok(ips)
+  }
+
+  /**
+   * Acquire initial credentials for a principal using its password, like `kinit`.
+   *
+   * Set `out_ccache` in the options to store the credentials in a credential cache right away.
+   * An expired password is returned as [Krb5Error::PasswordExpired], see
+   * [Krb5Context::acquire_initial_creds_with_password_change] to change it in that case.
+   *
+   * [krb5_init_creds_set_password](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_init_creds_set_password.html)
+   *
+   * # Arguments
+   *
+   *  * principal: the client principal to authenticate as
+   *  * password: the password of the principal
+   *  * options: options for the initial credentials request
+   */
+  pub fn acquire_initial_creds_with_password(
+    &self,
+    principal: &Krb5Principal,
+    password: &str,
+    options: &Krb5InitCredsOptions,
+  ) -> Result<Krb5Creds<'_>, Krb5Error> {
+    let opt = options.to_opt(self)?;
+
+    let mut request = InitCredsContext::init(self, principal, &opt)?;
+    request.set_password(password)?;
+    request.get(principal)
+  }
+
+  /**
+   * Acquire a ticket for the `kadmin/changepw` service using the current password, as required
+   * by [Krb5Context::change_password]. The KDC issues this ticket for expired passwords as
+   * well.
+   *
+   * Only the options affecting preauthentication are used: the ticket expires after five
+   * minutes, and it is neither forwardable, proxiable nor renewable, nor stored in
+   * `out_ccache`.
+   *
+   * [krb5_init_creds_set_service](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_init_creds_set_service.html)
+   *
+   * # Arguments
+   *
+   *  * principal: the principal whose password to change
+   *  * password: the current password of the principal
+   *  * options: options for the initial credentials request
+   */
+  pub fn acquire_password_change_creds(
+    &self,
+    principal: &Krb5Principal,
+    password: &str,
+    options: &Krb5InitCredsOptions,
+  ) -> Result<Krb5Creds<'_>, Krb5Error> {
+    let options = options.password_change_options();
+    let opt = options.to_opt(self)?;
+
+    let mut request = InitCredsContext::init(self, principal, &opt)?;
+    request.set_service(PASSWORD_CHANGE_SERVICE)?;
+    request.set_password(password)?;
+    request.get(principal)
+  }
+
+  /**
+   * Acquire initial credentials using a password, and have the user change it first if it has
+   * expired, which is the login flow of `kinit` and the PAM modules.
+   *
+   * If the KDC reports the password as expired, a `kadmin/changepw` ticket is acquired with the
+   * old password and `new_password` is called for the new one. If the server rejects the new
+   * password as not acceptable (e.g. too short), `new_password` is called again with the
+   * rejected result, so the policy message can be shown to the user, up to three times. After
+   * a successful change, the credentials are acquired with the new password.
+   *
+   * If `new_password` returns `None`, the flow is aborted with [Krb5Error::PasswordExpired], or
+   * [Krb5Error::PasswordChangeRejected] after a rejected attempt. Other rejections end the flow
+   * with [Krb5Error::PasswordChangeRejected] as well.
+   *
+   * # Arguments
+   *
+   *  * principal: the client principal to authenticate as
+   *  * password: the (current) password of the principal
+   *  * options: options for the initial credentials request
+   *  * new_password: asks the user for a new password, given the result of the previous
+   *    rejected attempt, if any
+   */
+  pub fn acquire_initial_creds_with_password_change<F>(
+    &self,
+    principal: &Krb5Principal,
+    password: &str,
+    options: &Krb5InitCredsOptions,
+    mut new_password: F,
+  ) -> Result<Krb5Creds<'_>, Krb5Error>
+  where
+    F: FnMut(Option<&Krb5PasswordChangeResult>) -> Option<String>,
+  {
+    let expired = match self.acquire_initial_creds_with_password(principal, password, options) {
+      Err(error @ Krb5Error::PasswordExpired { .. }) => error,
+      result => return result,
+    };
+
+    let creds = self.acquire_password_change_creds(principal, password, options)?;
+
+    let mut rejected: Option<Krb5PasswordChangeResult> = None;
+    let mut tries = 0;
+
+    loop {
+      let password = match new_password(rejected.as_ref()) {
+        Some(password) => password,
+        None => {
+          return Err(match rejected {
+            Some(result) => Krb5Error::PasswordChangeRejected { result },
+            None => expired,
+          })
+        },
+      };
+
+      let result = match self.change_password(&creds, &password) {
+        Ok(result) if result.is_success() => {
+          let creds = self.acquire_initial_creds_with_password(principal, &password, options);
+          zeroize_string(password);
+          return creds;
+        },
+        result => {
+          zeroize_string(password);
+          result?
+        },
+      };
+
+      tries += 1;
+
+      if result.code != KRB5_KPASSWD_SOFTERROR as c_int || tries == PASSWORD_CHANGE_TRIES {
+        return Err(Krb5Error::PasswordChangeRejected { result });
+      }
+
+      rejected = Some(result);
+    }
+  }
+
+  /**
+   * Change the password of the authenticated principal with the kpasswd protocol.
+   *
+   * The credentials have to be an initial ticket for the `kadmin/changepw` service, obtained
+   * with the current password. Whether the password was changed is reported in the returned
+   * result, see [Krb5PasswordChangeResult::status]; errors are only returned if the request
+   * itself failed.
+   *
+   * Wraps [krb5_change_password](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_change_password.html)
+   *
+   * # Arguments
+   *
+   *  * creds: credentials for the `kadmin/changepw` service
+   *  * new_password: the new password
+   */
+  pub fn change_password(&self, creds: &Krb5Creds, new_password: &str) -> Result<Krb5PasswordChangeResult, Krb5Error> {
+    let new_password = string_to_c_string(new_password)?;
+    let mut result_code: c_int = 0;
+    let mut result_code_string: MaybeUninit<krb5_data> = MaybeUninit::zeroed();
+    let mut result_string: MaybeUninit<krb5_data> = MaybeUninit::zeroed();
+
+    // libkrb5 does not modify the credentials, the parameter just lacks the const qualifier
+    let code: krb5_error_code = unsafe {
+      krb5_change_password(
+        self.context,
+        &creds.creds as *const krb5_creds as *mut krb5_creds,
+        new_password.as_ptr(),
+        &mut result_code,
+        result_code_string.as_mut_ptr(),
+        result_string.as_mut_ptr(),
+      )
+    };
+
+    zeroize_c_string(new_password);
+
+    krb5_error_code_escape_hatch(self, code)?;
+
+    Ok(Krb5PasswordChangeResult::from_raw(
+      self,
+      result_code,
+      unsafe { result_code_string.assume_init() },
+      unsafe { result_string.assume_init() },
+    ))
+  }
+
+  /**
+   * Change the password of a principal, like the `kpasswd` command: authenticate to the
+   * `kadmin/changepw` service with the old password and send the new one.
+   *
+   * A rejected password (e.g. one that violates the password policy) is returned as the decoded
+   * status, not as an error. Errors are returned if the old password is wrong or the servers
+   * could not be reached. Use [Krb5Context::with_kpasswd_server] to direct the request at a
+   * specific server.
+   *
+   * # Arguments
+   *
+   *  * principal: the principal whose password to change
+   *  * old_password: the current password of the principal
+   *  * new_password: the new password
+   */
+  pub fn kpasswd(
+    &self,
+    principal: &Krb5Principal,
+    old_password: &str,
+    new_password: &str,
+  ) -> Result<Krb5PasswordChangeStatus, Krb5Error> {
+    let creds = self.acquire_password_change_creds(principal, old_password, &Krb5InitCredsOptions::default())?;
+
+    Ok(self.change_password(&creds, new_password)?.status())
+  }
+
+  /**
+   * Set the password of a principal with the kpasswd protocol (RFC 3244), e.g. to reset the
+   * password of another user from an administration tool.
+   *
+   * The request is authenticated with credentials for the `kadmin/changepw` service of the
+   * realm, either given directly or obtained from a credential cache (with a TGT of the
+   * caller). Whether the password was changed is reported in the returned result; errors are
+   * only returned if the request itself failed, e.g. because the kpasswd server could not be
+   * reached.
+   *
+   * Wraps [krb5_set_password](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_set_password.html)
+   * and [krb5_set_password_using_ccache](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_set_password_using_ccache.html)
+   *
+   * # Arguments
+   *
+   *  * auth: the credentials or credential cache to authenticate with
+   *  * new_password: the new password
+   *  * target: the principal whose password to set, or `None` for the authenticated principal
+   */
+  pub fn set_password<'c, A: Into<Krb5PasswordAuth<'c>>>(
+    &self,
+    auth: A,
+    new_password: &str,
+    target: Option<&Krb5Principal>,
+  ) -> Result<Krb5PasswordChangeResult, Krb5Error> {
+    let auth = auth.into();
+
+    // unlike krb5_set_password, krb5_set_password_using_ccache requires the target principal
+    let default_target = match (&auth, target) {
+      (Krb5PasswordAuth::CCache(ccache), None) => match ccache.get_principal()? {
+        Some(principal) => Some(principal),
+        None => return Err(Krb5Error::NullPointerDereference),
+      },
+      _ => None,
+    };
+    let target = target.or(default_target.as_ref());
+    let target_ptr = target.map_or(std::ptr::null_mut(), |target| target.principal);
+
+    let new_password = string_to_c_string(new_password)?;
+    let mut result_code: c_int = 0;
+    let mut result_code_string: MaybeUninit<krb5_data> = MaybeUninit::zeroed();
+    let mut result_string: MaybeUninit<krb5_data> = MaybeUninit::zeroed();
+
+    let code: krb5_error_code = match auth {
+      // libkrb5 does not modify the credentials, the parameter just lacks the const qualifier
+      Krb5PasswordAuth::Creds(creds) => unsafe {
+        krb5_set_password(
+          self.context,
+          &creds.creds as *const krb5_creds as *mut krb5_creds,
+          new_password.as_ptr(),
+          target_ptr,
+          &mut result_code,
+          result_code_string.as_mut_ptr(),
+          result_string.as_mut_ptr(),
+        )
+      },
+      Krb5PasswordAuth::CCache(ccache) => unsafe {
+        krb5_set_password_using_ccache(
+          self.context,
+          ccache.ccache,
+          new_password.as_ptr(),
+          target_ptr,
+          &mut result_code,
+          result_code_string.as_mut_ptr(),
+          result_string.as_mut_ptr(),
+        )
+      },
+    };
+
+    zeroize_c_string(new_password);
+
+    krb5_error_code_escape_hatch(self, code)?;
+
+    Ok(Krb5PasswordChangeResult::from_raw(
+      self,
+      result_code,
+      unsafe { result_code_string.assume_init() },
+      unsafe { result_string.assume_init() },
+    ))
+  }
+
+  /**
+   * Generate cryptographically secure random bytes from the libkrb5 random number generator.
+   *
+   * Wraps [krb5_c_random_make_octets](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_c_random_make_octets.html)
+   *
+   * # Arguments
+   *
+   *  * len: the number of bytes to generate
+   */
+  pub fn random_bytes(&self, len: usize) -> Result<Vec<u8>, Krb5Error> {
+    let mut bytes = vec![0; len];
+    let mut data = buffer_to_krb5_data(&mut bytes);
+
+    let code: krb5_error_code = unsafe { krb5_c_random_make_octets(self.context, &mut data) };
+
+    krb5_error_code_escape_hatch(self, code)?;
+
+    Ok(bytes)
+  }
+
   /**
    * Canonicalize a hostname, possibly using name service.
    *
+   * Name service results are not guaranteed to be valid UTF-8, so the hostname is returned as
+   * an OS string.
+   *
+   * Needs MIT krb5 1.15 or later.
+   *
    * Wraps [krb5_expand_hostname](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_expand_hostname.html)
    */
-  /* TODO: this produces invalid UTF-8?
-  pub fn expand_hostname(&self, hostname: &str) -> Result<String, Krb5Error> {
-      let hostname_c = string_to_c_string(hostname)?;
-      let mut cstr_ptr: MaybeUninit<*mut c_char> = MaybeUninit::zeroed();
+  #[cfg(krb5_1_15)]
+  pub fn expand_hostname<H: AsRef<OsStr>>(&self, hostname: H) -> Result<OsString, Krb5Error> {
+    let hostname_c = os_str_to_c_string(hostname.as_ref())?;
+    let mut cstr_ptr: MaybeUninit<*mut c_char> = MaybeUninit::zeroed();
 
-      let code: krb5_error_code = unsafe { krb5_expand_hostname(self.context, hostname_c, cstr_ptr.as_mut_ptr()) };
+    let code: krb5_error_code =
+      unsafe { krb5_expand_hostname(self.context, hostname_c.as_ptr(), cstr_ptr.as_mut_ptr()) };
 
-      krb5_error_code_escape_hatch(self, code)?;
-      let cstr_ptr = unsafe { cstr_ptr.assume_init() };
+    krb5_error_code_escape_hatch(self, code)?;
+    let cstr_ptr = unsafe { cstr_ptr.assume_init() };
 
-      let result = c_string_to_string(cstr_ptr);
-      unsafe { krb5_free_string(self.context, cstr_ptr) };
+    let result = c_string_to_os_string(cstr_ptr);
+    unsafe { krb5_free_string(self.context, cstr_ptr) };
 
-      result
+    result
+  }
+
+  /**
+   * Install a hook that is called with the realm and the encoded request before libkrb5 sends a
+   * request to a KDC, e.g. to send it through a proxy (MS-KKDCP) or with custom timeouts. If
+   * the hook returns a reply, it is used as the reply of the KDC and libkrb5 does not send
+   * the request itself. Errors of the hook end the operation that sent the request.
+   *
+   * libkrb5 locates the KDCs of the realm before calling the hook, so the realm needs KDCs in
+   * krb5.conf (see [Krb5Context::with_profile_values]) or DNS, even if the hook answers all
+   * requests and they are never contacted.
+   *
+   * Replaces the previous hook of the context. Only available with MIT krb5 1.15 or later.
+   *
+   * Wraps [krb5_set_kdc_send_hook](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_set_kdc_send_hook.html)
+   *
+   * # Arguments
+   *
+   *  * hook: gets the realm and the request, and returns the reply, or `None` to have libkrb5
+   *    send the request
+   */
+  #[cfg(all(krb5_mit, krb5_1_15))]
+  pub fn set_kdc_send_hook<F>(&mut self, hook: F)
+  where
+    F: Fn(&str, &[u8]) -> Result<Option<Vec<u8>>, Krb5Error> + 'static,
+  {
+    let hook = Box::new(KdcSendHook { hook: Box::new(hook) });
+    let data = &*hook as *const KdcSendHook as *mut c_void;
+
+    unsafe { krb5_set_kdc_send_hook(self.context, Some(kdc_send_hook), data) };
+
+    self.kdc_send_hook = Some(hook);
+  }
+
+  /**
+   * Remove the hook installed with [Krb5Context::set_kdc_send_hook], so libkrb5 sends all
+   * requests to KDCs itself again.
+   */
+  #[cfg(all(krb5_mit, krb5_1_15))]
+  pub fn clear_kdc_send_hook(&mut self) {
+    unsafe { krb5_set_kdc_send_hook(self.context, None, std::ptr::null_mut()) };
+
+    self.kdc_send_hook = None;
+  }
+
+  /**
+   * Remove the hook installed with [Krb5Context::set_kdc_send_hook] and return it, e.g. to
+   * call it from a new hook.
+   */
+  #[cfg(all(krb5_mit, krb5_1_15, feature = "async"))]
+  pub(crate) fn take_kdc_send_hook(&mut self) -> Option<Box<KdcSendHook>> {
+    unsafe { krb5_set_kdc_send_hook(self.context, None, std::ptr::null_mut()) };
+
+    self.kdc_send_hook.take()
+  }
+
+  /**
+   * Set how strings returned by libkrb5 that are not valid UTF-8 are converted.
+   *
+   * The policy applies to names and other values that are read through this context (realms,
+   * hostnames, principal names, credential cache and key table names). Error messages are
+   * always converted lossily, so an odd byte in a message does not hide the original error.
+   */
+  pub fn set_string_policy(&mut self, policy: Krb5StringPolicy) {
+    self.string_policy = policy;
+  }
+
+  /**
+   * Return the current string conversion policy, see [Krb5Context::set_string_policy].
+   */
+  pub fn string_policy(&self) -> Krb5StringPolicy {
+    self.string_policy
+  }
+
+  /**
+   * Return the version of the Kerberos library this crate was built against, e.g. `1.19.2`, or
+   * `None` if the build could not tell, e.g. with the `dlopen` feature. See
+   * [crate::Krb5Flavor::current] for the implementation.
+   */
+  pub fn library_version(&self) -> Option<&'static str> {
+    option_env!("KRB5_LIBRARY_VERSION")
+  }
+
+  /**
+   * Convert a C string returned by libkrb5 according to the string policy of the context.
+   */
+  pub(crate) fn c_string_to_string(&self, c_string: *const c_char) -> Result<String, Krb5Error> {
+    c_string_to_string_with_policy(c_string, self.string_policy)
   }
-  */
 
   /**
    * Get error message to a krb5 error code.
@@ -235,13 +832,13 @@ impl Krb5Context {
   pub(crate) fn error_code_to_message(&self, code: krb5_error_code) -> String {
     let message: *const c_char = unsafe { krb5_get_error_message(self.context, code) };
 
-    match c_string_to_string(message) {
-      Ok(string) => {
-        unsafe { krb5_free_error_message(self.context, message) };
-        string
-      }
-      Err(error) => error.to_string(),
-    }
+    let string = match c_string_to_string_lossy(message) {
+      Ok(string) => string,
+      Err(error) => return error.to_string(),
+    };
+    unsafe { krb5_free_error_message(self.context, message) };
+
+    string
   }
 }
 
@@ -0,0 +1,512 @@
+/*!
+ * Rustic wrapper for krb5 credentials.
+ */
+use std::fmt::{Debug, Formatter};
+use std::marker::PhantomData;
+use std::mem::MaybeUninit;
+use std::net::IpAddr;
+use std::os::raw::c_int;
+
+use libkrb5_sys::*;
+
+use crate::address::{addresses_to_ips, AddressList};
+use crate::ccache::Krb5CCache;
+use crate::context::Krb5Context;
+use crate::data::krb5_data_as_slice;
+use crate::enctype::Krb5Enctype;
+use crate::error::{krb5_error_code_escape_hatch, Krb5Error};
+use crate::error_message::Krb5KdcError;
+use crate::keyblock::Krb5Keyblock;
+#[cfg(not(all(krb5_mit, krb5_1_15)))]
+use crate::keytab::Krb5Keytab;
+use crate::principal::{copy_principal, Krb5Principal};
+use crate::secret::zeroize_c_string;
+use crate::strconv::string_to_c_string;
+use crate::timestamp::Krb5Timestamp;
+
+/**
+ * Wrapper struct for krb5 credentials (a ticket together with its session key and metadata).
+ *
+ * The session key and the tickets are redacted from `Debug` output, which only shows their
+ * lengths and the enctype of the session key.
+ *
+ * https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/types/krb5_creds.html
+ */
+pub struct Krb5Creds<'a> {
+  pub(crate) context: &'a Krb5Context,
+  pub(crate) creds: krb5_creds,
+}
+
+/**
+ * Free the contents of a credentials structure.
+ *
+ * [krb5_free_cred_contents](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_free_cred_contents.html)
+ */
+impl<'a> Drop for Krb5Creds<'a> {
+  fn drop(&mut self) {
+    unsafe {
+      krb5_free_cred_contents(self.context.context, &mut self.creds);
+    }
+  }
+}
+
+impl<'a> Krb5Creds<'a> {
+  /**
+   * Return the IP addresses the ticket is restricted to, or an empty list if the ticket can be
+   * used from any address. Addresses of other types are skipped.
+   */
+  pub fn addresses(&self) -> Vec<IpAddr> {
+    addresses_to_ips(self.creds.addresses)
+  }
+
+  /**
+   * Return the time of the initial authentication.
+   */
+  pub fn auth_time(&self) -> Krb5Timestamp {
+    Krb5Timestamp::from_raw(self.creds.times.authtime)
+  }
+
+  /**
+   * Return a copy of the client principal.
+   */
+  pub fn client(&self) -> Result<Krb5Principal<'a>, Krb5Error> {
+    copy_principal(self.context, self.creds.client)
+  }
+
+  /**
+   * Return the time the ticket expires.
+   */
+  pub fn end_time(&self) -> Krb5Timestamp {
+    Krb5Timestamp::from_raw(self.creds.times.endtime)
+  }
+
+  /**
+   * Return a copy of the session key of the credentials.
+   */
+  pub fn keyblock(&self) -> Result<Krb5Keyblock<'a>, Krb5Error> {
+    Krb5Keyblock::copy_from(self.context, &self.creds.keyblock)
+  }
+
+  /**
+   * Return the latest time the ticket can be renewed until, which is unset if the ticket is
+   * not renewable.
+   */
+  pub fn renew_till(&self) -> Krb5Timestamp {
+    Krb5Timestamp::from_raw(self.creds.times.renew_till)
+  }
+
+  /**
+   * Return the second ticket (for user-to-user authentication), or an empty slice if there is
+   * none. The slice borrows the credentials and is not copied.
+   */
+  pub fn second_ticket(&self) -> &[u8] {
+    krb5_data_as_slice(&self.creds.second_ticket)
+  }
+
+  /**
+   * Return a copy of the server principal.
+   */
+  pub fn server(&self) -> Result<Krb5Principal<'a>, Krb5Error> {
+    copy_principal(self.context, self.creds.server)
+  }
+
+  /**
+   * Return the time the ticket becomes valid.
+   */
+  pub fn start_time(&self) -> Krb5Timestamp {
+    Krb5Timestamp::from_raw(self.creds.times.starttime)
+  }
+
+  /**
+   * Return the encoded ticket. The slice borrows the credentials and is not copied.
+   */
+  pub fn ticket(&self) -> &[u8] {
+    krb5_data_as_slice(&self.creds.ticket)
+  }
+
+  /**
+   * Return the ticket flags (`TKT_FLG_*`).
+   */
+  pub fn ticket_flags(&self) -> krb5_flags {
+    self.creds.ticket_flags
+  }
+}
+
+impl<'a> Debug for Krb5Creds<'a> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+    f.debug_struct("Krb5Creds")
+      .field("client", &principal_name(self.context, self.creds.client))
+      .field("server", &principal_name(self.context, self.creds.server))
+      .field("auth_time", &self.auth_time())
+      .field("start_time", &self.start_time())
+      .field("end_time", &self.end_time())
+      .field("renew_till", &self.renew_till())
+      .field("ticket_flags", &self.ticket_flags())
+      .field(
+        "session_key_enctype",
+        &Krb5Enctype::from_raw(self.creds.keyblock.enctype),
+      )
+      .field(
+        "session_key",
+        &format_args!("<{} bytes redacted>", self.creds.keyblock.length),
+      )
+      .field("ticket", &format_args!("<{} bytes redacted>", self.creds.ticket.length))
+      .field(
+        "second_ticket",
+        &format_args!("<{} bytes redacted>", self.creds.second_ticket.length),
+      )
+      .finish()
+  }
+}
+
+/**
+ * Overwrite the session key with zeroes. The ticket itself is encrypted for the service and
+ * left untouched.
+ */
+#[cfg(feature = "zeroize")]
+impl<'a> ::zeroize::Zeroize for Krb5Creds<'a> {
+  fn zeroize(&mut self) {
+    let keyblock = &mut self.creds.keyblock;
+
+    if !keyblock.contents.is_null() {
+      crate::secret::zeroize(unsafe { std::slice::from_raw_parts_mut(keyblock.contents, keyblock.length as usize) });
+    }
+  }
+}
+
+/**
+ * libkrb5 zeroes the session key when the credentials are freed.
+ */
+#[cfg(feature = "zeroize")]
+impl<'a> ::zeroize::ZeroizeOnDrop for Krb5Creds<'a> {}
+
+/**
+ * Return the name of a principal for `Debug` output, or `None` if it is not set.
+ */
+fn principal_name(context: &Krb5Context, principal: krb5_principal) -> Option<String> {
+  if principal.is_null() {
+    return None;
+  }
+
+  copy_principal(context, principal)
+    .and_then(|principal| principal.unparse_name())
+    .ok()
+}
+
+/**
+ * Convert the error code of an initial credentials request to a Krb5Error wrapped in a Result.
+ *
+ * An expired password and missing preauthentication are returned as structured errors, other
+ * errors name the client principal.
+ */
+pub(crate) fn init_creds_error_code_escape_hatch(
+  context: &Krb5Context,
+  code: krb5_error_code,
+  client: &Krb5Principal,
+  kdc_error: Option<Krb5KdcError>,
+) -> Result<(), Krb5Error> {
+  if code == 0 {
+    return Ok(());
+  }
+
+  let client_name = client.unparse_name().unwrap_or_default();
+
+  match code {
+    KRB5KDC_ERR_KEY_EXP => return Err(Krb5Error::PasswordExpired { principal: client_name }),
+    KRB5KDC_ERR_PREAUTH_REQUIRED => {
+      let pa_types = kdc_error.map(|kdc_error| kdc_error.pa_types()).unwrap_or_default();
+      return Err(Krb5Error::PreauthRequired { pa_types });
+    },
+    _ => {},
+  }
+
+  let error = match krb5_error_code_escape_hatch(context, code) {
+    Err(Krb5Error::LibraryError { code, message }) => match kdc_error {
+      Some(kdc_error) => Krb5Error::KdcError {
+        code,
+        message,
+        kdc_error,
+      },
+      None => Krb5Error::LibraryError { code, message },
+    },
+    Err(error) => error,
+    Ok(()) => return Ok(()),
+  };
+
+  Err(error.context(format!("while getting initial credentials for {}", client_name)))
+}
+
+/**
+ * Owned libkrb5 initial credentials request, which keeps the KRB-ERROR of a failed request.
+ *
+ * libkrb5 keeps pointers to the options and the key table, so they have to outlive the request.
+ */
+pub(crate) struct InitCredsContext<'a, 'o> {
+  context: &'a Krb5Context,
+  ctx: krb5_init_creds_context,
+  borrowed: PhantomData<&'o InitCredsOpt<'a>>,
+}
+
+impl<'a, 'o> InitCredsContext<'a, 'o> {
+  /**
+   * Create an initial credentials request for a client principal.
+   *
+   * [krb5_init_creds_init](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_init_creds_init.html)
+   */
+  pub(crate) fn init(
+    context: &'a Krb5Context,
+    client: &Krb5Principal,
+    opt: &'o InitCredsOpt<'a>,
+  ) -> Result<InitCredsContext<'a, 'o>, Krb5Error> {
+    let mut ctx_ptr: MaybeUninit<krb5_init_creds_context> = MaybeUninit::zeroed();
+
+    let code: krb5_error_code = unsafe {
+      krb5_init_creds_init(
+        context.context,
+        client.principal,
+        None,
+        std::ptr::null_mut(),
+        0,
+        opt.opt,
+        ctx_ptr.as_mut_ptr(),
+      )
+    };
+
+    krb5_error_code_escape_hatch(context, code)?;
+
+    Ok(InitCredsContext {
+      context,
+      ctx: unsafe { ctx_ptr.assume_init() },
+      borrowed: PhantomData,
+    })
+  }
+
+  /**
+   * Run the exchange with the KDC and return the acquired credentials.
+   *
+   * [krb5_init_creds_get](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_init_creds_get.html)
+   */
+  pub(crate) fn get(&mut self, client: &Krb5Principal) -> Result<Krb5Creds<'a>, Krb5Error> {
+    let code: krb5_error_code = unsafe { krb5_init_creds_get(self.context.context, self.ctx) };
+
+    let kdc_error = if code != 0 { self.get_error() } else { None };
+
+    init_creds_error_code_escape_hatch(self.context, code, client, kdc_error)?;
+
+    let mut creds_ptr: MaybeUninit<krb5_creds> = MaybeUninit::zeroed();
+
+    let code: krb5_error_code =
+      unsafe { krb5_init_creds_get_creds(self.context.context, self.ctx, creds_ptr.as_mut_ptr()) };
+
+    krb5_error_code_escape_hatch(self.context, code)?;
+
+    Ok(Krb5Creds {
+      context: self.context,
+      creds: unsafe { creds_ptr.assume_init() },
+    })
+  }
+
+  /**
+   * Use keys from a key table to authenticate.
+   *
+   * [krb5_init_creds_set_keytab](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_init_creds_set_keytab.html)
+   */
+  #[cfg(not(all(krb5_mit, krb5_1_15)))]
+  pub(crate) fn set_keytab(&mut self, keytab: &'o Krb5Keytab<'a>) -> Result<(), Krb5Error> {
+    let code: krb5_error_code = unsafe { krb5_init_creds_set_keytab(self.context.context, self.ctx, keytab.keytab) };
+
+    krb5_error_code_escape_hatch(self.context, code)
+  }
+
+  /**
+   * Use a password to authenticate. libkrb5 keeps its own copy of the password.
+   *
+   * [krb5_init_creds_set_password](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_init_creds_set_password.html)
+   */
+  pub(crate) fn set_password(&mut self, password: &str) -> Result<(), Krb5Error> {
+    let password = string_to_c_string(password)?;
+
+    let code: krb5_error_code =
+      unsafe { krb5_init_creds_set_password(self.context.context, self.ctx, password.as_ptr()) };
+
+    zeroize_c_string(password);
+
+    krb5_error_code_escape_hatch(self.context, code)
+  }
+
+  /**
+   * Request a ticket for another service than the ticket-granting service, e.g.
+   * `kadmin/changepw`.
+   *
+   * [krb5_init_creds_set_service](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_init_creds_set_service.html)
+   */
+  pub(crate) fn set_service(&mut self, service: &str) -> Result<(), Krb5Error> {
+    let service = string_to_c_string(service)?;
+
+    let code: krb5_error_code =
+      unsafe { krb5_init_creds_set_service(self.context.context, self.ctx, service.as_ptr()) };
+
+    krb5_error_code_escape_hatch(self.context, code)
+  }
+
+  /**
+   * Return a copy of the KRB-ERROR the KDC answered the last request with, if any.
+   *
+   * [krb5_init_creds_get_error](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_init_creds_get_error.html)
+   */
+  fn get_error(&self) -> Option<Krb5KdcError> {
+    let mut error: *mut krb5_error = std::ptr::null_mut();
+
+    let code: krb5_error_code = unsafe { krb5_init_creds_get_error(self.context.context, self.ctx, &mut error) };
+
+    if code != 0 || error.is_null() {
+      return None;
+    }
+
+    let kdc_error = Krb5KdcError::from_raw(unsafe { &*error });
+    unsafe { krb5_free_error(self.context.context, error) };
+
+    Some(kdc_error)
+  }
+}
+
+/**
+ * Free an initial credentials request.
+ *
+ * [krb5_init_creds_free](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_init_creds_free.html)
+ */
+impl<'a, 'o> Drop for InitCredsContext<'a, 'o> {
+  fn drop(&mut self) {
+    unsafe {
+      krb5_init_creds_free(self.context.context, self.ctx);
+    }
+  }
+}
+
+/**
+ * Options for acquiring initial credentials.
+ *
+ * Options that are `None` keep the library (or krb5.conf) defaults.
+ *
+ * https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/types/krb5_get_init_creds_opt.html
+ */
+#[derive(Debug, Default)]
+pub struct Krb5InitCredsOptions<'c> {
+  /// requested ticket lifetime in seconds
+  pub tkt_life: Option<krb5_deltat>,
+  /// requested renewable lifetime in seconds
+  pub renew_life: Option<krb5_deltat>,
+  /// request a forwardable ticket
+  pub forwardable: Option<bool>,
+  /// request a proxiable ticket
+  pub proxiable: Option<bool>,
+  /// the encryption types to request for the session key, in order of preference
+  pub etype_list: Option<Vec<Krb5Enctype>>,
+  /// restrict the ticket to these client addresses (an empty list requests an addressless ticket)
+  pub addresses: Option<Vec<IpAddr>>,
+  /// store the acquired credentials in this credential cache (which is initialized for the client
+  /// principal)
+  pub out_ccache: Option<&'c Krb5CCache<'c>>,
+}
+
+impl<'c> Krb5InitCredsOptions<'c> {
+  /**
+   * Derive the options for a `kadmin/changepw` ticket, like libkrb5 does when it changes an
+   * expired password: options affecting preauthentication are kept, and the ticket is
+   * short-lived, not forwardable, proxiable or renewable, and not stored.
+   */
+  pub(crate) fn password_change_options(&self) -> Krb5InitCredsOptions<'c> {
+    Krb5InitCredsOptions {
+      tkt_life: Some(5 * 60),
+      renew_life: Some(0),
+      forwardable: Some(false),
+      proxiable: Some(false),
+      etype_list: self.etype_list.clone(),
+      addresses: None,
+      out_ccache: None,
+    }
+  }
+
+  /**
+   * Allocate a libkrb5 options structure and apply the options.
+   *
+   * [krb5_get_init_creds_opt_alloc](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_get_init_creds_opt_alloc.html)
+   */
+  pub(crate) fn to_opt<'a>(&self, context: &'a Krb5Context) -> Result<InitCredsOpt<'a>, Krb5Error> {
+    let mut opt_ptr: MaybeUninit<*mut krb5_get_init_creds_opt> = MaybeUninit::zeroed();
+
+    let code: krb5_error_code = unsafe { krb5_get_init_creds_opt_alloc(context.context, opt_ptr.as_mut_ptr()) };
+
+    krb5_error_code_escape_hatch(context, code)?;
+
+    let mut opt = InitCredsOpt {
+      context,
+      opt: unsafe { opt_ptr.assume_init() },
+      etype_list: Vec::new(),
+      addresses: None,
+    };
+
+    if let Some(tkt_life) = self.tkt_life {
+      unsafe { krb5_get_init_creds_opt_set_tkt_life(opt.opt, tkt_life) };
+    }
+
+    if let Some(renew_life) = self.renew_life {
+      unsafe { krb5_get_init_creds_opt_set_renew_life(opt.opt, renew_life) };
+    }
+
+    if let Some(forwardable) = self.forwardable {
+      unsafe { krb5_get_init_creds_opt_set_forwardable(opt.opt, forwardable as i32) };
+    }
+
+    if let Some(proxiable) = self.proxiable {
+      unsafe { krb5_get_init_creds_opt_set_proxiable(opt.opt, proxiable as i32) };
+    }
+
+    if let Some(etype_list) = &self.etype_list {
+      // libkrb5 keeps the pointer, so the list is owned by the options structure
+      opt.etype_list = etype_list.iter().map(|enctype| enctype.as_raw()).collect();
+      unsafe {
+        krb5_get_init_creds_opt_set_etype_list(opt.opt, opt.etype_list.as_mut_ptr(), opt.etype_list.len() as c_int)
+      };
+    }
+
+    if let Some(addresses) = &self.addresses {
+      // libkrb5 keeps the pointer, so the list is owned by the options structure
+      let addresses = opt.addresses.insert(AddressList::from_ips(addresses));
+      unsafe { krb5_get_init_creds_opt_set_address_list(opt.opt, addresses.as_mut_ptr()) };
+    }
+
+    if let Some(ccache) = self.out_ccache {
+      let code: krb5_error_code =
+        unsafe { krb5_get_init_creds_opt_set_out_ccache(context.context, opt.opt, ccache.ccache) };
+
+      krb5_error_code_escape_hatch(context, code)?;
+    }
+
+    Ok(opt)
+  }
+}
+
+/**
+ * Owned libkrb5 initial credentials options structure.
+ */
+#[derive(Debug)]
+pub(crate) struct InitCredsOpt<'a> {
+  pub(crate) context: &'a Krb5Context,
+  pub(crate) opt: *mut krb5_get_init_creds_opt,
+  etype_list: Vec<krb5_enctype>,
+  addresses: Option<AddressList>,
+}
+
+/**
+ * Free an initial credentials options structure.
+ *
+ * [krb5_get_init_creds_opt_free](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_get_init_creds_opt_free.html)
+ */
+impl<'a> Drop for InitCredsOpt<'a> {
+  fn drop(&mut self) {
+    unsafe {
+      krb5_get_init_creds_opt_free(self.context.context, self.opt);
+    }
+  }
+}
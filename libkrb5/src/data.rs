@@ -0,0 +1,216 @@
+/*!
+ * Conversion between Rust byte buffers and `krb5_data`.
+ */
+use std::fmt::{Debug, Formatter};
+use std::ops::Deref;
+use std::os::raw::{c_char, c_uint};
+
+use libkrb5_sys::*;
+
+use crate::context::Krb5Context;
+#[cfg(feature = "zeroize")]
+use crate::secret::zeroize;
+
+/**
+ * An octet string (`krb5_data`).
+ *
+ * The bytes are either borrowed from a byte slice, owned in a byte vector, or allocated by
+ * libkrb5. Data allocated by libkrb5 is freed when the value is dropped.
+ *
+ * https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/types/krb5_data.html
+ */
+pub struct Krb5Data<'a> {
+  inner: Krb5DataInner<'a>,
+}
+
+enum Krb5DataInner<'a> {
+  Borrowed(&'a [u8]),
+  Owned(Vec<u8>),
+  Library { context: &'a Krb5Context, data: krb5_data },
+}
+
+/**
+ * Free data allocated by libkrb5. With the `zeroize` feature, owned bytes are overwritten with
+ * zeroes first, since they may hold key material, e.g. the KRB-CRED messages of
+ * [crate::Krb5AuthContext::mk_1cred].
+ *
+ * [krb5_free_data_contents](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_free_data_contents.html)
+ */
+impl<'a> Drop for Krb5Data<'a> {
+  fn drop(&mut self) {
+    #[cfg(feature = "zeroize")]
+    ::zeroize::Zeroize::zeroize(self);
+
+    if let Krb5DataInner::Library { context, data } = &mut self.inner {
+      unsafe { krb5_free_data_contents(context.context, data) };
+    }
+  }
+}
+
+impl<'a> Krb5Data<'a> {
+  /**
+   * Take ownership of the contents of a `krb5_data` allocated by libkrb5.
+   */
+  pub(crate) fn from_raw(context: &'a Krb5Context, data: krb5_data) -> Krb5Data<'a> {
+    Krb5Data {
+      inner: Krb5DataInner::Library { context, data },
+    }
+  }
+
+  /**
+   * Return a `krb5_data` that borrows the bytes, see [slice_to_krb5_data].
+   */
+  #[cfg(all(krb5_mit, krb5_1_11))]
+  pub(crate) fn as_krb5_data(&self) -> krb5_data {
+    slice_to_krb5_data(self.as_bytes())
+  }
+
+  /**
+   * Return the bytes.
+   */
+  pub fn as_bytes(&self) -> &[u8] {
+    match &self.inner {
+      Krb5DataInner::Borrowed(bytes) => bytes,
+      Krb5DataInner::Owned(bytes) => bytes,
+      Krb5DataInner::Library { data, .. } => krb5_data_as_slice(data),
+    }
+  }
+
+  /**
+   * Return the number of bytes.
+   */
+  pub fn len(&self) -> usize {
+    self.as_bytes().len()
+  }
+
+  /**
+   * Return `true` if there are no bytes.
+   */
+  pub fn is_empty(&self) -> bool {
+    self.as_bytes().is_empty()
+  }
+
+  /**
+   * Convert into a byte vector, copying the bytes unless they are already owned by a vector.
+   */
+  pub fn into_vec(mut self) -> Vec<u8> {
+    match &mut self.inner {
+      Krb5DataInner::Owned(bytes) => std::mem::take(bytes),
+      _ => self.as_bytes().to_vec(),
+    }
+  }
+}
+
+/**
+ * Overwrite owned bytes with zeroes. Borrowed bytes are left alone.
+ */
+#[cfg(feature = "zeroize")]
+impl<'a> ::zeroize::Zeroize for Krb5Data<'a> {
+  fn zeroize(&mut self) {
+    match &mut self.inner {
+      Krb5DataInner::Borrowed(_) => {},
+      Krb5DataInner::Owned(bytes) => zeroize(bytes),
+      Krb5DataInner::Library { data, .. } => {
+        if !data.data.is_null() {
+          zeroize(unsafe { std::slice::from_raw_parts_mut(data.data as *mut u8, data.length as usize) });
+        }
+      },
+    }
+  }
+}
+
+#[cfg(feature = "zeroize")]
+impl<'a> ::zeroize::ZeroizeOnDrop for Krb5Data<'a> {}
+
+impl<'a> AsRef<[u8]> for Krb5Data<'a> {
+  fn as_ref(&self) -> &[u8] {
+    self.as_bytes()
+  }
+}
+
+impl<'a> From<&'a [u8]> for Krb5Data<'a> {
+  fn from(bytes: &'a [u8]) -> Self {
+    Krb5Data {
+      inner: Krb5DataInner::Borrowed(bytes),
+    }
+  }
+}
+
+impl<'a> From<Vec<u8>> for Krb5Data<'a> {
+  fn from(bytes: Vec<u8>) -> Self {
+    Krb5Data {
+      inner: Krb5DataInner::Owned(bytes),
+    }
+  }
+}
+
+impl<'a> Deref for Krb5Data<'a> {
+  type Target = [u8];
+
+  fn deref(&self) -> &[u8] {
+    self.as_bytes()
+  }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> PartialEq<T> for Krb5Data<'a> {
+  fn eq(&self, other: &T) -> bool {
+    self.as_bytes() == other.as_ref()
+  }
+}
+
+impl<'a> Eq for Krb5Data<'a> {}
+
+/**
+ * Only show the length, the data may be key material or an encrypted ticket.
+ */
+impl<'a> Debug for Krb5Data<'a> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(f, "Krb5Data(<{} bytes>)", self.len())
+  }
+}
+
+/**
+ * Borrow the contents of a `krb5_data`.
+ */
+pub(crate) fn krb5_data_as_slice(data: &krb5_data) -> &[u8] {
+  if data.data.is_null() || data.length == 0 {
+    return &[];
+  }
+
+  unsafe { std::slice::from_raw_parts(data.data as *const u8, data.length as usize) }
+}
+
+/**
+ * Copy the contents of a `krb5_data` into a byte vector.
+ */
+pub(crate) fn krb5_data_to_vec(data: &krb5_data) -> Vec<u8> {
+  krb5_data_as_slice(data).to_vec()
+}
+
+/**
+ * Create a `krb5_data` that borrows the given bytes.
+ *
+ * The result must not outlive `bytes`, and must only be passed to functions that do not modify
+ * or free it.
+ */
+pub(crate) fn slice_to_krb5_data(bytes: &[u8]) -> krb5_data {
+  krb5_data {
+    magic: 0,
+    length: bytes.len() as c_uint,
+    data: bytes.as_ptr() as *mut c_char,
+  }
+}
+
+/**
+ * Create a `krb5_data` that points into the given buffer, for functions that write their output
+ * into caller allocated memory.
+ *
+ * The result must not outlive `buffer`, and must not be freed.
+ */
+pub(crate) fn buffer_to_krb5_data(buffer: &mut [u8]) -> krb5_data {
+  krb5_data {
+    magic: 0,
+    length: buffer.len() as c_uint,
+    data: buffer.as_mut_ptr() as *mut c_char,
+  }
+}
@@ -0,0 +1,57 @@
+/*!
+ * Decoding of the constrained delegation buffer of a PAC (S4U_DELEGATION_INFO, MS-PAC section
+ * 2.9).
+ */
+use crate::error::Krb5Error;
+use crate::ndr::NdrReader;
+
+/**
+ * The constrained delegation information of a PAC (S4U_DELEGATION_INFO), which a KDC adds to
+ * tickets issued via S4U2Proxy.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Krb5PacDelegationInfo {
+  /// the service the ticket was issued for by S4U2Proxy
+  pub proxy_target: String,
+  /// the services that requested tickets on behalf of the client, in the order of the
+  /// delegation chain
+  pub transited_services: Vec<String>,
+}
+
+impl Krb5PacDelegationInfo {
+  /**
+   * Decode the contents of a `KRB5_PAC_DELEGATION_INFO` buffer.
+   *
+   * # Arguments
+   *
+   *  * data: the contents of the buffer
+   */
+  pub fn decode(data: &[u8]) -> Result<Krb5PacDelegationInfo, Krb5Error> {
+    let mut reader = NdrReader::type_serialization(data)?;
+
+    let proxy_target = reader.unicode_string()?;
+    let transited_count = reader.u32()?;
+    let transited_present = reader.pointer()?;
+
+    let proxy_target = reader.unicode_string_contents(proxy_target)?;
+
+    let mut transited_services = Vec::new();
+    if transited_present {
+      reader.conformance(transited_count)?;
+
+      let mut headers = Vec::new();
+      for _ in 0..transited_count {
+        headers.push(reader.unicode_string()?);
+      }
+
+      for header in headers {
+        transited_services.push(reader.unicode_string_contents(header)?);
+      }
+    }
+
+    Ok(Krb5PacDelegationInfo {
+      proxy_target,
+      transited_services,
+    })
+  }
+}
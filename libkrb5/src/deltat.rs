@@ -0,0 +1,84 @@
+/*!
+ * Conversion of krb5 time durations in the lifetime syntax of kinit and krb5.conf.
+ */
+use std::os::raw::c_char;
+use std::time::Duration;
+
+use libkrb5_sys::*;
+
+use crate::error::{error_message, Krb5Error};
+use crate::strconv::{c_string_to_string, string_to_c_string};
+
+/**
+ * Parse a time duration like kinit does for the ticket lifetime, e.g. "10h", "7d", "1h30m",
+ * "90" (seconds) or "1:30:00".
+ *
+ * Negative durations are rejected.
+ *
+ * [krb5_string_to_deltat](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_string_to_deltat.html)
+ *
+ * # Arguments
+ *
+ *  * string: the duration to parse
+ */
+pub fn string_to_deltat(string: &str) -> Result<Duration, Krb5Error> {
+  let string_c = string_to_c_string(string)?;
+  let mut deltat: krb5_deltat = 0;
+
+  // libkrb5 does not modify the string, the parameter just lacks the const qualifier
+  let code: krb5_error_code = unsafe { krb5_string_to_deltat(string_c.as_ptr() as *mut c_char, &mut deltat) };
+
+  if code != 0 || deltat < 0 {
+    return Err(bad_format(format!("Invalid time duration: {}", string)));
+  }
+
+  Ok(Duration::from_secs(deltat as u64))
+}
+
+/**
+ * Format a time duration in the syntax accepted by [string_to_deltat], e.g. "10 hours" or
+ * "7 days 0:00:00".
+ *
+ * Fractions of a second are truncated, and durations that do not fit into a `krb5_deltat`
+ * (about 68 years) are rejected.
+ *
+ * [krb5_deltat_to_string](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_deltat_to_string.html)
+ *
+ * # Arguments
+ *
+ *  * duration: the duration to format
+ */
+pub fn deltat_to_string(duration: Duration) -> Result<String, Krb5Error> {
+  if duration.as_secs() > krb5_deltat::MAX as u64 {
+    return Err(bad_format(format!("Time duration out of range: {:?}", duration)));
+  }
+
+  let mut buffer: [c_char; 128] = [0; 128];
+
+  let code: krb5_error_code = unsafe {
+    krb5_deltat_to_string(
+      duration.as_secs() as krb5_deltat,
+      buffer.as_mut_ptr(),
+      buffer.len() as size_t,
+    )
+  };
+
+  if code != 0 {
+    return Err(Krb5Error::LibraryError {
+      code,
+      message: error_message(code),
+    });
+  }
+
+  c_string_to_string(buffer.as_ptr())
+}
+
+/**
+ * Build the error for a duration that cannot be parsed or formatted.
+ */
+fn bad_format(message: String) -> Krb5Error {
+  Krb5Error::LibraryError {
+    code: KRB5_DELTAT_BADFORMAT,
+    message,
+  }
+}
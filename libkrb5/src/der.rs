@@ -0,0 +1,33 @@
+/*!
+ * Minimal decoding of DER data (X.690), for the parts of Kerberos messages libkrb5 does not
+ * expose.
+ */
+
+/**
+ * Split a DER element into its tag, its contents and the remaining input, or return `None` if
+ * the input is truncated or the length is not encoded in the definite form.
+ */
+pub(crate) fn der_element(input: &[u8]) -> Option<(u8, &[u8], &[u8])> {
+  let (&tag, input) = input.split_first()?;
+  let (&first, input) = input.split_first()?;
+
+  let (length, input) = if first & 0x80 == 0 {
+    (first as usize, input)
+  } else {
+    let count = (first & 0x7f) as usize;
+    if count == 0 || count > std::mem::size_of::<usize>() || input.len() < count {
+      return None;
+    }
+
+    let length = input[..count]
+      .iter()
+      .fold(0usize, |length, byte| (length << 8) | *byte as usize);
+    (length, &input[count..])
+  };
+
+  if input.len() < length {
+    return None;
+  }
+
+  Some((tag, &input[..length], &input[length..]))
+}
@@ -0,0 +1,288 @@
+/*!
+ * Encryption types.
+ */
+use std::fmt::{Display, Formatter};
+use std::os::raw::c_char;
+use std::str::FromStr;
+
+use libkrb5_sys::*;
+
+use crate::context::Krb5Context;
+use crate::error::{krb5_error_code_escape_hatch, Krb5Error};
+use crate::strconv::{c_string_to_string, string_to_c_string};
+
+/**
+ * A krb5 encryption type (`ENCTYPE_*`).
+ *
+ * New variants may be added when libkrb5 adds encryption types, so matches need a wildcard arm.
+ *
+ * https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/types/krb5_enctype.html
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Krb5Enctype {
+  /// `des-cbc-crc` (weak)
+  DesCbcCrc,
+  /// `des-cbc-md4` (weak)
+  DesCbcMd4,
+  /// `des-cbc-md5` (weak)
+  DesCbcMd5,
+  /// `des3-cbc-sha1` (deprecated)
+  Des3CbcSha1,
+  /// `aes128-cts-hmac-sha1-96`
+  Aes128CtsHmacSha1_96,
+  /// `aes256-cts-hmac-sha1-96`
+  Aes256CtsHmacSha1_96,
+  /// `aes128-cts-hmac-sha256-128`
+  Aes128CtsHmacSha256_128,
+  /// `aes256-cts-hmac-sha384-192`
+  Aes256CtsHmacSha384_192,
+  /// `arcfour-hmac` (deprecated)
+  ArcfourHmac,
+  /// `arcfour-hmac-exp` (weak)
+  ArcfourHmacExp,
+  /// `camellia128-cts-cmac`
+  Camellia128CtsCmac,
+  /// `camellia256-cts-cmac`
+  Camellia256CtsCmac,
+  /// any other encryption type number
+  Unknown(krb5_enctype),
+}
+
+impl Krb5Enctype {
+  /**
+   * Convert an encryption type number.
+   */
+  pub fn from_raw(enctype: krb5_enctype) -> Self {
+    use Krb5Enctype::*;
+
+    match enctype as u32 {
+      ENCTYPE_DES_CBC_CRC => DesCbcCrc,
+      ENCTYPE_DES_CBC_MD4 => DesCbcMd4,
+      ENCTYPE_DES_CBC_MD5 => DesCbcMd5,
+      ENCTYPE_DES3_CBC_SHA1 => Des3CbcSha1,
+      ENCTYPE_AES128_CTS_HMAC_SHA1_96 => Aes128CtsHmacSha1_96,
+      ENCTYPE_AES256_CTS_HMAC_SHA1_96 => Aes256CtsHmacSha1_96,
+      ENCTYPE_AES128_CTS_HMAC_SHA256_128 => Aes128CtsHmacSha256_128,
+      ENCTYPE_AES256_CTS_HMAC_SHA384_192 => Aes256CtsHmacSha384_192,
+      ENCTYPE_ARCFOUR_HMAC => ArcfourHmac,
+      ENCTYPE_ARCFOUR_HMAC_EXP => ArcfourHmacExp,
+      ENCTYPE_CAMELLIA128_CTS_CMAC => Camellia128CtsCmac,
+      ENCTYPE_CAMELLIA256_CTS_CMAC => Camellia256CtsCmac,
+      _ => Unknown(enctype),
+    }
+  }
+
+  /**
+   * Return the encryption type number.
+   */
+  pub fn as_raw(self) -> krb5_enctype {
+    use Krb5Enctype::*;
+
+    let enctype = match self {
+      DesCbcCrc => ENCTYPE_DES_CBC_CRC,
+      DesCbcMd4 => ENCTYPE_DES_CBC_MD4,
+      DesCbcMd5 => ENCTYPE_DES_CBC_MD5,
+      Des3CbcSha1 => ENCTYPE_DES3_CBC_SHA1,
+      Aes128CtsHmacSha1_96 => ENCTYPE_AES128_CTS_HMAC_SHA1_96,
+      Aes256CtsHmacSha1_96 => ENCTYPE_AES256_CTS_HMAC_SHA1_96,
+      Aes128CtsHmacSha256_128 => ENCTYPE_AES128_CTS_HMAC_SHA256_128,
+      Aes256CtsHmacSha384_192 => ENCTYPE_AES256_CTS_HMAC_SHA384_192,
+      ArcfourHmac => ENCTYPE_ARCFOUR_HMAC,
+      ArcfourHmacExp => ENCTYPE_ARCFOUR_HMAC_EXP,
+      Camellia128CtsCmac => ENCTYPE_CAMELLIA128_CTS_CMAC,
+      Camellia256CtsCmac => ENCTYPE_CAMELLIA256_CTS_CMAC,
+      Unknown(enctype) => return enctype,
+    };
+
+    enctype as krb5_enctype
+  }
+
+  /**
+   * Return the block size of the cipher of the encryption type in bytes.
+   *
+   * [krb5_c_block_size](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_c_block_size.html)
+   *
+   * # Arguments
+   *
+   *  * context: the Krb5Context instance
+   */
+  pub fn block_size(self, context: &Krb5Context) -> Result<usize, Krb5Error> {
+    let mut blocksize: size_t = 0;
+
+    let code: krb5_error_code = unsafe { krb5_c_block_size(context.context, self.as_raw(), &mut blocksize) };
+
+    krb5_error_code_escape_hatch(context, code)?;
+
+    Ok(blocksize as usize)
+  }
+
+  /**
+   * Return a human readable description, e.g. "AES-256 CTS mode with 96-bit SHA-1 HMAC".
+   *
+   * [krb5_enctype_to_string](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_enctype_to_string.html)
+   */
+  pub fn description(self) -> Result<String, Krb5Error> {
+    let mut buffer: [c_char; 128] = [0; 128];
+
+    let code: krb5_error_code =
+      unsafe { krb5_enctype_to_string(self.as_raw(), buffer.as_mut_ptr(), buffer.len() as size_t) };
+
+    if code != 0 {
+      return Err(unknown_enctype(self, code));
+    }
+
+    c_string_to_string(buffer.as_ptr())
+  }
+
+  /**
+   * Parse an encryption type name as used in krb5.conf, e.g. "aes256-cts-hmac-sha1-96" or an
+   * alias like "aes256-cts".
+   *
+   * [krb5_string_to_enctype](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_string_to_enctype.html)
+   *
+   * # Arguments
+   *
+   *  * name: the encryption type name
+   */
+  pub fn from_name(name: &str) -> Result<Self, Krb5Error> {
+    let name_c = string_to_c_string(name)?;
+    let mut enctype: krb5_enctype = 0;
+
+    // libkrb5 does not modify the string, the parameter just lacks the const qualifier
+    let code: krb5_error_code = unsafe { krb5_string_to_enctype(name_c.as_ptr() as *mut c_char, &mut enctype) };
+
+    if code != 0 {
+      return Err(Krb5Error::LibraryError {
+        code,
+        message: format!("Unknown encryption type name: {}", name),
+      });
+    }
+
+    Ok(Krb5Enctype::from_raw(enctype))
+  }
+
+  /**
+   * Return true if libkrb5 supports the encryption type.
+   *
+   * [krb5_c_valid_enctype](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_c_valid_enctype.html)
+   */
+  pub fn is_valid(self) -> bool {
+    unsafe { krb5_c_valid_enctype(self.as_raw()) != 0 }
+  }
+
+  /**
+   * Return the length of keys of the encryption type in bytes.
+   *
+   * [krb5_c_keylengths](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_c_keylengths.html)
+   *
+   * # Arguments
+   *
+   *  * context: the Krb5Context instance
+   */
+  pub fn key_length(self, context: &Krb5Context) -> Result<usize, Krb5Error> {
+    let (_, keylength) = self.key_lengths(context)?;
+
+    Ok(keylength)
+  }
+
+  /**
+   * Return the number of random bytes needed to create a key of the encryption type, see
+   * [crate::Krb5Keyblock::from_random_bytes].
+   *
+   * [krb5_c_keylengths](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_c_keylengths.html)
+   *
+   * # Arguments
+   *
+   *  * context: the Krb5Context instance
+   */
+  pub fn random_length(self, context: &Krb5Context) -> Result<usize, Krb5Error> {
+    let (keybytes, _) = self.key_lengths(context)?;
+
+    Ok(keybytes)
+  }
+
+  /**
+   * Return the name of the encryption type as used in krb5.conf.
+   *
+   * [krb5_enctype_to_name](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_enctype_to_name.html)
+   *
+   * # Arguments
+   *
+   *  * shortest: return the shortest alias (e.g. "aes256-cts") instead of the canonical name
+   */
+  pub fn name(self, shortest: bool) -> Result<String, Krb5Error> {
+    let mut buffer: [c_char; 64] = [0; 64];
+
+    let code: krb5_error_code = unsafe {
+      krb5_enctype_to_name(
+        self.as_raw(),
+        shortest as krb5_boolean,
+        buffer.as_mut_ptr(),
+        buffer.len() as size_t,
+      )
+    };
+
+    if code != 0 {
+      return Err(unknown_enctype(self, code));
+    }
+
+    c_string_to_string(buffer.as_ptr())
+  }
+}
+
+impl Krb5Enctype {
+  /**
+   * Return the number of random input bytes and the key length.
+   */
+  fn key_lengths(self, context: &Krb5Context) -> Result<(usize, usize), Krb5Error> {
+    let mut keybytes: size_t = 0;
+    let mut keylength: size_t = 0;
+
+    let code: krb5_error_code =
+      unsafe { krb5_c_keylengths(context.context, self.as_raw(), &mut keybytes, &mut keylength) };
+
+    krb5_error_code_escape_hatch(context, code)?;
+
+    Ok((keybytes as usize, keylength as usize))
+  }
+}
+
+impl From<krb5_enctype> for Krb5Enctype {
+  fn from(enctype: krb5_enctype) -> Self {
+    Krb5Enctype::from_raw(enctype)
+  }
+}
+
+impl From<Krb5Enctype> for krb5_enctype {
+  fn from(enctype: Krb5Enctype) -> Self {
+    enctype.as_raw()
+  }
+}
+
+/**
+ * Display the canonical name, or the number for unknown encryption types (like klist does).
+ */
+impl Display for Krb5Enctype {
+  fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+    match self.name(false) {
+      Ok(name) => write!(f, "{}", name),
+      Err(_) => write!(f, "etype {}", self.as_raw()),
+    }
+  }
+}
+
+impl FromStr for Krb5Enctype {
+  type Err = Krb5Error;
+
+  fn from_str(name: &str) -> Result<Self, Self::Err> {
+    Krb5Enctype::from_name(name)
+  }
+}
+
+fn unknown_enctype(enctype: Krb5Enctype, code: krb5_error_code) -> Krb5Error {
+  Krb5Error::LibraryError {
+    code,
+    message: format!("Unknown encryption type: {}", enctype.as_raw()),
+  }
+}
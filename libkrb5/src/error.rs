@@ -5,21 +5,65 @@ use std::fmt::{Display, Formatter};
 use libkrb5_sys::*;
 
 use crate::context::Krb5Context;
+use crate::error_code::Krb5ErrorCode;
+use crate::error_message::Krb5KdcError;
+use crate::password::Krb5PasswordChangeResult;
+use crate::strconv::c_string_to_string_lossy;
 
+/**
+ * Result type of the fallible functions of this crate.
+ */
+pub type Result<T> = std::result::Result<T, Krb5Error>;
+
+/**
+ * Errors of this crate.
+ *
+ * New variants may be added for further error cases, so matches need a wildcard arm.
+ */
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum Krb5Error {
-  LibraryError { message: String },
+  /// an error reported by libkrb5
+  LibraryError {
+    code: krb5_error_code,
+    message: String,
+  },
   NullPointerDereference,
-  StringConversion { error: Option<IntoStringError> },
+  StringConversion {
+    error: Option<IntoStringError>,
+  },
   MaxVarArgsExceeded,
+  Io {
+    error: std::io::Error,
+  },
+  /// an error reported by libkrb5 after the KDC rejected a request with a KRB-ERROR
+  KdcError {
+    code: krb5_error_code,
+    message: String,
+    kdc_error: Krb5KdcError,
+  },
+  /// the password (or key) of the principal has expired and has to be changed
+  /// (`KRB5KDC_ERR_KEY_EXP`)
+  PasswordExpired {
+    principal: String,
+  },
+  /// the KDC requires preauthentication with one of the given preauthentication types, which
+  /// could not be performed (`KRB5KDC_ERR_PREAUTH_REQUIRED`)
+  PreauthRequired {
+    pa_types: Vec<krb5_preauthtype>,
+  },
+  /// the kpasswd server rejected a password change (`KRB5_CHPW_FAIL`)
+  PasswordChangeRejected {
+    result: Krb5PasswordChangeResult,
+  },
 }
 
 impl Display for Krb5Error {
-  fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
     use Krb5Error::*;
 
     match self {
-      LibraryError { message } => write!(f, "Library error: {}", message),
+      LibraryError { message, .. } => write!(f, "Library error: {}", message),
       NullPointerDereference => write!(f, "NULL Pointer dereference error"),
       StringConversion { error } => match error {
         Some(error) => write!(f, "String conversion / UTF8 error: {}", error),
@@ -29,11 +73,160 @@ impl Display for Krb5Error {
         f,
         "Maximum number of supported arguments for a variadic function exceeded."
       ),
+      Io { error } => write!(f, "I/O error: {}", error),
+      KdcError { message, .. } => write!(f, "KDC error: {}", message),
+      PasswordExpired { principal } => write!(f, "Password for {} has expired", principal),
+      PreauthRequired { pa_types } => write!(f, "Additional preauthentication required: {:?}", pa_types),
+      PasswordChangeRejected { result } => match result.server_message.as_str() {
+        "" => write!(f, "Password change rejected: {}", result.code_string),
+        message => write!(f, "Password change rejected: {}: {}", result.code_string, message),
+      },
     }
   }
 }
 
-impl Error for Krb5Error {}
+impl Krb5Error {
+  /**
+   * Prepend a description of the failed operation to the message of a library error, like
+   * `krb5_prepend_error_message`, e.g. "while resolving ccache FILE:/tmp/krb5cc_0: No
+   * credentials cache found". The error code is kept. Other errors are returned unchanged.
+   *
+   * # Arguments
+   *
+   *  * context: description of the failed operation, usually naming the object it failed on
+   */
+  pub fn context<C: Display>(self, context: C) -> Krb5Error {
+    match self {
+      Krb5Error::LibraryError { code, message } => Krb5Error::LibraryError {
+        code,
+        message: format!("{}: {}", context, message),
+      },
+      Krb5Error::KdcError {
+        code,
+        message,
+        kdc_error,
+      } => Krb5Error::KdcError {
+        code,
+        message: format!("{}: {}", context, message),
+        kdc_error,
+      },
+      error => error,
+    }
+  }
+
+  /**
+   * Return the libkrb5 error code of a library error or of a structured error created from one,
+   * or `None` for other errors.
+   */
+  pub fn error_code(&self) -> Option<Krb5ErrorCode> {
+    self.raw_code().map(Krb5ErrorCode::from_raw)
+  }
+
+  /**
+   * Return true if the error is caused by the clock difference between this host and the KDC or
+   * the peer exceeding the allowed clock skew.
+   */
+  pub fn is_clock_skew(&self) -> bool {
+    matches!(
+      self.error_code(),
+      Some(Krb5ErrorCode::Skew) | Some(Krb5ErrorCode::KdcrepSkew)
+    )
+  }
+
+  /**
+   * Return true if the error is caused by an expired ticket, which has to be renewed or
+   * acquired again.
+   */
+  pub fn is_expired_ticket(&self) -> bool {
+    self.is(Krb5ErrorCode::TktExpired)
+  }
+
+  /**
+   * Return true if no KDC of the realm could be found or reached.
+   */
+  pub fn is_kdc_unreachable(&self) -> bool {
+    matches!(
+      self.error_code(),
+      Some(Krb5ErrorCode::KdcUnreach) | Some(Krb5ErrorCode::RealmCantResolve)
+    )
+  }
+
+  /**
+   * Return true if the error is likely transient, so the same operation may succeed when it is
+   * retried later (e.g. an unreachable or busy KDC, or an interrupted I/O operation).
+   *
+   * Expired tickets and clock skew are not retryable as such: the credentials have to be
+   * renewed or the clock has to be fixed first.
+   */
+  pub fn is_retryable(&self) -> bool {
+    use std::io::ErrorKind;
+
+    let kind = match self {
+      Krb5Error::LibraryError { code, .. } if *code > 0 => io_error_kind(*code),
+      Krb5Error::LibraryError { .. } | Krb5Error::KdcError { .. } => {
+        return self.is_kdc_unreachable() || self.is(Krb5ErrorCode::SvcUnavailable);
+      },
+      Krb5Error::Io { error } => error.kind(),
+      _ => return false,
+    };
+
+    matches!(
+      kind,
+      ErrorKind::Interrupted
+        | ErrorKind::TimedOut
+        | ErrorKind::WouldBlock
+        | ErrorKind::ConnectionRefused
+        | ErrorKind::ConnectionReset
+        | ErrorKind::ConnectionAborted
+    )
+  }
+
+  /**
+   * Return the KRB-ERROR the KDC rejected the request with, if the error was caused by one.
+   */
+  pub fn kdc_error(&self) -> Option<&Krb5KdcError> {
+    match self {
+      Krb5Error::KdcError { kdc_error, .. } => Some(kdc_error),
+      _ => None,
+    }
+  }
+
+  /**
+   * Return true if this is a library error with the given error code.
+   *
+   * # Arguments
+   *
+   *  * code: a [Krb5ErrorCode] or a raw libkrb5 error code
+   */
+  pub fn is<C: Into<krb5_error_code>>(&self, code: C) -> bool {
+    self.raw_code() == Some(code.into())
+  }
+
+  /**
+   * Return the raw libkrb5 error code of a library error or of a structured error created from
+   * one, or `None` for other errors.
+   */
+  pub fn raw_code(&self) -> Option<krb5_error_code> {
+    match self {
+      Krb5Error::LibraryError { code, .. } | Krb5Error::KdcError { code, .. } => Some(*code),
+      Krb5Error::PasswordExpired { .. } => Some(KRB5KDC_ERR_KEY_EXP),
+      Krb5Error::PreauthRequired { .. } => Some(KRB5KDC_ERR_PREAUTH_REQUIRED),
+      Krb5Error::PasswordChangeRejected { .. } => Some(KRB5_CHPW_FAIL),
+      _ => None,
+    }
+  }
+}
+
+impl Error for Krb5Error {
+  fn source(&self) -> Option<&(dyn Error + 'static)> {
+    match self {
+      Krb5Error::StringConversion { error: Some(error) } => Some(error),
+      Krb5Error::Io { error } => Some(error),
+      Krb5Error::KdcError { kdc_error, .. } => Some(kdc_error),
+      _ => None,
+    }
+  }
+}
 
 impl From<IntoStringError> for Krb5Error {
   fn from(error: IntoStringError) -> Self {
@@ -41,6 +234,95 @@ impl From<IntoStringError> for Krb5Error {
   }
 }
 
+impl From<std::io::Error> for Krb5Error {
+  fn from(error: std::io::Error) -> Self {
+    Krb5Error::Io { error }
+  }
+}
+
+/**
+ * Return the message for a libkrb5 error code without a [Krb5Context], e.g. in `Drop`
+ * implementations or after the context has been freed.
+ *
+ * The message is looked up in the com_err tables, so it lacks the details libkrb5 records in a
+ * context for the last error (see [Krb5Error::LibraryError] messages). System error numbers
+ * are described as well.
+ *
+ * [krb5_get_error_message](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_get_error_message.html)
+ *
+ * # Arguments
+ *
+ *  * code: a libkrb5 error code
+ */
+pub fn error_message(code: krb5_error_code) -> String {
+  #[cfg(feature = "dlopen")]
+  if let Err(message) = libkrb5_sys::load() {
+    return message;
+  }
+
+  // without a context, libkrb5 returns a static or thread-local string that must not be freed
+  let message = unsafe { krb5_get_error_message(std::ptr::null_mut(), code) };
+
+  match c_string_to_string_lossy(message) {
+    Ok(message) => message,
+    Err(_) => format!("Unknown error code {}", code),
+  }
+}
+
+/**
+ * Convert into an I/O error, e.g. to propagate errors through `Read`/`Write` implementations.
+ *
+ * I/O errors are unwrapped, other errors are wrapped with an [std::io::ErrorKind] matching the
+ * error code where possible (e.g. `NotFound` for a missing credential cache or key table entry,
+ * `TimedOut` if no KDC could be reached).
+ */
+impl From<Krb5Error> for std::io::Error {
+  fn from(error: Krb5Error) -> Self {
+    use std::io::ErrorKind;
+
+    match error {
+      Krb5Error::Io { error } => error,
+      error => {
+        let kind = match &error {
+          Krb5Error::LibraryError { code, .. } | Krb5Error::KdcError { code, .. } => io_error_kind(*code),
+          Krb5Error::StringConversion { .. } => ErrorKind::InvalidData,
+          Krb5Error::PasswordExpired { .. }
+          | Krb5Error::PreauthRequired { .. }
+          | Krb5Error::PasswordChangeRejected { .. } => ErrorKind::PermissionDenied,
+          _ => ErrorKind::Other,
+        };
+
+        std::io::Error::new(kind, error)
+      },
+    }
+  }
+}
+
+/**
+ * Return the I/O error kind for a libkrb5 error code.
+ */
+fn io_error_kind(code: krb5_error_code) -> std::io::ErrorKind {
+  use std::io::ErrorKind;
+
+  // libkrb5 passes through system error numbers
+  if code > 0 {
+    return std::io::Error::from_raw_os_error(code).kind();
+  }
+
+  match Krb5ErrorCode::from_raw(code) {
+    Krb5ErrorCode::FccNofile
+    | Krb5ErrorCode::CcNotfound
+    | Krb5ErrorCode::KtNotfound
+    | Krb5ErrorCode::CPrincipalUnknown
+    | Krb5ErrorCode::SPrincipalUnknown
+    | Krb5ErrorCode::RealmUnknown => ErrorKind::NotFound,
+    Krb5ErrorCode::FccPerm => ErrorKind::PermissionDenied,
+    Krb5ErrorCode::KdcUnreach => ErrorKind::TimedOut,
+    Krb5ErrorCode::CcFormat => ErrorKind::InvalidData,
+    _ => ErrorKind::Other,
+  }
+}
+
 /**
 * Convert krb5 error code to a Krb5Error wrapped in a Result.
 *
@@ -59,12 +341,13 @@ krb5_error_code_escape_hatch(&context, code)?;
 *
 * Ok(()) if `code` is 0 or Krb5Error result.
 */
-#[must_use]
-pub(crate) fn krb5_error_code_escape_hatch(context: &Krb5Context, code: krb5_error_code) -> Result<(), Krb5Error> {
+#[must_use = "errors of libkrb5 calls have to be propagated"]
+pub(crate) fn krb5_error_code_escape_hatch(context: &Krb5Context, code: krb5_error_code) -> Result<()> {
   if code == 0 {
     Ok(())
   } else {
     Err(Krb5Error::LibraryError {
+      code,
       message: context.error_code_to_message(code),
     })
   }
@@ -0,0 +1,783 @@
+/*!
+ * Typed krb5 error codes.
+ *
+ * The variants are generated from the krb5 error table of MIT krb5
+ * (`lib/krb5/error_tables/krb5_err.et`).
+ */
+use std::fmt::{Display, Formatter};
+
+use libkrb5_sys::*;
+
+use crate::error::error_message;
+
+/**
+ * A libkrb5 error code of the krb5 error table (`KRB5KDC_ERR_*`, `KRB5KRB_AP_ERR_*`, `KRB5_*`).
+ *
+ * Codes of other error tables and system error numbers are represented by
+ * [Krb5ErrorCode::Unknown]. New variants may be added with new libkrb5 versions, so matches
+ * need a wildcard arm.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Krb5ErrorCode {
+  /// No error (`KRB5KDC_ERR_NONE`)
+  NoError,
+  /// Client's entry in database has expired (`KRB5KDC_ERR_NAME_EXP`)
+  NameExp,
+  /// Server's entry in database has expired (`KRB5KDC_ERR_SERVICE_EXP`)
+  ServiceExp,
+  /// Requested protocol version not supported (`KRB5KDC_ERR_BAD_PVNO`)
+  BadPvno,
+  /// Client's key is encrypted in an old master key (`KRB5KDC_ERR_C_OLD_MAST_KVNO`)
+  COldMastKvno,
+  /// Server's key is encrypted in an old master key (`KRB5KDC_ERR_S_OLD_MAST_KVNO`)
+  SOldMastKvno,
+  /// Client not found in Kerberos database (`KRB5KDC_ERR_C_PRINCIPAL_UNKNOWN`)
+  CPrincipalUnknown,
+  /// Server not found in Kerberos database (`KRB5KDC_ERR_S_PRINCIPAL_UNKNOWN`)
+  SPrincipalUnknown,
+  /// Principal has multiple entries in Kerberos database (`KRB5KDC_ERR_PRINCIPAL_NOT_UNIQUE`)
+  PrincipalNotUnique,
+  /// Client or server has a null key (`KRB5KDC_ERR_NULL_KEY`)
+  NullKey,
+  /// Ticket is ineligible for postdating (`KRB5KDC_ERR_CANNOT_POSTDATE`)
+  CannotPostdate,
+  /// Requested effective lifetime is negative or too short (`KRB5KDC_ERR_NEVER_VALID`)
+  NeverValid,
+  /// KDC policy rejects request (`KRB5KDC_ERR_POLICY`)
+  Policy,
+  /// KDC can't fulfill requested option (`KRB5KDC_ERR_BADOPTION`)
+  Badoption,
+  /// KDC has no support for encryption type (`KRB5KDC_ERR_ETYPE_NOSUPP`)
+  EtypeNosupp,
+  /// KDC has no support for checksum type (`KRB5KDC_ERR_SUMTYPE_NOSUPP`)
+  SumtypeNosupp,
+  /// KDC has no support for padata type (`KRB5KDC_ERR_PADATA_TYPE_NOSUPP`)
+  PadataTypeNosupp,
+  /// KDC has no support for transited type (`KRB5KDC_ERR_TRTYPE_NOSUPP`)
+  TrtypeNosupp,
+  /// Client's credentials have been revoked (`KRB5KDC_ERR_CLIENT_REVOKED`)
+  ClientRevoked,
+  /// Credentials for server have been revoked (`KRB5KDC_ERR_SERVICE_REVOKED`)
+  ServiceRevoked,
+  /// TGT has been revoked (`KRB5KDC_ERR_TGT_REVOKED`)
+  TgtRevoked,
+  /// Client not yet valid - try again later (`KRB5KDC_ERR_CLIENT_NOTYET`)
+  ClientNotyet,
+  /// Server not yet valid - try again later (`KRB5KDC_ERR_SERVICE_NOTYET`)
+  ServiceNotyet,
+  /// Password has expired (`KRB5KDC_ERR_KEY_EXP`)
+  KeyExp,
+  /// Preauthentication failed (`KRB5KDC_ERR_PREAUTH_FAILED`)
+  PreauthFailed,
+  /// Additional pre-authentication required (`KRB5KDC_ERR_PREAUTH_REQUIRED`)
+  PreauthRequired,
+  /// Requested server and ticket don't match (`KRB5KDC_ERR_SERVER_NOMATCH`)
+  ServerNomatch,
+  /// Server principal valid for user2user only (`KRB5KDC_ERR_MUST_USE_USER2USER`)
+  MustUseUser2user,
+  /// KDC policy rejects transited path (`KRB5KDC_ERR_PATH_NOT_ACCEPTED`)
+  PathNotAccepted,
+  /// A service is not available that is required to process the request
+  /// (`KRB5KDC_ERR_SVC_UNAVAILABLE`)
+  SvcUnavailable,
+  /// Decrypt integrity check failed (`KRB5KRB_AP_ERR_BAD_INTEGRITY`)
+  BadIntegrity,
+  /// Ticket expired (`KRB5KRB_AP_ERR_TKT_EXPIRED`)
+  TktExpired,
+  /// Ticket not yet valid (`KRB5KRB_AP_ERR_TKT_NYV`)
+  TktNyv,
+  /// Request is a replay (`KRB5KRB_AP_ERR_REPEAT`)
+  Repeat,
+  /// The ticket isn't for us (`KRB5KRB_AP_ERR_NOT_US`)
+  NotUs,
+  /// Ticket/authenticator don't match (`KRB5KRB_AP_ERR_BADMATCH`)
+  Badmatch,
+  /// Clock skew too great (`KRB5KRB_AP_ERR_SKEW`)
+  Skew,
+  /// Incorrect net address (`KRB5KRB_AP_ERR_BADADDR`)
+  Badaddr,
+  /// Protocol version mismatch (`KRB5KRB_AP_ERR_BADVERSION`)
+  Badversion,
+  /// Invalid message type (`KRB5KRB_AP_ERR_MSG_TYPE`)
+  MsgType,
+  /// Message stream modified (`KRB5KRB_AP_ERR_MODIFIED`)
+  Modified,
+  /// Message out of order (`KRB5KRB_AP_ERR_BADORDER`)
+  Badorder,
+  /// Illegal cross-realm ticket (`KRB5KRB_AP_ERR_ILL_CR_TKT`)
+  IllCrTkt,
+  /// Key version is not available (`KRB5KRB_AP_ERR_BADKEYVER`)
+  Badkeyver,
+  /// Service key not available (`KRB5KRB_AP_ERR_NOKEY`)
+  Nokey,
+  /// Mutual authentication failed (`KRB5KRB_AP_ERR_MUT_FAIL`)
+  MutFail,
+  /// Incorrect message direction (`KRB5KRB_AP_ERR_BADDIRECTION`)
+  Baddirection,
+  /// Alternative authentication method required (`KRB5KRB_AP_ERR_METHOD`)
+  Method,
+  /// Incorrect sequence number in message (`KRB5KRB_AP_ERR_BADSEQ`)
+  Badseq,
+  /// Inappropriate type of checksum in message (`KRB5KRB_AP_ERR_INAPP_CKSUM`)
+  InappCksum,
+  /// Policy rejects transited path (`KRB5KRB_AP_PATH_NOT_ACCEPTED`)
+  ApPathNotAccepted,
+  /// Response too big for UDP, retry with TCP (`KRB5KRB_ERR_RESPONSE_TOO_BIG`)
+  ResponseTooBig,
+  /// Generic error (see e-text) (`KRB5KRB_ERR_GENERIC`)
+  Generic,
+  /// Field is too long for this implementation (`KRB5KRB_ERR_FIELD_TOOLONG`)
+  FieldToolong,
+  /// Client not trusted (`KRB5KDC_ERR_CLIENT_NOT_TRUSTED`)
+  ClientNotTrusted,
+  /// KDC not trusted (`KRB5KDC_ERR_KDC_NOT_TRUSTED`)
+  KdcNotTrusted,
+  /// Invalid signature (`KRB5KDC_ERR_INVALID_SIG`)
+  InvalidSig,
+  /// Key parameters not accepted (`KRB5KDC_ERR_DH_KEY_PARAMETERS_NOT_ACCEPTED`)
+  DhKeyParametersNotAccepted,
+  /// Certificate mismatch (`KRB5KDC_ERR_CERTIFICATE_MISMATCH`)
+  CertificateMismatch,
+  /// No ticket granting ticket (`KRB5KRB_AP_ERR_NO_TGT`)
+  NoTgt,
+  /// Realm not local to KDC (`KRB5KDC_ERR_WRONG_REALM`)
+  WrongRealm,
+  /// User to user required (`KRB5KRB_AP_ERR_USER_TO_USER_REQUIRED`)
+  UserToUserRequired,
+  /// Can't verify certificate (`KRB5KDC_ERR_CANT_VERIFY_CERTIFICATE`)
+  CantVerifyCertificate,
+  /// Invalid certificate (`KRB5KDC_ERR_INVALID_CERTIFICATE`)
+  InvalidCertificate,
+  /// Revoked certificate (`KRB5KDC_ERR_REVOKED_CERTIFICATE`)
+  RevokedCertificate,
+  /// Revocation status unknown (`KRB5KDC_ERR_REVOCATION_STATUS_UNKNOWN`)
+  RevocationStatusUnknown,
+  /// Revocation status unavailable (`KRB5KDC_ERR_REVOCATION_STATUS_UNAVAILABLE`)
+  RevocationStatusUnavailable,
+  /// Client name mismatch (`KRB5KDC_ERR_CLIENT_NAME_MISMATCH`)
+  ClientNameMismatch,
+  /// KDC name mismatch (`KRB5KDC_ERR_KDC_NAME_MISMATCH`)
+  KdcNameMismatch,
+  /// Inconsistent key purpose (`KRB5KDC_ERR_INCONSISTENT_KEY_PURPOSE`)
+  InconsistentKeyPurpose,
+  /// Digest in certificate not accepted (`KRB5KDC_ERR_DIGEST_IN_CERT_NOT_ACCEPTED`)
+  DigestInCertNotAccepted,
+  /// Checksum must be included (`KRB5KDC_ERR_PA_CHECKSUM_MUST_BE_INCLUDED`)
+  PaChecksumMustBeIncluded,
+  /// Digest in signed-data not accepted (`KRB5KDC_ERR_DIGEST_IN_SIGNED_DATA_NOT_ACCEPTED`)
+  DigestInSignedDataNotAccepted,
+  /// Public key encryption not supported (`KRB5KDC_ERR_PUBLIC_KEY_ENCRYPTION_NOT_SUPPORTED`)
+  PublicKeyEncryptionNotSupported,
+  /// The IAKERB proxy could not find a KDC (`KRB5KRB_AP_ERR_IAKERB_KDC_NOT_FOUND`)
+  IakerbKdcNotFound,
+  /// The KDC did not respond to the IAKERB proxy (`KRB5KRB_AP_ERR_IAKERB_KDC_NO_RESPONSE`)
+  IakerbKdcNoResponse,
+  /// Preauthentication expired (`KRB5KDC_ERR_PREAUTH_EXPIRED`)
+  PreauthExpired,
+  /// More preauthentication data is required (`KRB5KDC_ERR_MORE_PREAUTH_DATA_REQUIRED`)
+  MorePreauthDataRequired,
+  /// An unsupported critical FAST option was requested (`KRB5KDC_ERR_UNKNOWN_CRITICAL_FAST_OPTION`)
+  UnknownCriticalFastOption,
+  /// No acceptable KDF offered (`KRB5KDC_ERR_NO_ACCEPTABLE_KDF`)
+  NoAcceptableKdf,
+  /// $Id$ (`KRB5_ERR_RCSID`)
+  ErrRcsid,
+  /// Invalid flag for file lock mode (`KRB5_LIBOS_BADLOCKFLAG`)
+  LibosBadlockflag,
+  /// Cannot read password (`KRB5_LIBOS_CANTREADPWD`)
+  LibosCantreadpwd,
+  /// Password mismatch (`KRB5_LIBOS_BADPWDMATCH`)
+  LibosBadpwdmatch,
+  /// Password read interrupted (`KRB5_LIBOS_PWDINTR`)
+  LibosPwdintr,
+  /// Illegal character in component name (`KRB5_PARSE_ILLCHAR`)
+  ParseIllchar,
+  /// Malformed representation of principal (`KRB5_PARSE_MALFORMED`)
+  ParseMalformed,
+  /// Can't open/find Kerberos configuration file (`KRB5_CONFIG_CANTOPEN`)
+  ConfigCantopen,
+  /// Improper format of Kerberos configuration file (`KRB5_CONFIG_BADFORMAT`)
+  ConfigBadformat,
+  /// Insufficient space to return complete information (`KRB5_CONFIG_NOTENUFSPACE`)
+  ConfigNotenufspace,
+  /// Invalid message type specified for encoding (`KRB5_BADMSGTYPE`)
+  Badmsgtype,
+  /// Credential cache name malformed (`KRB5_CC_BADNAME`)
+  CcBadname,
+  /// Unknown credential cache type (`KRB5_CC_UNKNOWN_TYPE`)
+  CcUnknownType,
+  /// Matching credential not found (`KRB5_CC_NOTFOUND`)
+  CcNotfound,
+  /// End of credential cache reached (`KRB5_CC_END`)
+  CcEnd,
+  /// Request did not supply a ticket (`KRB5_NO_TKT_SUPPLIED`)
+  NoTktSupplied,
+  /// Wrong principal in request (`KRB5KRB_AP_WRONG_PRINC`)
+  WrongPrinc,
+  /// Ticket has invalid flag set (`KRB5KRB_AP_ERR_TKT_INVALID`)
+  TktInvalid,
+  /// Requested principal and ticket don't match (`KRB5_PRINC_NOMATCH`)
+  PrincNomatch,
+  /// KDC reply did not match expectations (`KRB5_KDCREP_MODIFIED`)
+  KdcrepModified,
+  /// Clock skew too great in KDC reply (`KRB5_KDCREP_SKEW`)
+  KdcrepSkew,
+  /// Client/server realm mismatch in initial ticket request (`KRB5_IN_TKT_REALM_MISMATCH`)
+  InTktRealmMismatch,
+  /// Program lacks support for encryption type (`KRB5_PROG_ETYPE_NOSUPP`)
+  ProgEtypeNosupp,
+  /// Program lacks support for key type (`KRB5_PROG_KEYTYPE_NOSUPP`)
+  ProgKeytypeNosupp,
+  /// Requested encryption type not used in message (`KRB5_WRONG_ETYPE`)
+  WrongEtype,
+  /// Program lacks support for checksum type (`KRB5_PROG_SUMTYPE_NOSUPP`)
+  ProgSumtypeNosupp,
+  /// Cannot find KDC for requested realm (`KRB5_REALM_UNKNOWN`)
+  RealmUnknown,
+  /// Kerberos service unknown (`KRB5_SERVICE_UNKNOWN`)
+  ServiceUnknown,
+  /// Cannot contact any KDC for requested realm (`KRB5_KDC_UNREACH`)
+  KdcUnreach,
+  /// No local name found for principal name (`KRB5_NO_LOCALNAME`)
+  NoLocalname,
+  /// Mutual authentication failed (`KRB5_MUTUAL_FAILED`)
+  MutualFailed,
+  /// Replay cache type is already registered (`KRB5_RC_TYPE_EXISTS`)
+  RcTypeExists,
+  /// No more memory to allocate (in replay cache code) (`KRB5_RC_MALLOC`)
+  RcMalloc,
+  /// Replay cache type is unknown (`KRB5_RC_TYPE_NOTFOUND`)
+  RcTypeNotfound,
+  /// Generic unknown RC error (`KRB5_RC_UNKNOWN`)
+  RcUnknown,
+  /// Message is a replay (`KRB5_RC_REPLAY`)
+  RcReplay,
+  /// Replay cache I/O operation failed (`KRB5_RC_IO`)
+  RcIo,
+  /// Replay cache type does not support non-volatile storage (`KRB5_RC_NOIO`)
+  RcNoio,
+  /// Replay cache name parse/format error (`KRB5_RC_PARSE`)
+  RcParse,
+  /// End-of-file on replay cache I/O (`KRB5_RC_IO_EOF`)
+  RcIoEof,
+  /// No more memory to allocate (in replay cache I/O code) (`KRB5_RC_IO_MALLOC`)
+  RcIoMalloc,
+  /// Permission denied in replay cache code (`KRB5_RC_IO_PERM`)
+  RcIoPerm,
+  /// I/O error in replay cache i/o code (`KRB5_RC_IO_IO`)
+  RcIoIo,
+  /// Generic unknown RC/IO error (`KRB5_RC_IO_UNKNOWN`)
+  RcIoUnknown,
+  /// Insufficient system space to store replay information (`KRB5_RC_IO_SPACE`)
+  RcIoSpace,
+  /// Can't open/find realm translation file (`KRB5_TRANS_CANTOPEN`)
+  TransCantopen,
+  /// Improper format of realm translation file (`KRB5_TRANS_BADFORMAT`)
+  TransBadformat,
+  /// Can't open/find lname translation database (`KRB5_LNAME_CANTOPEN`)
+  LnameCantopen,
+  /// No translation available for requested principal (`KRB5_LNAME_NOTRANS`)
+  LnameNotrans,
+  /// Improper format of translation database entry (`KRB5_LNAME_BADFORMAT`)
+  LnameBadformat,
+  /// Cryptosystem internal error (`KRB5_CRYPTO_INTERNAL`)
+  CryptoInternal,
+  /// Key table name malformed (`KRB5_KT_BADNAME`)
+  KtBadname,
+  /// Unknown Key table type (`KRB5_KT_UNKNOWN_TYPE`)
+  KtUnknownType,
+  /// Key table entry not found (`KRB5_KT_NOTFOUND`)
+  KtNotfound,
+  /// End of key table reached (`KRB5_KT_END`)
+  KtEnd,
+  /// Cannot write to specified key table (`KRB5_KT_NOWRITE`)
+  KtNowrite,
+  /// Error writing to key table (`KRB5_KT_IOERR`)
+  KtIoerr,
+  /// Cannot find ticket for requested realm (`KRB5_NO_TKT_IN_RLM`)
+  NoTktInRlm,
+  /// DES key has bad parity (`KRB5DES_BAD_KEYPAR`)
+  BadKeypar,
+  /// DES key is a weak key (`KRB5DES_WEAK_KEY`)
+  WeakKey,
+  /// Bad encryption type (`KRB5_BAD_ENCTYPE`)
+  BadEnctype,
+  /// Key size is incompatible with encryption type (`KRB5_BAD_KEYSIZE`)
+  BadKeysize,
+  /// Message size is incompatible with encryption type (`KRB5_BAD_MSIZE`)
+  BadMsize,
+  /// Credentials cache type is already registered. (`KRB5_CC_TYPE_EXISTS`)
+  CcTypeExists,
+  /// Key table type is already registered. (`KRB5_KT_TYPE_EXISTS`)
+  KtTypeExists,
+  /// Credentials cache I/O operation failed (`KRB5_CC_IO`)
+  CcIo,
+  /// Credentials cache permissions incorrect (`KRB5_FCC_PERM`)
+  FccPerm,
+  /// No credentials cache found (`KRB5_FCC_NOFILE`)
+  FccNofile,
+  /// Internal credentials cache error (`KRB5_FCC_INTERNAL`)
+  FccInternal,
+  /// Error writing to credentials cache (`KRB5_CC_WRITE`)
+  CcWrite,
+  /// No more memory to allocate (in credentials cache code) (`KRB5_CC_NOMEM`)
+  CcNomem,
+  /// Bad format in credentials cache (`KRB5_CC_FORMAT`)
+  CcFormat,
+  /// No credentials found with supported encryption types (`KRB5_CC_NOT_KTYPE`)
+  CcNotKtype,
+  /// Invalid KDC option combination (library internal error) (`KRB5_INVALID_FLAGS`)
+  InvalidFlags,
+  /// Request missing second ticket (`KRB5_NO_2ND_TKT`)
+  No2ndTkt,
+  /// No credentials supplied to library routine (`KRB5_NOCREDS_SUPPLIED`)
+  NocredsSupplied,
+  /// Bad sendauth version was sent (`KRB5_SENDAUTH_BADAUTHVERS`)
+  SendauthBadauthvers,
+  /// Bad application version was sent (via sendauth) (`KRB5_SENDAUTH_BADAPPLVERS`)
+  SendauthBadapplvers,
+  /// Bad response (during sendauth exchange) (`KRB5_SENDAUTH_BADRESPONSE`)
+  SendauthBadresponse,
+  /// Server rejected authentication (during sendauth exchange) (`KRB5_SENDAUTH_REJECTED`)
+  SendauthRejected,
+  /// Unsupported preauthentication type (`KRB5_PREAUTH_BAD_TYPE`)
+  PreauthBadType,
+  /// Required preauthentication key not supplied (`KRB5_PREAUTH_NO_KEY`)
+  PreauthNoKey,
+  /// Generic preauthentication failure (`KRB5_PREAUTH_FAILED`)
+  ClientPreauthFailed,
+  /// Unsupported replay cache format version number (`KRB5_RCACHE_BADVNO`)
+  RcacheBadvno,
+  /// Unsupported credentials cache format version number (`KRB5_CCACHE_BADVNO`)
+  CcacheBadvno,
+  /// Unsupported key table format version number (`KRB5_KEYTAB_BADVNO`)
+  KeytabBadvno,
+  /// Program lacks support for address type (`KRB5_PROG_ATYPE_NOSUPP`)
+  ProgAtypeNosupp,
+  /// Message replay detection requires rcache parameter (`KRB5_RC_REQUIRED`)
+  RcRequired,
+  /// Hostname cannot be canonicalized (`KRB5_ERR_BAD_HOSTNAME`)
+  ErrBadHostname,
+  /// Cannot determine realm for host (`KRB5_ERR_HOST_REALM_UNKNOWN`)
+  ErrHostRealmUnknown,
+  /// Conversion to service principal undefined for name type (`KRB5_SNAME_UNSUPP_NAMETYPE`)
+  SnameUnsuppNametype,
+  /// Initial Ticket response appears to be Version 4 error (`KRB5KRB_AP_ERR_V4_REPLY`)
+  V4Reply,
+  /// Cannot resolve network address for KDC in requested realm (`KRB5_REALM_CANT_RESOLVE`)
+  RealmCantResolve,
+  /// Requesting ticket can't get forwardable tickets (`KRB5_TKT_NOT_FORWARDABLE`)
+  TktNotForwardable,
+  /// Bad principal name while trying to forward credentials (`KRB5_FWD_BAD_PRINCIPAL`)
+  FwdBadPrincipal,
+  /// Looping detected inside krb5_get_in_tkt (`KRB5_GET_IN_TKT_LOOP`)
+  GetInTktLoop,
+  /// Configuration file does not specify default realm (`KRB5_CONFIG_NODEFREALM`)
+  ConfigNodefrealm,
+  /// Bad SAM flags in obtain_sam_padata (`KRB5_SAM_UNSUPPORTED`)
+  SamUnsupported,
+  /// Invalid encryption type in SAM challenge (`KRB5_SAM_INVALID_ETYPE`)
+  SamInvalidEtype,
+  /// Missing checksum in SAM challenge (`KRB5_SAM_NO_CHECKSUM`)
+  SamNoChecksum,
+  /// Bad checksum in SAM challenge (`KRB5_SAM_BAD_CHECKSUM`)
+  SamBadChecksum,
+  /// Keytab name too long (`KRB5_KT_NAME_TOOLONG`)
+  KtNameToolong,
+  /// Key version number for principal in key table is incorrect (`KRB5_KT_KVNONOTFOUND`)
+  KtKvnonotfound,
+  /// This application has expired (`KRB5_APPL_EXPIRED`)
+  ApplExpired,
+  /// This Krb5 library has expired (`KRB5_LIB_EXPIRED`)
+  LibExpired,
+  /// New password cannot be zero length (`KRB5_CHPW_PWDNULL`)
+  ChpwPwdnull,
+  /// Password change failed (`KRB5_CHPW_FAIL`)
+  ChpwFail,
+  /// Bad format in keytab (`KRB5_KT_FORMAT`)
+  KtFormat,
+  /// Encryption type not permitted (`KRB5_NOPERM_ETYPE`)
+  NopermEtype,
+  /// No supported encryption types (config file error?) (`KRB5_CONFIG_ETYPE_NOSUPP`)
+  ConfigEtypeNosupp,
+  /// Program called an obsolete, deleted function (`KRB5_OBSOLETE_FN`)
+  ObsoleteFn,
+  /// unknown getaddrinfo failure (`KRB5_EAI_FAIL`)
+  EaiFail,
+  /// no data available for host/domain name (`KRB5_EAI_NODATA`)
+  EaiNodata,
+  /// host/domain name not found (`KRB5_EAI_NONAME`)
+  EaiNoname,
+  /// service name unknown (`KRB5_EAI_SERVICE`)
+  EaiService,
+  /// Cannot determine realm for numeric host address (`KRB5_ERR_NUMERIC_REALM`)
+  ErrNumericRealm,
+  /// Invalid key generation parameters from KDC (`KRB5_ERR_BAD_S2K_PARAMS`)
+  ErrBadS2kParams,
+  /// service not available (`KRB5_ERR_NO_SERVICE`)
+  ErrNoService,
+  /// Ccache function not supported: read-only ccache type (`KRB5_CC_READONLY`)
+  CcReadonly,
+  /// Ccache function not supported: not implemented (`KRB5_CC_NOSUPP`)
+  CcNosupp,
+  /// Invalid format of Kerberos lifetime or clock skew string (`KRB5_DELTAT_BADFORMAT`)
+  DeltatBadformat,
+  /// Supplied data not handled by this plugin (`KRB5_PLUGIN_NO_HANDLE`)
+  PluginNoHandle,
+  /// Plugin does not support the operation (`KRB5_PLUGIN_OP_NOTSUPP`)
+  PluginOpNotsupp,
+  /// Invalid UTF-8 string (`KRB5_ERR_INVALID_UTF8`)
+  ErrInvalidUtf8,
+  /// FAST protected pre-authentication required but not supported by KDC (`KRB5_ERR_FAST_REQUIRED`)
+  ErrFastRequired,
+  /// Auth context must contain local address (`KRB5_LOCAL_ADDR_REQUIRED`)
+  LocalAddrRequired,
+  /// Auth context must contain remote address (`KRB5_REMOTE_ADDR_REQUIRED`)
+  RemoteAddrRequired,
+  /// Tracing unsupported (`KRB5_TRACE_NOSUPP`)
+  TraceNosupp,
+  /// any other error code
+  Unknown(krb5_error_code),
+}
+
+/// The error codes known to [Krb5ErrorCode] and their numbers.
+const ERROR_CODES: [(Krb5ErrorCode, krb5_error_code); 208] = [
+  (Krb5ErrorCode::NoError, KRB5KDC_ERR_NONE),
+  (Krb5ErrorCode::NameExp, KRB5KDC_ERR_NAME_EXP),
+  (Krb5ErrorCode::ServiceExp, KRB5KDC_ERR_SERVICE_EXP),
+  (Krb5ErrorCode::BadPvno, KRB5KDC_ERR_BAD_PVNO),
+  (Krb5ErrorCode::COldMastKvno, KRB5KDC_ERR_C_OLD_MAST_KVNO),
+  (Krb5ErrorCode::SOldMastKvno, KRB5KDC_ERR_S_OLD_MAST_KVNO),
+  (Krb5ErrorCode::CPrincipalUnknown, KRB5KDC_ERR_C_PRINCIPAL_UNKNOWN),
+  (Krb5ErrorCode::SPrincipalUnknown, KRB5KDC_ERR_S_PRINCIPAL_UNKNOWN),
+  (Krb5ErrorCode::PrincipalNotUnique, KRB5KDC_ERR_PRINCIPAL_NOT_UNIQUE),
+  (Krb5ErrorCode::NullKey, KRB5KDC_ERR_NULL_KEY),
+  (Krb5ErrorCode::CannotPostdate, KRB5KDC_ERR_CANNOT_POSTDATE),
+  (Krb5ErrorCode::NeverValid, KRB5KDC_ERR_NEVER_VALID),
+  (Krb5ErrorCode::Policy, KRB5KDC_ERR_POLICY),
+  (Krb5ErrorCode::Badoption, KRB5KDC_ERR_BADOPTION),
+  (Krb5ErrorCode::EtypeNosupp, KRB5KDC_ERR_ETYPE_NOSUPP),
+  (Krb5ErrorCode::SumtypeNosupp, KRB5KDC_ERR_SUMTYPE_NOSUPP),
+  (Krb5ErrorCode::PadataTypeNosupp, KRB5KDC_ERR_PADATA_TYPE_NOSUPP),
+  (Krb5ErrorCode::TrtypeNosupp, KRB5KDC_ERR_TRTYPE_NOSUPP),
+  (Krb5ErrorCode::ClientRevoked, KRB5KDC_ERR_CLIENT_REVOKED),
+  (Krb5ErrorCode::ServiceRevoked, KRB5KDC_ERR_SERVICE_REVOKED),
+  (Krb5ErrorCode::TgtRevoked, KRB5KDC_ERR_TGT_REVOKED),
+  (Krb5ErrorCode::ClientNotyet, KRB5KDC_ERR_CLIENT_NOTYET),
+  (Krb5ErrorCode::ServiceNotyet, KRB5KDC_ERR_SERVICE_NOTYET),
+  (Krb5ErrorCode::KeyExp, KRB5KDC_ERR_KEY_EXP),
+  (Krb5ErrorCode::PreauthFailed, KRB5KDC_ERR_PREAUTH_FAILED),
+  (Krb5ErrorCode::PreauthRequired, KRB5KDC_ERR_PREAUTH_REQUIRED),
+  (Krb5ErrorCode::ServerNomatch, KRB5KDC_ERR_SERVER_NOMATCH),
+  (Krb5ErrorCode::MustUseUser2user, KRB5KDC_ERR_MUST_USE_USER2USER),
+  (Krb5ErrorCode::PathNotAccepted, KRB5KDC_ERR_PATH_NOT_ACCEPTED),
+  (Krb5ErrorCode::SvcUnavailable, KRB5KDC_ERR_SVC_UNAVAILABLE),
+  (Krb5ErrorCode::BadIntegrity, KRB5KRB_AP_ERR_BAD_INTEGRITY),
+  (Krb5ErrorCode::TktExpired, KRB5KRB_AP_ERR_TKT_EXPIRED),
+  (Krb5ErrorCode::TktNyv, KRB5KRB_AP_ERR_TKT_NYV),
+  (Krb5ErrorCode::Repeat, KRB5KRB_AP_ERR_REPEAT),
+  (Krb5ErrorCode::NotUs, KRB5KRB_AP_ERR_NOT_US),
+  (Krb5ErrorCode::Badmatch, KRB5KRB_AP_ERR_BADMATCH),
+  (Krb5ErrorCode::Skew, KRB5KRB_AP_ERR_SKEW),
+  (Krb5ErrorCode::Badaddr, KRB5KRB_AP_ERR_BADADDR),
+  (Krb5ErrorCode::Badversion, KRB5KRB_AP_ERR_BADVERSION),
+  (Krb5ErrorCode::MsgType, KRB5KRB_AP_ERR_MSG_TYPE),
+  (Krb5ErrorCode::Modified, KRB5KRB_AP_ERR_MODIFIED),
+  (Krb5ErrorCode::Badorder, KRB5KRB_AP_ERR_BADORDER),
+  (Krb5ErrorCode::IllCrTkt, KRB5KRB_AP_ERR_ILL_CR_TKT),
+  (Krb5ErrorCode::Badkeyver, KRB5KRB_AP_ERR_BADKEYVER),
+  (Krb5ErrorCode::Nokey, KRB5KRB_AP_ERR_NOKEY),
+  (Krb5ErrorCode::MutFail, KRB5KRB_AP_ERR_MUT_FAIL),
+  (Krb5ErrorCode::Baddirection, KRB5KRB_AP_ERR_BADDIRECTION),
+  (Krb5ErrorCode::Method, KRB5KRB_AP_ERR_METHOD),
+  (Krb5ErrorCode::Badseq, KRB5KRB_AP_ERR_BADSEQ),
+  (Krb5ErrorCode::InappCksum, KRB5KRB_AP_ERR_INAPP_CKSUM),
+  (Krb5ErrorCode::ApPathNotAccepted, KRB5KRB_AP_PATH_NOT_ACCEPTED),
+  (Krb5ErrorCode::ResponseTooBig, KRB5KRB_ERR_RESPONSE_TOO_BIG),
+  (Krb5ErrorCode::Generic, KRB5KRB_ERR_GENERIC),
+  (Krb5ErrorCode::FieldToolong, KRB5KRB_ERR_FIELD_TOOLONG),
+  (Krb5ErrorCode::ClientNotTrusted, KRB5KDC_ERR_CLIENT_NOT_TRUSTED),
+  (Krb5ErrorCode::KdcNotTrusted, KRB5KDC_ERR_KDC_NOT_TRUSTED),
+  (Krb5ErrorCode::InvalidSig, KRB5KDC_ERR_INVALID_SIG),
+  (
+    Krb5ErrorCode::DhKeyParametersNotAccepted,
+    KRB5KDC_ERR_DH_KEY_PARAMETERS_NOT_ACCEPTED,
+  ),
+  (Krb5ErrorCode::CertificateMismatch, KRB5KDC_ERR_CERTIFICATE_MISMATCH),
+  (Krb5ErrorCode::NoTgt, KRB5KRB_AP_ERR_NO_TGT),
+  (Krb5ErrorCode::WrongRealm, KRB5KDC_ERR_WRONG_REALM),
+  (Krb5ErrorCode::UserToUserRequired, KRB5KRB_AP_ERR_USER_TO_USER_REQUIRED),
+  (
+    Krb5ErrorCode::CantVerifyCertificate,
+    KRB5KDC_ERR_CANT_VERIFY_CERTIFICATE,
+  ),
+  (Krb5ErrorCode::InvalidCertificate, KRB5KDC_ERR_INVALID_CERTIFICATE),
+  (Krb5ErrorCode::RevokedCertificate, KRB5KDC_ERR_REVOKED_CERTIFICATE),
+  (
+    Krb5ErrorCode::RevocationStatusUnknown,
+    KRB5KDC_ERR_REVOCATION_STATUS_UNKNOWN,
+  ),
+  (
+    Krb5ErrorCode::RevocationStatusUnavailable,
+    KRB5KDC_ERR_REVOCATION_STATUS_UNAVAILABLE,
+  ),
+  (Krb5ErrorCode::ClientNameMismatch, KRB5KDC_ERR_CLIENT_NAME_MISMATCH),
+  (Krb5ErrorCode::KdcNameMismatch, KRB5KDC_ERR_KDC_NAME_MISMATCH),
+  (
+    Krb5ErrorCode::InconsistentKeyPurpose,
+    KRB5KDC_ERR_INCONSISTENT_KEY_PURPOSE,
+  ),
+  (
+    Krb5ErrorCode::DigestInCertNotAccepted,
+    KRB5KDC_ERR_DIGEST_IN_CERT_NOT_ACCEPTED,
+  ),
+  (
+    Krb5ErrorCode::PaChecksumMustBeIncluded,
+    KRB5KDC_ERR_PA_CHECKSUM_MUST_BE_INCLUDED,
+  ),
+  (
+    Krb5ErrorCode::DigestInSignedDataNotAccepted,
+    KRB5KDC_ERR_DIGEST_IN_SIGNED_DATA_NOT_ACCEPTED,
+  ),
+  (
+    Krb5ErrorCode::PublicKeyEncryptionNotSupported,
+    KRB5KDC_ERR_PUBLIC_KEY_ENCRYPTION_NOT_SUPPORTED,
+  ),
+  (Krb5ErrorCode::IakerbKdcNotFound, KRB5KRB_AP_ERR_IAKERB_KDC_NOT_FOUND),
+  (
+    Krb5ErrorCode::IakerbKdcNoResponse,
+    KRB5KRB_AP_ERR_IAKERB_KDC_NO_RESPONSE,
+  ),
+  (Krb5ErrorCode::PreauthExpired, KRB5KDC_ERR_PREAUTH_EXPIRED),
+  (
+    Krb5ErrorCode::MorePreauthDataRequired,
+    KRB5KDC_ERR_MORE_PREAUTH_DATA_REQUIRED,
+  ),
+  (
+    Krb5ErrorCode::UnknownCriticalFastOption,
+    KRB5KDC_ERR_UNKNOWN_CRITICAL_FAST_OPTION,
+  ),
+  (Krb5ErrorCode::NoAcceptableKdf, KRB5KDC_ERR_NO_ACCEPTABLE_KDF),
+  (Krb5ErrorCode::ErrRcsid, KRB5_ERR_RCSID),
+  (Krb5ErrorCode::LibosBadlockflag, KRB5_LIBOS_BADLOCKFLAG),
+  (Krb5ErrorCode::LibosCantreadpwd, KRB5_LIBOS_CANTREADPWD),
+  (Krb5ErrorCode::LibosBadpwdmatch, KRB5_LIBOS_BADPWDMATCH),
+  (Krb5ErrorCode::LibosPwdintr, KRB5_LIBOS_PWDINTR),
+  (Krb5ErrorCode::ParseIllchar, KRB5_PARSE_ILLCHAR),
+  (Krb5ErrorCode::ParseMalformed, KRB5_PARSE_MALFORMED),
+  (Krb5ErrorCode::ConfigCantopen, KRB5_CONFIG_CANTOPEN),
+  (Krb5ErrorCode::ConfigBadformat, KRB5_CONFIG_BADFORMAT),
+  (Krb5ErrorCode::ConfigNotenufspace, KRB5_CONFIG_NOTENUFSPACE),
+  (Krb5ErrorCode::Badmsgtype, KRB5_BADMSGTYPE),
+  (Krb5ErrorCode::CcBadname, KRB5_CC_BADNAME),
+  (Krb5ErrorCode::CcUnknownType, KRB5_CC_UNKNOWN_TYPE),
+  (Krb5ErrorCode::CcNotfound, KRB5_CC_NOTFOUND),
+  (Krb5ErrorCode::CcEnd, KRB5_CC_END),
+  (Krb5ErrorCode::NoTktSupplied, KRB5_NO_TKT_SUPPLIED),
+  (Krb5ErrorCode::WrongPrinc, KRB5KRB_AP_WRONG_PRINC),
+  (Krb5ErrorCode::TktInvalid, KRB5KRB_AP_ERR_TKT_INVALID),
+  (Krb5ErrorCode::PrincNomatch, KRB5_PRINC_NOMATCH),
+  (Krb5ErrorCode::KdcrepModified, KRB5_KDCREP_MODIFIED),
+  (Krb5ErrorCode::KdcrepSkew, KRB5_KDCREP_SKEW),
+  (Krb5ErrorCode::InTktRealmMismatch, KRB5_IN_TKT_REALM_MISMATCH),
+  (Krb5ErrorCode::ProgEtypeNosupp, KRB5_PROG_ETYPE_NOSUPP),
+  (Krb5ErrorCode::ProgKeytypeNosupp, KRB5_PROG_KEYTYPE_NOSUPP),
+  (Krb5ErrorCode::WrongEtype, KRB5_WRONG_ETYPE),
+  (Krb5ErrorCode::ProgSumtypeNosupp, KRB5_PROG_SUMTYPE_NOSUPP),
+  (Krb5ErrorCode::RealmUnknown, KRB5_REALM_UNKNOWN),
+  (Krb5ErrorCode::ServiceUnknown, KRB5_SERVICE_UNKNOWN),
+  (Krb5ErrorCode::KdcUnreach, KRB5_KDC_UNREACH),
+  (Krb5ErrorCode::NoLocalname, KRB5_NO_LOCALNAME),
+  (Krb5ErrorCode::MutualFailed, KRB5_MUTUAL_FAILED),
+  (Krb5ErrorCode::RcTypeExists, KRB5_RC_TYPE_EXISTS),
+  (Krb5ErrorCode::RcMalloc, KRB5_RC_MALLOC),
+  (Krb5ErrorCode::RcTypeNotfound, KRB5_RC_TYPE_NOTFOUND),
+  (Krb5ErrorCode::RcUnknown, KRB5_RC_UNKNOWN),
+  (Krb5ErrorCode::RcReplay, KRB5_RC_REPLAY),
+  (Krb5ErrorCode::RcIo, KRB5_RC_IO),
+  (Krb5ErrorCode::RcNoio, KRB5_RC_NOIO),
+  (Krb5ErrorCode::RcParse, KRB5_RC_PARSE),
+  (Krb5ErrorCode::RcIoEof, KRB5_RC_IO_EOF),
+  (Krb5ErrorCode::RcIoMalloc, KRB5_RC_IO_MALLOC),
+  (Krb5ErrorCode::RcIoPerm, KRB5_RC_IO_PERM),
+  (Krb5ErrorCode::RcIoIo, KRB5_RC_IO_IO),
+  (Krb5ErrorCode::RcIoUnknown, KRB5_RC_IO_UNKNOWN),
+  (Krb5ErrorCode::RcIoSpace, KRB5_RC_IO_SPACE),
+  (Krb5ErrorCode::TransCantopen, KRB5_TRANS_CANTOPEN),
+  (Krb5ErrorCode::TransBadformat, KRB5_TRANS_BADFORMAT),
+  (Krb5ErrorCode::LnameCantopen, KRB5_LNAME_CANTOPEN),
+  (Krb5ErrorCode::LnameNotrans, KRB5_LNAME_NOTRANS),
+  (Krb5ErrorCode::LnameBadformat, KRB5_LNAME_BADFORMAT),
+  (Krb5ErrorCode::CryptoInternal, KRB5_CRYPTO_INTERNAL),
+  (Krb5ErrorCode::KtBadname, KRB5_KT_BADNAME),
+  (Krb5ErrorCode::KtUnknownType, KRB5_KT_UNKNOWN_TYPE),
+  (Krb5ErrorCode::KtNotfound, KRB5_KT_NOTFOUND),
+  (Krb5ErrorCode::KtEnd, KRB5_KT_END),
+  (Krb5ErrorCode::KtNowrite, KRB5_KT_NOWRITE),
+  (Krb5ErrorCode::KtIoerr, KRB5_KT_IOERR),
+  (Krb5ErrorCode::NoTktInRlm, KRB5_NO_TKT_IN_RLM),
+  (Krb5ErrorCode::BadKeypar, KRB5DES_BAD_KEYPAR),
+  (Krb5ErrorCode::WeakKey, KRB5DES_WEAK_KEY),
+  (Krb5ErrorCode::BadEnctype, KRB5_BAD_ENCTYPE),
+  (Krb5ErrorCode::BadKeysize, KRB5_BAD_KEYSIZE),
+  (Krb5ErrorCode::BadMsize, KRB5_BAD_MSIZE),
+  (Krb5ErrorCode::CcTypeExists, KRB5_CC_TYPE_EXISTS),
+  (Krb5ErrorCode::KtTypeExists, KRB5_KT_TYPE_EXISTS),
+  (Krb5ErrorCode::CcIo, KRB5_CC_IO),
+  (Krb5ErrorCode::FccPerm, KRB5_FCC_PERM),
+  (Krb5ErrorCode::FccNofile, KRB5_FCC_NOFILE),
+  (Krb5ErrorCode::FccInternal, KRB5_FCC_INTERNAL),
+  (Krb5ErrorCode::CcWrite, KRB5_CC_WRITE),
+  (Krb5ErrorCode::CcNomem, KRB5_CC_NOMEM),
+  (Krb5ErrorCode::CcFormat, KRB5_CC_FORMAT),
+  (Krb5ErrorCode::CcNotKtype, KRB5_CC_NOT_KTYPE),
+  (Krb5ErrorCode::InvalidFlags, KRB5_INVALID_FLAGS),
+  (Krb5ErrorCode::No2ndTkt, KRB5_NO_2ND_TKT),
+  (Krb5ErrorCode::NocredsSupplied, KRB5_NOCREDS_SUPPLIED),
+  (Krb5ErrorCode::SendauthBadauthvers, KRB5_SENDAUTH_BADAUTHVERS),
+  (Krb5ErrorCode::SendauthBadapplvers, KRB5_SENDAUTH_BADAPPLVERS),
+  (Krb5ErrorCode::SendauthBadresponse, KRB5_SENDAUTH_BADRESPONSE),
+  (Krb5ErrorCode::SendauthRejected, KRB5_SENDAUTH_REJECTED),
+  (Krb5ErrorCode::PreauthBadType, KRB5_PREAUTH_BAD_TYPE),
+  (Krb5ErrorCode::PreauthNoKey, KRB5_PREAUTH_NO_KEY),
+  (Krb5ErrorCode::ClientPreauthFailed, KRB5_PREAUTH_FAILED),
+  (Krb5ErrorCode::RcacheBadvno, KRB5_RCACHE_BADVNO),
+  (Krb5ErrorCode::CcacheBadvno, KRB5_CCACHE_BADVNO),
+  (Krb5ErrorCode::KeytabBadvno, KRB5_KEYTAB_BADVNO),
+  (Krb5ErrorCode::ProgAtypeNosupp, KRB5_PROG_ATYPE_NOSUPP),
+  (Krb5ErrorCode::RcRequired, KRB5_RC_REQUIRED),
+  (Krb5ErrorCode::ErrBadHostname, KRB5_ERR_BAD_HOSTNAME),
+  (Krb5ErrorCode::ErrHostRealmUnknown, KRB5_ERR_HOST_REALM_UNKNOWN),
+  (Krb5ErrorCode::SnameUnsuppNametype, KRB5_SNAME_UNSUPP_NAMETYPE),
+  (Krb5ErrorCode::V4Reply, KRB5KRB_AP_ERR_V4_REPLY),
+  (Krb5ErrorCode::RealmCantResolve, KRB5_REALM_CANT_RESOLVE),
+  (Krb5ErrorCode::TktNotForwardable, KRB5_TKT_NOT_FORWARDABLE),
+  (Krb5ErrorCode::FwdBadPrincipal, KRB5_FWD_BAD_PRINCIPAL),
+  (Krb5ErrorCode::GetInTktLoop, KRB5_GET_IN_TKT_LOOP),
+  (Krb5ErrorCode::ConfigNodefrealm, KRB5_CONFIG_NODEFREALM),
+  (Krb5ErrorCode::SamUnsupported, KRB5_SAM_UNSUPPORTED),
+  (Krb5ErrorCode::SamInvalidEtype, KRB5_SAM_INVALID_ETYPE),
+  (Krb5ErrorCode::SamNoChecksum, KRB5_SAM_NO_CHECKSUM),
+  (Krb5ErrorCode::SamBadChecksum, KRB5_SAM_BAD_CHECKSUM),
+  (Krb5ErrorCode::KtNameToolong, KRB5_KT_NAME_TOOLONG),
+  (Krb5ErrorCode::KtKvnonotfound, KRB5_KT_KVNONOTFOUND),
+  (Krb5ErrorCode::ApplExpired, KRB5_APPL_EXPIRED),
+  (Krb5ErrorCode::LibExpired, KRB5_LIB_EXPIRED),
+  (Krb5ErrorCode::ChpwPwdnull, KRB5_CHPW_PWDNULL),
+  (Krb5ErrorCode::ChpwFail, KRB5_CHPW_FAIL),
+  (Krb5ErrorCode::KtFormat, KRB5_KT_FORMAT),
+  (Krb5ErrorCode::NopermEtype, KRB5_NOPERM_ETYPE),
+  (Krb5ErrorCode::ConfigEtypeNosupp, KRB5_CONFIG_ETYPE_NOSUPP),
+  (Krb5ErrorCode::ObsoleteFn, KRB5_OBSOLETE_FN),
+  (Krb5ErrorCode::EaiFail, KRB5_EAI_FAIL),
+  (Krb5ErrorCode::EaiNodata, KRB5_EAI_NODATA),
+  (Krb5ErrorCode::EaiNoname, KRB5_EAI_NONAME),
+  (Krb5ErrorCode::EaiService, KRB5_EAI_SERVICE),
+  (Krb5ErrorCode::ErrNumericRealm, KRB5_ERR_NUMERIC_REALM),
+  (Krb5ErrorCode::ErrBadS2kParams, KRB5_ERR_BAD_S2K_PARAMS),
+  (Krb5ErrorCode::ErrNoService, KRB5_ERR_NO_SERVICE),
+  (Krb5ErrorCode::CcReadonly, KRB5_CC_READONLY),
+  (Krb5ErrorCode::CcNosupp, KRB5_CC_NOSUPP),
+  (Krb5ErrorCode::DeltatBadformat, KRB5_DELTAT_BADFORMAT),
+  (Krb5ErrorCode::PluginNoHandle, KRB5_PLUGIN_NO_HANDLE),
+  (Krb5ErrorCode::PluginOpNotsupp, KRB5_PLUGIN_OP_NOTSUPP),
+  (Krb5ErrorCode::ErrInvalidUtf8, KRB5_ERR_INVALID_UTF8),
+  (Krb5ErrorCode::ErrFastRequired, KRB5_ERR_FAST_REQUIRED),
+  (Krb5ErrorCode::LocalAddrRequired, KRB5_LOCAL_ADDR_REQUIRED),
+  (Krb5ErrorCode::RemoteAddrRequired, KRB5_REMOTE_ADDR_REQUIRED),
+  (Krb5ErrorCode::TraceNosupp, KRB5_TRACE_NOSUPP),
+];
+
+/**
+ * Raw values of commonly matched error codes.
+ *
+ * These can be compared with [Krb5Error::raw_code](crate::Krb5Error::raw_code) or passed to
+ * [Krb5Error::is](crate::Krb5Error::is) without depending on `libkrb5-sys`. All other codes are
+ * available through [Krb5ErrorCode].
+ */
+pub mod codes {
+  pub use libkrb5_sys::{
+    krb5_error_code,
+    KRB5KDC_ERR_CLIENT_REVOKED,
+    KRB5KDC_ERR_C_PRINCIPAL_UNKNOWN,
+    KRB5KDC_ERR_ETYPE_NOSUPP,
+    KRB5KDC_ERR_KEY_EXP,
+    KRB5KDC_ERR_POLICY,
+    KRB5KDC_ERR_PREAUTH_FAILED,
+    KRB5KDC_ERR_PREAUTH_REQUIRED,
+    KRB5KDC_ERR_SVC_UNAVAILABLE,
+    KRB5KDC_ERR_S_PRINCIPAL_UNKNOWN,
+    KRB5KRB_AP_ERR_BADMATCH,
+    KRB5KRB_AP_ERR_BAD_INTEGRITY,
+    KRB5KRB_AP_ERR_MODIFIED,
+    KRB5KRB_AP_ERR_NOKEY,
+    KRB5KRB_AP_ERR_REPEAT,
+    KRB5KRB_AP_ERR_SKEW,
+    KRB5KRB_AP_ERR_TKT_EXPIRED,
+    KRB5KRB_AP_WRONG_PRINC,
+    KRB5_CC_END,
+    KRB5_CC_FORMAT,
+    KRB5_CC_NOTFOUND,
+    KRB5_DELTAT_BADFORMAT,
+    KRB5_FCC_NOFILE,
+    KRB5_FCC_PERM,
+    KRB5_KDCREP_SKEW,
+    KRB5_KDC_UNREACH,
+    KRB5_KT_END,
+    KRB5_KT_KVNONOTFOUND,
+    KRB5_KT_NOTFOUND,
+    KRB5_PREAUTH_FAILED,
+    KRB5_REALM_CANT_RESOLVE,
+    KRB5_REALM_UNKNOWN,
+  };
+}
+
+impl Krb5ErrorCode {
+  /**
+   * Convert a libkrb5 error code.
+   */
+  pub fn from_raw(code: krb5_error_code) -> Self {
+    ERROR_CODES
+      .iter()
+      .find(|(_, raw)| *raw == code)
+      .map(|(error_code, _)| *error_code)
+      .unwrap_or(Krb5ErrorCode::Unknown(code))
+  }
+
+  /**
+   * Return the libkrb5 error code.
+   */
+  pub fn as_raw(self) -> krb5_error_code {
+    if let Krb5ErrorCode::Unknown(code) = self {
+      return code;
+    }
+
+    ERROR_CODES
+      .iter()
+      .find(|(error_code, _)| *error_code == self)
+      .map(|(_, raw)| *raw)
+      .expect("all known error codes are listed in ERROR_CODES")
+  }
+}
+
+/**
+ * Display the message of the error code, see [error_message].
+ */
+impl Display for Krb5ErrorCode {
+  fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+    write!(f, "{}", error_message(self.as_raw()))
+  }
+}
+
+impl From<krb5_error_code> for Krb5ErrorCode {
+  fn from(code: krb5_error_code) -> Self {
+    Krb5ErrorCode::from_raw(code)
+  }
+}
+
+impl From<Krb5ErrorCode> for krb5_error_code {
+  fn from(error_code: Krb5ErrorCode) -> Self {
+    error_code.as_raw()
+  }
+}
@@ -0,0 +1,259 @@
+/*!
+ * Rustic wrapper for KRB-ERROR protocol messages.
+ */
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::mem::MaybeUninit;
+
+use libkrb5_sys::*;
+
+use crate::context::Krb5Context;
+use crate::data::{krb5_data_to_vec, slice_to_krb5_data, Krb5Data};
+use crate::der::der_element;
+use crate::error::{krb5_error_code_escape_hatch, Krb5Error};
+use crate::principal::{copy_principal, Krb5Principal};
+
+/**
+ * A KRB-ERROR message, as returned by a KDC or an application server.
+ *
+ * https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/types/krb5_error.html
+ */
+#[derive(Debug)]
+pub struct Krb5ErrorMessage<'a> {
+  /// client time, if known
+  pub ctime: krb5_timestamp,
+  /// microseconds of the client time
+  pub cusec: krb5_int32,
+  /// server time
+  pub stime: krb5_timestamp,
+  /// microseconds of the server time
+  pub susec: krb5_int32,
+  /// protocol error code (`KDC_ERR_*` / `KRB_AP_ERR_*` from RFC 4120), see
+  /// [Krb5ErrorMessage::library_code]
+  pub error: krb5_ui_4,
+  /// client principal, if known
+  pub client: Option<Krb5Principal<'a>>,
+  /// server principal
+  pub server: Krb5Principal<'a>,
+  /// human readable error description
+  pub text: Option<String>,
+  /// additional error data, e.g. encoded PA-DATA
+  pub e_data: Vec<u8>,
+}
+
+impl<'a> Krb5ErrorMessage<'a> {
+  /**
+   * Return the libkrb5 error code (`KRB5KDC_ERR_*` / `KRB5KRB_AP_ERR_*`) corresponding to the
+   * protocol error code.
+   */
+  pub fn library_code(&self) -> krb5_error_code {
+    KRB5KDC_ERR_NONE + self.error as krb5_error_code
+  }
+
+  /**
+   * Encode the error as a KRB-ERROR message.
+   *
+   * [krb5_mk_error](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_mk_error.html)
+   *
+   * # Arguments
+   *
+   *  * context: the Krb5Context instance
+   */
+  pub fn mk_error(&self, context: &Krb5Context) -> Result<Vec<u8>, Krb5Error> {
+    let text = self.text.as_deref().unwrap_or("");
+
+    let dec_err = krb5_error {
+      magic: 0,
+      ctime: self.ctime,
+      cusec: self.cusec,
+      susec: self.susec,
+      stime: self.stime,
+      error: self.error,
+      client: match &self.client {
+        Some(client) => client.principal,
+        None => std::ptr::null_mut(),
+      },
+      server: self.server.principal,
+      text: slice_to_krb5_data(text.as_bytes()),
+      e_data: slice_to_krb5_data(&self.e_data),
+    };
+    let mut enc_err: MaybeUninit<krb5_data> = MaybeUninit::zeroed();
+
+    let code: krb5_error_code = unsafe { krb5_mk_error(context.context, &dec_err, enc_err.as_mut_ptr()) };
+
+    krb5_error_code_escape_hatch(context, code)?;
+
+    let message = Krb5Data::from_raw(context, unsafe { enc_err.assume_init() }).into_vec();
+
+    Ok(message)
+  }
+
+  /**
+   * Decode a KRB-ERROR message.
+   *
+   * [krb5_rd_error](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_rd_error.html)
+   *
+   * # Arguments
+   *
+   *  * context: the Krb5Context instance
+   *  * message: the encoded KRB-ERROR message
+   */
+  pub fn rd_error(context: &'a Krb5Context, message: &[u8]) -> Result<Krb5ErrorMessage<'a>, Krb5Error> {
+    let enc_errbuf = slice_to_krb5_data(message);
+    let mut dec_error: MaybeUninit<*mut krb5_error> = MaybeUninit::zeroed();
+
+    let code: krb5_error_code = unsafe { krb5_rd_error(context.context, &enc_errbuf, dec_error.as_mut_ptr()) };
+
+    krb5_error_code_escape_hatch(context, code)?;
+
+    let dec_error = unsafe { dec_error.assume_init() };
+    let result = Krb5ErrorMessage::from_raw(context, unsafe { &*dec_error });
+    unsafe { krb5_free_error(context.context, dec_error) };
+
+    result
+  }
+
+  /**
+   * Copy a decoded `krb5_error` structure.
+   */
+  fn from_raw(context: &'a Krb5Context, error: &krb5_error) -> Result<Krb5ErrorMessage<'a>, Krb5Error> {
+    let client = if error.client.is_null() {
+      None
+    } else {
+      Some(copy_principal(context, error.client)?)
+    };
+
+    Ok(Krb5ErrorMessage {
+      ctime: error.ctime,
+      cusec: error.cusec,
+      stime: error.stime,
+      susec: error.susec,
+      error: error.error,
+      client,
+      server: copy_principal(context, error.server)?,
+      text: error_text(error),
+      e_data: krb5_data_to_vec(&error.e_data),
+    })
+  }
+}
+
+/**
+ * The KRB-ERROR a KDC answered a failed request with, see [Krb5Error::KdcError].
+ *
+ * https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/types/krb5_error.html
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Krb5KdcError {
+  /// protocol error code (`KDC_ERR_*` from RFC 4120), see [Krb5KdcError::library_code]
+  pub error: krb5_ui_4,
+  /// server time
+  pub stime: krb5_timestamp,
+  /// human readable error description (e-text)
+  pub text: Option<String>,
+  /// additional error data (e-data), e.g. the preauthentication types accepted by the KDC
+  pub e_data: Vec<u8>,
+}
+
+impl Krb5KdcError {
+  /**
+   * Copy the relevant fields of a decoded `krb5_error` structure.
+   */
+  pub(crate) fn from_raw(error: &krb5_error) -> Self {
+    Krb5KdcError {
+      error: error.error,
+      stime: error.stime,
+      text: error_text(error),
+      e_data: krb5_data_to_vec(&error.e_data),
+    }
+  }
+
+  /**
+   * Return the libkrb5 error code (`KRB5KDC_ERR_*`) corresponding to the protocol error code.
+   */
+  pub fn library_code(&self) -> krb5_error_code {
+    KRB5KDC_ERR_NONE + self.error as krb5_error_code
+  }
+
+  /**
+   * Return the preauthentication types (`KRB5_PADATA_*`) listed in the e-data, which the KDC
+   * sends with `KDC_ERR_PREAUTH_REQUIRED` and `KDC_ERR_PREAUTH_FAILED`.
+   *
+   * Returns an empty list if the e-data is not an encoded METHOD-DATA sequence.
+   */
+  pub fn pa_types(&self) -> Vec<krb5_preauthtype> {
+    decode_method_data(&self.e_data).unwrap_or_default()
+  }
+}
+
+/**
+ * Display the protocol error code and the e-text, if any.
+ */
+impl Display for Krb5KdcError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+    match &self.text {
+      Some(text) => write!(f, "KRB-ERROR {}: {}", self.error, text),
+      None => write!(f, "KRB-ERROR {}", self.error),
+    }
+  }
+}
+
+impl Error for Krb5KdcError {}
+
+/**
+ * Return the text of a decoded `krb5_error` structure, if it has one.
+ */
+fn error_text(error: &krb5_error) -> Option<String> {
+  let text = krb5_data_to_vec(&error.text);
+
+  if text.is_empty() {
+    None
+  } else {
+    Some(String::from_utf8_lossy(&text).trim_end_matches('\0').to_string())
+  }
+}
+
+/**
+ * Decode the padata types of a DER encoded METHOD-DATA (RFC 4120, section 5.9.1):
+ *
+ * ```text
+ * METHOD-DATA ::= SEQUENCE OF PA-DATA
+ * PA-DATA ::= SEQUENCE {
+ *   padata-type  [1] Int32,
+ *   padata-value [2] OCTET STRING
+ * }
+ * ```
+ */
+fn decode_method_data(input: &[u8]) -> Option<Vec<krb5_preauthtype>> {
+  let (tag, mut sequence, rest) = der_element(input)?;
+  if tag != 0x30 || !rest.is_empty() {
+    return None;
+  }
+
+  let mut pa_types = Vec::new();
+  while !sequence.is_empty() {
+    let (tag, pa_data, rest) = der_element(sequence)?;
+    if tag != 0x30 {
+      return None;
+    }
+    sequence = rest;
+
+    let (tag, padata_type, _) = der_element(pa_data)?;
+    if tag != 0xa1 {
+      return None;
+    }
+
+    let (tag, integer, _) = der_element(padata_type)?;
+    if tag != 0x02 || integer.is_empty() || integer.len() > 4 {
+      return None;
+    }
+
+    // sign extend the two's complement big-endian integer
+    let initial: i32 = if integer[0] & 0x80 != 0 { -1 } else { 0 };
+    let value = integer
+      .iter()
+      .fold(initial, |value, byte| (value << 8) | i32::from(*byte));
+    pa_types.push(value);
+  }
+
+  Some(pa_types)
+}
@@ -0,0 +1,44 @@
+/*!
+ * The Kerberos implementation this crate is linked against.
+ */
+use std::fmt::{Display, Formatter};
+
+/**
+ * A Kerberos 5 implementation.
+ *
+ * Some wrappers are only available with MIT krb5, e.g. [crate::Krb5CCache::dup] and
+ * [crate::Krb5Context::set_kdc_send_hook]. They are gated with `cfg(krb5_mit)` at build time,
+ * and [Krb5Flavor::current] tells at run time which implementation was linked.
+ *
+ * New variants may be added, so matches need a wildcard arm.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Krb5Flavor {
+  /// MIT Kerberos 5
+  Mit,
+  /// Heimdal Kerberos
+  Heimdal,
+}
+
+impl Krb5Flavor {
+  /**
+   * Return the implementation libkrb5-sys linked, as detected by its build script.
+   */
+  pub fn current() -> Krb5Flavor {
+    if cfg!(krb5_heimdal) {
+      Krb5Flavor::Heimdal
+    } else {
+      Krb5Flavor::Mit
+    }
+  }
+}
+
+impl Display for Krb5Flavor {
+  fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+    match self {
+      Krb5Flavor::Mit => write!(f, "MIT"),
+      Krb5Flavor::Heimdal => write!(f, "Heimdal"),
+    }
+  }
+}
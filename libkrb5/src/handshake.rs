@@ -0,0 +1,417 @@
+/*!
+ * Kerberos authentication handshake over TCP streams.
+ *
+ * The client sends an AP request and the server answers with an AP reply (mutual
+ * authentication). All messages are framed with a 4 byte big endian length prefix. Afterwards,
+ * both sides hold a [KerberosSession] that protects application data with KRB-PRIV messages.
+ *
+ * With the `async` feature, the handshake can also be done over any tokio stream, e.g. a
+ * `tokio::net::TcpStream` or a TLS stream.
+ */
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpStream};
+
+#[cfg(feature = "async")]
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+use crate::auth_context::{Krb5ApOptions, Krb5AuthContext, Krb5AuthContextFlags};
+use crate::ccache::Krb5CCache;
+use crate::context::Krb5Context;
+use crate::data::Krb5Data;
+use crate::error::Krb5Error;
+use crate::keytab::Krb5Keytab;
+use crate::principal::Krb5Principal;
+
+/**
+ * Maximum size of a received frame, to bound the memory a peer can make us allocate.
+ */
+const MAX_FRAME_LENGTH: usize = 16 * 1024 * 1024;
+
+/**
+ * Client side of the handshake.
+ */
+#[derive(Debug)]
+pub struct KerberosClient<'a> {
+  context: &'a Krb5Context,
+  ccache: &'a Krb5CCache<'a>,
+  service: String,
+  hostname: String,
+}
+
+impl<'a> KerberosClient<'a> {
+  /**
+   * Create a client that authenticates to `service/hostname` with credentials from a credential
+   * cache.
+   *
+   * # Arguments
+   *
+   *  * context: the Krb5Context instance
+   *  * ccache: the credential cache holding the client's credentials
+   *  * service: the service name, e.g. `host`
+   *  * hostname: the host name of the service
+   */
+  pub fn new(context: &'a Krb5Context, ccache: &'a Krb5CCache<'a>, service: &str, hostname: &str) -> Self {
+    KerberosClient {
+      context,
+      ccache,
+      service: String::from(service),
+      hostname: String::from(hostname),
+    }
+  }
+
+  /**
+   * Authenticate to the server on the other end of a connected stream.
+   *
+   * # Arguments
+   *
+   *  * stream: the connection to the server
+   */
+  pub fn connect(&self, mut stream: TcpStream) -> Result<KerberosSession<'a>, Krb5Error> {
+    let mut auth_context = new_session_auth_context(self.context, stream.local_addr()?, stream.peer_addr()?)?;
+
+    let request = self.request(&mut auth_context)?;
+    write_frame(&mut stream, &request)?;
+
+    let reply = read_frame(&mut stream)?;
+    auth_context.rd_rep(&reply)?;
+
+    Ok(KerberosSession {
+      auth_context,
+      stream,
+      peer: None,
+    })
+  }
+
+  /**
+   * Authenticate to the server on the other end of an async stream.
+   *
+   * The addresses of the connection are bound to the session, like [KerberosClient::connect]
+   * does with the addresses of the TCP connection, and have to be the same ones the server
+   * sees. The AP request is created on the calling thread, which only contacts the KDC if the
+   * service ticket is not in the credential cache yet, see
+   * [crate::Krb5AsyncClient::get_credentials] to get it without blocking.
+   *
+   * # Arguments
+   *
+   *  * stream: the connection to the server
+   *  * local_addr: the address of this end of the connection
+   *  * peer_addr: the address of the server
+   */
+  #[cfg(feature = "async")]
+  pub async fn connect_async<S>(
+    &self,
+    mut stream: S,
+    local_addr: SocketAddr,
+    peer_addr: SocketAddr,
+  ) -> Result<KerberosSession<'a, S>, Krb5Error>
+  where
+    S: AsyncRead + AsyncWrite + Unpin,
+  {
+    let mut auth_context = new_session_auth_context(self.context, local_addr, peer_addr)?;
+
+    let request = self.request(&mut auth_context)?;
+    write_frame_async(&mut stream, &request).await?;
+
+    let reply = read_frame_async(&mut stream).await?;
+    auth_context.rd_rep(&reply)?;
+
+    Ok(KerberosSession {
+      auth_context,
+      stream,
+      peer: None,
+    })
+  }
+
+  /**
+   * Create the AP request for the service, asking for mutual authentication.
+   */
+  fn request(&self, auth_context: &mut Krb5AuthContext<'a>) -> Result<Krb5Data<'a>, Krb5Error> {
+    auth_context.mk_req(
+      &self.service,
+      &self.hostname,
+      self.ccache,
+      Krb5ApOptions::MUTUAL_REQUIRED,
+    )
+  }
+}
+
+/**
+ * Server side of the handshake.
+ */
+#[derive(Debug)]
+pub struct KerberosServer<'a> {
+  context: &'a Krb5Context,
+  keytab: Option<&'a Krb5Keytab<'a>>,
+  server: Option<&'a Krb5Principal<'a>>,
+}
+
+impl<'a> KerberosServer<'a> {
+  /**
+   * Create a server that accepts clients authenticating with keys from a key table.
+   *
+   * # Arguments
+   *
+   *  * context: the Krb5Context instance
+   *  * keytab: the key table holding the service keys, or `None` to use the default key table
+   *  * server: the expected server principal, or `None` to accept any principal in the key
+   *    table
+   */
+  pub fn new(
+    context: &'a Krb5Context,
+    keytab: Option<&'a Krb5Keytab<'a>>,
+    server: Option<&'a Krb5Principal<'a>>,
+  ) -> Self {
+    KerberosServer {
+      context,
+      keytab,
+      server,
+    }
+  }
+
+  /**
+   * Authenticate the client on the other end of a connected stream.
+   *
+   * # Arguments
+   *
+   *  * stream: the connection to the client
+   */
+  pub fn accept(&self, mut stream: TcpStream) -> Result<KerberosSession<'a>, Krb5Error> {
+    let mut auth_context = new_session_auth_context(self.context, stream.local_addr()?, stream.peer_addr()?)?;
+
+    let request = read_frame(&mut stream)?;
+    let ap_request = auth_context.rd_req(&request, self.server, self.keytab)?;
+
+    let reply = auth_context.mk_rep()?;
+    write_frame(&mut stream, &reply)?;
+
+    Ok(KerberosSession {
+      auth_context,
+      stream,
+      peer: Some(ap_request.client),
+    })
+  }
+
+  /**
+   * Authenticate the client on the other end of an async stream.
+   *
+   * The addresses of the connection are bound to the session, like [KerberosServer::accept]
+   * does with the addresses of the TCP connection, and have to be the same ones the client
+   * sees.
+   *
+   * # Arguments
+   *
+   *  * stream: the connection to the client
+   *  * local_addr: the address of this end of the connection
+   *  * peer_addr: the address of the client
+   */
+  #[cfg(feature = "async")]
+  pub async fn accept_async<S>(
+    &self,
+    mut stream: S,
+    local_addr: SocketAddr,
+    peer_addr: SocketAddr,
+  ) -> Result<KerberosSession<'a, S>, Krb5Error>
+  where
+    S: AsyncRead + AsyncWrite + Unpin,
+  {
+    let mut auth_context = new_session_auth_context(self.context, local_addr, peer_addr)?;
+
+    let request = read_frame_async(&mut stream).await?;
+    let ap_request = auth_context.rd_req(&request, self.server, self.keytab)?;
+
+    let reply = auth_context.mk_rep()?;
+    write_frame_async(&mut stream, &reply).await?;
+
+    Ok(KerberosSession {
+      auth_context,
+      stream,
+      peer: Some(ap_request.client),
+    })
+  }
+}
+
+/**
+ * An authenticated connection, established by [KerberosClient::connect] or
+ * [KerberosServer::accept], or over an async stream by [KerberosClient::connect_async] or
+ * [KerberosServer::accept_async].
+ */
+#[derive(Debug)]
+pub struct KerberosSession<'a, S = TcpStream> {
+  pub(crate) auth_context: Krb5AuthContext<'a>,
+  pub(crate) stream: S,
+  pub(crate) peer: Option<Krb5Principal<'a>>,
+}
+
+impl<'a, S: Read + Write> KerberosSession<'a, S> {
+  /**
+   * Receive a frame from the peer and unwrap it.
+   */
+  pub fn receive(&mut self) -> Result<Krb5Data<'a>, Krb5Error> {
+    let message = read_frame(&mut self.stream)?;
+
+    self.unwrap(&message)
+  }
+
+  /**
+   * Wrap data and send it to the peer as one frame.
+   *
+   * # Arguments
+   *
+   *  * data: the application data
+   */
+  pub fn send(&mut self, data: &[u8]) -> Result<(), Krb5Error> {
+    let message = self.wrap(data)?;
+
+    write_frame(&mut self.stream, &message)
+  }
+}
+
+#[cfg(feature = "async")]
+impl<'a, S: AsyncRead + AsyncWrite + Unpin> KerberosSession<'a, S> {
+  /**
+   * Receive a frame from the peer over an async stream and unwrap it.
+   */
+  pub async fn receive_async(&mut self) -> Result<Krb5Data<'a>, Krb5Error> {
+    let message = read_frame_async(&mut self.stream).await?;
+
+    self.unwrap(&message)
+  }
+
+  /**
+   * Wrap data and send it to the peer over an async stream as one frame.
+   *
+   * # Arguments
+   *
+   *  * data: the application data
+   */
+  pub async fn send_async(&mut self, data: &[u8]) -> Result<(), Krb5Error> {
+    let message = self.wrap(data)?;
+
+    write_frame_async(&mut self.stream, &message).await
+  }
+}
+
+impl<'a, S> KerberosSession<'a, S> {
+  /**
+   * Return the authenticated client principal on the server side, or `None` on the client side.
+   */
+  pub fn peer(&self) -> Option<&Krb5Principal<'a>> {
+    self.peer.as_ref()
+  }
+
+  /**
+   * Return the underlying stream.
+   */
+  pub fn stream(&self) -> &S {
+    &self.stream
+  }
+
+  /**
+   * Decrypt and verify a KRB-PRIV message created by the peer.
+   *
+   * # Arguments
+   *
+   *  * message: the KRB-PRIV message
+   */
+  pub fn unwrap(&mut self, message: &[u8]) -> Result<Krb5Data<'a>, Krb5Error> {
+    self.auth_context.rd_priv(message)
+  }
+
+  /**
+   * Encrypt and integrity protect application data as a KRB-PRIV message for the peer.
+   *
+   * # Arguments
+   *
+   *  * data: the application data
+   */
+  pub fn wrap(&mut self, data: &[u8]) -> Result<Krb5Data<'a>, Krb5Error> {
+    self.auth_context.mk_priv(data)
+  }
+}
+
+/**
+ * Create an authentication context that binds the addresses of the connection and uses
+ * sequence numbers.
+ */
+fn new_session_auth_context(
+  context: &Krb5Context,
+  local_addr: SocketAddr,
+  peer_addr: SocketAddr,
+) -> Result<Krb5AuthContext<'_>, Krb5Error> {
+  let mut auth_context = Krb5AuthContext::init(context)?;
+
+  auth_context.set_flags(Krb5AuthContextFlags::DO_SEQUENCE)?;
+  auth_context.set_addrs(Some(&local_addr), Some(&peer_addr))?;
+
+  Ok(auth_context)
+}
+
+/**
+ * Decode the length prefix of a frame, and check it against [MAX_FRAME_LENGTH].
+ */
+fn frame_length(prefix: [u8; 4]) -> Result<usize, Krb5Error> {
+  let length = u32::from_be_bytes(prefix) as usize;
+  if length > MAX_FRAME_LENGTH {
+    return Err(Krb5Error::Io {
+      error: std::io::Error::new(
+        std::io::ErrorKind::InvalidData,
+        format!(
+          "frame of {} bytes exceeds the maximum of {} bytes",
+          length, MAX_FRAME_LENGTH
+        ),
+      ),
+    });
+  }
+
+  Ok(length)
+}
+
+/**
+ * Read a length prefixed frame.
+ */
+pub(crate) fn read_frame<R: Read>(reader: &mut R) -> Result<Vec<u8>, Krb5Error> {
+  let mut length = [0u8; 4];
+  reader.read_exact(&mut length)?;
+
+  let mut frame = vec![0u8; frame_length(length)?];
+  reader.read_exact(&mut frame)?;
+
+  Ok(frame)
+}
+
+/**
+ * Read a length prefixed frame from an async stream.
+ */
+#[cfg(feature = "async")]
+pub(crate) async fn read_frame_async<R: AsyncRead + Unpin>(reader: &mut R) -> Result<Vec<u8>, Krb5Error> {
+  let mut length = [0u8; 4];
+  reader.read_exact(&mut length).await?;
+
+  let mut frame = vec![0u8; frame_length(length)?];
+  reader.read_exact(&mut frame).await?;
+
+  Ok(frame)
+}
+
+/**
+ * Write a length prefixed frame.
+ */
+pub(crate) fn write_frame<W: Write>(writer: &mut W, frame: &[u8]) -> Result<(), Krb5Error> {
+  writer.write_all(&(frame.len() as u32).to_be_bytes())?;
+  writer.write_all(frame)?;
+  writer.flush()?;
+
+  Ok(())
+}
+
+/**
+ * Write a length prefixed frame to an async stream.
+ */
+#[cfg(feature = "async")]
+pub(crate) async fn write_frame_async<W: AsyncWrite + Unpin>(writer: &mut W, frame: &[u8]) -> Result<(), Krb5Error> {
+  writer.write_all(&(frame.len() as u32).to_be_bytes()).await?;
+  writer.write_all(frame).await?;
+  writer.flush().await?;
+
+  Ok(())
+}
@@ -0,0 +1,143 @@
+/*!
+ * Scatter/gather buffers for in-place encryption with krb5 keys.
+ */
+use std::marker::PhantomData;
+use std::os::raw::{c_char, c_uint};
+
+use libkrb5_sys::*;
+
+/**
+ * The role of a buffer in a [Krb5CryptoIov].
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Krb5CryptoType {
+  /// space for the header (e.g. the confounder) written by encryption
+  Header,
+  /// data that is encrypted or decrypted in place
+  Data,
+  /// data that is integrity protected but not encrypted
+  SignOnly,
+  /// space for padding to the block size of the enctype
+  Padding,
+  /// space for the trailer (e.g. the checksum) written by encryption
+  Trailer,
+}
+
+impl Krb5CryptoType {
+  pub(crate) fn to_raw(self) -> krb5_cryptotype {
+    let raw = match self {
+      Krb5CryptoType::Header => KRB5_CRYPTO_TYPE_HEADER,
+      Krb5CryptoType::Data => KRB5_CRYPTO_TYPE_DATA,
+      Krb5CryptoType::SignOnly => KRB5_CRYPTO_TYPE_SIGN_ONLY,
+      Krb5CryptoType::Padding => KRB5_CRYPTO_TYPE_PADDING,
+      Krb5CryptoType::Trailer => KRB5_CRYPTO_TYPE_TRAILER,
+    };
+
+    raw as krb5_cryptotype
+  }
+}
+
+/**
+ * A list of buffers for [crate::Krb5Keyblock::encrypt_iov] and
+ * [crate::Krb5Keyblock::decrypt_iov], which process the data without copying it into a single
+ * message.
+ *
+ * The header, padding and trailer buffers must have the sizes reported by
+ * [crate::Krb5Keyblock::crypto_length].
+ *
+ * https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/types/krb5_crypto_iov.html
+ */
+#[derive(Debug, Default)]
+pub struct Krb5CryptoIov<'b> {
+  pub(crate) iov: Vec<krb5_crypto_iov>,
+  buffers: PhantomData<&'b mut [u8]>,
+}
+
+impl<'b> Krb5CryptoIov<'b> {
+  /**
+   * Create an empty buffer list.
+   */
+  pub fn new() -> Self {
+    Krb5CryptoIov::default()
+  }
+
+  /**
+   * Append a buffer that is encrypted or decrypted in place.
+   */
+  pub fn data(&mut self, buffer: &'b mut [u8]) -> &mut Self {
+    self.push(Krb5CryptoType::Data, buffer)
+  }
+
+  /**
+   * Append the buffer for the header.
+   */
+  pub fn header(&mut self, buffer: &'b mut [u8]) -> &mut Self {
+    self.push(Krb5CryptoType::Header, buffer)
+  }
+
+  /**
+   * Return true if no buffers have been added.
+   */
+  pub fn is_empty(&self) -> bool {
+    self.iov.is_empty()
+  }
+
+  /**
+   * Return the number of buffers.
+   */
+  pub fn len(&self) -> usize {
+    self.iov.len()
+  }
+
+  /**
+   * Append the buffer for the padding.
+   */
+  pub fn padding(&mut self, buffer: &'b mut [u8]) -> &mut Self {
+    self.push(Krb5CryptoType::Padding, buffer)
+  }
+
+  /**
+   * Append a buffer of the given type.
+   *
+   * # Arguments
+   *
+   *  * crypto_type: the role of the buffer
+   *  * buffer: the buffer, which libkrb5 reads from or writes to depending on `crypto_type`
+   */
+  pub fn push(&mut self, crypto_type: Krb5CryptoType, buffer: &'b mut [u8]) -> &mut Self {
+    self.iov.push(krb5_crypto_iov {
+      flags: crypto_type.to_raw(),
+      data: krb5_data {
+        magic: 0,
+        length: buffer.len() as c_uint,
+        data: buffer.as_mut_ptr() as *mut c_char,
+      },
+    });
+
+    self
+  }
+
+  /**
+   * Append a buffer that is integrity protected but neither encrypted nor modified.
+   */
+  pub fn sign_only(&mut self, buffer: &'b [u8]) -> &mut Self {
+    // libkrb5 only reads SIGN_ONLY buffers
+    self.iov.push(krb5_crypto_iov {
+      flags: Krb5CryptoType::SignOnly.to_raw(),
+      data: krb5_data {
+        magic: 0,
+        length: buffer.len() as c_uint,
+        data: buffer.as_ptr() as *mut c_char,
+      },
+    });
+
+    self
+  }
+
+  /**
+   * Append the buffer for the trailer.
+   */
+  pub fn trailer(&mut self, buffer: &'b mut [u8]) -> &mut Self {
+    self.push(Krb5CryptoType::Trailer, buffer)
+  }
+}
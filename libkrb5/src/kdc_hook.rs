@@ -0,0 +1,141 @@
+/*!
+ * Hook into the requests libkrb5 sends to KDCs, see [crate::Krb5Context::set_kdc_send_hook].
+ */
+use std::cell::Cell;
+use std::ffi::CString;
+use std::fmt::{Debug, Formatter};
+use std::os::raw::{c_char, c_void};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use libkrb5_sys::*;
+
+use crate::context::Krb5Context;
+use crate::data::{krb5_data_as_slice, slice_to_krb5_data};
+use crate::error::Krb5Error;
+use crate::error_message::Krb5KdcError;
+
+/**
+ * The signature of KDC send hooks: given the realm and the encoded request, return the reply of
+ * the KDC, or `None` to have libkrb5 send the request.
+ */
+pub(crate) type KdcSendFn = dyn Fn(&str, &[u8]) -> Result<Option<Vec<u8>>, Krb5Error>;
+
+/**
+ * A KDC send hook, owned by the context it is installed in.
+ */
+pub(crate) struct KdcSendHook {
+  pub(crate) hook: Box<KdcSendFn>,
+}
+
+impl Debug for KdcSendHook {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("KdcSendHook").finish_non_exhaustive()
+  }
+}
+
+/**
+ * Call the hook of a context before libkrb5 sends a request to a KDC.
+ *
+ * A reply of the hook is handed to libkrb5 in a `krb5_data` allocated by libkrb5, which frees
+ * it. Errors of the hook are returned with their message, so they end up in the error of the
+ * failed operation. Panics are caught, since they must not unwind into libkrb5.
+ *
+ * [krb5_pre_send_fn](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/types/krb5_pre_send_fn.html)
+ */
+pub(crate) unsafe extern "C" fn kdc_send_hook(
+  context: krb5_context,
+  data: *mut c_void,
+  realm: *const krb5_data,
+  message: *const krb5_data,
+  _new_message_out: *mut *mut krb5_data,
+  new_reply_out: *mut *mut krb5_data,
+) -> krb5_error_code {
+  let hook = &*(data as *const KdcSendHook);
+  let realm = String::from_utf8_lossy(krb5_data_as_slice(&*realm));
+  let message = krb5_data_as_slice(&*message);
+
+  let error = match catch_unwind(AssertUnwindSafe(|| (hook.hook)(&realm, message))) {
+    Ok(Ok(None)) => return 0,
+    Ok(Ok(Some(reply))) => return krb5_copy_data(context, &slice_to_krb5_data(&reply), new_reply_out),
+    Ok(Err(error)) => error,
+    Err(_) => Krb5Error::LibraryError {
+      code: KRB5_KDC_UNREACH,
+      message: String::from("KDC send hook panicked"),
+    },
+  };
+
+  let code = error.raw_code().unwrap_or(KRB5_KDC_UNREACH);
+  let message = match error {
+    Krb5Error::LibraryError { message, .. } | Krb5Error::KdcError { message, .. } => message,
+    error => error.to_string(),
+  };
+  let message = CString::new(message.replace('\0', "")).unwrap_or_default();
+  krb5_set_error_message(context, code, b"%s\0".as_ptr() as *const c_char, message.as_ptr());
+
+  code
+}
+
+/**
+ * Run `f` while recording the replies of KDCs, and return its result together with the
+ * KRB-ERROR of the last reply, if it was one. This recovers the KRB-ERROR for libkrb5 functions
+ * that only return an error code, e.g. `krb5_get_init_creds_keytab`.
+ *
+ * Replies returned by a KDC send hook are not seen by libkrb5's receive hook.
+ *
+ * [krb5_set_kdc_recv_hook](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_set_kdc_recv_hook.html)
+ */
+pub(crate) fn capture_kdc_error<T, F: FnOnce() -> T>(context: &Krb5Context, f: F) -> (T, Option<Krb5KdcError>) {
+  let last_error: Cell<Option<Krb5KdcError>> = Cell::new(None);
+
+  /* removes the hook again, even if `f` panics */
+  struct RecvHookGuard<'c>(&'c Krb5Context);
+
+  impl<'c> Drop for RecvHookGuard<'c> {
+    fn drop(&mut self) {
+      unsafe { krb5_set_kdc_recv_hook(self.0.context, None, std::ptr::null_mut()) };
+    }
+  }
+
+  unsafe {
+    krb5_set_kdc_recv_hook(
+      context.context,
+      Some(kdc_error_recv_hook),
+      &last_error as *const Cell<Option<Krb5KdcError>> as *mut c_void,
+    )
+  };
+  let guard = RecvHookGuard(context);
+  let result = f();
+  drop(guard);
+
+  (result, last_error.take())
+}
+
+/**
+ * Keep the KRB-ERROR of a reply, or forget the previous one if the reply is something else.
+ *
+ * The error code is passed on, so libkrb5 carries on as without the hook.
+ *
+ * [krb5_post_recv_fn](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/types/krb5_post_recv_fn.html)
+ */
+unsafe extern "C" fn kdc_error_recv_hook(
+  context: krb5_context,
+  data: *mut c_void,
+  code: krb5_error_code,
+  _realm: *const krb5_data,
+  _message: *const krb5_data,
+  reply: *const krb5_data,
+  _new_reply_out: *mut *mut krb5_data,
+) -> krb5_error_code {
+  let last_error = &*(data as *const Cell<Option<Krb5KdcError>>);
+  last_error.set(None);
+
+  if code == 0 && !reply.is_null() {
+    let mut error: *mut krb5_error = std::ptr::null_mut();
+    if krb5_rd_error(context, reply, &mut error) == 0 {
+      last_error.set(Some(Krb5KdcError::from_raw(&*error)));
+      krb5_free_error(context, error);
+    }
+  }
+
+  code
+}
@@ -0,0 +1,811 @@
+/*!
+ * Rustic wrapper for krb5 keyblocks.
+ */
+use std::fmt::{Debug, Formatter};
+use std::mem::MaybeUninit;
+use std::os::raw::c_uint;
+
+use libkrb5_sys::*;
+
+use crate::checksum::{Krb5Checksum, Krb5ChecksumType};
+use crate::context::Krb5Context;
+use crate::data::{buffer_to_krb5_data, slice_to_krb5_data};
+use crate::enctype::Krb5Enctype;
+use crate::error::{krb5_error_code_escape_hatch, Krb5Error};
+use crate::iov::{Krb5CryptoIov, Krb5CryptoType};
+use crate::keytab::Krb5KeytabKey;
+use crate::keyusage::Krb5KeyUsage;
+use crate::secret::{constant_time_eq, zeroize};
+use crate::strconv::string_to_c_string;
+
+/**
+ * Wrapper struct for a krb5 keyblock (an encryption type and the raw key).
+ *
+ * The key is redacted from `Debug` output, which only shows the enctype and the key length.
+ *
+ * https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/types/krb5_keyblock.html
+ */
+pub struct Krb5Keyblock<'a> {
+  pub(crate) context: &'a Krb5Context,
+  pub(crate) keyblock: *mut krb5_keyblock,
+}
+
+/**
+ * Free a keyblock. libkrb5 zeroes the key before releasing the memory.
+ *
+ * [krb5_free_keyblock](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_free_keyblock.html)
+ */
+impl<'a> Drop for Krb5Keyblock<'a> {
+  fn drop(&mut self) {
+    unsafe {
+      krb5_free_keyblock(self.context.context, self.keyblock);
+    }
+  }
+}
+
+impl<'a> Krb5Keyblock<'a> {
+  /**
+   * Create a keyblock from raw key bytes.
+   *
+   * [krb5_init_keyblock](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_init_keyblock.html)
+   *
+   * # Arguments
+   *
+   *  * context: the Krb5Context instance
+   *  * enctype: the encryption type of the key
+   *  * key: the raw key bytes
+   */
+  pub fn new(context: &'a Krb5Context, enctype: Krb5Enctype, key: &[u8]) -> Result<Krb5Keyblock<'a>, Krb5Error> {
+    let mut keyblock_ptr: MaybeUninit<*mut krb5_keyblock> = MaybeUninit::zeroed();
+
+    let code: krb5_error_code = unsafe {
+      krb5_init_keyblock(
+        context.context,
+        enctype.as_raw(),
+        key.len() as size_t,
+        keyblock_ptr.as_mut_ptr(),
+      )
+    };
+
+    krb5_error_code_escape_hatch(context, code)?;
+
+    let keyblock = Krb5Keyblock {
+      context,
+      keyblock: unsafe { keyblock_ptr.assume_init() },
+    };
+
+    if !key.is_empty() {
+      unsafe { std::ptr::copy_nonoverlapping(key.as_ptr(), (*keyblock.keyblock).contents, key.len()) };
+    }
+
+    Ok(keyblock)
+  }
+
+  /**
+   * Create a keyblock from the key of a key table entry.
+   *
+   * # Arguments
+   *
+   *  * context: the Krb5Context instance
+   *  * key: the key, see [crate::Krb5KeytabEntry::key]
+   */
+  pub fn from_keytab_key(context: &'a Krb5Context, key: &Krb5KeytabKey) -> Result<Krb5Keyblock<'a>, Krb5Error> {
+    Krb5Keyblock::new(context, key.enctype(), key.as_bytes())
+  }
+
+  /**
+   * Create a key of the given enctype from random bytes, e.g. the output of an external key
+   * derivation function.
+   *
+   * [krb5_c_random_to_key](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_c_random_to_key.html)
+   *
+   * # Arguments
+   *
+   *  * context: the Krb5Context instance
+   *  * enctype: the encryption type of the key
+   *  * random: the random bytes, [Krb5Enctype::random_length] of them
+   */
+  pub fn from_random_bytes(
+    context: &'a Krb5Context,
+    enctype: Krb5Enctype,
+    random: &[u8],
+  ) -> Result<Krb5Keyblock<'a>, Krb5Error> {
+    let key_length = enctype.key_length(context)?;
+    let keyblock = Krb5Keyblock::new(context, enctype, &vec![0; key_length])?;
+    // libkrb5 does not modify the input, the parameter just lacks the const qualifier
+    let mut random_data = slice_to_krb5_data(random);
+
+    let code: krb5_error_code =
+      unsafe { krb5_c_random_to_key(context.context, enctype.as_raw(), &mut random_data, keyblock.keyblock) };
+
+    krb5_error_code_escape_hatch(context, code)?;
+
+    Ok(keyblock)
+  }
+
+  /**
+   * Generate a random key of the given enctype.
+   *
+   * [krb5_c_make_random_key](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_c_make_random_key.html)
+   *
+   * # Arguments
+   *
+   *  * context: the Krb5Context instance
+   *  * enctype: the encryption type of the key
+   */
+  pub fn generate(context: &'a Krb5Context, enctype: Krb5Enctype) -> Result<Krb5Keyblock<'a>, Krb5Error> {
+    // allocate an empty keyblock, libkrb5 allocates the contents
+    let keyblock = Krb5Keyblock::new(context, enctype, &[])?;
+
+    let code: krb5_error_code = unsafe { krb5_c_make_random_key(context.context, enctype.as_raw(), keyblock.keyblock) };
+
+    krb5_error_code_escape_hatch(context, code)?;
+
+    Ok(keyblock)
+  }
+
+  /**
+   * Derive a key from a password.
+   *
+   * [krb5_c_string_to_key_with_params](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_c_string_to_key_with_params.html)
+   *
+   * # Arguments
+   *
+   *  * context: the Krb5Context instance
+   *  * enctype: the encryption type of the key
+   *  * password: the password
+   *  * salt: the salt, usually [crate::Krb5Principal::salt] of the principal the key is for
+   *  * params: enctype specific string-to-key parameters, e.g. the iteration count as 4 byte
+   *    big endian number for the AES enctypes, or `None` for the defaults
+   */
+  pub fn string_to_key(
+    context: &'a Krb5Context,
+    enctype: Krb5Enctype,
+    password: &[u8],
+    salt: &[u8],
+    params: Option<&[u8]>,
+  ) -> Result<Krb5Keyblock<'a>, Krb5Error> {
+    let string = slice_to_krb5_data(password);
+    let salt = slice_to_krb5_data(salt);
+    let params = params.map(slice_to_krb5_data);
+    let params_ptr = match &params {
+      Some(params) => params as *const krb5_data,
+      None => std::ptr::null(),
+    };
+
+    // allocate an empty keyblock, libkrb5 allocates the contents
+    let keyblock = Krb5Keyblock::new(context, enctype, &[])?;
+
+    let code: krb5_error_code = unsafe {
+      krb5_c_string_to_key_with_params(
+        context.context,
+        enctype.as_raw(),
+        &string,
+        &salt,
+        params_ptr,
+        keyblock.keyblock,
+      )
+    };
+
+    krb5_error_code_escape_hatch(context, code)?;
+
+    Ok(keyblock)
+  }
+
+  /**
+   * Create a copy of a libkrb5 keyblock.
+   *
+   * [krb5_copy_keyblock](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_copy_keyblock.html)
+   */
+  pub(crate) fn copy_from(
+    context: &'a Krb5Context,
+    keyblock: *const krb5_keyblock,
+  ) -> Result<Krb5Keyblock<'a>, Krb5Error> {
+    let mut keyblock_ptr: MaybeUninit<*mut krb5_keyblock> = MaybeUninit::zeroed();
+
+    let code: krb5_error_code = unsafe { krb5_copy_keyblock(context.context, keyblock, keyblock_ptr.as_mut_ptr()) };
+
+    krb5_error_code_escape_hatch(context, code)?;
+
+    Ok(Krb5Keyblock {
+      context,
+      keyblock: unsafe { keyblock_ptr.assume_init() },
+    })
+  }
+
+  /**
+   * Return the raw key bytes.
+   */
+  pub fn contents(&self) -> &[u8] {
+    let keyblock = unsafe { &*self.keyblock };
+
+    if keyblock.contents.is_null() {
+      return &[];
+    }
+
+    unsafe { std::slice::from_raw_parts(keyblock.contents, keyblock.length as usize) }
+  }
+
+  /**
+   * Return the size of the header, padding or trailer buffer of a [Krb5CryptoIov] for the
+   * enctype of the key.
+   *
+   * [krb5_c_crypto_length](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_c_crypto_length.html)
+   *
+   * # Arguments
+   *
+   *  * crypto_type: the buffer type
+   */
+  pub fn crypto_length(&self, crypto_type: Krb5CryptoType) -> Result<usize, Krb5Error> {
+    let mut size: c_uint = 0;
+
+    let code: krb5_error_code = unsafe {
+      krb5_c_crypto_length(
+        self.context.context,
+        self.enctype().as_raw(),
+        crypto_type.to_raw(),
+        &mut size,
+      )
+    };
+
+    krb5_error_code_escape_hatch(self.context, code)?;
+
+    Ok(size as usize)
+  }
+
+  /**
+   * Decrypt data encrypted with [Krb5Keyblock::encrypt].
+   *
+   * [krb5_c_decrypt](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_c_decrypt.html)
+   *
+   * # Arguments
+   *
+   *  * usage: the key usage the data was encrypted with
+   *  * state: the cipher state to chain consecutive messages, or `None`
+   *  * ciphertext: the encrypted data
+   */
+  pub fn decrypt(
+    &self,
+    usage: Krb5KeyUsage,
+    state: Option<&mut Krb5CipherState>,
+    ciphertext: &[u8],
+  ) -> Result<Vec<u8>, Krb5Error> {
+    let input = krb5_enc_data {
+      magic: 0,
+      enctype: self.enctype().as_raw(),
+      kvno: 0,
+      ciphertext: slice_to_krb5_data(ciphertext),
+    };
+    let state_ptr = cipher_state_ptr(state);
+
+    // the plaintext is never longer than the ciphertext
+    let mut plaintext = vec![0; ciphertext.len()];
+    let mut output = buffer_to_krb5_data(&mut plaintext);
+
+    let code: krb5_error_code = unsafe {
+      krb5_c_decrypt(
+        self.context.context,
+        self.keyblock,
+        usage.as_raw(),
+        state_ptr,
+        &input,
+        &mut output,
+      )
+    };
+
+    if let Err(error) = krb5_error_code_escape_hatch(self.context, code) {
+      zeroize(&mut plaintext);
+      return Err(error);
+    }
+
+    plaintext.truncate(output.length as usize);
+
+    Ok(plaintext)
+  }
+
+  /**
+   * Decrypt and verify the buffers of `iov` in place, see [Krb5Keyblock::encrypt_iov].
+   *
+   * [krb5_c_decrypt_iov](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_c_decrypt_iov.html)
+   *
+   * # Arguments
+   *
+   *  * usage: the key usage the data was encrypted with
+   *  * state: the cipher state to chain consecutive messages, or `None`
+   *  * iov: the header, data, sign only, padding and trailer buffers
+   */
+  pub fn decrypt_iov(
+    &self,
+    usage: Krb5KeyUsage,
+    state: Option<&mut Krb5CipherState>,
+    iov: &mut Krb5CryptoIov,
+  ) -> Result<(), Krb5Error> {
+    let state_ptr = cipher_state_ptr(state);
+
+    let code: krb5_error_code = unsafe {
+      krb5_c_decrypt_iov(
+        self.context.context,
+        self.keyblock,
+        usage.as_raw(),
+        state_ptr,
+        iov.iov.as_mut_ptr(),
+        iov.iov.len() as size_t,
+      )
+    };
+
+    krb5_error_code_escape_hatch(self.context, code)
+  }
+
+  /**
+   * Encrypt data with the key.
+   *
+   * The key usage number separates keys derived for different purposes, both sides have to use
+   * the same number.
+   *
+   * [krb5_c_encrypt](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_c_encrypt.html)
+   *
+   * # Arguments
+   *
+   *  * usage: the key usage
+   *  * state: the cipher state to chain consecutive messages, or `None`
+   *  * plaintext: the data to encrypt
+   */
+  pub fn encrypt(
+    &self,
+    usage: Krb5KeyUsage,
+    state: Option<&mut Krb5CipherState>,
+    plaintext: &[u8],
+  ) -> Result<Vec<u8>, Krb5Error> {
+    let length = self.encrypt_length(plaintext.len())?;
+    let input = slice_to_krb5_data(plaintext);
+    let state_ptr = cipher_state_ptr(state);
+    let mut ciphertext = vec![0; length];
+    let mut output = krb5_enc_data {
+      magic: 0,
+      enctype: self.enctype().as_raw(),
+      kvno: 0,
+      ciphertext: buffer_to_krb5_data(&mut ciphertext),
+    };
+
+    let code: krb5_error_code = unsafe {
+      krb5_c_encrypt(
+        self.context.context,
+        self.keyblock,
+        usage.as_raw(),
+        state_ptr,
+        &input,
+        &mut output,
+      )
+    };
+
+    krb5_error_code_escape_hatch(self.context, code)?;
+
+    ciphertext.truncate(output.ciphertext.length as usize);
+
+    Ok(ciphertext)
+  }
+
+  /**
+   * Return the length of the ciphertext [Krb5Keyblock::encrypt] produces for a plaintext of the
+   * given length.
+   *
+   * [krb5_c_encrypt_length](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_c_encrypt_length.html)
+   *
+   * # Arguments
+   *
+   *  * plaintext_len: the length of the plaintext in bytes
+   */
+  pub fn encrypt_length(&self, plaintext_len: usize) -> Result<usize, Krb5Error> {
+    let mut length: size_t = 0;
+
+    let code: krb5_error_code = unsafe {
+      krb5_c_encrypt_length(
+        self.context.context,
+        self.enctype().as_raw(),
+        plaintext_len as size_t,
+        &mut length,
+      )
+    };
+
+    krb5_error_code_escape_hatch(self.context, code)?;
+
+    Ok(length as usize)
+  }
+
+  /**
+   * Encrypt the data buffers of `iov` in place, and fill in the header, padding and trailer
+   * buffers. Sign only buffers are integrity protected but not encrypted.
+   *
+   * [krb5_c_encrypt_iov](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_c_encrypt_iov.html)
+   *
+   * # Arguments
+   *
+   *  * usage: the key usage
+   *  * state: the cipher state to chain consecutive messages, or `None`
+   *  * iov: the header, data, sign only, padding and trailer buffers
+   */
+  pub fn encrypt_iov(
+    &self,
+    usage: Krb5KeyUsage,
+    state: Option<&mut Krb5CipherState>,
+    iov: &mut Krb5CryptoIov,
+  ) -> Result<(), Krb5Error> {
+    let state_ptr = cipher_state_ptr(state);
+
+    let code: krb5_error_code = unsafe {
+      krb5_c_encrypt_iov(
+        self.context.context,
+        self.keyblock,
+        usage.as_raw(),
+        state_ptr,
+        iov.iov.as_mut_ptr(),
+        iov.iov.len() as size_t,
+      )
+    };
+
+    krb5_error_code_escape_hatch(self.context, code)
+  }
+
+  /**
+   * Return the encryption type of the key.
+   */
+  pub fn enctype(&self) -> Krb5Enctype {
+    Krb5Enctype::from_raw(unsafe { (*self.keyblock).enctype })
+  }
+
+  /**
+   * Return true if the key has no bytes.
+   */
+  pub fn is_empty(&self) -> bool {
+    self.len() == 0
+  }
+
+  /**
+   * Create a cipher state for chaining consecutive encryptions or decryptions with the key.
+   *
+   * [krb5_c_init_state](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_c_init_state.html)
+   *
+   * # Arguments
+   *
+   *  * usage: the key usage
+   */
+  pub fn init_state<'k>(&'k self, usage: Krb5KeyUsage) -> Result<Krb5CipherState<'k, 'a>, Krb5Error> {
+    let mut state: MaybeUninit<krb5_data> = MaybeUninit::zeroed();
+
+    let code: krb5_error_code =
+      unsafe { krb5_c_init_state(self.context.context, self.keyblock, usage.as_raw(), state.as_mut_ptr()) };
+
+    krb5_error_code_escape_hatch(self.context, code)?;
+
+    Ok(Krb5CipherState {
+      key: self,
+      state: unsafe { state.assume_init() },
+    })
+  }
+
+  /**
+   * Combine this key with another key using the KRB-FX-CF2 function (RFC 6113), e.g. to derive
+   * the FAST armor key.
+   *
+   * The combined key has the enctype of this key.
+   *
+   * [krb5_c_fx_cf2_simple](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_c_fx_cf2_simple.html)
+   *
+   * # Arguments
+   *
+   *  * pepper1: the pepper for this key
+   *  * other: the other key
+   *  * pepper2: the pepper for the other key
+   */
+  pub fn fx_cf2(&self, pepper1: &str, other: &Krb5Keyblock, pepper2: &str) -> Result<Krb5Keyblock<'a>, Krb5Error> {
+    let pepper1_c = string_to_c_string(pepper1)?;
+    let pepper2_c = string_to_c_string(pepper2)?;
+    let mut keyblock_ptr: MaybeUninit<*mut krb5_keyblock> = MaybeUninit::zeroed();
+
+    let code: krb5_error_code = unsafe {
+      krb5_c_fx_cf2_simple(
+        self.context.context,
+        self.keyblock,
+        pepper1_c.as_ptr(),
+        other.keyblock,
+        pepper2_c.as_ptr(),
+        keyblock_ptr.as_mut_ptr(),
+      )
+    };
+
+    krb5_error_code_escape_hatch(self.context, code)?;
+
+    Ok(Krb5Keyblock {
+      context: self.context,
+      keyblock: unsafe { keyblock_ptr.assume_init() },
+    })
+  }
+
+  /**
+   * Compute a checksum of data with the key.
+   *
+   * [krb5_c_make_checksum](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_c_make_checksum.html)
+   *
+   * # Arguments
+   *
+   *  * cksumtype: the checksum type (`CKSUMTYPE_*`), or 0 for the mandatory checksum type of
+   *    the enctype of the key
+   *  * usage: the key usage
+   *  * data: the data to checksum
+   */
+  pub fn make_checksum(
+    &self,
+    checksum_type: Option<Krb5ChecksumType>,
+    usage: Krb5KeyUsage,
+    data: &[u8],
+  ) -> Result<Krb5Checksum, Krb5Error> {
+    let cksumtype = checksum_type.map_or(0, Krb5ChecksumType::as_raw);
+    let input = slice_to_krb5_data(data);
+    let mut cksum: MaybeUninit<krb5_checksum> = MaybeUninit::zeroed();
+
+    let code: krb5_error_code = unsafe {
+      krb5_c_make_checksum(
+        self.context.context,
+        cksumtype,
+        self.keyblock,
+        usage.as_raw(),
+        &input,
+        cksum.as_mut_ptr(),
+      )
+    };
+
+    krb5_error_code_escape_hatch(self.context, code)?;
+
+    let mut cksum = unsafe { cksum.assume_init() };
+    let checksum = Krb5Checksum::from_raw(&cksum);
+    unsafe { krb5_free_checksum_contents(self.context.context, &mut cksum) };
+
+    Ok(checksum)
+  }
+
+  /**
+   * Return the size of the padding buffer of a [Krb5CryptoIov] for data of the given length.
+   *
+   * [krb5_c_padding_length](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_c_padding_length.html)
+   *
+   * # Arguments
+   *
+   *  * data_len: the total length of the data buffers in bytes
+   */
+  pub fn padding_length(&self, data_len: usize) -> Result<usize, Krb5Error> {
+    let mut size: c_uint = 0;
+
+    let code: krb5_error_code = unsafe {
+      krb5_c_padding_length(
+        self.context.context,
+        self.enctype().as_raw(),
+        data_len as size_t,
+        &mut size,
+      )
+    };
+
+    krb5_error_code_escape_hatch(self.context, code)?;
+
+    Ok(size as usize)
+  }
+
+  /**
+   * Compute the pseudo-random function of the enctype of the key (RFC 3961) over input data.
+   *
+   * The output length depends on the enctype, see
+   * [krb5_c_prf_length](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_c_prf_length.html).
+   *
+   * [krb5_c_prf](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_c_prf.html)
+   *
+   * # Arguments
+   *
+   *  * input: the input data
+   */
+  pub fn prf(&self, input: &[u8]) -> Result<Vec<u8>, Krb5Error> {
+    let mut length: size_t = 0;
+
+    let code: krb5_error_code =
+      unsafe { krb5_c_prf_length(self.context.context, self.enctype().as_raw(), &mut length) };
+
+    krb5_error_code_escape_hatch(self.context, code)?;
+
+    // libkrb5 does not modify the input, the parameter just lacks the const qualifier
+    let mut input = slice_to_krb5_data(input);
+    let mut prf = vec![0; length as usize];
+    let mut output = buffer_to_krb5_data(&mut prf);
+
+    let code: krb5_error_code = unsafe { krb5_c_prf(self.context.context, self.keyblock, &mut input, &mut output) };
+
+    krb5_error_code_escape_hatch(self.context, code)?;
+
+    Ok(prf)
+  }
+
+  /**
+   * Compute `length` bytes of the PRF+ function (RFC 6113) over input data, which repeats the
+   * pseudo-random function of the enctype with a counter.
+   *
+   * [krb5_c_prfplus](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_c_prfplus.html)
+   *
+   * # Arguments
+   *
+   *  * input: the input data
+   *  * length: the number of bytes to compute, at most 255 times the PRF length of the enctype
+   */
+  pub fn prf_plus(&self, input: &[u8], length: usize) -> Result<Vec<u8>, Krb5Error> {
+    let input = slice_to_krb5_data(input);
+    let mut prf = vec![0; length];
+    let mut output = buffer_to_krb5_data(&mut prf);
+
+    let code: krb5_error_code = unsafe { krb5_c_prfplus(self.context.context, self.keyblock, &input, &mut output) };
+
+    krb5_error_code_escape_hatch(self.context, code)?;
+
+    Ok(prf)
+  }
+
+  /**
+   * Derive a key of the given enctype from the PRF+ output of this key over input data.
+   *
+   * [krb5_c_derive_prfplus](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_c_derive_prfplus.html)
+   *
+   * # Arguments
+   *
+   *  * input: the input data
+   *  * enctype: the encryption type of the derived key, or `None` for the enctype of this key
+   */
+  pub fn derive_prf_plus(&self, input: &[u8], enctype: Option<Krb5Enctype>) -> Result<Krb5Keyblock<'a>, Krb5Error> {
+    let input = slice_to_krb5_data(input);
+    let mut keyblock_ptr: MaybeUninit<*mut krb5_keyblock> = MaybeUninit::zeroed();
+
+    let code: krb5_error_code = unsafe {
+      krb5_c_derive_prfplus(
+        self.context.context,
+        self.keyblock,
+        &input,
+        enctype.map_or(ENCTYPE_NULL as krb5_enctype, Krb5Enctype::as_raw),
+        keyblock_ptr.as_mut_ptr(),
+      )
+    };
+
+    krb5_error_code_escape_hatch(self.context, code)?;
+
+    Ok(Krb5Keyblock {
+      context: self.context,
+      keyblock: unsafe { keyblock_ptr.assume_init() },
+    })
+  }
+
+  /**
+   * Create a copy of the key.
+   *
+   * [krb5_copy_keyblock](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_copy_keyblock.html)
+   */
+  pub fn try_clone(&self) -> Result<Krb5Keyblock<'a>, Krb5Error> {
+    Krb5Keyblock::copy_from(self.context, self.keyblock)
+  }
+
+  /**
+   * Verify a checksum of data with the key.
+   *
+   * Returns `false` if the checksum does not match, and an error if it cannot be computed, e.g.
+   * because the checksum type is not compatible with the key.
+   *
+   * [krb5_c_verify_checksum](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_c_verify_checksum.html)
+   *
+   * # Arguments
+   *
+   *  * usage: the key usage the checksum was made with
+   *  * data: the data the checksum was computed over
+   *  * checksum: the checksum to verify
+   */
+  pub fn verify_checksum(&self, usage: Krb5KeyUsage, data: &[u8], checksum: &Krb5Checksum) -> Result<bool, Krb5Error> {
+    let input = slice_to_krb5_data(data);
+    let cksum = checksum.as_krb5_checksum();
+    let mut valid: krb5_boolean = 0;
+
+    let code: krb5_error_code = unsafe {
+      krb5_c_verify_checksum(
+        self.context.context,
+        self.keyblock,
+        usage.as_raw(),
+        &input,
+        &cksum,
+        &mut valid,
+      )
+    };
+
+    krb5_error_code_escape_hatch(self.context, code)?;
+
+    Ok(valid != 0)
+  }
+
+  /**
+   * Return the length of the key in bytes.
+   */
+  pub fn len(&self) -> usize {
+    self.contents().len()
+  }
+}
+
+/**
+ * Keys are equal if they have the same enctype and contents. The contents are compared in
+ * constant time.
+ */
+impl<'a, 'b> PartialEq<Krb5Keyblock<'b>> for Krb5Keyblock<'a> {
+  fn eq(&self, other: &Krb5Keyblock<'b>) -> bool {
+    self.enctype() == other.enctype() && constant_time_eq(self.contents(), other.contents())
+  }
+}
+
+impl<'a> Eq for Krb5Keyblock<'a> {}
+
+impl<'a> AsRef<[u8]> for Krb5Keyblock<'a> {
+  fn as_ref(&self) -> &[u8] {
+    self.contents()
+  }
+}
+
+/**
+ * Overwrite the key with zeroes. The keyblock stays usable, but holds an all-zero key.
+ */
+#[cfg(feature = "zeroize")]
+impl<'a> ::zeroize::Zeroize for Krb5Keyblock<'a> {
+  fn zeroize(&mut self) {
+    let keyblock = unsafe { &mut *self.keyblock };
+
+    if !keyblock.contents.is_null() {
+      zeroize(unsafe { std::slice::from_raw_parts_mut(keyblock.contents, keyblock.length as usize) });
+    }
+  }
+}
+
+/**
+ * libkrb5 zeroes the key when the keyblock is freed.
+ */
+#[cfg(feature = "zeroize")]
+impl<'a> ::zeroize::ZeroizeOnDrop for Krb5Keyblock<'a> {}
+
+impl<'a> Debug for Krb5Keyblock<'a> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+    f.debug_struct("Krb5Keyblock")
+      .field("enctype", &self.enctype())
+      .field("contents", &format_args!("<{} bytes redacted>", self.len()))
+      .finish()
+  }
+}
+
+/**
+ * Cipher state of a key, which chains consecutive encryptions or decryptions.
+ *
+ * The state is updated by every call it is passed to, so messages have to be decrypted in the
+ * order they were encrypted.
+ */
+#[derive(Debug)]
+pub struct Krb5CipherState<'k, 'a> {
+  key: &'k Krb5Keyblock<'a>,
+  state: krb5_data,
+}
+
+/**
+ * Free a cipher state.
+ *
+ * [krb5_c_free_state](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_c_free_state.html)
+ */
+impl<'k, 'a> Drop for Krb5CipherState<'k, 'a> {
+  fn drop(&mut self) {
+    unsafe {
+      krb5_c_free_state(self.key.context.context, self.key.keyblock, &mut self.state);
+    }
+  }
+}
+
+/**
+ * Return a pointer to an optional cipher state, or NULL.
+ */
+fn cipher_state_ptr(state: Option<&mut Krb5CipherState>) -> *const krb5_data {
+  match state {
+    Some(state) => &state.state,
+    None => std::ptr::null(),
+  }
+}
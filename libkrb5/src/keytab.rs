@@ -0,0 +1,891 @@
+/*!
+ * Rustic wrapper for krb5 key tables.
+ */
+use std::ffi::{OsStr, OsString};
+use std::fmt::{Debug, Formatter};
+#[cfg(krb5_mit)]
+use std::fs::File;
+use std::fs::OpenOptions;
+use std::io::{ErrorKind, Write};
+use std::mem::MaybeUninit;
+use std::os::raw::{c_char, c_int, c_uint};
+#[cfg(unix)]
+use std::os::unix::fs::OpenOptionsExt;
+use std::path::Path;
+#[cfg(krb5_mit)]
+use std::path::PathBuf;
+
+use libkrb5_sys::*;
+
+use crate::context::Krb5Context;
+#[cfg(all(krb5_mit, krb5_1_15))]
+use crate::creds::init_creds_error_code_escape_hatch;
+#[cfg(not(all(krb5_mit, krb5_1_15)))]
+use crate::creds::InitCredsContext;
+use crate::creds::{Krb5Creds, Krb5InitCredsOptions};
+use crate::enctype::Krb5Enctype;
+use crate::error::{krb5_error_code_escape_hatch, Krb5Error};
+#[cfg(all(krb5_mit, krb5_1_15))]
+use crate::kdc_hook::capture_kdc_error;
+use crate::principal::{copy_principal, Krb5Principal};
+#[cfg(krb5_mit)]
+use crate::profile::profile_values;
+use crate::secret::zeroize;
+use crate::strconv::{c_string_to_os_string, os_str_to_c_string};
+use crate::timestamp::Krb5Timestamp;
+
+/**
+ * Version number at the start of a key table file (big-endian).
+ */
+const KEYTAB_FILE_VERSION: [u8; 2] = [0x05, 0x02];
+
+/**
+ * Environment variable that overrides the default key table name.
+ */
+#[cfg(krb5_mit)]
+const KTNAME_ENV_VAR: &str = "KRB5_KTNAME";
+
+/**
+ * Wrapper struct for a krb5 key table.
+ *
+ * https://web.mit.edu/kerberos/krb5-1.16/doc/basic/keytab_def.html
+ */
+#[derive(Debug)]
+pub struct Krb5Keytab<'a> {
+  pub(crate) context: &'a Krb5Context,
+  pub(crate) keytab: krb5_keytab,
+}
+
+/**
+ * Close a key table handle.
+ *
+ * [krb5_kt_close](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_kt_close.html)
+ */
+impl<'a> Drop for Krb5Keytab<'a> {
+  fn drop(&mut self) {
+    unsafe {
+      krb5_kt_close(self.context.context, self.keytab);
+    }
+  }
+}
+
+impl<'a> Krb5Keytab<'a> {
+  /**
+   * Acquire initial credentials for a principal using its key from this key table, like
+   * `kinit -k`.
+   *
+   * Set `out_ccache` in the options to store the credentials in a credential cache right away.
+   *
+   * If the KDC rejects the request, the error carries its KRB-ERROR (see
+   * [Krb5Error::kdc_error]). With MIT krb5 1.15 or later, the request is sent like
+   * `krb5_get_init_creds_keytab` does, so a request rejected by a replica KDC (e.g. because a
+   * new key has not been propagated yet) is retried with the primary KDC; the KRB-ERROR is
+   * recorded with a KDC receive hook. Otherwise the request API is used, which keeps the
+   * KRB-ERROR but does not retry with the primary KDC.
+   *
+   * [krb5_get_init_creds_keytab](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_get_init_creds_keytab.html)
+   * and [krb5_set_kdc_recv_hook](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_set_kdc_recv_hook.html),
+   * or [krb5_init_creds_get](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_init_creds_get.html)
+   *
+   * # Arguments
+   *
+   *  * principal: the client principal to authenticate as
+   *  * options: options for the initial credentials request
+   */
+  #[cfg(all(krb5_mit, krb5_1_15))]
+  pub fn acquire_initial_creds(
+    &self,
+    principal: &Krb5Principal,
+    options: &Krb5InitCredsOptions,
+  ) -> Result<Krb5Creds<'a>, Krb5Error> {
+    let opt = options.to_opt(self.context)?;
+    let mut creds_ptr: MaybeUninit<krb5_creds> = MaybeUninit::zeroed();
+
+    let (code, kdc_error) = capture_kdc_error(self.context, || unsafe {
+      krb5_get_init_creds_keytab(
+        self.context.context,
+        creds_ptr.as_mut_ptr(),
+        principal.principal,
+        self.keytab,
+        0,
+        std::ptr::null(),
+        opt.opt,
+      )
+    });
+
+    init_creds_error_code_escape_hatch(self.context, code, principal, kdc_error)?;
+
+    let creds = Krb5Creds {
+      context: self.context,
+      creds: unsafe { creds_ptr.assume_init() },
+    };
+
+    Ok(creds)
+  }
+
+  /**
+   * Acquire initial credentials for a principal using its key from this key table, like `kinit
+   * -k`.
+   *
+   * Set `out_ccache` in the options to store the credentials in a credential cache right away.
+   *
+   * If the KDC rejects the request, the error carries its KRB-ERROR (see
+   * [Krb5Error::kdc_error]). A request rejected by a replica KDC is not retried with the
+   * primary KDC, which needs MIT krb5 1.15 or later.
+   *
+   * [krb5_init_creds_get](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_init_creds_get.html)
+   *
+   * # Arguments
+   *
+   *  * principal: the client principal to authenticate as
+   *  * options: options for the initial credentials request
+   */
+  #[cfg(not(all(krb5_mit, krb5_1_15)))]
+  pub fn acquire_initial_creds(
+    &self,
+    principal: &Krb5Principal,
+    options: &Krb5InitCredsOptions,
+  ) -> Result<Krb5Creds<'a>, Krb5Error> {
+    let opt = options.to_opt(self.context)?;
+
+    let mut request = InitCredsContext::init(self.context, principal, &opt)?;
+    request.set_keytab(self)?;
+
+    request.get(principal)
+  }
+
+  /**
+   * Add an entry to the key table.
+   *
+   * [krb5_kt_add_entry](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_kt_add_entry.html)
+   */
+  pub fn add_entry(&mut self, entry: &Krb5KeytabEntry) -> Result<(), Krb5Error> {
+    let mut entry = entry.entry;
+
+    let code: krb5_error_code = unsafe { krb5_kt_add_entry(self.context.context, self.keytab, &mut entry) };
+
+    krb5_error_code_escape_hatch(self.context, code)?;
+
+    Ok(())
+  }
+
+  /**
+   * Add raw key material for a principal to the key table.
+   *
+   * The key is copied by the key table implementation; combined with a `MEMORY:` key table this
+   * allows using keys obtained from a secrets store without ever writing them to disk.
+   *
+   * # Arguments
+   *
+   *  * principal: the principal the key belongs to
+   *  * kvno: the key version number
+   *  * enctype: the encryption type of the key
+   *  * key: the raw key bytes
+   */
+  pub fn add_key(
+    &mut self,
+    principal: &Krb5Principal,
+    kvno: krb5_kvno,
+    enctype: Krb5Enctype,
+    key: &[u8],
+  ) -> Result<(), Krb5Error> {
+    let timestamp = Krb5Timestamp::now(self.context)?;
+
+    let mut entry = krb5_keytab_entry {
+      magic: 0,
+      principal: principal.principal,
+      timestamp: timestamp.as_raw(),
+      vno: kvno,
+      key: krb5_keyblock {
+        magic: 0,
+        enctype: enctype.as_raw(),
+        length: key.len() as u32,
+        contents: key.as_ptr() as *mut krb5_octet,
+      },
+    };
+
+    let code: krb5_error_code = unsafe { krb5_kt_add_entry(self.context.context, self.keytab, &mut entry) };
+
+    krb5_error_code_escape_hatch(self.context, code)?;
+
+    Ok(())
+  }
+
+  /**
+   * Copy entries of this key table into another key table.
+   *
+   * This replaces the usual `ktutil` "rkt / wkt" dance for merging key tables. Returns the
+   * number of copied entries.
+   *
+   * # Arguments
+   *
+   *  * dest: the key table to add the entries to
+   *  * filter: restricts which entries are copied; use `Krb5KeytabFilter::default()` to copy
+   *    all entries
+   */
+  pub fn copy_to(&self, dest: &mut Krb5Keytab, filter: &Krb5KeytabFilter) -> Result<usize, Krb5Error> {
+    let mut copied = 0;
+
+    for entry in self.entries()? {
+      let entry = entry?;
+
+      if filter.matches(&entry) {
+        dest.add_entry(&entry)?;
+        copied += 1;
+      }
+    }
+
+    Ok(copied)
+  }
+
+  /**
+   * Resolve the default key table.
+   *
+   * [krb5_kt_default](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_kt_default.html)
+   *
+   * # Arguments
+   *
+   *  * context: the Krb5Context instance
+   */
+  pub fn default(context: &Krb5Context) -> Result<Krb5Keytab<'_>, Krb5Error> {
+    let mut keytab_ptr: MaybeUninit<krb5_keytab> = MaybeUninit::zeroed();
+
+    let code: krb5_error_code = unsafe { krb5_kt_default(context.context, keytab_ptr.as_mut_ptr()) };
+
+    krb5_error_code_escape_hatch(context, code)?;
+
+    let keytab = Krb5Keytab {
+      context,
+      keytab: unsafe { keytab_ptr.assume_init() },
+    };
+
+    Ok(keytab)
+  }
+
+  /**
+   * Explain how the default key table name is determined and whether the key table file is
+   * usable.
+   *
+   * The default key table name is taken from the `KRB5_KTNAME` environment variable if it is
+   * set (and the context is not a secure context), otherwise from `default_keytab_name` in
+   * the `[libdefaults]` section of krb5.conf, and finally from the default compiled into the
+   * library. For file based key tables the diagnostics also report whether the file exists
+   * and can be opened by the current user, which helps to turn "Key table file not found"
+   * errors into something actionable.
+   */
+  #[cfg(krb5_mit)]
+  pub fn default_diagnostics(context: &Krb5Context) -> Result<Krb5KeytabDefaultDiagnostics, Krb5Error> {
+    // secure contexts ignore the environment
+    let env_name = if context.secure {
+      None
+    } else {
+      std::env::var(KTNAME_ENV_VAR).ok()
+    };
+    let profile_name = profile_values(context, &["libdefaults", "default_keytab_name"])?
+      .into_iter()
+      .next();
+    let name = Krb5Keytab::default_name(context)?;
+
+    let source = if env_name.as_ref() == Some(&name) {
+      Krb5KeytabDefaultSource::Environment
+    } else if profile_name.is_some() {
+      Krb5KeytabDefaultSource::Profile
+    } else {
+      Krb5KeytabDefaultSource::CompiledDefault
+    };
+
+    let path = keytab_file_path(&name);
+    let (exists, readable) = match &path {
+      Some(path) => (path.exists(), path.is_file() && File::open(path).is_ok()),
+      None => (false, false),
+    };
+
+    Ok(Krb5KeytabDefaultDiagnostics {
+      env_name,
+      profile_name,
+      name,
+      source,
+      path,
+      exists,
+      readable,
+    })
+  }
+
+  /**
+   * Return the name of the default key table.
+   *
+   * [krb5_kt_default_name](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_kt_default_name.html)
+   *
+   * # Arguments
+   *
+   *  * context: the Krb5Context instance
+   */
+  pub fn default_name(context: &Krb5Context) -> Result<String, Krb5Error> {
+    let mut name: [c_char; MAX_KEYTAB_NAME_LEN as usize + 1] = [0; MAX_KEYTAB_NAME_LEN as usize + 1];
+
+    let code: krb5_error_code =
+      unsafe { krb5_kt_default_name(context.context, name.as_mut_ptr(), name.len() as c_int) };
+
+    krb5_error_code_escape_hatch(context, code)?;
+
+    context.c_string_to_string(name.as_ptr())
+  }
+
+  /**
+   * Iterate over the entries of the key table.
+   *
+   * [krb5_kt_start_seq_get](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_kt_start_seq_get.html)
+   */
+  pub fn entries<'k>(&'k self) -> Result<Krb5KeytabCursor<'a, 'k>, Krb5Error> {
+    let mut cursor_ptr: MaybeUninit<krb5_kt_cursor> = MaybeUninit::zeroed();
+
+    let code: krb5_error_code =
+      unsafe { krb5_kt_start_seq_get(self.context.context, self.keytab, cursor_ptr.as_mut_ptr()) };
+
+    krb5_error_code_escape_hatch(self.context, code).map_err(|error| {
+      error.context(format!(
+        "while reading keytab {}",
+        self.get_name().unwrap_or_default().to_string_lossy()
+      ))
+    })?;
+
+    let cursor = Krb5KeytabCursor {
+      keytab: self,
+      cursor: unsafe { cursor_ptr.assume_init() },
+    };
+
+    Ok(cursor)
+  }
+
+  /**
+   * Return the name of the key table.
+   *
+   * [krb5_kt_get_name](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_kt_get_name.html)
+   */
+  pub fn get_name(&self) -> Result<OsString, Krb5Error> {
+    let mut name: [c_char; MAX_KEYTAB_NAME_LEN as usize + 1] = [0; MAX_KEYTAB_NAME_LEN as usize + 1];
+
+    let code: krb5_error_code = unsafe {
+      krb5_kt_get_name(
+        self.context.context,
+        self.keytab,
+        name.as_mut_ptr(),
+        name.len() as c_uint,
+      )
+    };
+
+    krb5_error_code_escape_hatch(self.context, code)?;
+
+    c_string_to_os_string(name.as_ptr())
+  }
+
+  /**
+   * Return the type of the key table.
+   *
+   * [krb5_kt_get_type](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_kt_get_type.html)
+   */
+  pub fn get_type(&self) -> Result<String, Krb5Error> {
+    let kttype: *const c_char = unsafe { krb5_kt_get_type(self.context.context, self.keytab) };
+
+    self.context.c_string_to_string(kttype)
+  }
+
+  /**
+   * Check that the key table exists and contains at least one entry.
+   *
+   * Returns a `KRB5_KT_NOTFOUND` error naming the key table if it is nonexistent or empty,
+   * which makes it possible to report a misconfigured service keytab at startup rather than
+   * on the first failed authentication attempt.
+   *
+   * Needs MIT krb5 1.11 or later.
+   *
+   * [krb5_kt_have_content](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_kt_have_content.html)
+   */
+  #[cfg(krb5_1_11)]
+  pub fn has_content(&self) -> Result<(), Krb5Error> {
+    let code: krb5_error_code = unsafe { krb5_kt_have_content(self.context.context, self.keytab) };
+
+    if code == KRB5_KT_NOTFOUND {
+      /* keep the original code even if the name can not be read */
+      let name = self
+        .get_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+      return Err(Krb5Error::LibraryError {
+        code,
+        message: format!("Key table {} is nonexistent or empty", name),
+      });
+    }
+
+    krb5_error_code_escape_hatch(self.context, code)
+  }
+
+  /**
+   * Create a key table that only lives in process memory.
+   *
+   * `MEMORY:` key tables with the same name share their contents within a process until the
+   * last handle is closed.
+   *
+   * # Arguments
+   *
+   *  * context: the Krb5Context instance
+   *  * name: the residual name of the memory key table
+   */
+  pub fn memory<N: AsRef<OsStr>>(context: &'a Krb5Context, name: N) -> Result<Krb5Keytab<'a>, Krb5Error> {
+    let mut memory_name = OsString::from("MEMORY:");
+    memory_name.push(name);
+
+    Krb5Keytab::resolve(context, memory_name)
+  }
+
+  /**
+   * Copy all entries of another key table into this key table.
+   *
+   * # Arguments
+   *
+   *  * source: the key table to read entries from
+   */
+  pub fn populate_from(&mut self, source: &Krb5Keytab) -> Result<(), Krb5Error> {
+    source.copy_to(self, &Krb5KeytabFilter::default())?;
+
+    Ok(())
+  }
+
+  /**
+   * Resolve a key table name.
+   *
+   * [krb5_kt_resolve](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_kt_resolve.html)
+   *
+   * # Arguments
+   *
+   *  * context: the Krb5Context instance
+   *  * name: the key table name to be resolved, in the form `type:residual`, where the residual
+   *    may be a file path that is not valid UTF-8
+   *
+   */
+  pub fn resolve<N: AsRef<OsStr>>(context: &'a Krb5Context, name: N) -> Result<Krb5Keytab<'a>, Krb5Error> {
+    let name = name.as_ref();
+    let name_c = os_str_to_c_string(name)?;
+
+    let mut keytab_ptr: MaybeUninit<krb5_keytab> = MaybeUninit::zeroed();
+
+    let code: krb5_error_code = unsafe { krb5_kt_resolve(context.context, name_c.as_ptr(), keytab_ptr.as_mut_ptr()) };
+
+    krb5_error_code_escape_hatch(context, code)
+      .map_err(|error| error.context(format!("while resolving keytab {}", name.to_string_lossy())))?;
+
+    let keytab = Krb5Keytab {
+      context,
+      keytab: unsafe { keytab_ptr.assume_init() },
+    };
+
+    Ok(keytab)
+  }
+
+  /**
+   * Summarize the key table contents like `klist -k -t -e`, grouped by principal.
+   *
+   * Principals are listed in the order of their first appearance in the key table.
+   */
+  pub fn summary(&self) -> Result<Vec<Krb5KeytabSummary>, Krb5Error> {
+    let mut summaries: Vec<Krb5KeytabSummary> = Vec::new();
+
+    for entry in self.entries()? {
+      let entry = entry?;
+      let principal = entry.principal()?.unparse_name()?;
+
+      let key = Krb5KeytabKeyInfo {
+        kvno: entry.kvno(),
+        enctype: entry.enctype(),
+        enctype_name: entry.enctype().to_string(),
+        timestamp: entry.timestamp(),
+        timestamp_string: entry.timestamp().to_string(),
+      };
+
+      match summaries.iter_mut().find(|summary| summary.principal == principal) {
+        Some(summary) => summary.keys.push(key),
+        None => summaries.push(Krb5KeytabSummary {
+          principal,
+          keys: vec![key],
+        }),
+      }
+    }
+
+    Ok(summaries)
+  }
+
+  /**
+   * Open a key table file for writing.
+   *
+   * Depending on the implementation, `FILE:` key table handles can be read-only, so adding
+   * entries to them fails. This resolves the path as `WRFILE:` instead, and creates an empty
+   * key table file with permissions restricted to the current user if it does not exist yet.
+   *
+   * # Arguments
+   *
+   *  * context: the Krb5Context instance
+   *  * path: the path of the key table file
+   */
+  pub fn writable_file<P: AsRef<Path>>(context: &'a Krb5Context, path: P) -> Result<Krb5Keytab<'a>, Krb5Error> {
+    let path = path.as_ref();
+    let mut name = OsString::from("WRFILE:");
+    name.push(path);
+
+    let mut options = OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    options.mode(0o600);
+
+    match options.open(path) {
+      // libkrb5 rejects empty files, so start with the file format version (0x0502) like
+      // ktutil does
+      Ok(mut file) => file.write_all(&KEYTAB_FILE_VERSION)?,
+      Err(error) if error.kind() == ErrorKind::AlreadyExists => {},
+      Err(error) => return Err(error.into()),
+    };
+
+    Krb5Keytab::resolve(context, name)
+  }
+}
+
+/**
+ * Selection criteria for copying key table entries.
+ *
+ * Criteria that are `None` match all entries.
+ */
+#[derive(Debug, Default)]
+pub struct Krb5KeytabFilter<'p> {
+  /// only match entries for this principal
+  pub principal: Option<&'p Krb5Principal<'p>>,
+  /// only match entries with at least this key version number
+  pub min_kvno: Option<krb5_kvno>,
+}
+
+impl<'p> Krb5KeytabFilter<'p> {
+  /**
+   * Check whether a key table entry satisfies all criteria.
+   *
+   * [krb5_principal_compare](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_principal_compare.html)
+   */
+  pub fn matches(&self, entry: &Krb5KeytabEntry) -> bool {
+    if let Some(min_kvno) = self.min_kvno {
+      if entry.kvno() < min_kvno {
+        return false;
+      }
+    }
+
+    if let Some(principal) = self.principal {
+      let equal = unsafe { krb5_principal_compare(entry.context.context, entry.entry.principal, principal.principal) };
+
+      if equal == 0 {
+        return false;
+      }
+    }
+
+    true
+  }
+}
+
+/**
+ * Wrapper struct for a key table cursor.
+ *
+ * Yields copies of the key table entries; the cursor is released when it is dropped.
+ */
+#[derive(Debug)]
+pub struct Krb5KeytabCursor<'a, 'k> {
+  pub(crate) keytab: &'k Krb5Keytab<'a>,
+  pub(crate) cursor: krb5_kt_cursor,
+}
+
+/**
+ * Release a key table cursor.
+ *
+ * [krb5_kt_end_seq_get](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_kt_end_seq_get.html)
+ */
+impl<'a, 'k> Drop for Krb5KeytabCursor<'a, 'k> {
+  fn drop(&mut self) {
+    unsafe {
+      krb5_kt_end_seq_get(self.keytab.context.context, self.keytab.keytab, &mut self.cursor);
+    }
+  }
+}
+
+/**
+ * Implement Rustic iterator for key table entries.
+ *
+ * [krb5_kt_next_entry](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_kt_next_entry.html)
+ */
+impl<'a, 'k> Iterator for Krb5KeytabCursor<'a, 'k> {
+  type Item = Result<Krb5KeytabEntry<'a>, Krb5Error>;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    let context = self.keytab.context;
+    let mut entry_ptr: MaybeUninit<krb5_keytab_entry> = MaybeUninit::zeroed();
+
+    let code: krb5_error_code = unsafe {
+      krb5_kt_next_entry(
+        context.context,
+        self.keytab.keytab,
+        entry_ptr.as_mut_ptr(),
+        &mut self.cursor,
+      )
+    };
+
+    if code == KRB5_KT_END {
+      return None;
+    }
+
+    if let Err(error) = krb5_error_code_escape_hatch(context, code) {
+      return Some(Err(error));
+    }
+
+    let entry = Krb5KeytabEntry {
+      context,
+      entry: unsafe { entry_ptr.assume_init() },
+    };
+
+    Some(Ok(entry))
+  }
+}
+
+/**
+ * Wrapper struct for a key table entry.
+ *
+ * The key is redacted from `Debug` output, which only shows the enctype and the key length.
+ *
+ * https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/types/krb5_keytab_entry.html
+ */
+pub struct Krb5KeytabEntry<'a> {
+  pub(crate) context: &'a Krb5Context,
+  pub(crate) entry: krb5_keytab_entry,
+}
+
+/**
+ * Free the contents of a key table entry.
+ *
+ * [krb5_free_keytab_entry_contents](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_free_keytab_entry_contents.html)
+ */
+impl<'a> Drop for Krb5KeytabEntry<'a> {
+  fn drop(&mut self) {
+    unsafe {
+      krb5_free_keytab_entry_contents(self.context.context, &mut self.entry);
+    }
+  }
+}
+
+impl<'a> Krb5KeytabEntry<'a> {
+  /**
+   * Return the encryption type of the entry's key.
+   */
+  pub fn enctype(&self) -> Krb5Enctype {
+    Krb5Enctype::from_raw(self.entry.key.enctype)
+  }
+
+  /**
+   * Return a copy of the entry's key.
+   *
+   * The copy is wiped from memory when it is dropped and is not included in its `Debug` output.
+   */
+  pub fn key(&self) -> Krb5KeytabKey {
+    let key = &self.entry.key;
+
+    let bytes = if key.contents.is_null() {
+      Vec::new()
+    } else {
+      unsafe { std::slice::from_raw_parts(key.contents, key.length as usize) }.to_vec()
+    };
+
+    Krb5KeytabKey {
+      enctype: Krb5Enctype::from_raw(key.enctype),
+      bytes,
+    }
+  }
+
+  /**
+   * Return the key version number of the entry.
+   */
+  pub fn kvno(&self) -> krb5_kvno {
+    self.entry.vno
+  }
+
+  /**
+   * Return a copy of the principal the entry belongs to.
+   */
+  pub fn principal(&self) -> Result<Krb5Principal<'a>, Krb5Error> {
+    copy_principal(self.context, self.entry.principal)
+  }
+
+  /**
+   * Return the time the entry was written to the key table.
+   */
+  pub fn timestamp(&self) -> Krb5Timestamp {
+    Krb5Timestamp::from_raw(self.entry.timestamp)
+  }
+}
+
+impl<'a> Debug for Krb5KeytabEntry<'a> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+    f.debug_struct("Krb5KeytabEntry")
+      .field(
+        "principal",
+        &self.principal().and_then(|principal| principal.unparse_name()).ok(),
+      )
+      .field("kvno", &self.kvno())
+      .field("timestamp", &self.timestamp())
+      .field("enctype", &self.enctype())
+      .field("key", &format_args!("<{} bytes redacted>", self.entry.key.length))
+      .finish()
+  }
+}
+
+/**
+ * Where the default key table name was taken from.
+ */
+#[cfg(krb5_mit)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Krb5KeytabDefaultSource {
+  /// the `KRB5_KTNAME` environment variable
+  Environment,
+  /// `default_keytab_name` in the `[libdefaults]` section of krb5.conf
+  Profile,
+  /// the default compiled into libkrb5, as neither of the above is set
+  CompiledDefault,
+}
+
+/**
+ * Diagnostics about the resolution of the default key table, see
+ * [Krb5Keytab::default_diagnostics].
+ */
+#[cfg(krb5_mit)]
+#[derive(Debug)]
+pub struct Krb5KeytabDefaultDiagnostics {
+  /// value of the `KRB5_KTNAME` environment variable, if set and the context is not a secure
+  /// context
+  pub env_name: Option<String>,
+  /// value of `default_keytab_name` in the `[libdefaults]` section of krb5.conf, if set
+  pub profile_name: Option<String>,
+  /// the resolved default key table name
+  pub name: String,
+  /// where the resolved name was taken from
+  pub source: Krb5KeytabDefaultSource,
+  /// path of the key table file, if the default key table is file based
+  pub path: Option<PathBuf>,
+  /// whether the key table file exists
+  pub exists: bool,
+  /// whether the key table file is a regular file that can be opened for reading by the current
+  /// user
+  pub readable: bool,
+}
+
+/**
+ * Return the file path of a `FILE:` or `WRFILE:` key table name, or a name without a type
+ * prefix.
+ */
+#[cfg(krb5_mit)]
+fn keytab_file_path(name: &str) -> Option<PathBuf> {
+  match name.find(':') {
+    // a single letter prefix is a Windows drive letter, not a key table type
+    Some(index) if index > 1 => match &name[..index] {
+      "FILE" | "WRFILE" => Some(PathBuf::from(&name[index + 1..])),
+      _ => None,
+    },
+    _ => Some(PathBuf::from(name)),
+  }
+}
+
+/**
+ * Copy of the key material of a key table entry.
+ *
+ * The key bytes are zeroed when the value is dropped, and redacted from `Debug` output.
+ */
+pub struct Krb5KeytabKey {
+  enctype: Krb5Enctype,
+  bytes: Vec<u8>,
+}
+
+impl Krb5KeytabKey {
+  /**
+   * Return the raw key bytes.
+   */
+  pub fn as_bytes(&self) -> &[u8] {
+    &self.bytes
+  }
+
+  /**
+   * Return the encryption type of the key.
+   */
+  pub fn enctype(&self) -> Krb5Enctype {
+    self.enctype
+  }
+}
+
+impl AsRef<[u8]> for Krb5KeytabKey {
+  fn as_ref(&self) -> &[u8] {
+    &self.bytes
+  }
+}
+
+impl Debug for Krb5KeytabKey {
+  fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+    f.debug_struct("Krb5KeytabKey")
+      .field("enctype", &self.enctype)
+      .field("bytes", &format_args!("<{} bytes redacted>", self.bytes.len()))
+      .finish()
+  }
+}
+
+impl Drop for Krb5KeytabKey {
+  fn drop(&mut self) {
+    zeroize(&mut self.bytes);
+  }
+}
+
+#[cfg(feature = "zeroize")]
+impl ::zeroize::Zeroize for Krb5KeytabKey {
+  fn zeroize(&mut self) {
+    zeroize(&mut self.bytes);
+  }
+}
+
+#[cfg(feature = "zeroize")]
+impl ::zeroize::ZeroizeOnDrop for Krb5KeytabKey {}
+
+/**
+ * Summary of the keys stored for one principal in a key table.
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct Krb5KeytabSummary {
+  /// the principal name
+  pub principal: String,
+  /// the keys stored for the principal, in key table order
+  pub keys: Vec<Krb5KeytabKeyInfo>,
+}
+
+impl Krb5KeytabSummary {
+  /**
+   * Return the highest key version number stored for the principal.
+   *
+   * This can be compared with the kvno the KDC reports to check whether the key table is up to
+   * date.
+   */
+  pub fn max_kvno(&self) -> Option<krb5_kvno> {
+    self.keys.iter().map(|key| key.kvno).max()
+  }
+}
+
+/**
+ * Key metadata of a single key table entry (without the key material).
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct Krb5KeytabKeyInfo {
+  /// the key version number
+  pub kvno: krb5_kvno,
+  /// the encryption type
+  pub enctype: Krb5Enctype,
+  /// the encryption type name, e.g. `aes256-cts-hmac-sha1-96`
+  pub enctype_name: String,
+  /// the time the entry was written to the key table
+  pub timestamp: Krb5Timestamp,
+  /// the time the entry was written to the key table, formatted like klist does
+  pub timestamp_string: String,
+}
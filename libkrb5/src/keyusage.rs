@@ -0,0 +1,148 @@
+/*!
+ * Key usage numbers.
+ */
+use libkrb5_sys::*;
+
+/**
+ * A krb5 key usage number (`KRB5_KEYUSAGE_*`), which selects the key derived from a base key
+ * for a specific purpose (RFC 4120, section 7.5.1).
+ *
+ * Both sides have to use the same key usage, so encrypting or checksumming data with one usage
+ * and processing it with another one fails. Applications that define their own protocol use
+ * [Krb5KeyUsage::AppDataEncrypt] and [Krb5KeyUsage::AppDataChecksum], or their own number with
+ * [Krb5KeyUsage::Unknown].
+ *
+ * New variants may be added for further key usages, so matches need a wildcard arm.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Krb5KeyUsage {
+  /// AS-REQ PA-ENC-TIMESTAMP padata timestamp, encrypted with the client key (1)
+  AsReqPaEncTimestamp,
+  /// the encrypted part of a ticket, encrypted with the service key (2)
+  KdcRepTicket,
+  /// the encrypted part of an AS-REP, encrypted with the client key (3)
+  AsRepEncPart,
+  /// TGS-REQ authorization data, encrypted with the TGS session key (4)
+  TgsReqAdSessionKey,
+  /// TGS-REQ authorization data, encrypted with the TGS authenticator subkey (5)
+  TgsReqAdSubkey,
+  /// TGS-REQ PA-TGS-REQ authenticator checksum, keyed with the TGS session key (6)
+  TgsReqAuthChecksum,
+  /// TGS-REQ PA-TGS-REQ authenticator, encrypted with the TGS session key (7)
+  TgsReqAuthenticator,
+  /// the encrypted part of a TGS-REP, encrypted with the TGS session key (8)
+  TgsRepEncPartSessionKey,
+  /// the encrypted part of a TGS-REP, encrypted with the TGS authenticator subkey (9)
+  TgsRepEncPartSubkey,
+  /// AP-REQ authenticator checksum, keyed with the session key (10)
+  ApReqAuthChecksum,
+  /// AP-REQ authenticator, encrypted with the session key (11)
+  ApReqAuthenticator,
+  /// the encrypted part of an AP-REP, encrypted with the session key (12)
+  ApRepEncPart,
+  /// the encrypted part of a KRB-PRIV message (13)
+  KrbPrivEncPart,
+  /// the encrypted part of a KRB-CRED message (14)
+  KrbCredEncPart,
+  /// the checksum of a KRB-SAFE message (15)
+  KrbSafeChecksum,
+  /// application data encrypted with a key from a Kerberos exchange (16)
+  AppDataEncrypt,
+  /// checksum of application data with a key from a Kerberos exchange (17)
+  AppDataChecksum,
+  /// the checksum of a KRB-ERROR message (18)
+  KrbErrorChecksum,
+  /// AD-KDCIssued checksum (19)
+  AdKdcIssuedChecksum,
+  /// GSS-API MIC tokens (RFC 4121) (22)
+  GssTokenMic,
+  /// GSS-API wrap tokens without confidentiality (RFC 4121) (23)
+  GssTokenWrapInteg,
+  /// GSS-API wrap tokens with confidentiality (RFC 4121) (24)
+  GssTokenWrapPriv,
+  /// any other key usage number, e.g. one defined by an application protocol
+  Unknown(krb5_keyusage),
+}
+
+impl Krb5KeyUsage {
+  /**
+   * Convert a key usage number.
+   */
+  pub fn from_raw(usage: krb5_keyusage) -> Self {
+    use Krb5KeyUsage::*;
+
+    match usage as u32 {
+      KRB5_KEYUSAGE_AS_REQ_PA_ENC_TS => AsReqPaEncTimestamp,
+      KRB5_KEYUSAGE_KDC_REP_TICKET => KdcRepTicket,
+      KRB5_KEYUSAGE_AS_REP_ENCPART => AsRepEncPart,
+      KRB5_KEYUSAGE_TGS_REQ_AD_SESSKEY => TgsReqAdSessionKey,
+      KRB5_KEYUSAGE_TGS_REQ_AD_SUBKEY => TgsReqAdSubkey,
+      KRB5_KEYUSAGE_TGS_REQ_AUTH_CKSUM => TgsReqAuthChecksum,
+      KRB5_KEYUSAGE_TGS_REQ_AUTH => TgsReqAuthenticator,
+      KRB5_KEYUSAGE_TGS_REP_ENCPART_SESSKEY => TgsRepEncPartSessionKey,
+      KRB5_KEYUSAGE_TGS_REP_ENCPART_SUBKEY => TgsRepEncPartSubkey,
+      KRB5_KEYUSAGE_AP_REQ_AUTH_CKSUM => ApReqAuthChecksum,
+      KRB5_KEYUSAGE_AP_REQ_AUTH => ApReqAuthenticator,
+      KRB5_KEYUSAGE_AP_REP_ENCPART => ApRepEncPart,
+      KRB5_KEYUSAGE_KRB_PRIV_ENCPART => KrbPrivEncPart,
+      KRB5_KEYUSAGE_KRB_CRED_ENCPART => KrbCredEncPart,
+      KRB5_KEYUSAGE_KRB_SAFE_CKSUM => KrbSafeChecksum,
+      KRB5_KEYUSAGE_APP_DATA_ENCRYPT => AppDataEncrypt,
+      KRB5_KEYUSAGE_APP_DATA_CKSUM => AppDataChecksum,
+      KRB5_KEYUSAGE_KRB_ERROR_CKSUM => KrbErrorChecksum,
+      KRB5_KEYUSAGE_AD_KDCISSUED_CKSUM => AdKdcIssuedChecksum,
+      KRB5_KEYUSAGE_GSS_TOK_MIC => GssTokenMic,
+      KRB5_KEYUSAGE_GSS_TOK_WRAP_INTEG => GssTokenWrapInteg,
+      KRB5_KEYUSAGE_GSS_TOK_WRAP_PRIV => GssTokenWrapPriv,
+      _ => Unknown(usage),
+    }
+  }
+
+  /**
+   * Return the key usage number.
+   */
+  pub fn as_raw(self) -> krb5_keyusage {
+    use Krb5KeyUsage::*;
+
+    let usage = match self {
+      AsReqPaEncTimestamp => KRB5_KEYUSAGE_AS_REQ_PA_ENC_TS,
+      KdcRepTicket => KRB5_KEYUSAGE_KDC_REP_TICKET,
+      AsRepEncPart => KRB5_KEYUSAGE_AS_REP_ENCPART,
+      TgsReqAdSessionKey => KRB5_KEYUSAGE_TGS_REQ_AD_SESSKEY,
+      TgsReqAdSubkey => KRB5_KEYUSAGE_TGS_REQ_AD_SUBKEY,
+      TgsReqAuthChecksum => KRB5_KEYUSAGE_TGS_REQ_AUTH_CKSUM,
+      TgsReqAuthenticator => KRB5_KEYUSAGE_TGS_REQ_AUTH,
+      TgsRepEncPartSessionKey => KRB5_KEYUSAGE_TGS_REP_ENCPART_SESSKEY,
+      TgsRepEncPartSubkey => KRB5_KEYUSAGE_TGS_REP_ENCPART_SUBKEY,
+      ApReqAuthChecksum => KRB5_KEYUSAGE_AP_REQ_AUTH_CKSUM,
+      ApReqAuthenticator => KRB5_KEYUSAGE_AP_REQ_AUTH,
+      ApRepEncPart => KRB5_KEYUSAGE_AP_REP_ENCPART,
+      KrbPrivEncPart => KRB5_KEYUSAGE_KRB_PRIV_ENCPART,
+      KrbCredEncPart => KRB5_KEYUSAGE_KRB_CRED_ENCPART,
+      KrbSafeChecksum => KRB5_KEYUSAGE_KRB_SAFE_CKSUM,
+      AppDataEncrypt => KRB5_KEYUSAGE_APP_DATA_ENCRYPT,
+      AppDataChecksum => KRB5_KEYUSAGE_APP_DATA_CKSUM,
+      KrbErrorChecksum => KRB5_KEYUSAGE_KRB_ERROR_CKSUM,
+      AdKdcIssuedChecksum => KRB5_KEYUSAGE_AD_KDCISSUED_CKSUM,
+      GssTokenMic => KRB5_KEYUSAGE_GSS_TOK_MIC,
+      GssTokenWrapInteg => KRB5_KEYUSAGE_GSS_TOK_WRAP_INTEG,
+      GssTokenWrapPriv => KRB5_KEYUSAGE_GSS_TOK_WRAP_PRIV,
+      Unknown(usage) => return usage,
+    };
+
+    usage as krb5_keyusage
+  }
+}
+
+impl From<krb5_keyusage> for Krb5KeyUsage {
+  fn from(usage: krb5_keyusage) -> Self {
+    Krb5KeyUsage::from_raw(usage)
+  }
+}
+
+impl From<Krb5KeyUsage> for krb5_keyusage {
+  fn from(usage: Krb5KeyUsage) -> Self {
+    usage.as_raw()
+  }
+}
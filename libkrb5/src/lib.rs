@@ -2,22 +2,135 @@
  * Kerberos 5 client lib wrapper library base module.
  *
  */
+mod acceptor;
+pub use acceptor::{AuthenticatedPeer, ServiceAcceptor};
+
+mod address;
+
+mod auth_context;
+pub use auth_context::{
+  Krb5ApOptions,
+  Krb5ApRequest,
+  Krb5AuthContext,
+  Krb5AuthContextFlags,
+  Krb5Authenticator,
+  Krb5GenAddrsFlags,
+};
+
+mod authdata;
+pub use authdata::{Krb5Authdata, Krb5AuthdataType};
+
 mod ccache;
-pub use ccache::Krb5CCache;
+pub use ccache::{Krb5CCache, Krb5CCacheCursor, Krb5ServiceTicketInfo};
 
 mod cccol;
 pub use cccol::Krb5CCCol;
 
+mod checksum;
+pub use checksum::{Krb5Checksum, Krb5ChecksumType};
+
 mod context;
 pub use context::Krb5Context;
 
+mod creds;
+pub use creds::{Krb5Creds, Krb5InitCredsOptions};
+
+mod data;
+pub use data::Krb5Data;
+
+mod delegation_info;
+pub use delegation_info::Krb5PacDelegationInfo;
+
+mod deltat;
+pub use deltat::{deltat_to_string, string_to_deltat};
+
+mod der;
+
+mod enctype;
+pub use enctype::Krb5Enctype;
+
 mod error;
-pub use error::Krb5Error;
+pub use error::{error_message, Krb5Error, Result};
+
+mod error_code;
+pub use error_code::{codes, Krb5ErrorCode};
+
+mod error_message;
+pub use error_message::{Krb5ErrorMessage, Krb5KdcError};
+
+mod flavor;
+pub use flavor::Krb5Flavor;
+
+mod handshake;
+pub use handshake::{KerberosClient, KerberosServer, KerberosSession};
+
+mod iov;
+pub use iov::{Krb5CryptoIov, Krb5CryptoType};
+
+#[cfg(all(krb5_mit, krb5_1_15))]
+mod kdc_hook;
+
+mod keyblock;
+pub use keyblock::{Krb5CipherState, Krb5Keyblock};
+
+mod keyusage;
+pub use keyusage::Krb5KeyUsage;
+
+mod keytab;
+pub use keytab::{
+  Krb5Keytab,
+  Krb5KeytabCursor,
+  Krb5KeytabEntry,
+  Krb5KeytabFilter,
+  Krb5KeytabKey,
+  Krb5KeytabKeyInfo,
+  Krb5KeytabSummary,
+};
+#[cfg(krb5_mit)]
+pub use keytab::{Krb5KeytabDefaultDiagnostics, Krb5KeytabDefaultSource};
+
+#[cfg(feature = "pac-logon-info")]
+mod logon_info;
+#[cfg(feature = "pac-logon-info")]
+pub use logon_info::{Krb5PacGroup, Krb5PacLogonInfo, Krb5PacSid, Krb5Sid};
+
+mod ndr;
+
+#[cfg(feature = "async")]
+mod nonblocking;
+#[cfg(feature = "async")]
+pub use nonblocking::{Krb5AsyncClient, Krb5KdcReplyFuture, Krb5KdcTransport};
+
+mod pac;
+pub use pac::{Krb5Pac, Krb5PacBufferType};
+
+mod password;
+pub use password::{Krb5PasswordAuth, Krb5PasswordChangeResult, Krb5PasswordChangeStatus, Krb5PasswordPolicy};
 
 mod principal;
 pub use principal::{Krb5Principal, Krb5PrincipalData};
 
+#[cfg(krb5_mit)]
+mod profile;
+
+mod rcache;
+pub use rcache::Krb5RCache;
+
+#[cfg(feature = "async")]
+mod renewal;
+#[cfg(feature = "async")]
+pub use renewal::{TicketRenewalState, TicketRenewalTask};
+
+mod secret;
+
 mod strconv;
+pub use strconv::Krb5StringPolicy;
+
+mod ticket;
+pub use ticket::Krb5Ticket;
+
+mod timestamp;
+pub use timestamp::Krb5Timestamp;
 
 #[allow(dead_code)]
 static C_FALSE: u32 = 0;
@@ -0,0 +1,376 @@
+/*!
+ * Decoding of the logon information buffer of a PAC (KERB_VALIDATION_INFO, MS-PAC section 2.5).
+ */
+use std::fmt::{Display, Formatter};
+
+use crate::error::Krb5Error;
+use crate::ndr::NdrReader;
+
+/**
+ * A Windows security identifier (SID), e.g. of a user or a group.
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Krb5Sid {
+  /// the revision, always 1
+  pub revision: u8,
+  /// the identifier authority, a big-endian 48-bit number (5 for NT authority)
+  pub identifier_authority: [u8; 6],
+  /// the sub-authorities, the last one is the relative identifier (RID) for most SIDs
+  pub sub_authorities: Vec<u32>,
+}
+
+impl Krb5Sid {
+  /**
+   * Return the SID of an account of a domain, given the SID of the domain and the relative
+   * identifier of the account.
+   *
+   * # Arguments
+   *
+   *  * rid: the relative identifier
+   */
+  pub fn with_rid(&self, rid: u32) -> Krb5Sid {
+    let mut sid = self.clone();
+    sid.sub_authorities.push(rid);
+    sid
+  }
+}
+
+/**
+ * Display the SID in the string format, e.g. "S-1-5-21-1004336348-1177238915-682003330-512".
+ */
+impl Display for Krb5Sid {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    let authority = self
+      .identifier_authority
+      .iter()
+      .fold(0u64, |authority, byte| (authority << 8) | u64::from(*byte));
+
+    if authority >> 32 == 0 {
+      write!(f, "S-{}-{}", self.revision, authority)?;
+    } else {
+      write!(f, "S-{}-0x{:012X}", self.revision, authority)?;
+    }
+
+    for sub_authority in &self.sub_authorities {
+      write!(f, "-{}", sub_authority)?;
+    }
+
+    Ok(())
+  }
+}
+
+/**
+ * A group membership, given as a relative identifier in a domain (GROUP_MEMBERSHIP).
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Krb5PacGroup {
+  /// the relative identifier of the group
+  pub rid: u32,
+  /// the attributes of the membership (`SE_GROUP_*` flags)
+  pub attributes: u32,
+}
+
+/**
+ * A group membership, given as a SID (KERB_SID_AND_ATTRIBUTES).
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Krb5PacSid {
+  /// the SID of the group
+  pub sid: Krb5Sid,
+  /// the attributes of the membership (`SE_GROUP_*` flags)
+  pub attributes: u32,
+}
+
+/**
+ * The logon information of a PAC (KERB_VALIDATION_INFO), with the account and the group
+ * memberships of the client.
+ *
+ * Times are given as Windows FILETIME values (100 nanosecond intervals since 1601), where
+ * `0x7FFF_FFFF_FFFF_FFFF` means never. The user session key is not decoded, since it is not
+ * used in Kerberos.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Krb5PacLogonInfo {
+  /// the time the client logged on
+  pub logon_time: u64,
+  /// the time the logon session ends
+  pub logoff_time: u64,
+  /// the time the logon session is ended forcibly
+  pub kick_off_time: u64,
+  /// the time the password was last set
+  pub password_last_set: u64,
+  /// the time the password may be changed
+  pub password_can_change: u64,
+  /// the time the password expires
+  pub password_must_change: u64,
+  /// the account name (sAMAccountName)
+  pub effective_name: String,
+  /// the full name of the user
+  pub full_name: String,
+  /// the path of the logon script
+  pub logon_script: String,
+  /// the path of the roaming profile
+  pub profile_path: String,
+  /// the path of the home directory
+  pub home_directory: String,
+  /// the drive letter of the home directory
+  pub home_directory_drive: String,
+  /// the number of successful logons
+  pub logon_count: u16,
+  /// the number of logons with a wrong password
+  pub bad_password_count: u16,
+  /// the relative identifier of the account in the logon domain
+  pub user_id: u32,
+  /// the relative identifier of the primary group of the account in the logon domain
+  pub primary_group_id: u32,
+  /// the groups of the logon domain the account is a member of
+  pub groups: Vec<Krb5PacGroup>,
+  /// flags describing the logon (`LOGON_*`, e.g. 0x20 if `extra_sids` is set)
+  pub user_flags: u32,
+  /// the NetBIOS name of the domain controller that authenticated the client
+  pub logon_server: String,
+  /// the NetBIOS name of the logon domain
+  pub logon_domain_name: String,
+  /// the SID of the logon domain
+  pub logon_domain_id: Option<Krb5Sid>,
+  /// the account flags (`USER_*`, userAccountControl)
+  pub user_account_control: u32,
+  /// the status of a subauthentication package
+  pub sub_auth_status: u32,
+  /// the time of the last successful interactive logon
+  pub last_successful_interactive_logon: u64,
+  /// the time of the last failed interactive logon
+  pub last_failed_interactive_logon: u64,
+  /// the number of failed interactive logons since the last successful one
+  pub failed_interactive_logon_count: u32,
+  /// further groups of other domains, and well-known SIDs
+  pub extra_sids: Vec<Krb5PacSid>,
+  /// the SID of the domain of the resource groups
+  pub resource_group_domain_sid: Option<Krb5Sid>,
+  /// the domain local groups of the resource domain the account is a member of
+  pub resource_groups: Vec<Krb5PacGroup>,
+}
+
+impl Krb5PacLogonInfo {
+  /**
+   * Decode the contents of a `KRB5_PAC_LOGON_INFO` buffer.
+   *
+   * # Arguments
+   *
+   *  * data: the contents of the buffer
+   */
+  pub fn decode(data: &[u8]) -> Result<Krb5PacLogonInfo, Krb5Error> {
+    let mut reader = NdrReader::type_serialization(data)?;
+
+    let logon_time = reader.filetime()?;
+    let logoff_time = reader.filetime()?;
+    let kick_off_time = reader.filetime()?;
+    let password_last_set = reader.filetime()?;
+    let password_can_change = reader.filetime()?;
+    let password_must_change = reader.filetime()?;
+    let effective_name = reader.unicode_string()?;
+    let full_name = reader.unicode_string()?;
+    let logon_script = reader.unicode_string()?;
+    let profile_path = reader.unicode_string()?;
+    let home_directory = reader.unicode_string()?;
+    let home_directory_drive = reader.unicode_string()?;
+    let logon_count = reader.u16()?;
+    let bad_password_count = reader.u16()?;
+    let user_id = reader.u32()?;
+    let primary_group_id = reader.u32()?;
+    let group_count = reader.u32()?;
+    let groups_present = reader.pointer()?;
+    let user_flags = reader.u32()?;
+    reader.bytes(16)?; // user session key
+    let logon_server = reader.unicode_string()?;
+    let logon_domain_name = reader.unicode_string()?;
+    let logon_domain_id_present = reader.pointer()?;
+    reader.bytes(8)?; // reserved
+    let user_account_control = reader.u32()?;
+    let sub_auth_status = reader.u32()?;
+    let last_successful_interactive_logon = reader.filetime()?;
+    let last_failed_interactive_logon = reader.filetime()?;
+    let failed_interactive_logon_count = reader.u32()?;
+    reader.u32()?; // reserved
+    let sid_count = reader.u32()?;
+    let extra_sids_present = reader.pointer()?;
+    let resource_group_domain_sid_present = reader.pointer()?;
+    let resource_group_count = reader.u32()?;
+    let resource_groups_present = reader.pointer()?;
+
+    // the referents of the pointers follow the structure, in the order of the pointers
+    let effective_name = reader.unicode_string_contents(effective_name)?;
+    let full_name = reader.unicode_string_contents(full_name)?;
+    let logon_script = reader.unicode_string_contents(logon_script)?;
+    let profile_path = reader.unicode_string_contents(profile_path)?;
+    let home_directory = reader.unicode_string_contents(home_directory)?;
+    let home_directory_drive = reader.unicode_string_contents(home_directory_drive)?;
+    let groups = reader.groups(groups_present, group_count)?;
+    let logon_server = reader.unicode_string_contents(logon_server)?;
+    let logon_domain_name = reader.unicode_string_contents(logon_domain_name)?;
+    let logon_domain_id = reader.optional_sid(logon_domain_id_present)?;
+    let extra_sids = reader.extra_sids(extra_sids_present, sid_count)?;
+    let resource_group_domain_sid = reader.optional_sid(resource_group_domain_sid_present)?;
+    let resource_groups = reader.groups(resource_groups_present, resource_group_count)?;
+
+    Ok(Krb5PacLogonInfo {
+      logon_time,
+      logoff_time,
+      kick_off_time,
+      password_last_set,
+      password_can_change,
+      password_must_change,
+      effective_name,
+      full_name,
+      logon_script,
+      profile_path,
+      home_directory,
+      home_directory_drive,
+      logon_count,
+      bad_password_count,
+      user_id,
+      primary_group_id,
+      groups,
+      user_flags,
+      logon_server,
+      logon_domain_name,
+      logon_domain_id,
+      user_account_control,
+      sub_auth_status,
+      last_successful_interactive_logon,
+      last_failed_interactive_logon,
+      failed_interactive_logon_count,
+      extra_sids,
+      resource_group_domain_sid,
+      resource_groups,
+    })
+  }
+
+  /**
+   * Return the SID of the account, or `None` if the SID of the logon domain is missing.
+   */
+  pub fn user_sid(&self) -> Option<Krb5Sid> {
+    self
+      .logon_domain_id
+      .as_ref()
+      .map(|domain| domain.with_rid(self.user_id))
+  }
+
+  /**
+   * Return the SIDs of all groups the account is a member of: the groups of the logon domain,
+   * the extra SIDs and the resource groups.
+   */
+  pub fn group_sids(&self) -> Vec<Krb5Sid> {
+    let mut sids = Vec::new();
+
+    if let Some(domain) = &self.logon_domain_id {
+      sids.extend(self.groups.iter().map(|group| domain.with_rid(group.rid)));
+    }
+
+    sids.extend(self.extra_sids.iter().map(|extra_sid| extra_sid.sid.clone()));
+
+    if let Some(domain) = &self.resource_group_domain_sid {
+      sids.extend(self.resource_groups.iter().map(|group| domain.with_rid(group.rid)));
+    }
+
+    sids
+  }
+}
+
+impl<'d> NdrReader<'d> {
+  fn u8(&mut self) -> Result<u8, Krb5Error> {
+    Ok(self.bytes(1)?[0])
+  }
+
+  /**
+   * Read a FILETIME, which consists of the low and the high 32 bits.
+   */
+  fn filetime(&mut self) -> Result<u64, Krb5Error> {
+    let low = self.u32()?;
+    let high = self.u32()?;
+
+    Ok(u64::from(high) << 32 | u64::from(low))
+  }
+
+  /**
+   * Read an array of GROUP_MEMBERSHIP structures.
+   */
+  fn groups(&mut self, present: bool, count: u32) -> Result<Vec<Krb5PacGroup>, Krb5Error> {
+    if !present {
+      return Ok(Vec::new());
+    }
+
+    self.conformance(count)?;
+
+    let mut groups = Vec::new();
+    for _ in 0..count {
+      let rid = self.u32()?;
+      let attributes = self.u32()?;
+      groups.push(Krb5PacGroup { rid, attributes });
+    }
+
+    Ok(groups)
+  }
+
+  /**
+   * Read a SID, which is a conformant structure.
+   */
+  fn sid(&mut self) -> Result<Krb5Sid, Krb5Error> {
+    self.u32()?; // maximum count
+    let revision = self.u8()?;
+    let count = self.u8()?;
+
+    let mut identifier_authority = [0u8; 6];
+    identifier_authority.copy_from_slice(self.bytes(6)?);
+
+    let mut sub_authorities = Vec::with_capacity(count as usize);
+    for _ in 0..count {
+      sub_authorities.push(self.u32()?);
+    }
+
+    Ok(Krb5Sid {
+      revision,
+      identifier_authority,
+      sub_authorities,
+    })
+  }
+
+  fn optional_sid(&mut self, present: bool) -> Result<Option<Krb5Sid>, Krb5Error> {
+    if !present {
+      return Ok(None);
+    }
+
+    Ok(Some(self.sid()?))
+  }
+
+  /**
+   * Read an array of KERB_SID_AND_ATTRIBUTES structures, followed by the referents of their SID
+   * pointers.
+   */
+  fn extra_sids(&mut self, present: bool, count: u32) -> Result<Vec<Krb5PacSid>, Krb5Error> {
+    if !present {
+      return Ok(Vec::new());
+    }
+
+    self.conformance(count)?;
+
+    let mut entries = Vec::new();
+    for _ in 0..count {
+      let sid_present = self.pointer()?;
+      let attributes = self.u32()?;
+      entries.push((sid_present, attributes));
+    }
+
+    let mut extra_sids = Vec::new();
+    for (sid_present, attributes) in entries {
+      if sid_present {
+        extra_sids.push(Krb5PacSid {
+          sid: self.sid()?,
+          attributes,
+        });
+      }
+    }
+
+    Ok(extra_sids)
+  }
+}
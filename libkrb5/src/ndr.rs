@@ -0,0 +1,147 @@
+/*!
+ * Decoding of NDR data (MS-RPCE section 2.2.5), e.g. of PAC buffers.
+ */
+use std::convert::TryInto;
+
+use crate::error::Krb5Error;
+
+/**
+ * Length of the common and private headers of the type serialization (MS-RPCE section 2.2.6)
+ * in front of the NDR data.
+ */
+const NDR_HEADERS_LENGTH: usize = 16;
+
+/**
+ * The header of an RPC_UNICODE_STRING, whose contents follow as a deferred referent.
+ */
+pub(crate) struct UnicodeStringHeader {
+  length: u16,
+  present: bool,
+}
+
+/**
+ * Reader for little-endian NDR data with 32-bit pointers (MS-RPCE).
+ */
+pub(crate) struct NdrReader<'d> {
+  data: &'d [u8],
+  offset: usize,
+}
+
+impl<'d> NdrReader<'d> {
+  /**
+   * Start reading a type serialization with a top-level pointer, like the NDR encoded PAC
+   * buffers, returning a reader positioned at the referent.
+   */
+  pub(crate) fn type_serialization(data: &'d [u8]) -> Result<NdrReader<'d>, Krb5Error> {
+    let mut reader = NdrReader { data, offset: 0 };
+
+    let header = reader.bytes(NDR_HEADERS_LENGTH)?;
+    // version 1, little endian, header length 8
+    if header[0..4] != [0x01, 0x10, 0x08, 0x00] {
+      return Err(malformed("unsupported type serialization header"));
+    }
+
+    if !reader.pointer()? {
+      return Err(malformed("NDR data is NULL"));
+    }
+
+    Ok(reader)
+  }
+
+  /**
+   * Read the given number of bytes.
+   */
+  pub(crate) fn bytes(&mut self, len: usize) -> Result<&'d [u8], Krb5Error> {
+    let end = self
+      .offset
+      .checked_add(len)
+      .filter(|end| *end <= self.data.len())
+      .ok_or_else(|| malformed("truncated NDR data"))?;
+
+    let bytes = &self.data[self.offset..end];
+    self.offset = end;
+
+    Ok(bytes)
+  }
+
+  /**
+   * Skip to the next multiple of the given alignment.
+   */
+  pub(crate) fn align(&mut self, alignment: usize) -> Result<(), Krb5Error> {
+    let padding = (alignment - self.offset % alignment) % alignment;
+    self.bytes(padding).map(|_| ())
+  }
+
+  pub(crate) fn u16(&mut self) -> Result<u16, Krb5Error> {
+    self.align(2)?;
+    Ok(u16::from_le_bytes(self.bytes(2)?.try_into().unwrap()))
+  }
+
+  pub(crate) fn u32(&mut self) -> Result<u32, Krb5Error> {
+    self.align(4)?;
+    Ok(u32::from_le_bytes(self.bytes(4)?.try_into().unwrap()))
+  }
+
+  /**
+   * Read a pointer, and return `true` if it is not NULL.
+   */
+  pub(crate) fn pointer(&mut self) -> Result<bool, Krb5Error> {
+    Ok(self.u32()? != 0)
+  }
+
+  /**
+   * Read the maximum count of a conformant array, and check that it holds the expected number
+   * of elements.
+   */
+  pub(crate) fn conformance(&mut self, count: u32) -> Result<(), Krb5Error> {
+    if self.u32()? < count {
+      return Err(malformed("array is shorter than its element count"));
+    }
+
+    Ok(())
+  }
+
+  pub(crate) fn unicode_string(&mut self) -> Result<UnicodeStringHeader, Krb5Error> {
+    let length = self.u16()?;
+    self.u16()?; // maximum length
+    let present = self.pointer()?;
+
+    Ok(UnicodeStringHeader { length, present })
+  }
+
+  /**
+   * Read the contents of an RPC_UNICODE_STRING, a conformant varying array of UTF-16 code
+   * units.
+   */
+  pub(crate) fn unicode_string_contents(&mut self, header: UnicodeStringHeader) -> Result<String, Krb5Error> {
+    if !header.present {
+      return Ok(String::new());
+    }
+
+    self.u32()?; // maximum count
+    self.u32()?; // offset
+    let count = self.u32()? as usize;
+
+    if count * 2 < header.length as usize {
+      return Err(malformed("string is shorter than its length"));
+    }
+
+    let bytes = self.bytes(count * 2)?;
+    let units: Vec<u16> = bytes
+      .chunks_exact(2)
+      .take(header.length as usize / 2)
+      .map(|unit| u16::from_le_bytes([unit[0], unit[1]]))
+      .collect();
+
+    Ok(String::from_utf16_lossy(&units))
+  }
+}
+
+/**
+ * Build the error for NDR data that cannot be decoded.
+ */
+pub(crate) fn malformed(message: &str) -> Krb5Error {
+  Krb5Error::Io {
+    error: std::io::Error::new(std::io::ErrorKind::InvalidData, message),
+  }
+}
@@ -0,0 +1,474 @@
+/*!
+ * Credential acquisition for async services, available with the `async` feature.
+ *
+ * libkrb5 talks to the KDC with blocking sockets, so the requests are run on the blocking
+ * thread pool of the tokio runtime instead of stalling its worker threads. Contexts, credential
+ * caches and principals must not leave the thread that uses them, so each request creates its
+ * own context, credential caches and principals are passed by name, and the credentials are
+ * handed over in a credential cache.
+ *
+ * With MIT krb5, the requests to KDCs can be handed to an async [Krb5KdcTransport] instead of
+ * libkrb5's blocking sockets.
+ *
+ * libkrb5 only has coarse timeouts for the requests to KDCs, which can not be configured per
+ * call, so [Krb5AsyncClient::with_timeout] bounds how long a request is waited for instead.
+ * Timeouts and cancellation are only available for the requests of [Krb5AsyncClient]; the
+ * blocking functions keep libkrb5's timeouts, unless their context sends the requests itself
+ * with [Krb5Context::set_kdc_send_hook].
+ */
+use std::fmt::{Debug, Formatter};
+use std::future::Future;
+use std::net::IpAddr;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+#[cfg(all(krb5_mit, krb5_1_15))]
+use std::task::Poll;
+use std::time::Duration;
+
+use libkrb5_sys::*;
+use tokio::sync::Notify;
+
+use crate::ccache::{Krb5CCache, Krb5ServiceTicketInfo};
+use crate::context::Krb5Context;
+use crate::creds::Krb5InitCredsOptions;
+use crate::enctype::Krb5Enctype;
+use crate::error::Krb5Error;
+use crate::password::Krb5PasswordChangeStatus;
+use crate::principal::parse_name;
+use crate::secret::zeroize_string;
+
+/**
+ * The future of a [Krb5KdcTransport] request.
+ */
+pub type Krb5KdcReplyFuture<'a> = Pin<Box<dyn Future<Output = Result<Option<Vec<u8>>, Krb5Error>> + Send + 'a>>;
+
+/**
+ * An async transport for the requests to KDCs, e.g. over a custom UDP or TCP connection or
+ * an HTTPS proxy (MS-KKDCP), with full control over proxying and timeouts.
+ *
+ * See [Krb5AsyncClient::with_transport].
+ */
+pub trait Krb5KdcTransport: Send + Sync {
+  /**
+   * Send an encoded request to a KDC of a realm, and return the encoded reply, or `None` to
+   * have libkrb5 send the request itself.
+   *
+   * # Arguments
+   *
+   *  * realm: the realm of the KDC
+   *  * request: the encoded request
+   */
+  fn send<'a>(&'a self, realm: &'a str, request: &'a [u8]) -> Krb5KdcReplyFuture<'a>;
+}
+
+/**
+ * Runs blocking libkrb5 requests on the blocking thread pool of the tokio runtime.
+ *
+ * Every request creates a new context, with [Krb5Context::init] or the function given to
+ * [Krb5AsyncClient::with_context], which is dropped when the request is done. The client is
+ * cheap to clone, e.g. to give a single call its own timeout.
+ *
+ * Dropping the future of a request stops waiting for it. With a transport or a timeout, it also
+ * cancels the request: requests to KDCs that are not sent yet fail, and a request waiting for a
+ * [Krb5KdcTransport] stops waiting. A request that is already inside libkrb5's blocking sockets
+ * runs to completion on the blocking thread pool, but its result is discarded.
+ */
+#[derive(Clone)]
+pub struct Krb5AsyncClient {
+  new_context: Arc<dyn Fn() -> Result<Krb5Context, Krb5Error> + Send + Sync>,
+  transport: Option<Arc<dyn Krb5KdcTransport>>,
+  timeout: Option<Duration>,
+}
+
+impl Debug for Krb5AsyncClient {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("Krb5AsyncClient").finish_non_exhaustive()
+  }
+}
+
+impl Default for Krb5AsyncClient {
+  fn default() -> Self {
+    Krb5AsyncClient::new()
+  }
+}
+
+impl Krb5AsyncClient {
+  /**
+   * Create a client whose requests use the default configuration, see [Krb5Context::init].
+   */
+  pub fn new() -> Krb5AsyncClient {
+    Krb5AsyncClient::with_context(Krb5Context::init)
+  }
+
+  /**
+   * Create a client whose requests use contexts created by a function, e.g. to use
+   * [Krb5Context::init_secure] or to override configuration values with
+   * [Krb5Context::with_profile_values].
+   *
+   * # Arguments
+   *
+   *  * new_context: creates the context of a request, on the thread the request runs on
+   */
+  pub fn with_context<F>(new_context: F) -> Krb5AsyncClient
+  where
+    F: Fn() -> Result<Krb5Context, Krb5Error> + Send + Sync + 'static,
+  {
+    Krb5AsyncClient {
+      new_context: Arc::new(new_context),
+      transport: None,
+      timeout: None,
+    }
+  }
+
+  /**
+   * Hand the requests to KDCs to an async transport instead of sending them with libkrb5's
+   * blocking sockets. The request waits for the transport on its thread of the blocking thread
+   * pool, while the transport runs on the runtime the request was started from.
+   *
+   * libkrb5 still locates the KDCs of a realm before handing over a request, so the realm
+   * needs KDCs in krb5.conf or DNS, see [Krb5Context::set_kdc_send_hook]. Requests the
+   * transport returns `None` for are passed on to the KDC send hook of the context, if it has
+   * one.
+   *
+   * # Arguments
+   *
+   *  * transport: sends the requests to KDCs
+   */
+  #[cfg(all(krb5_mit, krb5_1_15))]
+  pub fn with_transport<T: Krb5KdcTransport + 'static>(mut self, transport: T) -> Krb5AsyncClient {
+    self.transport = Some(Arc::new(transport));
+    self
+  }
+
+  /**
+   * Give up on requests that take longer than a timeout, and cancel them. The error of a
+   * request that timed out is an [std::io::Error] of kind [std::io::ErrorKind::TimedOut].
+   *
+   * The timeout needs the time driver of the tokio runtime. There is no such timeout for the
+   * blocking versions of the requests, but their contexts can send the requests to KDCs with
+   * custom timeouts using [Krb5Context::set_kdc_send_hook].
+   *
+   * # Arguments
+   *
+   *  * timeout: how long to wait for a request, including all requests to KDCs it makes
+   */
+  pub fn with_timeout(mut self, timeout: Duration) -> Krb5AsyncClient {
+    self.timeout = Some(timeout);
+    self
+  }
+
+  /**
+   * Run a blocking function with a new context on the blocking thread pool, and wait for its
+   * result. This is the building block of the other requests, for the operations they do not
+   * cover.
+   *
+   * A panic of the function is resumed in the calling task. The timeout and cancellation of
+   * the client apply to the requests to KDCs the function makes.
+   *
+   * # Arguments
+   *
+   *  * f: the function to run
+   */
+  pub async fn run<F, T>(&self, f: F) -> Result<T, Krb5Error>
+  where
+    F: FnOnce(&Krb5Context) -> Result<T, Krb5Error> + Send + 'static,
+    T: Send + 'static,
+  {
+    let new_context = Arc::clone(&self.new_context);
+    let transport = self.transport.clone();
+    let runtime = tokio::runtime::Handle::current();
+    let cancellation = Arc::new(Cancellation::default());
+    let _cancel_on_drop = CancelOnDrop(Arc::clone(&cancellation));
+    // the hook is only needed to hand requests to the transport or to cancel them on timeout
+    let hook_needed = transport.is_some() || self.timeout.is_some();
+
+    let task = tokio::task::spawn_blocking(move || {
+      let mut context = new_context()?;
+      if hook_needed {
+        install_kdc_send_hook(&mut context, transport, runtime, cancellation);
+      }
+
+      f(&context)
+    });
+
+    let result = match self.timeout {
+      Some(timeout) => match tokio::time::timeout(timeout, task).await {
+        Ok(result) => result,
+        Err(_) => {
+          return Err(Krb5Error::Io {
+            error: std::io::Error::new(std::io::ErrorKind::TimedOut, "KDC request timed out"),
+          })
+        },
+      },
+      None => task.await,
+    };
+
+    match result {
+      Ok(result) => result,
+      Err(error) if error.is_panic() => std::panic::resume_unwind(error.into_panic()),
+      Err(error) => Err(Krb5Error::Io {
+        error: std::io::Error::new(std::io::ErrorKind::Interrupted, error.to_string()),
+      }),
+    }
+  }
+
+  /**
+   * Acquire initial credentials for a principal using its password, like `kinit`, and store
+   * them in a credential cache, which is initialized for the principal.
+   *
+   * The `out_ccache` of the options is ignored. The password is wiped once the request is done.
+   * See [Krb5Context::acquire_initial_creds_with_password] for the blocking version.
+   *
+   * # Arguments
+   *
+   *  * ccache: the name of the credential cache, e.g. `FILE:/tmp/krb5cc_service`
+   *  * principal: the name of the client principal to authenticate as
+   *  * password: the password of the principal
+   *  * options: options for the initial credentials request
+   */
+  pub async fn get_init_creds_password(
+    &self,
+    ccache: &str,
+    principal: &str,
+    password: String,
+    options: &Krb5InitCredsOptions<'_>,
+  ) -> Result<Krb5ServiceTicketInfo, Krb5Error> {
+    let ccache = String::from(ccache);
+    let principal = String::from(principal);
+    let options = SendableInitCredsOptions::from(options);
+
+    self
+      .run(move |context| {
+        let result = acquire_with_password(context, &ccache, &principal, &password, &options);
+        zeroize_string(password);
+        result
+      })
+      .await
+  }
+
+  /**
+   * Get credentials for a service, from the credential cache or from the KDC with the ticket
+   * granting ticket of the credential cache. New credentials are stored in the credential
+   * cache, so they can be used right away with a context of the calling thread.
+   *
+   * See [Krb5CCache::get_credentials] for the blocking version.
+   *
+   * # Arguments
+   *
+   *  * ccache: the name of the credential cache
+   *  * server: the name of the service principal, e.g. `HTTP/www.example.com@EXAMPLE.COM`
+   */
+  pub async fn get_credentials(&self, ccache: &str, server: &str) -> Result<Krb5ServiceTicketInfo, Krb5Error> {
+    let ccache = String::from(ccache);
+    let server = String::from(server);
+
+    self
+      .run(move |context| {
+        let ccache = Krb5CCache::resolve(context, &ccache)?;
+        let server = parse_name(context, &server)?;
+        ccache.get_service_ticket(&server)
+      })
+      .await
+  }
+
+  /**
+   * Change the password of a principal, like `kpasswd`. The passwords are wiped once the
+   * request is done.
+   *
+   * See [Krb5Context::kpasswd] for the blocking version.
+   *
+   * # Arguments
+   *
+   *  * principal: the name of the principal whose password to change
+   *  * old_password: the current password of the principal
+   *  * new_password: the new password
+   */
+  pub async fn change_password(
+    &self,
+    principal: &str,
+    old_password: String,
+    new_password: String,
+  ) -> Result<Krb5PasswordChangeStatus, Krb5Error> {
+    let principal = String::from(principal);
+
+    self
+      .run(move |context| {
+        let result = parse_name(context, &principal)
+          .and_then(|principal| context.kpasswd(&principal, &old_password, &new_password));
+        zeroize_string(old_password);
+        zeroize_string(new_password);
+        result
+      })
+      .await
+  }
+}
+
+/**
+ * Fail the requests to KDCs of a context once the request is cancelled, and hand them to the
+ * transport of the client, if it has one. Requests the transport does not answer are passed on
+ * to the hook the context already has.
+ */
+#[cfg(all(krb5_mit, krb5_1_15))]
+fn install_kdc_send_hook(
+  context: &mut Krb5Context,
+  transport: Option<Arc<dyn Krb5KdcTransport>>,
+  runtime: tokio::runtime::Handle,
+  cancellation: Arc<Cancellation>,
+) {
+  let previous = context.take_kdc_send_hook();
+
+  context.set_kdc_send_hook(move |realm, request| {
+    if cancellation.is_cancelled() {
+      return Err(Krb5Error::LibraryError {
+        code: KRB5_KDC_UNREACH,
+        message: String::from("Request cancelled"),
+      });
+    }
+
+    if let Some(transport) = &transport {
+      if let Some(reply) = runtime.block_on(cancellation.cancellable(transport.send(realm, request)))? {
+        return Ok(Some(reply));
+      }
+    }
+
+    match &previous {
+      Some(previous) => (previous.hook)(realm, request),
+      None => Ok(None),
+    }
+  });
+}
+
+/**
+ * Heimdal has no KDC send hook, so requests that are already running can not be cancelled.
+ */
+#[cfg(not(all(krb5_mit, krb5_1_15)))]
+fn install_kdc_send_hook(
+  _context: &mut Krb5Context,
+  _transport: Option<Arc<dyn Krb5KdcTransport>>,
+  _runtime: tokio::runtime::Handle,
+  _cancellation: Arc<Cancellation>,
+) {
+}
+
+/**
+ * The cancellation state of a request, shared with the KDC send hook on the blocking thread
+ * pool.
+ */
+#[derive(Debug, Default)]
+struct Cancellation {
+  cancelled: AtomicBool,
+  notify: Notify,
+}
+
+impl Cancellation {
+  #[cfg(all(krb5_mit, krb5_1_15))]
+  fn is_cancelled(&self) -> bool {
+    self.cancelled.load(Ordering::Acquire)
+  }
+
+  fn cancel(&self) {
+    self.cancelled.store(true, Ordering::Release);
+    self.notify.notify_waiters();
+  }
+
+  /**
+   * Wait for a transport request, unless the request is cancelled first.
+   */
+  #[cfg(all(krb5_mit, krb5_1_15))]
+  async fn cancellable(&self, reply: Krb5KdcReplyFuture<'_>) -> Result<Option<Vec<u8>>, Krb5Error> {
+    let mut reply = reply;
+    let notified = self.notify.notified();
+    tokio::pin!(notified);
+    // register for the notification before checking the flag, so a cancellation in between is
+    // not missed
+    notified.as_mut().enable();
+
+    std::future::poll_fn(|cx| {
+      if self.is_cancelled() || notified.as_mut().poll(cx).is_ready() {
+        return Poll::Ready(Err(Krb5Error::LibraryError {
+          code: KRB5_KDC_UNREACH,
+          message: String::from("Request cancelled"),
+        }));
+      }
+
+      reply.as_mut().poll(cx)
+    })
+    .await
+  }
+}
+
+/**
+ * Cancels a request when the future waiting for it completes or is dropped.
+ */
+struct CancelOnDrop(Arc<Cancellation>);
+
+impl Drop for CancelOnDrop {
+  fn drop(&mut self) {
+    self.0.cancel();
+  }
+}
+
+/**
+ * The options of an initial credentials request, without the credential cache, which can not
+ * be moved to another thread.
+ */
+#[derive(Debug, Clone)]
+pub(crate) struct SendableInitCredsOptions {
+  tkt_life: Option<krb5_deltat>,
+  renew_life: Option<krb5_deltat>,
+  forwardable: Option<bool>,
+  proxiable: Option<bool>,
+  etype_list: Option<Vec<Krb5Enctype>>,
+  addresses: Option<Vec<IpAddr>>,
+}
+
+impl From<&Krb5InitCredsOptions<'_>> for SendableInitCredsOptions {
+  fn from(options: &Krb5InitCredsOptions<'_>) -> Self {
+    SendableInitCredsOptions {
+      tkt_life: options.tkt_life,
+      renew_life: options.renew_life,
+      forwardable: options.forwardable,
+      proxiable: options.proxiable,
+      etype_list: options.etype_list.clone(),
+      addresses: options.addresses.clone(),
+    }
+  }
+}
+
+impl SendableInitCredsOptions {
+  /**
+   * Restore the options on the thread of the request, storing the credentials in a credential
+   * cache.
+   */
+  pub(crate) fn to_options<'c>(&self, out_ccache: &'c Krb5CCache<'c>) -> Krb5InitCredsOptions<'c> {
+    Krb5InitCredsOptions {
+      tkt_life: self.tkt_life,
+      renew_life: self.renew_life,
+      forwardable: self.forwardable,
+      proxiable: self.proxiable,
+      etype_list: self.etype_list.clone(),
+      addresses: self.addresses.clone(),
+      out_ccache: Some(out_ccache),
+    }
+  }
+}
+
+/**
+ * Acquire initial credentials with a password into the named credential cache.
+ */
+fn acquire_with_password(
+  context: &Krb5Context,
+  ccache: &str,
+  principal: &str,
+  password: &str,
+  options: &SendableInitCredsOptions,
+) -> Result<Krb5ServiceTicketInfo, Krb5Error> {
+  let ccache = Krb5CCache::resolve(context, ccache)?;
+  let principal = parse_name(context, principal)?;
+
+  let options = options.to_options(&ccache);
+
+  let creds = context.acquire_initial_creds_with_password(&principal, password, &options)?;
+  Krb5ServiceTicketInfo::from_creds(context, &creds)
+}
@@ -0,0 +1,465 @@
+/*!
+ * Rustic wrapper for the Privilege Attribute Certificate (PAC) of Active Directory tickets.
+ */
+use std::hash::{Hash, Hasher};
+use std::mem::MaybeUninit;
+use std::os::raw::c_char;
+
+use libkrb5_sys::*;
+
+use crate::context::Krb5Context;
+use crate::data::{slice_to_krb5_data, Krb5Data};
+use crate::delegation_info::Krb5PacDelegationInfo;
+use crate::error::{error_message, krb5_error_code_escape_hatch, Krb5Error};
+use crate::keyblock::Krb5Keyblock;
+#[cfg(feature = "pac-logon-info")]
+use crate::logon_info::Krb5PacLogonInfo;
+use crate::principal::Krb5Principal;
+use crate::timestamp::Krb5Timestamp;
+
+/**
+ * PAC buffer types of MS-PAC that are not defined by the krb5.h of older libkrb5 versions.
+ */
+const PAC_CLIENT_CLAIMS_INFO: krb5_ui_4 = 13;
+const PAC_DEVICE_INFO: krb5_ui_4 = 14;
+const PAC_DEVICE_CLAIMS_INFO: krb5_ui_4 = 15;
+const PAC_TICKET_CHECKSUM: krb5_ui_4 = 16;
+const PAC_ATTRIBUTES_INFO: krb5_ui_4 = 17;
+const PAC_REQUESTOR: krb5_ui_4 = 18;
+
+/// the system error number libkrb5 returns for missing PAC buffers
+const ENOENT: krb5_error_code = 2;
+
+/**
+ * The type of a PAC buffer (`KRB5_PAC_*`, MS-PAC section 2.4), which determines the NDR or
+ * binary structure of its contents.
+ *
+ * New variants may be added for further buffer types, so matches need a wildcard arm. Types
+ * are compared by their number, so e.g. `Unknown(1)` equals `LogonInfo`.
+ */
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub enum Krb5PacBufferType {
+  /// KERB_VALIDATION_INFO with the user and group SIDs of the client (1)
+  LogonInfo,
+  /// PAC_CREDENTIAL_INFO with encrypted credentials of the client, e.g. from PKINIT (2)
+  CredentialsInfo,
+  /// the server signature, keyed with the key of the service (6)
+  ServerChecksum,
+  /// the KDC signature, keyed with the key of the KDC (7)
+  PrivsvrChecksum,
+  /// PAC_CLIENT_INFO with the client name and the authentication time (10)
+  ClientInfo,
+  /// S4U_DELEGATION_INFO with the services of a constrained delegation (11)
+  DelegationInfo,
+  /// UPN_DNS_INFO with the user principal name and the DNS domain of the client (12)
+  UpnDnsInfo,
+  /// the claims of the client (13)
+  ClientClaimsInfo,
+  /// PAC_DEVICE_INFO with the SIDs of the device of the client (14)
+  DeviceInfo,
+  /// the claims of the device of the client (15)
+  DeviceClaimsInfo,
+  /// the ticket signature, keyed with the key of the KDC (16)
+  TicketChecksum,
+  /// PAC_ATTRIBUTES_INFO with flags describing how the PAC was requested (17)
+  AttributesInfo,
+  /// PAC_REQUESTOR with the SID of the client that requested the ticket (18)
+  Requestor,
+  /// any other buffer type
+  Unknown(krb5_ui_4),
+}
+
+impl Krb5PacBufferType {
+  /**
+   * Convert a buffer type number.
+   */
+  pub fn from_raw(buffer_type: krb5_ui_4) -> Self {
+    use Krb5PacBufferType::*;
+
+    match buffer_type {
+      KRB5_PAC_LOGON_INFO => LogonInfo,
+      KRB5_PAC_CREDENTIALS_INFO => CredentialsInfo,
+      KRB5_PAC_SERVER_CHECKSUM => ServerChecksum,
+      KRB5_PAC_PRIVSVR_CHECKSUM => PrivsvrChecksum,
+      KRB5_PAC_CLIENT_INFO => ClientInfo,
+      KRB5_PAC_DELEGATION_INFO => DelegationInfo,
+      KRB5_PAC_UPN_DNS_INFO => UpnDnsInfo,
+      PAC_CLIENT_CLAIMS_INFO => ClientClaimsInfo,
+      PAC_DEVICE_INFO => DeviceInfo,
+      PAC_DEVICE_CLAIMS_INFO => DeviceClaimsInfo,
+      PAC_TICKET_CHECKSUM => TicketChecksum,
+      PAC_ATTRIBUTES_INFO => AttributesInfo,
+      PAC_REQUESTOR => Requestor,
+      _ => Unknown(buffer_type),
+    }
+  }
+
+  /**
+   * Return the buffer type number.
+   */
+  pub fn as_raw(self) -> krb5_ui_4 {
+    use Krb5PacBufferType::*;
+
+    match self {
+      LogonInfo => KRB5_PAC_LOGON_INFO,
+      CredentialsInfo => KRB5_PAC_CREDENTIALS_INFO,
+      ServerChecksum => KRB5_PAC_SERVER_CHECKSUM,
+      PrivsvrChecksum => KRB5_PAC_PRIVSVR_CHECKSUM,
+      ClientInfo => KRB5_PAC_CLIENT_INFO,
+      DelegationInfo => KRB5_PAC_DELEGATION_INFO,
+      UpnDnsInfo => KRB5_PAC_UPN_DNS_INFO,
+      ClientClaimsInfo => PAC_CLIENT_CLAIMS_INFO,
+      DeviceInfo => PAC_DEVICE_INFO,
+      DeviceClaimsInfo => PAC_DEVICE_CLAIMS_INFO,
+      TicketChecksum => PAC_TICKET_CHECKSUM,
+      AttributesInfo => PAC_ATTRIBUTES_INFO,
+      Requestor => PAC_REQUESTOR,
+      Unknown(buffer_type) => buffer_type,
+    }
+  }
+
+  /**
+   * Return `true` for the signature buffers, whose contents are a signature type followed by
+   * the signature (PAC_SIGNATURE_DATA).
+   */
+  pub fn is_signature(self) -> bool {
+    use Krb5PacBufferType::*;
+
+    matches!(self, ServerChecksum | PrivsvrChecksum | TicketChecksum)
+  }
+}
+
+impl PartialEq for Krb5PacBufferType {
+  fn eq(&self, other: &Self) -> bool {
+    self.as_raw() == other.as_raw()
+  }
+}
+
+impl Eq for Krb5PacBufferType {}
+
+impl Hash for Krb5PacBufferType {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    self.as_raw().hash(state);
+  }
+}
+
+impl From<krb5_ui_4> for Krb5PacBufferType {
+  fn from(buffer_type: krb5_ui_4) -> Self {
+    Krb5PacBufferType::from_raw(buffer_type)
+  }
+}
+
+impl From<Krb5PacBufferType> for krb5_ui_4 {
+  fn from(buffer_type: Krb5PacBufferType) -> Self {
+    buffer_type.as_raw()
+  }
+}
+
+/**
+ * Wrapper struct for a PAC (MS-PAC), the authorization data an Active Directory KDC adds to
+ * tickets, e.g. with the group memberships of the client.
+ *
+ * A PAC consists of buffers identified by their type (`KRB5_PAC_*`). Parsing a PAC does not
+ * verify its signatures. The contents of the buffers are copied when the PAC is parsed or
+ * modified, so they can be borrowed from it.
+ *
+ * https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/types/krb5_pac.html
+ */
+#[derive(Debug)]
+pub struct Krb5Pac<'a> {
+  pub(crate) context: &'a Krb5Context,
+  pub(crate) pac: krb5_pac,
+  pub(crate) buffers: Vec<(Krb5PacBufferType, Vec<u8>)>,
+}
+
+/**
+ * Free a PAC.
+ *
+ * [krb5_pac_free](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_pac_free.html)
+ */
+impl<'a> Drop for Krb5Pac<'a> {
+  fn drop(&mut self) {
+    unsafe {
+      krb5_pac_free(self.context.context, self.pac);
+    }
+  }
+}
+
+impl<'a> Krb5Pac<'a> {
+  /**
+   * Create an empty PAC, to add buffers to and sign, e.g. for testing services.
+   *
+   * [krb5_pac_init](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_pac_init.html)
+   *
+   * # Arguments
+   *
+   *  * context: the Krb5Context instance
+   */
+  pub fn new(context: &'a Krb5Context) -> Result<Krb5Pac<'a>, Krb5Error> {
+    let mut pac: MaybeUninit<krb5_pac> = MaybeUninit::zeroed();
+
+    let code: krb5_error_code = unsafe { krb5_pac_init(context.context, pac.as_mut_ptr()) };
+
+    krb5_error_code_escape_hatch(context, code)?;
+
+    Ok(Krb5Pac {
+      context,
+      pac: unsafe { pac.assume_init() },
+      buffers: Vec::new(),
+    })
+  }
+
+  /**
+   * Parse a PAC from the contents of a `KRB5_AUTHDATA_WIN2K_PAC` authorization data element.
+   *
+   * [krb5_pac_parse](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_pac_parse.html)
+   *
+   * # Arguments
+   *
+   *  * context: the Krb5Context instance
+   *  * data: the encoded PAC
+   */
+  pub fn parse(context: &'a Krb5Context, data: &[u8]) -> Result<Krb5Pac<'a>, Krb5Error> {
+    let mut pac: MaybeUninit<krb5_pac> = MaybeUninit::zeroed();
+
+    let code: krb5_error_code = unsafe {
+      krb5_pac_parse(
+        context.context,
+        data.as_ptr() as *const _,
+        data.len() as size_t,
+        pac.as_mut_ptr(),
+      )
+    };
+
+    krb5_error_code_escape_hatch(context, code)?;
+
+    let mut pac = Krb5Pac {
+      context,
+      pac: unsafe { pac.assume_init() },
+      buffers: Vec::new(),
+    };
+    pac.copy_buffers()?;
+
+    Ok(pac)
+  }
+
+  /**
+   * Copy the contents of all buffers from libkrb5, after the PAC has been parsed or modified.
+   *
+   * [krb5_pac_get_buffer](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_pac_get_buffer.html)
+   */
+  fn copy_buffers(&mut self) -> Result<(), Krb5Error> {
+    let mut buffers = Vec::new();
+
+    for buffer_type in self.buffer_types()? {
+      let mut data: MaybeUninit<krb5_data> = MaybeUninit::zeroed();
+
+      let code: krb5_error_code =
+        unsafe { krb5_pac_get_buffer(self.context.context, self.pac, buffer_type.as_raw(), data.as_mut_ptr()) };
+
+      krb5_error_code_escape_hatch(self.context, code)?;
+
+      let data = Krb5Data::from_raw(self.context, unsafe { data.assume_init() });
+      buffers.push((buffer_type, data.as_bytes().to_vec()));
+    }
+
+    self.buffers = buffers;
+
+    Ok(())
+  }
+
+  /**
+   * Return the contents of the buffer with the given type, to be decoded according to the type.
+   *
+   * # Arguments
+   *
+   *  * buffer_type: a [Krb5PacBufferType] or a raw buffer type number (`KRB5_PAC_*`)
+   */
+  pub fn buffer<T: Into<Krb5PacBufferType>>(&self, buffer_type: T) -> Result<&[u8], Krb5Error> {
+    let buffer_type = buffer_type.into();
+
+    match self.buffers.iter().find(|(other, _)| *other == buffer_type) {
+      Some((_, contents)) => Ok(contents),
+      None => Err(Krb5Error::LibraryError {
+        code: ENOENT,
+        message: format!("{} (PAC buffer type {})", error_message(ENOENT), buffer_type.as_raw()),
+      }),
+    }
+  }
+
+  /**
+   * Return `true` if the PAC contains a buffer with the given type.
+   */
+  pub fn has_buffer<T: Into<Krb5PacBufferType>>(&self, buffer_type: T) -> bool {
+    let buffer_type = buffer_type.into();
+
+    self.buffers.iter().any(|(other, _)| *other == buffer_type)
+  }
+
+  /**
+   * Return the types of the buffers of the PAC, in the order they are stored in.
+   *
+   * [krb5_pac_get_types](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_pac_get_types.html)
+   */
+  pub fn buffer_types(&self) -> Result<Vec<Krb5PacBufferType>, Krb5Error> {
+    let mut len: size_t = 0;
+    let mut types: *mut krb5_ui_4 = std::ptr::null_mut();
+
+    let code: krb5_error_code = unsafe { krb5_pac_get_types(self.context.context, self.pac, &mut len, &mut types) };
+
+    krb5_error_code_escape_hatch(self.context, code)?;
+
+    if types.is_null() {
+      return Ok(Vec::new());
+    }
+
+    let buffer_types = unsafe { std::slice::from_raw_parts(types, len as usize) }
+      .iter()
+      .map(|buffer_type| Krb5PacBufferType::from_raw(*buffer_type))
+      .collect();
+
+    // the list is allocated with malloc, and krb5_free_string just frees its argument
+    unsafe { krb5_free_string(self.context.context, types as *mut c_char) };
+
+    Ok(buffer_types)
+  }
+
+  /**
+   * Return the types and the contents of all buffers of the PAC, in the order they are stored
+   * in.
+   */
+  pub fn buffers(&self) -> Vec<(Krb5PacBufferType, &[u8])> {
+    self
+      .buffers
+      .iter()
+      .map(|(buffer_type, contents)| (*buffer_type, contents.as_slice()))
+      .collect()
+  }
+
+  /**
+   * Decode the constrained delegation buffer, with the target and the transited services of an
+   * S4U2Proxy request, or return `None` if the PAC does not contain one.
+   */
+  pub fn delegation_info(&self) -> Result<Option<Krb5PacDelegationInfo>, Krb5Error> {
+    if !self.has_buffer(Krb5PacBufferType::DelegationInfo) {
+      return Ok(None);
+    }
+
+    Ok(Some(Krb5PacDelegationInfo::decode(
+      self.buffer(Krb5PacBufferType::DelegationInfo)?,
+    )?))
+  }
+
+  /**
+   * Decode the logon information buffer, with the SIDs of the client and of its groups.
+   */
+  #[cfg(feature = "pac-logon-info")]
+  pub fn logon_info(&self) -> Result<Krb5PacLogonInfo, Krb5Error> {
+    Krb5PacLogonInfo::decode(self.buffer(Krb5PacBufferType::LogonInfo)?)
+  }
+
+  /**
+   * Add a buffer with the given type and contents.
+   *
+   * [krb5_pac_add_buffer](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_pac_add_buffer.html)
+   *
+   * # Arguments
+   *
+   *  * buffer_type: a [Krb5PacBufferType] or a raw buffer type number (`KRB5_PAC_*`)
+   *  * data: the encoded contents of the buffer
+   */
+  pub fn add_buffer<T: Into<Krb5PacBufferType>>(&mut self, buffer_type: T, data: &[u8]) -> Result<(), Krb5Error> {
+    let data = slice_to_krb5_data(data);
+
+    let code: krb5_error_code =
+      unsafe { krb5_pac_add_buffer(self.context.context, self.pac, buffer_type.into().as_raw(), &data) };
+
+    krb5_error_code_escape_hatch(self.context, code)?;
+
+    self.copy_buffers()
+  }
+
+  /**
+   * Verify the signatures of the PAC and that its client info buffer matches the
+   * authentication time and the client principal of the ticket the PAC was taken from.
+   *
+   * The server signature is always verified, the KDC signature only if the key of the KDC is
+   * given, which services usually do not have.
+   *
+   * [krb5_pac_verify](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_pac_verify.html)
+   *
+   * # Arguments
+   *
+   *  * authtime: the authentication time of the ticket
+   *  * principal: the client principal of the ticket, or `None` to not check the client name
+   *  * server_key: the key of the service, for the server signature
+   *  * privsvr_key: the key of the KDC, for the KDC signature
+   */
+  pub fn verify(
+    &self,
+    authtime: Krb5Timestamp,
+    principal: Option<&Krb5Principal>,
+    server_key: &Krb5Keyblock,
+    privsvr_key: Option<&Krb5Keyblock>,
+  ) -> Result<(), Krb5Error> {
+    let code: krb5_error_code = unsafe {
+      krb5_pac_verify(
+        self.context.context,
+        self.pac,
+        authtime.as_raw(),
+        principal.map_or(std::ptr::null_mut(), |principal| principal.principal),
+        server_key.keyblock,
+        privsvr_key.map_or(std::ptr::null_mut(), |privsvr_key| privsvr_key.keyblock),
+      )
+    };
+
+    krb5_error_code_escape_hatch(self.context, code)
+  }
+
+  /**
+   * Add the client info and the signature buffers, and return the encoded PAC for a
+   * `KRB5_AUTHDATA_WIN2K_PAC` authorization data element.
+   *
+   * The client info buffer is added from the given authentication time and principal. If the
+   * PAC already contains one, it has to match them.
+   *
+   * [krb5_pac_sign](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_pac_sign.html)
+   *
+   * # Arguments
+   *
+   *  * authtime: the authentication time of the ticket
+   *  * principal: the client principal of the ticket
+   *  * server_key: the key of the service, for the server signature
+   *  * privsvr_key: the key of the KDC, for the KDC signature
+   */
+  pub fn sign(
+    &mut self,
+    authtime: Krb5Timestamp,
+    principal: &Krb5Principal,
+    server_key: &Krb5Keyblock,
+    privsvr_key: &Krb5Keyblock,
+  ) -> Result<Krb5Data<'a>, Krb5Error> {
+    let mut data: MaybeUninit<krb5_data> = MaybeUninit::zeroed();
+
+    let code: krb5_error_code = unsafe {
+      krb5_pac_sign(
+        self.context.context,
+        self.pac,
+        authtime.as_raw(),
+        principal.principal,
+        server_key.keyblock,
+        privsvr_key.keyblock,
+        data.as_mut_ptr(),
+      )
+    };
+
+    krb5_error_code_escape_hatch(self.context, code)?;
+    let data = Krb5Data::from_raw(self.context, unsafe { data.assume_init() });
+
+    // signing adds the client info and the signature buffers
+    self.copy_buffers()?;
+
+    Ok(data)
+  }
+}
@@ -0,0 +1,274 @@
+/*!
+ * Password changes via the kpasswd protocol (RFC 3244).
+ */
+use std::convert::TryInto;
+#[cfg(all(krb5_mit, krb5_1_11))]
+use std::mem::MaybeUninit;
+#[cfg(all(krb5_mit, krb5_1_11))]
+use std::os::raw::c_char;
+use std::os::raw::c_int;
+use std::time::Duration;
+
+use libkrb5_sys::*;
+
+use crate::ccache::Krb5CCache;
+use crate::context::Krb5Context;
+use crate::creds::Krb5Creds;
+use crate::data::Krb5Data;
+#[cfg(all(krb5_mit, krb5_1_11))]
+use crate::strconv::c_string_to_string_lossy;
+
+/**
+ * Credentials to authenticate a password change with, see [Krb5Context::set_password].
+ */
+#[derive(Debug)]
+pub enum Krb5PasswordAuth<'c> {
+  /// credentials for the `kadmin/changepw` service
+  Creds(&'c Krb5Creds<'c>),
+  /// a credential cache to obtain credentials for the `kadmin/changepw` service from
+  CCache(&'c Krb5CCache<'c>),
+}
+
+impl<'c> From<&'c Krb5Creds<'c>> for Krb5PasswordAuth<'c> {
+  fn from(creds: &'c Krb5Creds<'c>) -> Self {
+    Krb5PasswordAuth::Creds(creds)
+  }
+}
+
+impl<'c> From<&'c Krb5CCache<'c>> for Krb5PasswordAuth<'c> {
+  fn from(ccache: &'c Krb5CCache<'c>) -> Self {
+    Krb5PasswordAuth::CCache(ccache)
+  }
+}
+
+/**
+ * The decoded result of a password change request, see [Krb5PasswordChangeResult::status].
+ *
+ * New variants may be added for further result codes, so matches need a wildcard arm.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Krb5PasswordChangeStatus {
+  /// the password was changed (`KRB5_KPASSWD_SUCCESS`)
+  Success,
+  /// the server could not parse the request (`KRB5_KPASSWD_MALFORMED`)
+  Malformed { message: String },
+  /// the server failed to process the request (`KRB5_KPASSWD_HARDERROR`)
+  HardError { message: String },
+  /// the request was not authenticated correctly (`KRB5_KPASSWD_AUTHERROR`)
+  AuthError { message: String },
+  /// the new password was rejected, usually by the password policy (`KRB5_KPASSWD_SOFTERROR`)
+  SoftError { message: String },
+  /// the authenticated principal may not change the password (`KRB5_KPASSWD_ACCESSDENIED`)
+  AccessDenied { message: String },
+  /// the server does not support the protocol version (`KRB5_KPASSWD_BAD_VERSION`)
+  BadVersion { message: String },
+  /// the request was not authenticated with an initial ticket (`KRB5_KPASSWD_INITIAL_FLAG_NEEDED`)
+  InitialFlagNeeded { message: String },
+  /// any other result code
+  Unknown { code: c_int, message: String },
+}
+
+/**
+ * Length of the password policy Active Directory sends with rejected password changes.
+ */
+const AD_POLICY_INFO_LENGTH: usize = 30;
+
+/**
+ * Password property flag of an Active Directory password policy that requires complex
+ * passwords (`DOMAIN_PASSWORD_COMPLEX`).
+ */
+const AD_POLICY_COMPLEX: u32 = 0x0000_0001;
+
+/**
+ * The password policy an Active Directory domain controller sends when it rejects a new
+ * password, see [Krb5PasswordChangeResult::policy].
+ *
+ * The policy lists all requirements, not just the ones the rejected password violates.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Krb5PasswordPolicy {
+  /// the minimum number of characters, 0 if there is no minimum
+  pub min_length: u32,
+  /// the number of previous passwords the new password has to differ from
+  pub history_length: u32,
+  /// the password properties (`DOMAIN_PASSWORD_*` flags)
+  pub properties: u32,
+  /// how long a password may be used, `None` if passwords do not expire
+  pub max_age: Option<Duration>,
+  /// how long a password has to be used before it can be changed again, `None` if it can be
+  /// changed at any time
+  pub min_age: Option<Duration>,
+}
+
+impl Krb5PasswordPolicy {
+  /**
+   * Parse the password policy from the result string of a kpasswd reply, or return `None` if
+   * it is not one.
+   *
+   * The policy consists of two zero bytes followed by big-endian integers, see
+   * `decode_ad_policy_info` in libkrb5.
+   */
+  pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+    if bytes.len() != AD_POLICY_INFO_LENGTH || bytes[0..2] != [0, 0] {
+      return None;
+    }
+
+    let u32_at = |offset: usize| u32::from_be_bytes(bytes[offset..offset + 4].try_into().unwrap());
+    let u64_at = |offset: usize| u64::from_be_bytes(bytes[offset..offset + 8].try_into().unwrap());
+
+    Some(Krb5PasswordPolicy {
+      min_length: u32_at(2),
+      history_length: u32_at(6),
+      properties: u32_at(10),
+      max_age: policy_duration(u64_at(14)),
+      min_age: policy_duration(u64_at(22)),
+    })
+  }
+
+  /**
+   * Return `true` if passwords have to include numbers or symbols, and must not include parts
+   * of the user name.
+   */
+  pub fn complexity_required(&self) -> bool {
+    self.properties & AD_POLICY_COMPLEX != 0
+  }
+}
+
+/**
+ * Convert a duration of the password policy, in units of 100 nanoseconds, where 0 means that
+ * there is no limit.
+ */
+fn policy_duration(ticks: u64) -> Option<Duration> {
+  if ticks == 0 {
+    return None;
+  }
+
+  Some(Duration::new(ticks / 10_000_000, (ticks % 10_000_000) as u32 * 100))
+}
+
+/**
+ * The reply of the kpasswd server to a password change request.
+ *
+ * A rejected password change is not an error of the request itself, so it is reported here
+ * instead of as a [crate::Krb5Error].
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Krb5PasswordChangeResult {
+  /// the kpasswd result code (`KRB5_KPASSWD_*`), 0 on success
+  pub code: c_int,
+  /// the description of the result code, e.g. "Password changed"
+  pub code_string: String,
+  /// the message of the server for display, e.g. the password policy requirements of an Active
+  /// Directory domain controller, or an empty string if the server did not send one
+  pub server_message: String,
+  /// the raw result string of the server that `server_message` is decoded from
+  pub server_data: Vec<u8>,
+}
+
+impl Krb5PasswordChangeResult {
+  /**
+   * Return `true` if the password was changed.
+   */
+  pub fn is_success(&self) -> bool {
+    self.code == KRB5_KPASSWD_SUCCESS as c_int
+  }
+
+  /**
+   * Return the password policy the server sent along with a rejected password, or `None` if it
+   * did not send one. Only Active Directory domain controllers send a structured policy, other
+   * servers describe the requirements in the server message.
+   */
+  pub fn policy(&self) -> Option<Krb5PasswordPolicy> {
+    Krb5PasswordPolicy::from_bytes(&self.server_data)
+  }
+
+  /**
+   * Decode the result code, together with the message to show to the user.
+   *
+   * The message is the server message if there is one, and the description of the result code
+   * otherwise.
+   */
+  pub fn status(&self) -> Krb5PasswordChangeStatus {
+    use Krb5PasswordChangeStatus::*;
+
+    let message = if self.server_message.is_empty() {
+      self.code_string.clone()
+    } else {
+      self.server_message.clone()
+    };
+
+    match self.code as u32 {
+      KRB5_KPASSWD_SUCCESS => Success,
+      KRB5_KPASSWD_MALFORMED => Malformed { message },
+      KRB5_KPASSWD_HARDERROR => HardError { message },
+      KRB5_KPASSWD_AUTHERROR => AuthError { message },
+      KRB5_KPASSWD_SOFTERROR => SoftError { message },
+      KRB5_KPASSWD_ACCESSDENIED => AccessDenied { message },
+      KRB5_KPASSWD_BAD_VERSION => BadVersion { message },
+      KRB5_KPASSWD_INITIAL_FLAG_NEEDED => InitialFlagNeeded { message },
+      _ => Unknown {
+        code: self.code,
+        message,
+      },
+    }
+  }
+
+  /**
+   * Decode the result of a kpasswd request, taking ownership of the result strings.
+   *
+   * [krb5_chpw_message](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_chpw_message.html)
+   */
+  pub(crate) fn from_raw(
+    context: &Krb5Context,
+    code: c_int,
+    code_string: krb5_data,
+    result_string: krb5_data,
+  ) -> Krb5PasswordChangeResult {
+    let code_string = Krb5Data::from_raw(context, code_string);
+    let result_string = Krb5Data::from_raw(context, result_string);
+
+    let server_message = if result_string.is_empty() {
+      String::new()
+    } else {
+      server_message(context, &result_string)
+    };
+
+    Krb5PasswordChangeResult {
+      code,
+      code_string: String::from_utf8_lossy(&code_string).into_owned(),
+      server_message,
+      server_data: result_string.into_vec(),
+    }
+  }
+}
+
+/**
+ * Decode the result string of a kpasswd reply, which is either a text message or the password
+ * policy of an Active Directory server.
+ */
+#[cfg(all(krb5_mit, krb5_1_11))]
+fn server_message(context: &Krb5Context, result_string: &Krb5Data) -> String {
+  let mut message: MaybeUninit<*mut c_char> = MaybeUninit::zeroed();
+
+  let code: krb5_error_code =
+    unsafe { krb5_chpw_message(context.context, &result_string.as_krb5_data(), message.as_mut_ptr()) };
+
+  if code == 0 {
+    let message = unsafe { message.assume_init() };
+    let string = c_string_to_string_lossy(message).unwrap_or_default();
+    unsafe { krb5_free_string(context.context, message) };
+    string
+  } else {
+    String::from_utf8_lossy(result_string).into_owned()
+  }
+}
+
+/**
+ * Decode the result string of a kpasswd reply as text, without krb5_chpw_message, which only
+ * MIT krb5 has, since 1.11.
+ */
+#[cfg(not(all(krb5_mit, krb5_1_11)))]
+fn server_message(_context: &Krb5Context, result_string: &Krb5Data) -> String {
+  String::from_utf8_lossy(result_string).into_owned()
+}
@@ -1,13 +1,15 @@
 /*!
  * Rustic wrapper for krb5 principals.
  */
+use std::mem::MaybeUninit;
 use std::os::raw::c_char;
 
 use libkrb5_sys::*;
 
 use crate::context::Krb5Context;
-use crate::error::Krb5Error;
-use crate::strconv::c_string_to_string;
+use crate::data::Krb5Data;
+use crate::error::{krb5_error_code_escape_hatch, Krb5Error};
+use crate::strconv::string_to_c_string;
 
 /**
  * krb5 principal wrapper struct.
@@ -37,12 +39,92 @@ impl<'a> Krb5Principal<'a> {
   /**
    * Retrieve principal data.
    */
-  pub fn data(&self) -> Krb5PrincipalData {
+  pub fn data(&self) -> Krb5PrincipalData<'_> {
     Krb5PrincipalData {
-      context: &self.context,
+      context: self.context,
       principal_data: unsafe { *self.principal },
     }
   }
+
+  /**
+   * Return the default salt for password derived keys of the principal (the realm followed by
+   * the name components), see [crate::Krb5Keyblock::string_to_key].
+   *
+   * [krb5_principal2salt](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_principal2salt.html)
+   */
+  pub fn salt(&self) -> Result<Vec<u8>, Krb5Error> {
+    let mut salt: MaybeUninit<krb5_data> = MaybeUninit::zeroed();
+
+    let code: krb5_error_code = unsafe { krb5_principal2salt(self.context.context, self.principal, salt.as_mut_ptr()) };
+
+    krb5_error_code_escape_hatch(self.context, code)?;
+
+    let bytes = Krb5Data::from_raw(self.context, unsafe { salt.assume_init() }).into_vec();
+
+    Ok(bytes)
+  }
+
+  /**
+   * Convert the principal to its string representation.
+   *
+   * [krb5_unparse_name](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_unparse_name.html)
+   */
+  pub fn unparse_name(&self) -> Result<String, Krb5Error> {
+    let mut name: MaybeUninit<*mut c_char> = MaybeUninit::zeroed();
+
+    let code: krb5_error_code = unsafe { krb5_unparse_name(self.context.context, self.principal, name.as_mut_ptr()) };
+
+    krb5_error_code_escape_hatch(self.context, code)?;
+
+    let name = unsafe { name.assume_init() };
+
+    let string = self.context.c_string_to_string(name);
+    unsafe { krb5_free_unparsed_name(self.context.context, name) };
+
+    string
+  }
+}
+
+/**
+ * Copy a principal owned by another libkrb5 structure into a new Krb5Principal.
+ *
+ * [krb5_copy_principal](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_copy_principal.html)
+ */
+pub(crate) fn copy_principal(
+  context: &Krb5Context,
+  principal: krb5_const_principal,
+) -> Result<Krb5Principal<'_>, Krb5Error> {
+  let mut principal_ptr: MaybeUninit<krb5_principal> = MaybeUninit::zeroed();
+
+  let code: krb5_error_code = unsafe { krb5_copy_principal(context.context, principal, principal_ptr.as_mut_ptr()) };
+
+  krb5_error_code_escape_hatch(context, code)?;
+
+  let principal = Krb5Principal {
+    context,
+    principal: unsafe { principal_ptr.assume_init() },
+  };
+
+  Ok(principal)
+}
+
+/**
+ * Convert a principal name to a principal.
+ *
+ * [krb5_parse_name](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_parse_name.html)
+ */
+pub(crate) fn parse_name<'a>(context: &'a Krb5Context, name: &str) -> Result<Krb5Principal<'a>, Krb5Error> {
+  let name = string_to_c_string(name)?;
+  let mut principal_ptr: MaybeUninit<krb5_principal> = MaybeUninit::zeroed();
+
+  let code: krb5_error_code = unsafe { krb5_parse_name(context.context, name.as_ptr(), principal_ptr.as_mut_ptr()) };
+
+  krb5_error_code_escape_hatch(context, code)?;
+
+  Ok(Krb5Principal {
+    context,
+    principal: unsafe { principal_ptr.assume_init() },
+  })
 }
 
 /**
@@ -64,6 +146,6 @@ impl<'a> Krb5PrincipalData<'a> {
   pub fn realm(&self) -> Result<String, Krb5Error> {
     let realm: *const c_char = self.principal_data.realm.data;
 
-    c_string_to_string(realm)
+    self.context.c_string_to_string(realm)
   }
 }
@@ -0,0 +1,316 @@
+/*!
+ * Overrides of krb5.conf settings for a single context.
+ */
+use std::ffi::{CStr, CString, OsStr};
+use std::os::raw::{c_char, c_int, c_long, c_void};
+use std::sync::Arc;
+
+use libkrb5_sys::*;
+
+use crate::context::Krb5Context;
+use crate::error::{error_message, krb5_error_code_escape_hatch, Krb5Error};
+use crate::strconv::{os_str_to_c_string, string_to_c_string};
+
+/**
+ * A profile that returns the given values for one relation and delegates all other lookups to
+ * a base profile.
+ *
+ * libkrb5 copies the profile of a context, so the callback data is shared between the copies.
+ */
+struct ProfileOverlay {
+  base: profile_t,
+  names: Vec<CString>,
+  values: Vec<CString>,
+}
+
+// the base profile is only read, which libkrb5 supports from several threads
+unsafe impl Send for ProfileOverlay {}
+unsafe impl Sync for ProfileOverlay {}
+
+/**
+ * Release the base profile.
+ */
+impl Drop for ProfileOverlay {
+  fn drop(&mut self) {
+    unsafe { profile_release(self.base) };
+  }
+}
+
+impl ProfileOverlay {
+  /**
+   * Return `true` if a NULL-terminated list of names refers to the overridden relation.
+   */
+  unsafe fn matches(&self, names: *const *const c_char) -> bool {
+    if names.is_null() {
+      return false;
+    }
+
+    for (i, name) in self.names.iter().enumerate() {
+      let other = *names.add(i);
+      if other.is_null() || CStr::from_ptr(other) != name.as_c_str() {
+        return false;
+      }
+    }
+
+    (*names.add(self.names.len())).is_null()
+  }
+}
+
+/**
+ * The callbacks of an overlay profile, see `struct profile_vtable` in profile.h.
+ *
+ * The profile is read-only. Iteration is delegated to the base profile, so it does not show
+ * overridden values.
+ */
+static OVERLAY_VTABLE: profile_vtable = profile_vtable {
+  minor_ver: 1,
+  get_values: Some(overlay_get_values),
+  free_values: Some(overlay_free_values),
+  cleanup: Some(overlay_cleanup),
+  copy: Some(overlay_copy),
+  iterator_create: Some(overlay_iterator_create),
+  iterator: Some(overlay_iterator),
+  iterator_free: Some(overlay_iterator_free),
+  free_string: Some(overlay_free_string),
+  writable: None,
+  modified: None,
+  update_relation: None,
+  rename_section: None,
+  add_relation: None,
+  flush: None,
+};
+
+/**
+ * Create a profile that overrides the values of one relation of a base profile, e.g.
+ * `["realms", "EXAMPLE.COM", "kpasswd_server"]`. An empty list of values removes the relation.
+ * Takes ownership of the base profile.
+ */
+pub(crate) fn overlay_profile<N: AsRef<OsStr>, V: AsRef<OsStr>>(
+  base: profile_t,
+  names: &[N],
+  values: &[V],
+) -> Result<profile_t, Krb5Error> {
+  let mut overlay = ProfileOverlay {
+    base,
+    names: Vec::with_capacity(names.len()),
+    values: Vec::with_capacity(values.len()),
+  };
+
+  for name in names {
+    overlay.names.push(os_str_to_c_string(name.as_ref())?);
+  }
+
+  for value in values {
+    overlay.values.push(os_str_to_c_string(value.as_ref())?);
+  }
+
+  let cbdata = Arc::into_raw(Arc::new(overlay)) as *mut c_void;
+  let mut profile: profile_t = std::ptr::null_mut();
+
+  // libkrb5 copies the vtable, so passing a mutable pointer to the static one is fine
+  let code = unsafe {
+    profile_init_vtable(
+      &OVERLAY_VTABLE as *const profile_vtable as *mut profile_vtable,
+      cbdata,
+      &mut profile,
+    )
+  };
+
+  if code != 0 {
+    unsafe { overlay_cleanup(cbdata) };
+    return Err(Krb5Error::LibraryError {
+      code: code as krb5_error_code,
+      message: error_message(code as krb5_error_code),
+    });
+  }
+
+  Ok(profile)
+}
+
+/**
+ * Return the values of a relation in the profile of a context, e.g.
+ * `["libdefaults", "default_keytab_name"]`. The list is empty if the relation is not set.
+ */
+pub(crate) fn profile_values(context: &Krb5Context, names: &[&str]) -> Result<Vec<String>, Krb5Error> {
+  let names_c = names
+    .iter()
+    .map(|name| string_to_c_string(name))
+    .collect::<Result<Vec<CString>, Krb5Error>>()?;
+  let mut names_ptr: Vec<*const c_char> = names_c.iter().map(|name| name.as_ptr()).collect();
+  names_ptr.push(std::ptr::null());
+
+  let mut profile: profile_t = std::ptr::null_mut();
+
+  let code: krb5_error_code = unsafe { krb5_get_profile(context.context, &mut profile) };
+
+  krb5_error_code_escape_hatch(context, code)?;
+
+  let mut values: *mut *mut c_char = std::ptr::null_mut();
+  let code = unsafe { profile_get_values(profile, names_ptr.as_ptr(), &mut values) };
+  unsafe { profile_release(profile) };
+
+  if code == PROF_NO_RELATION as c_long || code == PROF_NO_SECTION as c_long {
+    return Ok(Vec::new());
+  }
+
+  if code != 0 {
+    return Err(Krb5Error::LibraryError {
+      code: code as krb5_error_code,
+      message: error_message(code as krb5_error_code),
+    });
+  }
+
+  let mut strings = Vec::new();
+  let mut result = Ok(());
+  for i in 0.. {
+    let value = unsafe { *values.add(i) };
+    if value.is_null() {
+      break;
+    }
+
+    match context.c_string_to_string(value) {
+      Ok(string) => strings.push(string),
+      Err(error) => {
+        result = Err(error);
+        break;
+      },
+    }
+  }
+  unsafe { profile_free_list(values) };
+
+  result.map(|_| strings)
+}
+
+/**
+ * Return the values of a relation as a NULL-terminated list of strings.
+ */
+unsafe extern "C" fn overlay_get_values(
+  cbdata: *mut c_void,
+  names: *const *const c_char,
+  ret_values: *mut *mut *mut c_char,
+) -> c_long {
+  let overlay = &*(cbdata as *const ProfileOverlay);
+
+  if overlay.matches(names) {
+    if overlay.values.is_empty() {
+      return PROF_NO_RELATION as c_long;
+    }
+
+    *ret_values = string_list(overlay.values.iter().map(|value| value.as_c_str()));
+    return 0;
+  }
+
+  let mut values: *mut *mut c_char = std::ptr::null_mut();
+
+  let code = profile_get_values(overlay.base, names, &mut values);
+  if code != 0 {
+    return code;
+  }
+
+  // the list is freed by overlay_free_values, so it has to be allocated the same way
+  let mut list = Vec::new();
+  let mut i = 0;
+  while !(*values.add(i)).is_null() {
+    list.push(CStr::from_ptr(*values.add(i)));
+    i += 1;
+  }
+
+  *ret_values = string_list(list.into_iter());
+  profile_free_list(values);
+
+  0
+}
+
+/**
+ * Free a list returned by [overlay_get_values].
+ */
+unsafe extern "C" fn overlay_free_values(_cbdata: *mut c_void, values: *mut *mut c_char) {
+  if values.is_null() {
+    return;
+  }
+
+  let mut len = 0;
+  while !(*values.add(len)).is_null() {
+    drop(CString::from_raw(*values.add(len)));
+    len += 1;
+  }
+
+  drop(Box::from_raw(std::ptr::slice_from_raw_parts_mut(values, len + 1)));
+}
+
+/**
+ * Release the callback data of a profile.
+ */
+unsafe extern "C" fn overlay_cleanup(cbdata: *mut c_void) {
+  drop(Arc::from_raw(cbdata as *const ProfileOverlay));
+}
+
+/**
+ * Share the callback data with a copy of the profile.
+ */
+unsafe extern "C" fn overlay_copy(cbdata: *mut c_void, ret_cbdata: *mut *mut c_void) -> c_long {
+  Arc::increment_strong_count(cbdata as *const ProfileOverlay);
+  *ret_cbdata = cbdata;
+
+  0
+}
+
+/**
+ * Create an iterator of the base profile. It is boxed, since the base profile resets the
+ * iterator when it ends.
+ */
+unsafe extern "C" fn overlay_iterator_create(
+  cbdata: *mut c_void,
+  names: *const *const c_char,
+  flags: c_int,
+  ret_iter: *mut *mut c_void,
+) -> c_long {
+  let overlay = &*(cbdata as *const ProfileOverlay);
+  let mut iter: *mut c_void = std::ptr::null_mut();
+
+  let code = profile_iterator_create(overlay.base, names, flags, &mut iter);
+  if code != 0 {
+    return code;
+  }
+
+  *ret_iter = Box::into_raw(Box::new(iter)) as *mut c_void;
+
+  0
+}
+
+/**
+ * Return the next name and value of an iterator of the base profile.
+ */
+unsafe extern "C" fn overlay_iterator(
+  _cbdata: *mut c_void,
+  iter: *mut c_void,
+  ret_name: *mut *mut c_char,
+  ret_value: *mut *mut c_char,
+) -> c_long {
+  profile_iterator(iter as *mut *mut c_void, ret_name, ret_value)
+}
+
+/**
+ * Free an iterator of the base profile.
+ */
+unsafe extern "C" fn overlay_iterator_free(_cbdata: *mut c_void, iter: *mut c_void) {
+  let mut iter = Box::from_raw(iter as *mut *mut c_void);
+  profile_iterator_free(&mut *iter);
+}
+
+/**
+ * Free a string returned by [overlay_iterator].
+ */
+unsafe extern "C" fn overlay_free_string(_cbdata: *mut c_void, string: *mut c_char) {
+  profile_release_string(string);
+}
+
+/**
+ * Copy strings into a NULL-terminated list, to be freed by [overlay_free_values].
+ */
+fn string_list<'s, I: Iterator<Item = &'s CStr>>(strings: I) -> *mut *mut c_char {
+  let mut list: Vec<*mut c_char> = strings.map(|string| CString::from(string).into_raw()).collect();
+  list.push(std::ptr::null_mut());
+
+  Box::into_raw(list.into_boxed_slice()) as *mut *mut c_char
+}
@@ -0,0 +1,198 @@
+/*!
+ * Rustic wrapper for krb5 replay caches.
+ */
+#[cfg(all(krb5_mit, not(feature = "dlopen")))]
+use std::ffi::OsStr;
+use std::mem::MaybeUninit;
+#[cfg(all(krb5_mit, not(feature = "dlopen")))]
+use std::os::raw::c_char;
+
+use libkrb5_sys::*;
+
+use crate::context::Krb5Context;
+use crate::data::slice_to_krb5_data;
+#[cfg(all(krb5_mit, krb5_1_18, not(feature = "dlopen")))]
+use crate::enctype::Krb5Enctype;
+use crate::error::{krb5_error_code_escape_hatch, Krb5Error};
+#[cfg(all(krb5_mit, not(feature = "dlopen")))]
+use crate::strconv::os_str_to_c_string;
+
+/*
+ * MIT krb5 exports the replay cache functions without declaring them in its public headers. The
+ * interface was replaced in krb5 1.18: replay caches are recovered when they are opened and
+ * expunged while storing, and entries are tagged with the checksum of encrypted authenticators.
+ */
+#[cfg(all(krb5_mit, krb5_1_18, not(feature = "dlopen")))]
+extern "C" {
+  fn k5_rc_resolve(context: krb5_context, name: *const c_char, rc_out: *mut krb5_rcache) -> krb5_error_code;
+  fn k5_rc_store(context: krb5_context, rc: krb5_rcache, authenticator: *const krb5_enc_data) -> krb5_error_code;
+  fn k5_rc_get_name(context: krb5_context, rc: krb5_rcache) -> *const c_char;
+}
+
+#[cfg(all(krb5_mit, not(krb5_1_18), not(feature = "dlopen")))]
+extern "C" {
+  fn krb5_rc_resolve_full(context: krb5_context, rc: *mut krb5_rcache, name: *mut c_char) -> krb5_error_code;
+  fn krb5_rc_recover_or_initialize(context: krb5_context, rc: krb5_rcache, lifespan: krb5_deltat) -> krb5_error_code;
+  fn krb5_rc_recover(context: krb5_context, rc: krb5_rcache) -> krb5_error_code;
+  fn krb5_rc_expunge(context: krb5_context, rc: krb5_rcache) -> krb5_error_code;
+  fn krb5_rc_get_name(context: krb5_context, rc: krb5_rcache) -> *mut c_char;
+}
+
+/**
+ * Wrapper struct for a krb5 replay cache handle.
+ *
+ * Replay caches detect authenticators that are used more than once. A replay cache is used by
+ * attaching it to an authentication context with [crate::Krb5AuthContext::set_rcache], or
+ * directly with [Krb5RCache::store].
+ *
+ * Replay caches other than the default one are opened by name with [Krb5RCache::resolve], with
+ * MIT krb5 only. Since krb5 1.18, replay caches are recovered when they are opened and expired
+ * entries are expunged while storing, so [Krb5RCache::recover] and [Krb5RCache::expunge] are
+ * only available for older releases, and [Krb5RCache::store] only for newer ones.
+ */
+#[derive(Debug)]
+pub struct Krb5RCache<'a> {
+  pub(crate) context: &'a Krb5Context,
+  pub(crate) rcache: krb5_rcache,
+}
+
+/**
+ * Close a replay cache.
+ *
+ * libkrb5 does not export a function to close a replay cache since krb5 1.18, so the handle is
+ * attached to a temporary authentication context, which closes it when it is freed.
+ */
+impl<'a> Drop for Krb5RCache<'a> {
+  fn drop(&mut self) {
+    let mut auth_context: MaybeUninit<krb5_auth_context> = MaybeUninit::zeroed();
+
+    unsafe {
+      if krb5_auth_con_init(self.context.context, auth_context.as_mut_ptr()) == 0 {
+        let auth_context = auth_context.assume_init();
+        krb5_auth_con_setrcache(self.context.context, auth_context, self.rcache);
+        krb5_auth_con_free(self.context.context, auth_context);
+      }
+    }
+  }
+}
+
+impl<'a> Krb5RCache<'a> {
+  /**
+   * Open the default replay cache for server use.
+   *
+   * [krb5_get_server_rcache](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_get_server_rcache.html)
+   *
+   * # Arguments
+   *
+   *  * context: the Krb5Context instance
+   */
+  pub fn server_default(context: &'a Krb5Context) -> Result<Krb5RCache<'a>, Krb5Error> {
+    // the argument is ignored by current libkrb5 versions
+    let piece = slice_to_krb5_data(b"");
+    let mut rcache_ptr: MaybeUninit<krb5_rcache> = MaybeUninit::zeroed();
+
+    let code: krb5_error_code = unsafe { krb5_get_server_rcache(context.context, &piece, rcache_ptr.as_mut_ptr()) };
+
+    krb5_error_code_escape_hatch(context, code)?;
+
+    let rcache = Krb5RCache {
+      context,
+      rcache: unsafe { rcache_ptr.assume_init() },
+    };
+
+    Ok(rcache)
+  }
+  /**
+   * Open a replay cache by name, e.g. `file2:/var/tmp/app.rcache` or `none:`, recovering its
+   * entries.
+   *
+   * # Arguments
+   *
+   *  * context: the Krb5Context instance
+   *  * name: the replay cache name, prefixed by its type
+   */
+  #[cfg(all(krb5_mit, not(feature = "dlopen")))]
+  pub fn resolve<N: AsRef<OsStr>>(context: &'a Krb5Context, name: N) -> Result<Krb5RCache<'a>, Krb5Error> {
+    let name_c = os_str_to_c_string(name.as_ref())?;
+    let mut rcache_ptr: MaybeUninit<krb5_rcache> = MaybeUninit::zeroed();
+
+    #[cfg(krb5_1_18)]
+    let code: krb5_error_code = unsafe { k5_rc_resolve(context.context, name_c.as_ptr(), rcache_ptr.as_mut_ptr()) };
+    #[cfg(not(krb5_1_18))]
+    let code: krb5_error_code =
+      unsafe { krb5_rc_resolve_full(context.context, rcache_ptr.as_mut_ptr(), name_c.as_ptr() as *mut c_char) };
+
+    krb5_error_code_escape_hatch(context, code)?;
+
+    let rcache = Krb5RCache {
+      context,
+      rcache: unsafe { rcache_ptr.assume_init() },
+    };
+
+    // a lifespan of 0 selects the clock skew of the context
+    #[cfg(not(krb5_1_18))]
+    krb5_error_code_escape_hatch(context, unsafe {
+      krb5_rc_recover_or_initialize(context.context, rcache.rcache, 0)
+    })?;
+
+    Ok(rcache)
+  }
+
+  /**
+   * Return the name of the replay cache, prefixed by its type.
+   */
+  #[cfg(all(krb5_mit, not(feature = "dlopen")))]
+  pub fn get_name(&self) -> Result<String, Krb5Error> {
+    #[cfg(krb5_1_18)]
+    let name: *const c_char = unsafe { k5_rc_get_name(self.context.context, self.rcache) };
+    #[cfg(not(krb5_1_18))]
+    let name: *const c_char = unsafe { krb5_rc_get_name(self.context.context, self.rcache) };
+
+    self.context.c_string_to_string(name)
+  }
+
+  /**
+   * Record an encrypted authenticator, and fail with `KRB5KRB_AP_ERR_REPEAT` if it has been
+   * recorded before.
+   *
+   * Entries are identified by the checksum at the end of the ciphertext.
+   *
+   * # Arguments
+   *
+   *  * enctype: the encryption type of the authenticator
+   *  * ciphertext: the encrypted authenticator
+   */
+  #[cfg(all(krb5_mit, krb5_1_18, not(feature = "dlopen")))]
+  pub fn store(&mut self, enctype: Krb5Enctype, ciphertext: &[u8]) -> Result<(), Krb5Error> {
+    let authenticator = krb5_enc_data {
+      magic: 0,
+      enctype: enctype.as_raw(),
+      kvno: 0,
+      ciphertext: slice_to_krb5_data(ciphertext),
+    };
+
+    let code: krb5_error_code = unsafe { k5_rc_store(self.context.context, self.rcache, &authenticator) };
+
+    krb5_error_code_escape_hatch(self.context, code)
+  }
+
+  /**
+   * Reload the entries of the replay cache from its storage.
+   */
+  #[cfg(all(krb5_mit, not(krb5_1_18), not(feature = "dlopen")))]
+  pub fn recover(&mut self) -> Result<(), Krb5Error> {
+    let code: krb5_error_code = unsafe { krb5_rc_recover(self.context.context, self.rcache) };
+
+    krb5_error_code_escape_hatch(self.context, code)
+  }
+
+  /**
+   * Remove expired entries from the replay cache.
+   */
+  #[cfg(all(krb5_mit, not(krb5_1_18), not(feature = "dlopen")))]
+  pub fn expunge(&mut self) -> Result<(), Krb5Error> {
+    let code: krb5_error_code = unsafe { krb5_rc_expunge(self.context.context, self.rcache) };
+
+    krb5_error_code_escape_hatch(self.context, code)
+  }
+}
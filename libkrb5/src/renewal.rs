@@ -0,0 +1,330 @@
+/*!
+ * Background renewal of the ticket granting ticket of a credential cache, available with the
+ * `async` feature.
+ */
+use std::time::{Duration, SystemTime};
+
+use libkrb5_sys::*;
+use tokio::sync::watch;
+
+use crate::ccache::Krb5CCache;
+use crate::context::Krb5Context;
+use crate::creds::{Krb5Creds, Krb5InitCredsOptions};
+use crate::error::Krb5Error;
+use crate::keytab::Krb5Keytab;
+use crate::nonblocking::{Krb5AsyncClient, SendableInitCredsOptions};
+use crate::principal::parse_name;
+use crate::timestamp::Krb5Timestamp;
+
+/**
+ * How long before the ticket granting ticket expires it is refreshed by default.
+ */
+const DEFAULT_REFRESH_BEFORE: Duration = Duration::from_secs(5 * 60);
+
+/**
+ * How long to wait after a failed refresh by default.
+ */
+const DEFAULT_RETRY_INTERVAL: Duration = Duration::from_secs(30);
+
+/**
+ * The state of the ticket granting ticket of a [TicketRenewalTask], e.g. for health checks.
+ *
+ * New variants may be added, so matches need a wildcard arm.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TicketRenewalState {
+  /// the credential cache has not been checked yet
+  Pending,
+  /// the credential cache holds a ticket granting ticket that is not due for a refresh
+  Valid {
+    /// the time the ticket expires
+    end_time: Krb5Timestamp,
+    /// the latest time the ticket can be renewed until, unset if it is not renewable
+    renew_till: Krb5Timestamp,
+  },
+  /// the last refresh failed, and is retried after the retry interval
+  Failed {
+    /// the description of the error
+    message: String,
+    /// the time the ticket granting ticket in the credential cache expires (or expired), if
+    /// there is one
+    end_time: Option<Krb5Timestamp>,
+  },
+}
+
+impl TicketRenewalState {
+  /**
+   * Return the time the ticket granting ticket in the credential cache expires, if it is
+   * known.
+   */
+  pub fn end_time(&self) -> Option<Krb5Timestamp> {
+    match self {
+      TicketRenewalState::Pending => None,
+      TicketRenewalState::Valid { end_time, .. } => Some(*end_time),
+      TicketRenewalState::Failed { end_time, .. } => *end_time,
+    }
+  }
+
+  /**
+   * Describe the ticket granting ticket of credentials as valid.
+   */
+  fn valid(creds: &Krb5Creds) -> TicketRenewalState {
+    TicketRenewalState::Valid {
+      end_time: creds.end_time(),
+      renew_till: creds.renew_till(),
+    }
+  }
+}
+
+/**
+ * Where to acquire a new ticket granting ticket from, once the current one can not be renewed
+ * anymore.
+ */
+#[derive(Debug, Clone)]
+struct KeytabSource {
+  keytab: Option<String>,
+  principal: String,
+  options: SendableInitCredsOptions,
+}
+
+/**
+ * Keeps the ticket granting ticket of a credential cache valid, like `k5start` or `kinit -R`
+ * in a loop.
+ *
+ * The task sleeps until shortly before the ticket expires, and then renews it with the KDC, or
+ * acquires a new one from a key table if it is not renewable anymore. Failed attempts are
+ * retried after the retry interval. The state after every attempt is published to the
+ * receivers of [TicketRenewalTask::subscribe].
+ *
+ * The KDC requests are run on the blocking thread pool, see [Krb5AsyncClient].
+ *
+ * ```no_run
+ * # async fn example() {
+ * use libkrb5::{Krb5AsyncClient, Krb5InitCredsOptions, TicketRenewalTask};
+ *
+ * let task = TicketRenewalTask::new(Krb5AsyncClient::new(), "FILE:/tmp/krb5cc_service")
+ *   .with_keytab(
+ *     None,
+ *     "service@EXAMPLE.COM",
+ *     &Krb5InitCredsOptions::default(),
+ *   );
+ * let state = task.subscribe();
+ * tokio::spawn(task.run());
+ * # }
+ * ```
+ */
+#[derive(Debug)]
+pub struct TicketRenewalTask {
+  client: Krb5AsyncClient,
+  ccache: String,
+  keytab: Option<KeytabSource>,
+  refresh_before: Duration,
+  retry_interval: Duration,
+  state: watch::Sender<TicketRenewalState>,
+}
+
+impl TicketRenewalTask {
+  /**
+   * Create a task that renews the ticket granting ticket of a credential cache.
+   *
+   * The ticket is refreshed five minutes before it expires, and failed attempts are retried
+   * after 30 seconds.
+   *
+   * # Arguments
+   *
+   *  * client: runs the KDC requests
+   *  * ccache: the name of the credential cache
+   */
+  pub fn new(client: Krb5AsyncClient, ccache: &str) -> TicketRenewalTask {
+    let (state, _) = watch::channel(TicketRenewalState::Pending);
+
+    TicketRenewalTask {
+      client,
+      ccache: String::from(ccache),
+      keytab: None,
+      refresh_before: DEFAULT_REFRESH_BEFORE,
+      retry_interval: DEFAULT_RETRY_INTERVAL,
+      state,
+    }
+  }
+
+  /**
+   * Acquire a new ticket granting ticket with a key from a key table, like `kinit -k`, if the
+   * credential cache has none or it can not be renewed.
+   *
+   * # Arguments
+   *
+   *  * keytab: the name of the key table, or `None` to use the default key table
+   *  * principal: the name of the client principal to authenticate as
+   *  * options: options for the initial credentials request, whose `out_ccache` is ignored
+   */
+  pub fn with_keytab(
+    mut self,
+    keytab: Option<&str>,
+    principal: &str,
+    options: &Krb5InitCredsOptions<'_>,
+  ) -> TicketRenewalTask {
+    self.keytab = Some(KeytabSource {
+      keytab: keytab.map(String::from),
+      principal: String::from(principal),
+      options: SendableInitCredsOptions::from(options),
+    });
+    self
+  }
+
+  /**
+   * Set how long before the ticket granting ticket expires it is refreshed.
+   */
+  pub fn with_refresh_before(mut self, refresh_before: Duration) -> TicketRenewalTask {
+    self.refresh_before = refresh_before;
+    self
+  }
+
+  /**
+   * Set how long to wait after a failed refresh. It is also the shortest time between two
+   * refreshes.
+   */
+  pub fn with_retry_interval(mut self, retry_interval: Duration) -> TicketRenewalTask {
+    self.retry_interval = retry_interval;
+    self
+  }
+
+  /**
+   * Return a receiver of the state of the ticket granting ticket, which is updated after every
+   * refresh.
+   */
+  pub fn subscribe(&self) -> watch::Receiver<TicketRenewalState> {
+    self.state.subscribe()
+  }
+
+  /**
+   * Check the ticket granting ticket once, renew or acquire it if it is due, and publish the
+   * resulting state.
+   */
+  pub async fn refresh(&self) -> TicketRenewalState {
+    let ccache = self.ccache.clone();
+    let keytab = self.keytab.clone();
+    let refresh_before = self.refresh_before;
+
+    let state = self
+      .client
+      .run(move |context| Ok(refresh_tgt(context, &ccache, keytab.as_ref(), refresh_before)))
+      .await
+      .unwrap_or_else(|error| TicketRenewalState::Failed {
+        message: error.to_string(),
+        end_time: None,
+      });
+
+    self.state.send_replace(state.clone());
+    state
+  }
+
+  /**
+   * Keep the ticket granting ticket valid until the task is dropped, e.g. by aborting it.
+   */
+  pub async fn run(self) {
+    loop {
+      let state = self.refresh().await;
+      tokio::time::sleep(self.next_refresh(&state)).await;
+    }
+  }
+
+  /**
+   * Return how long to sleep until the next refresh.
+   */
+  fn next_refresh(&self, state: &TicketRenewalState) -> Duration {
+    let refresh_at = match state {
+      TicketRenewalState::Valid { end_time, .. } => end_time.to_system_time().checked_sub(self.refresh_before),
+      _ => None,
+    };
+
+    refresh_at
+      .and_then(|refresh_at| refresh_at.duration_since(SystemTime::now()).ok())
+      .map_or(self.retry_interval, |wait| wait.max(self.retry_interval))
+  }
+}
+
+/**
+ * Renew or acquire the ticket granting ticket of a credential cache if it expires within
+ * `refresh_before`, and describe the outcome.
+ */
+fn refresh_tgt(
+  context: &Krb5Context,
+  ccache: &str,
+  keytab: Option<&KeytabSource>,
+  refresh_before: Duration,
+) -> TicketRenewalState {
+  let ccache = match Krb5CCache::resolve(context, ccache) {
+    Ok(ccache) => ccache,
+    Err(error) => {
+      return TicketRenewalState::Failed {
+        message: error.to_string(),
+        end_time: None,
+      }
+    },
+  };
+
+  // a credential cache that does not exist yet has no ticket
+  let current = ccache.cached_tgt().ok().flatten();
+  let end_time = current.as_ref().map(|tgt| tgt.end_time());
+
+  match try_refresh_tgt(context, ccache, current, keytab, refresh_before) {
+    Ok(state) => state,
+    Err(error) => TicketRenewalState::Failed {
+      message: error.to_string(),
+      end_time,
+    },
+  }
+}
+
+/**
+ * Renew or acquire the ticket granting ticket of a credential cache if it is due.
+ */
+fn try_refresh_tgt(
+  context: &Krb5Context,
+  mut ccache: Krb5CCache,
+  current: Option<Krb5Creds>,
+  keytab: Option<&KeytabSource>,
+  refresh_before: Duration,
+) -> Result<TicketRenewalState, Krb5Error> {
+  let now = Krb5Timestamp::now(context)?;
+  let due = Krb5Timestamp::from_raw(now.as_secs().saturating_add(refresh_before.as_secs() as u32) as krb5_timestamp);
+
+  if let Some(tgt) = &current {
+    if tgt.end_time() > due {
+      return Ok(TicketRenewalState::valid(tgt));
+    }
+
+    if tgt.renew_till() > now {
+      match ccache.renew() {
+        Ok(creds) => return Ok(TicketRenewalState::valid(&creds)),
+        Err(error) if keytab.is_none() => return Err(error),
+        Err(_) => {},
+      }
+    }
+  }
+
+  let source = match keytab {
+    Some(source) => source,
+    None => {
+      return Err(Krb5Error::LibraryError {
+        code: if current.is_some() {
+          KRB5KRB_AP_ERR_TKT_EXPIRED
+        } else {
+          KRB5_CC_NOTFOUND
+        },
+        message: String::from("No renewable ticket granting ticket and no key table to acquire one"),
+      })
+    },
+  };
+
+  let principal = parse_name(context, &source.principal)?;
+  let keytab = match &source.keytab {
+    Some(name) => Krb5Keytab::resolve(context, name)?,
+    None => Krb5Keytab::default(context)?,
+  };
+
+  let creds = keytab.acquire_initial_creds(&principal, &source.options.to_options(&ccache))?;
+  Ok(TicketRenewalState::valid(&creds))
+}
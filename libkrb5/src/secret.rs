@@ -0,0 +1,59 @@
+/*!
+ * Helpers for handling secret material (keys, passwords).
+ */
+use std::ffi::CString;
+#[cfg(not(feature = "zeroize"))]
+use std::sync::atomic::{compiler_fence, Ordering};
+
+/**
+ * Compare two buffers in time that only depends on their length, not on their contents.
+ */
+pub(crate) fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+  if a.len() != b.len() {
+    return false;
+  }
+
+  let mut difference: u8 = 0;
+  for (x, y) in a.iter().zip(b) {
+    // the volatile read keeps the compiler from ending the loop at the first difference
+    difference |= unsafe { std::ptr::read_volatile(x) } ^ unsafe { std::ptr::read_volatile(y) };
+  }
+
+  difference == 0
+}
+
+/**
+ * Overwrite a C string (e.g. a copy of a password) with zeroes and free it.
+ */
+pub(crate) fn zeroize_c_string(string: CString) {
+  let mut bytes = string.into_bytes();
+  zeroize(&mut bytes);
+}
+
+/**
+ * Overwrite a string (e.g. a password entered by the user) with zeroes and free it.
+ */
+pub(crate) fn zeroize_string(string: String) {
+  let mut bytes = string.into_bytes();
+  zeroize(&mut bytes);
+}
+
+/**
+ * Overwrite a buffer with zeroes in a way the compiler does not optimize away.
+ */
+#[cfg(feature = "zeroize")]
+pub(crate) fn zeroize(buffer: &mut [u8]) {
+  ::zeroize::Zeroize::zeroize(buffer);
+}
+
+/**
+ * Overwrite a buffer with zeroes in a way the compiler does not optimize away.
+ */
+#[cfg(not(feature = "zeroize"))]
+pub(crate) fn zeroize(buffer: &mut [u8]) {
+  for byte in buffer.iter_mut() {
+    unsafe { std::ptr::write_volatile(byte, 0) };
+  }
+
+  compiler_fence(Ordering::SeqCst);
+}
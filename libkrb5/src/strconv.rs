@@ -1,11 +1,48 @@
 /*!
  * Rust <-> C string conversion functions.
  */
-use std::ffi::{CStr, CString};
+use std::ffi::{CStr, CString, OsStr, OsString};
 use std::os::raw::c_char;
 
 use crate::error::Krb5Error;
 
+/**
+ * How to convert strings returned by libkrb5 that are not valid UTF-8, see
+ * [crate::Krb5Context::set_string_policy].
+ */
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Krb5StringPolicy {
+  /// fail with [Krb5Error::StringConversion] (the default)
+  #[default]
+  Strict,
+  /// replace invalid byte sequences with U+FFFD REPLACEMENT CHARACTER
+  Lossy,
+}
+
+/**
+ * Convert C string to Rust String according to the given policy.
+ */
+pub(crate) fn c_string_to_string_with_policy(
+  c_string: *const c_char,
+  policy: Krb5StringPolicy,
+) -> Result<String, Krb5Error> {
+  match policy {
+    Krb5StringPolicy::Strict => c_string_to_string(c_string),
+    Krb5StringPolicy::Lossy => c_string_to_string_lossy(c_string),
+  }
+}
+
+/**
+ * Convert C string to Rust String, replacing invalid UTF-8 sequences.
+ */
+pub(crate) fn c_string_to_string_lossy(c_string: *const c_char) -> Result<String, Krb5Error> {
+  if c_string.is_null() {
+    return Err(Krb5Error::NullPointerDereference);
+  }
+
+  Ok(unsafe { CStr::from_ptr(c_string) }.to_string_lossy().into_owned())
+}
+
 /**
  * Convert C string to Rust String.
  */
@@ -20,14 +57,61 @@ pub(crate) fn c_string_to_string(c_string: *const c_char) -> Result<String, Krb5
   }
 }
 
+/**
+ * Convert C string to an OS string (e.g. a file path).
+ *
+ * On Unix, the bytes are taken over unchanged, so names that are not valid UTF-8 work. On other
+ * platforms, the string has to be valid UTF-8.
+ */
+pub(crate) fn c_string_to_os_string(c_string: *const c_char) -> Result<OsString, Krb5Error> {
+  if c_string.is_null() {
+    return Err(Krb5Error::NullPointerDereference);
+  }
+
+  #[cfg(unix)]
+  {
+    use std::os::unix::ffi::OsStrExt;
+    Ok(OsStr::from_bytes(unsafe { CStr::from_ptr(c_string) }.to_bytes()).to_owned())
+  }
+  #[cfg(not(unix))]
+  {
+    c_string_to_string(c_string).map(OsString::from)
+  }
+}
+
 /**
  * Convert Rust String to C string.
+ *
+ * The returned CString owns the buffer, so it has to be kept alive for as long as the pointer
+ * obtained via `as_ptr()` is in use.
+ */
+pub(crate) fn string_to_c_string(string: &str) -> Result<CString, Krb5Error> {
+  match CString::new(string) {
+    Ok(value) => Ok(value),
+    Err(_) => Err(Krb5Error::StringConversion { error: None }),
+  }
+}
+
+/**
+ * Convert an OS string (e.g. a file path) to C string.
+ *
+ * On Unix, the bytes are passed on unchanged, so names that are not valid UTF-8 work. On other
+ * platforms, the string has to be valid Unicode.
  */
-pub(crate) fn string_to_c_string(string: &str) -> Result<*const c_char, Krb5Error> {
-  let cstring = match CString::new(string) {
-    Ok(value) => value,
-    Err(_) => return Err(Krb5Error::StringConversion { error: None }),
+pub(crate) fn os_str_to_c_string(string: &OsStr) -> Result<CString, Krb5Error> {
+  #[cfg(unix)]
+  let bytes = {
+    use std::os::unix::ffi::OsStrExt;
+    string.as_bytes()
+  };
+  #[cfg(not(unix))]
+  let bytes = match string.to_str() {
+    Some(string) => string.as_bytes(),
+    None => return Err(Krb5Error::StringConversion { error: None }),
   };
 
-  Ok(cstring.as_ptr())
+  match CString::new(bytes) {
+    Ok(value) => Ok(value),
+    Err(_) => Err(Krb5Error::StringConversion { error: None }),
+  }
 }
@@ -6,6 +6,13 @@ fn is_thread_safe() {
   assert_eq!(unsafe { libkrb5_sys::krb5_is_thread_safe() }, C_TRUE);
 }
 
+#[test]
+fn com_err_error_message() {
+  // the com_err tables are registered by libkrb5, no context is needed
+  let message = unsafe { std::ffi::CStr::from_ptr(libkrb5_sys::error_message(codes::KRB5_KDC_UNREACH as _)) };
+  assert_eq!(message.to_string_lossy(), error_message(codes::KRB5_KDC_UNREACH));
+}
+
 #[test]
 fn init() {
   let _context = Krb5Context::init().unwrap();
@@ -16,6 +23,14 @@ fn init_secure() {
   let _context = Krb5Context::init_secure().unwrap();
 }
 
+#[cfg(feature = "dlopen")]
+#[test]
+fn dlopen() {
+  // the library is loaded on first use, and the tests need it installed
+  assert_eq!(libkrb5_sys::load(), Ok(()));
+  assert!(!error_message(codes::KRB5_KDC_UNREACH).is_empty());
+}
+
 #[test]
 fn get_default_realm() {
   let context = Krb5Context::init().unwrap();
@@ -28,13 +43,43 @@ fn get_host_realms() {
   let _realms = context.get_host_realms(None).unwrap();
 }
 
-/*
+#[test]
+fn random_bytes() {
+  let context = Krb5Context::init().unwrap();
+  let first = context.random_bytes(32).unwrap();
+  let second = context.random_bytes(32).unwrap();
+  assert_eq!(first.len(), 32);
+  assert_ne!(first, second);
+  assert!(context.random_bytes(0).unwrap().is_empty());
+}
+
+#[cfg(krb5_1_15)]
 #[test]
 fn expand_hostname() {
-    let context = Krb5Context::init().unwrap();
-    let _expanded = context.expand_hostname("fedoraproject.org").unwrap();
+  let mut context = Krb5Context::init().unwrap();
+  context.set_string_policy(Krb5StringPolicy::Lossy);
+  let _expanded = context.expand_hostname("localhost").unwrap();
+}
+
+#[test]
+fn string_policy() {
+  let bytes = b"caf\xc3\xa9 \xff\0";
+  let c_string = bytes.as_ptr() as *const std::os::raw::c_char;
+
+  let mut context = Krb5Context::init().unwrap();
+  assert_eq!(context.string_policy(), Krb5StringPolicy::Strict);
+  assert!(matches!(
+    context.c_string_to_string(c_string),
+    Err(Krb5Error::StringConversion { .. })
+  ));
+
+  context.set_string_policy(Krb5StringPolicy::Lossy);
+  assert_eq!(context.c_string_to_string(c_string).unwrap(), "caf\u{e9} \u{fffd}");
+  assert!(matches!(
+    context.c_string_to_string(std::ptr::null()),
+    Err(Krb5Error::NullPointerDereference)
+  ));
 }
-*/
 
 #[test]
 fn cccol_new() {
@@ -67,3 +112,3295 @@ fn cccol_get_principal() {
     };
   }
 }
+
+#[test]
+fn keytab_default_name() {
+  let context = Krb5Context::init().unwrap();
+  let _name = Krb5Keytab::default_name(&context).unwrap();
+}
+
+#[cfg(krb5_1_11)]
+#[test]
+fn keytab_has_content() {
+  let context = Krb5Context::init().unwrap();
+
+  let keytab = Krb5Keytab::resolve(&context, "MEMORY:keytab_has_content").unwrap();
+  assert_eq!(keytab.get_type().unwrap(), "MEMORY");
+  match keytab.has_content() {
+    Err(Krb5Error::LibraryError { code, message }) => {
+      assert_eq!(code, libkrb5_sys::KRB5_KT_NOTFOUND);
+      assert!(message.contains("MEMORY:keytab_has_content"), "{}", message);
+    },
+    other => panic!("unexpected result: {:?}", other),
+  }
+
+  let keytab = Krb5Keytab::resolve(&context, "FILE:/nonexistent/krb5.keytab").unwrap();
+  match keytab.has_content() {
+    Err(Krb5Error::LibraryError { code, message }) => {
+      assert_eq!(code, libkrb5_sys::KRB5_KT_NOTFOUND);
+      assert!(message.contains("/nonexistent/krb5.keytab"), "{}", message);
+    },
+    other => panic!("unexpected result: {:?}", other),
+  }
+}
+
+#[test]
+fn keytab_memory_add_key() {
+  let context = Krb5Context::init().unwrap();
+  let components = [String::from("host"), String::from("example.com")];
+  let principal = context.build_principal("EXAMPLE.COM", &components).unwrap();
+
+  let mut keytab = Krb5Keytab::memory(&context, "keytab_memory_add_key").unwrap();
+  keytab
+    .add_key(&principal, 3, Krb5Enctype::Aes256CtsHmacSha1_96, &[0x42; 32])
+    .unwrap();
+  #[cfg(krb5_1_11)]
+  keytab.has_content().unwrap();
+
+  let entries: Vec<_> = keytab.entries().unwrap().map(|entry| entry.unwrap()).collect();
+  assert_eq!(entries.len(), 1);
+  assert_eq!(entries[0].kvno(), 3);
+  assert_eq!(entries[0].enctype(), Krb5Enctype::Aes256CtsHmacSha1_96);
+  assert_eq!(entries[0].principal().unwrap().data().realm().unwrap(), "EXAMPLE.COM");
+
+  let mut copy = Krb5Keytab::memory(&context, "keytab_memory_add_key_copy").unwrap();
+  copy.populate_from(&keytab).unwrap();
+  assert_eq!(copy.entries().unwrap().count(), 1);
+}
+
+#[test]
+fn keytab_copy_to() {
+  let context = Krb5Context::init().unwrap();
+  let host = [String::from("host"), String::from("example.com")];
+  let host = context.build_principal("EXAMPLE.COM", &host).unwrap();
+  let http = [String::from("HTTP"), String::from("example.com")];
+  let http = context.build_principal("EXAMPLE.COM", &http).unwrap();
+  let enctype = Krb5Enctype::Aes128CtsHmacSha1_96;
+
+  let mut source = Krb5Keytab::memory(&context, "keytab_copy_to_source").unwrap();
+  source.add_key(&host, 1, enctype, &[1; 16]).unwrap();
+  source.add_key(&host, 2, enctype, &[2; 16]).unwrap();
+  source.add_key(&http, 2, enctype, &[3; 16]).unwrap();
+
+  let mut dest = Krb5Keytab::memory(&context, "keytab_copy_to_dest").unwrap();
+  let filter = Krb5KeytabFilter {
+    principal: Some(&host),
+    min_kvno: Some(2),
+  };
+  assert_eq!(source.copy_to(&mut dest, &filter).unwrap(), 1);
+  assert_eq!(source.copy_to(&mut dest, &Krb5KeytabFilter::default()).unwrap(), 3);
+}
+
+#[test]
+fn keytab_acquire_initial_creds_empty() {
+  let context = Krb5Context::init().unwrap();
+  let components = [String::from("host"), String::from("example.com")];
+  let principal = context.build_principal("EXAMPLE.COM", &components).unwrap();
+
+  let keytab = Krb5Keytab::memory(&context, "keytab_acquire_initial_creds_empty").unwrap();
+  let options = Krb5InitCredsOptions {
+    tkt_life: Some(3600),
+    forwardable: Some(false),
+    etype_list: Some(vec![Krb5Enctype::Aes256CtsHmacSha1_96]),
+    ..Default::default()
+  };
+  assert!(keytab.acquire_initial_creds(&principal, &options).is_err());
+}
+
+#[test]
+#[cfg(all(krb5_mit, krb5_1_15))]
+fn keytab_acquire_initial_creds_kdc_error() {
+  use std::net::UdpSocket;
+  use std::sync::atomic::{AtomicUsize, Ordering};
+  use std::sync::Arc;
+
+  let context = Krb5Context::init().unwrap();
+  let tgs_components = [String::from("krbtgt"), String::from("EXAMPLE.COM")];
+  let tgs = context.build_principal("EXAMPLE.COM", &tgs_components).unwrap();
+
+  // a KDC that does not know the client
+  let reply = Krb5ErrorMessage {
+    ctime: 0,
+    cusec: 0,
+    stime: 1_600_000_000,
+    susec: 0,
+    error: 6,
+    client: None,
+    server: tgs,
+    text: Some(String::from("unknown client")),
+    e_data: Vec::new(),
+  }
+  .mk_error(&context)
+  .unwrap();
+  let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+  let kdc = socket.local_addr().unwrap().to_string();
+  let requests = Arc::new(AtomicUsize::new(0));
+  let counter = requests.clone();
+  std::thread::spawn(move || {
+    let mut buffer = [0u8; 4096];
+    while let Ok((_, peer)) = socket.recv_from(&mut buffer) {
+      counter.fetch_add(1, Ordering::SeqCst);
+      socket.send_to(&reply, peer).unwrap();
+    }
+  });
+
+  let context = context
+    .with_profile_values(&["realms", "EXAMPLE.COM", "kdc"], &[&kdc])
+    .unwrap()
+    .with_profile_values(&["libdefaults", "dns_lookup_kdc"], &["false"])
+    .unwrap();
+  let client_components = [String::from("host"), String::from("example.com")];
+  let principal = context.build_principal("EXAMPLE.COM", &client_components).unwrap();
+  let mut keytab = Krb5Keytab::memory(&context, "keytab_acquire_initial_creds_kdc_error").unwrap();
+  keytab
+    .add_key(&principal, 1, Krb5Enctype::Aes256CtsHmacSha1_96, &[0x12; 32])
+    .unwrap();
+
+  let error = keytab
+    .acquire_initial_creds(&principal, &Krb5InitCredsOptions::default())
+    .unwrap_err();
+  assert_eq!(error.raw_code(), Some(libkrb5_sys::KRB5KDC_ERR_C_PRINCIPAL_UNKNOWN));
+  let kdc_error = error.kdc_error().unwrap();
+  assert_eq!(kdc_error.error, 6);
+  assert_eq!(kdc_error.text.as_deref(), Some("unknown client"));
+
+  // the request was sent once
+  assert_eq!(requests.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn keytab_summary() {
+  let context = Krb5Context::init().unwrap();
+  let components = [String::from("host"), String::from("example.com")];
+  let principal = context.build_principal("EXAMPLE.COM", &components).unwrap();
+  assert_eq!(principal.unparse_name().unwrap(), "host/example.com@EXAMPLE.COM");
+
+  let mut keytab = Krb5Keytab::memory(&context, "keytab_summary").unwrap();
+  keytab
+    .add_key(&principal, 4, Krb5Enctype::Aes256CtsHmacSha1_96, &[4; 32])
+    .unwrap();
+  keytab
+    .add_key(&principal, 5, Krb5Enctype::Aes128CtsHmacSha1_96, &[5; 16])
+    .unwrap();
+
+  let summary = keytab.summary().unwrap();
+  assert_eq!(summary.len(), 1);
+  assert_eq!(summary[0].principal, "host/example.com@EXAMPLE.COM");
+  assert_eq!(summary[0].max_kvno(), Some(5));
+  let mut names: Vec<_> = summary[0].keys.iter().map(|key| key.enctype_name.as_str()).collect();
+  names.sort_unstable();
+  assert_eq!(names, ["aes128-cts-hmac-sha1-96", "aes256-cts-hmac-sha1-96"]);
+  let key = &summary[0].keys[0];
+  assert_eq!(key.timestamp_string, key.timestamp.format_short().unwrap());
+}
+
+#[test]
+#[cfg(unix)]
+fn keytab_writable_file() {
+  use std::os::unix::fs::PermissionsExt;
+
+  let context = Krb5Context::init().unwrap();
+  let components = [String::from("host"), String::from("example.com")];
+  let principal = context.build_principal("EXAMPLE.COM", &components).unwrap();
+
+  let path = std::env::temp_dir().join(format!("libkrb5-rs-test-{}.keytab", std::process::id()));
+  let _ = std::fs::remove_file(&path);
+
+  let mut keytab = Krb5Keytab::writable_file(&context, &path).unwrap();
+  keytab
+    .add_key(&principal, 1, Krb5Enctype::Aes128CtsHmacSha1_96, &[1; 16])
+    .unwrap();
+  #[cfg(krb5_1_11)]
+  keytab.has_content().unwrap();
+
+  let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+  assert_eq!(mode & 0o777, 0o600);
+
+  drop(keytab);
+  std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+#[cfg(unix)]
+fn non_utf8_paths() {
+  use std::ffi::OsStr;
+  use std::os::unix::ffi::OsStrExt;
+
+  let context = Krb5Context::init().unwrap();
+  let components = [String::from("host"), String::from("example.com")];
+  let principal = context.build_principal("EXAMPLE.COM", &components).unwrap();
+
+  let mut file_name = format!("libkrb5-rs-test-{}-", std::process::id()).into_bytes();
+  file_name.push(0xff);
+  let path = std::env::temp_dir().join(OsStr::from_bytes(&file_name));
+  let _ = std::fs::remove_file(&path);
+
+  let mut keytab = Krb5Keytab::writable_file(&context, &path).unwrap();
+  keytab
+    .add_key(&principal, 1, Krb5Enctype::Aes128CtsHmacSha1_96, &[1; 16])
+    .unwrap();
+  drop(keytab);
+
+  let mut name = std::ffi::OsString::from("FILE:");
+  name.push(&path);
+  let keytab = Krb5Keytab::resolve(&context, &name).unwrap();
+  assert_eq!(keytab.entries().unwrap().count(), 1);
+  assert_eq!(keytab.get_name().unwrap(), name);
+  drop(keytab);
+  std::fs::remove_file(&path).unwrap();
+
+  let mut name = std::ffi::OsString::from("FILE:");
+  name.push(&path);
+  let mut ccache = Krb5CCache::resolve(&context, &name).unwrap();
+  assert_eq!(ccache.get_name().unwrap(), path.as_os_str());
+  ccache.initialize(&principal).unwrap();
+  assert!(path.exists());
+  ccache.destroy().unwrap();
+  assert!(!path.exists());
+}
+
+#[test]
+fn keytab_entry_key() {
+  let context = Krb5Context::init().unwrap();
+  let components = [String::from("host"), String::from("example.com")];
+  let principal = context.build_principal("EXAMPLE.COM", &components).unwrap();
+
+  let mut keytab = Krb5Keytab::memory(&context, "keytab_entry_key").unwrap();
+  keytab
+    .add_key(&principal, 1, Krb5Enctype::Aes128CtsHmacSha1_96, &[0x5a; 16])
+    .unwrap();
+
+  let entry = keytab.entries().unwrap().next().unwrap().unwrap();
+  let key = entry.key();
+  assert_eq!(key.as_bytes(), &[0x5a; 16]);
+  assert_eq!(key.enctype(), Krb5Enctype::Aes128CtsHmacSha1_96);
+
+  let debug = format!("{:?}", key);
+  assert!(debug.contains("16 bytes redacted"));
+  assert!(!debug.contains("90"));
+}
+
+#[test]
+#[cfg(krb5_mit)]
+fn keytab_default_diagnostics() {
+  // secure contexts ignore KRB5_KTNAME, so the result does not depend on the environment
+  let context = Krb5Context::init_secure().unwrap();
+  let relation = ["libdefaults", "default_keytab_name"];
+
+  let profile_context = context
+    .with_profile_values(&relation, &["FILE:/nonexistent/profile.keytab"])
+    .unwrap();
+  let diagnostics = Krb5Keytab::default_diagnostics(&profile_context).unwrap();
+  assert_eq!(diagnostics.source, Krb5KeytabDefaultSource::Profile);
+  assert_eq!(
+    diagnostics.profile_name.as_deref(),
+    Some("FILE:/nonexistent/profile.keytab")
+  );
+  assert_eq!(diagnostics.name, "FILE:/nonexistent/profile.keytab");
+  assert_eq!(
+    diagnostics.path,
+    Some(std::path::PathBuf::from("/nonexistent/profile.keytab"))
+  );
+  assert!(!diagnostics.exists);
+  assert!(!diagnostics.readable);
+  assert_eq!(diagnostics.env_name, None);
+
+  // a directory can be opened, but is not a key table file
+  let directory = std::env::temp_dir();
+  let directory_context = context
+    .with_profile_values(&relation, &[format!("FILE:{}", directory.display())])
+    .unwrap();
+  let diagnostics = Krb5Keytab::default_diagnostics(&directory_context).unwrap();
+  assert_eq!(diagnostics.path, Some(directory));
+  assert!(diagnostics.exists);
+  assert!(!diagnostics.readable);
+
+  let compiled_context = context.with_profile_values(&relation, &[] as &[&str]).unwrap();
+  let diagnostics = Krb5Keytab::default_diagnostics(&compiled_context).unwrap();
+  assert_eq!(diagnostics.source, Krb5KeytabDefaultSource::CompiledDefault);
+  assert_eq!(diagnostics.profile_name, None);
+  assert_eq!(diagnostics.name, Krb5Keytab::default_name(&compiled_context).unwrap());
+  assert_ne!(diagnostics.name, "FILE:/nonexistent/profile.keytab");
+}
+
+#[test]
+fn auth_context_init() {
+  let context = Krb5Context::init().unwrap();
+  let mut auth_context = Krb5AuthContext::init(&context).unwrap();
+
+  auth_context
+    .set_req_cksumtype(Krb5ChecksumType::HmacSha1_96Aes256)
+    .unwrap();
+}
+
+#[test]
+fn auth_context_flags() {
+  let context = Krb5Context::init().unwrap();
+  let mut auth_context = Krb5AuthContext::init(&context).unwrap();
+
+  // a new authentication context uses timestamps
+  assert_eq!(auth_context.get_flags().unwrap(), Krb5AuthContextFlags::DO_TIME);
+
+  let flags = Krb5AuthContextFlags::DO_SEQUENCE | Krb5AuthContextFlags::RET_SEQUENCE;
+  auth_context.set_flags(flags).unwrap();
+  assert_eq!(auth_context.get_flags().unwrap(), flags);
+}
+
+#[test]
+fn auth_context_mk_req_without_credentials() {
+  let context = Krb5Context::init().unwrap();
+  let ccache = Krb5CCache::new_unique(&context, "MEMORY").unwrap();
+  let mut auth_context = Krb5AuthContext::init(&context).unwrap();
+
+  // the credential cache is not initialized, so there are no credentials to authenticate with
+  assert!(auth_context
+    .mk_req("host", "localhost", &ccache, Krb5ApOptions::empty())
+    .is_err());
+}
+
+#[test]
+fn auth_context_rd_req_invalid() {
+  let context = Krb5Context::init().unwrap();
+  let keytab = Krb5Keytab::memory(&context, "auth_context_rd_req_invalid").unwrap();
+  let mut auth_context = Krb5AuthContext::init(&context).unwrap();
+
+  assert!(auth_context.rd_req(b"not an AP-REQ", None, Some(&keytab)).is_err());
+}
+
+#[test]
+fn auth_context_mutual_authentication_without_exchange() {
+  let context = Krb5Context::init().unwrap();
+  let mut auth_context = Krb5AuthContext::init(&context).unwrap();
+
+  // no AP request has been processed, so there is no key to create or verify a reply with
+  assert!(auth_context.mk_rep().is_err());
+  assert!(auth_context.rd_rep(b"not an AP-REP").is_err());
+  assert!(auth_context.get_authenticator().is_err());
+}
+
+#[test]
+fn auth_context_priv_without_key() {
+  let context = Krb5Context::init().unwrap();
+  let mut auth_context = Krb5AuthContext::init(&context).unwrap();
+
+  assert!(auth_context.mk_priv(b"secret").is_err());
+  assert!(auth_context.rd_priv(b"not a KRB-PRIV").is_err());
+}
+
+#[test]
+fn auth_context_safe_without_key() {
+  let context = Krb5Context::init().unwrap();
+  let mut auth_context = Krb5AuthContext::init(&context).unwrap();
+
+  assert!(auth_context.mk_safe(b"payload").is_err());
+  assert!(auth_context.rd_safe(b"not a KRB-SAFE").is_err());
+}
+
+#[test]
+fn error_message_roundtrip() {
+  let context = Krb5Context::init().unwrap();
+  let components = [String::from("host"), String::from("example.com")];
+  let server = context.build_principal("EXAMPLE.COM", &components).unwrap();
+
+  let error = Krb5ErrorMessage {
+    ctime: 0,
+    cusec: 0,
+    stime: 1_600_000_000,
+    susec: 42,
+    error: (libkrb5_sys::KRB5KRB_AP_ERR_SKEW - libkrb5_sys::KRB5KDC_ERR_NONE) as u32,
+    client: None,
+    server,
+    text: Some(String::from("clock skew too great")),
+    e_data: Vec::new(),
+  };
+
+  let message = error.mk_error(&context).unwrap();
+  let decoded = Krb5ErrorMessage::rd_error(&context, &message).unwrap();
+
+  assert_eq!(decoded.stime, 1_600_000_000);
+  assert_eq!(decoded.susec, 42);
+  assert_eq!(decoded.library_code(), libkrb5_sys::KRB5KRB_AP_ERR_SKEW);
+  assert!(decoded.client.is_none());
+  assert_eq!(decoded.server.unparse_name().unwrap(), "host/example.com@EXAMPLE.COM");
+  assert_eq!(decoded.text.as_deref(), Some("clock skew too great"));
+
+  assert!(Krb5ErrorMessage::rd_error(&context, b"not a KRB-ERROR").is_err());
+}
+
+#[test]
+fn auth_context_addrs() {
+  use std::net::{SocketAddr, TcpListener, TcpStream};
+  use std::os::unix::io::AsRawFd;
+
+  let context = Krb5Context::init().unwrap();
+  let mut auth_context = Krb5AuthContext::init(&context).unwrap();
+
+  let local: SocketAddr = "192.0.2.1:4444".parse().unwrap();
+  let remote: SocketAddr = "[2001:db8::1]:88".parse().unwrap();
+  auth_context.set_addrs(Some(&local), Some(&remote)).unwrap();
+  auth_context.set_addrs(None, None).unwrap();
+
+  let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+  let stream = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+  auth_context
+    .genaddrs(
+      stream.as_raw_fd(),
+      Krb5GenAddrsFlags::LOCAL_FULL_ADDR | Krb5GenAddrsFlags::REMOTE_FULL_ADDR,
+    )
+    .unwrap();
+}
+
+#[test]
+fn auth_context_rcache() {
+  let context = Krb5Context::init().unwrap();
+  let mut auth_context = Krb5AuthContext::init(&context).unwrap();
+
+  assert!(!auth_context.has_rcache().unwrap());
+  assert!(auth_context.rcache().is_none());
+  auth_context.use_server_rcache().unwrap();
+  assert!(auth_context.has_rcache().unwrap());
+  assert!(auth_context.rcache().is_some());
+
+  // a second call keeps the attached replay cache
+  auth_context.use_server_rcache().unwrap();
+  assert!(auth_context.has_rcache().unwrap());
+  assert!(auth_context.rcache().is_some());
+}
+
+#[test]
+fn rcache_server_default() {
+  let context = Krb5Context::init().unwrap();
+
+  // dropping an unattached replay cache closes it
+  drop(Krb5RCache::server_default(&context).unwrap());
+
+  let mut auth_context = Krb5AuthContext::init(&context).unwrap();
+  auth_context
+    .set_rcache(Krb5RCache::server_default(&context).unwrap())
+    .unwrap();
+  auth_context
+    .set_rcache(Krb5RCache::server_default(&context).unwrap())
+    .unwrap();
+  assert!(auth_context.has_rcache().unwrap());
+}
+
+#[test]
+#[cfg(all(krb5_mit, krb5_1_18, not(feature = "dlopen")))]
+fn rcache_resolve_store() {
+  let context = Krb5Context::init().unwrap();
+  let path = std::env::temp_dir().join(format!("libkrb5-rs-test-{}.rcache", std::process::id()));
+  let name = format!("file2:{}", path.display());
+
+  let mut rcache = Krb5RCache::resolve(&context, &name).unwrap();
+  assert_eq!(rcache.get_name().unwrap(), name);
+
+  let enctype = Krb5Enctype::Aes256CtsHmacSha1_96;
+  rcache.store(enctype, &[0x11; 64]).unwrap();
+  rcache.store(enctype, &[0x22; 64]).unwrap();
+  match rcache.store(enctype, &[0x11; 64]) {
+    Err(Krb5Error::LibraryError { code, .. }) => assert_eq!(code, libkrb5_sys::KRB5KRB_AP_ERR_REPEAT),
+    other => panic!("replay was not detected: {:?}", other),
+  }
+
+  // the entries are shared through the file
+  let mut reopened = Krb5RCache::resolve(&context, &name).unwrap();
+  assert!(reopened.store(enctype, &[0x22; 64]).is_err());
+
+  let mut auth_context = Krb5AuthContext::init(&context).unwrap();
+  auth_context.set_rcache(reopened).unwrap();
+  assert!(auth_context.has_rcache().unwrap());
+  assert_eq!(auth_context.rcache().unwrap().get_name().unwrap(), name);
+
+  assert!(Krb5RCache::resolve(&context, "no-such-type:").is_err());
+  assert!(Krb5RCache::resolve(&context, "missing-separator").is_err());
+
+  drop(rcache);
+  std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn auth_context_keys() {
+  let context = Krb5Context::init().unwrap();
+  let enctype = Krb5Enctype::Aes256CtsHmacSha1_96;
+  let session_key = Krb5Keyblock::new(&context, enctype, &[0x11; 32]).unwrap();
+  let subkey = Krb5Keyblock::new(&context, enctype, &[0x22; 32]).unwrap();
+
+  let mut auth_context = Krb5AuthContext::init(&context).unwrap();
+  assert!(auth_context.get_key().unwrap().is_none());
+  assert!(auth_context.get_send_subkey().unwrap().is_none());
+  assert!(auth_context.get_recv_subkey().unwrap().is_none());
+
+  auth_context.set_user_user_key(&session_key).unwrap();
+  auth_context.set_send_subkey(Some(&subkey)).unwrap();
+  auth_context.set_recv_subkey(Some(&subkey)).unwrap();
+
+  let key = auth_context.get_key().unwrap().unwrap();
+  assert_eq!(key.enctype(), enctype);
+  assert_eq!(key.contents(), &[0x11; 32]);
+  assert_eq!(auth_context.get_send_subkey().unwrap().unwrap().contents(), &[0x22; 32]);
+  assert_eq!(auth_context.get_recv_subkey().unwrap().unwrap().contents(), &[0x22; 32]);
+
+  auth_context.set_send_subkey(None).unwrap();
+  assert!(auth_context.get_send_subkey().unwrap().is_none());
+}
+
+#[test]
+fn auth_context_priv_safe_roundtrip() {
+  use std::net::SocketAddr;
+
+  let context = Krb5Context::init().unwrap();
+  let key = Krb5Keyblock::new(&context, Krb5Enctype::Aes256CtsHmacSha1_96, &[0x33; 32]).unwrap();
+  let client_addr: SocketAddr = "192.0.2.1:40000".parse().unwrap();
+  let server_addr: SocketAddr = "192.0.2.2:4444".parse().unwrap();
+
+  let mut sender = Krb5AuthContext::init(&context).unwrap();
+  sender.set_user_user_key(&key).unwrap();
+  sender.set_addrs(Some(&client_addr), Some(&server_addr)).unwrap();
+  sender.set_flags(Krb5AuthContextFlags::DO_SEQUENCE).unwrap();
+
+  let mut receiver = Krb5AuthContext::init(&context).unwrap();
+  receiver.set_user_user_key(&key).unwrap();
+  receiver.set_addrs(Some(&server_addr), Some(&client_addr)).unwrap();
+  receiver.set_flags(Krb5AuthContextFlags::DO_SEQUENCE).unwrap();
+
+  assert_eq!(sender.get_local_seq_number().unwrap(), 0);
+  assert_eq!(receiver.get_remote_seq_number().unwrap(), 0);
+
+  let message = sender.mk_priv(b"confidential").unwrap();
+  assert!(!message.windows(12).any(|window| window == b"confidential"));
+  assert_eq!(receiver.rd_priv(&message).unwrap(), b"confidential");
+
+  assert_eq!(sender.get_local_seq_number().unwrap(), 1);
+  assert_eq!(receiver.get_remote_seq_number().unwrap(), 1);
+
+  // messages are rejected if they arrive out of order
+  let first = sender.mk_priv(b"first").unwrap();
+  let second = sender.mk_priv(b"second").unwrap();
+  assert!(receiver.rd_priv(&second).is_err());
+  assert_eq!(receiver.rd_priv(&first).unwrap(), b"first");
+  assert_eq!(receiver.rd_priv(&second).unwrap(), b"second");
+
+  let message = sender.mk_safe(b"tamper evident").unwrap();
+  assert_eq!(receiver.rd_safe(&message).unwrap(), b"tamper evident");
+
+  let mut tampered = message.to_vec();
+  let last = tampered.len() - 1;
+  tampered[last] ^= 0xff;
+  assert!(receiver.rd_safe(&tampered).is_err());
+}
+
+#[test]
+fn auth_context_sendauth_recvauth_failures() {
+  use std::io::Write;
+  use std::os::unix::net::UnixStream;
+
+  let context = Krb5Context::init().unwrap();
+  let components = [String::from("host"), String::from("example.com")];
+  let server = context.build_principal("EXAMPLE.COM", &components).unwrap();
+  let ccache = Krb5CCache::new_unique(&context, "MEMORY").unwrap();
+
+  // the peer is gone before the handshake starts
+  let (client_socket, peer) = UnixStream::pair().unwrap();
+  drop(peer);
+  let mut auth_context = Krb5AuthContext::init(&context).unwrap();
+  assert!(auth_context
+    .sendauth(
+      &client_socket,
+      "test_v1",
+      None,
+      &server,
+      Krb5ApOptions::MUTUAL_REQUIRED,
+      &ccache
+    )
+    .is_err());
+
+  // the peer does not speak the sendauth protocol
+  let (server_socket, mut peer) = UnixStream::pair().unwrap();
+  peer.write_all(b"\x00\x00\x00\x05hello").unwrap();
+  drop(peer);
+  let keytab = Krb5Keytab::memory(&context, "auth_context_sendauth_recvauth_failures").unwrap();
+  let mut auth_context = Krb5AuthContext::init(&context).unwrap();
+  assert!(auth_context
+    .recvauth(&server_socket, "test_v1", None, Some(&keytab))
+    .is_err());
+}
+
+#[test]
+fn auth_context_sendauth_recvauth_stream() {
+  use std::net::{TcpListener, TcpStream};
+
+  const KEYTAB_NAME: &str = "auth_context_sendauth_recvauth_stream";
+
+  let context = Krb5Context::init().unwrap();
+  let enctype = Krb5Enctype::Aes256CtsHmacSha1_96;
+  let service_key = Krb5Keyblock::new(&context, enctype, &[0x12; 32]).unwrap();
+  let other_key = Krb5Keyblock::new(&context, enctype, &[0x56; 32]).unwrap();
+  let session_key = Krb5Keyblock::new(&context, enctype, &[0x34; 32]).unwrap();
+
+  let components = [String::from("host"), String::from("example.com")];
+  let service = context.build_principal("EXAMPLE.COM", &components).unwrap();
+  let mut keytab = Krb5Keytab::memory(&context, KEYTAB_NAME).unwrap();
+  keytab.add_key(&service, 1, enctype, service_key.contents()).unwrap();
+
+  let client_components = [String::from("user")];
+  let client = context.build_principal("EXAMPLE.COM", &client_components).unwrap();
+  let mut ccache = Krb5CCache::new_unique(&context, "MEMORY").unwrap();
+  ccache.initialize(&client).unwrap();
+  let ticket = issue_ticket(&service_key, &session_key, None);
+  ccache.store_cred(&fake_creds(&context, &ticket, &session_key)).unwrap();
+
+  // a ticket the server can not decrypt
+  let mut bad_ccache = Krb5CCache::new_unique(&context, "MEMORY").unwrap();
+  bad_ccache.initialize(&client).unwrap();
+  let bad_ticket = issue_ticket(&other_key, &session_key, None);
+  bad_ccache
+    .store_cred(&fake_creds(&context, &bad_ticket, &session_key))
+    .unwrap();
+
+  // the server runs on its own thread with its own context, and finds the key table by name
+  let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+  let address = listener.local_addr().unwrap();
+  let server = std::thread::spawn(move || {
+    let context = Krb5Context::init().unwrap();
+    let components = [String::from("host"), String::from("example.com")];
+    let service = context.build_principal("EXAMPLE.COM", &components).unwrap();
+    let keytab = Krb5Keytab::memory(&context, KEYTAB_NAME).unwrap();
+
+    let mut clients = Vec::new();
+    for native in [true, false, false, false] {
+      let (mut stream, _) = listener.accept().unwrap();
+      let mut auth_context = Krb5AuthContext::init(&context).unwrap();
+      let ticket = if native {
+        auth_context.recvauth(&stream, "test_v1", Some(&service), Some(&keytab))
+      } else {
+        auth_context.recvauth_stream(&mut stream, "test_v1", Some(&service), Some(&keytab))
+      };
+      clients.push(ticket.map(|ticket| ticket.client().unwrap().unwrap().unparse_name().unwrap()));
+    }
+    clients
+      .into_iter()
+      .map(|client| client.map_err(|error| error.to_string()))
+      .collect::<Vec<_>>()
+  });
+
+  // against krb5_recvauth
+  let mut stream = TcpStream::connect(address).unwrap();
+  let mut auth_context = Krb5AuthContext::init(&context).unwrap();
+  auth_context
+    .sendauth_stream(
+      &mut stream,
+      "test_v1",
+      None,
+      &service,
+      Krb5ApOptions::MUTUAL_REQUIRED,
+      &ccache,
+    )
+    .unwrap();
+
+  // krb5_sendauth against the stream implementation
+  let stream = TcpStream::connect(address).unwrap();
+  let mut auth_context = Krb5AuthContext::init(&context).unwrap();
+  auth_context
+    .sendauth(
+      &stream,
+      "test_v1",
+      Some(&client),
+      &service,
+      Krb5ApOptions::MUTUAL_REQUIRED,
+      &ccache,
+    )
+    .unwrap();
+
+  // the application versions differ
+  let mut stream = TcpStream::connect(address).unwrap();
+  let mut auth_context = Krb5AuthContext::init(&context).unwrap();
+  match auth_context.sendauth_stream(&mut stream, "test_v2", None, &service, Krb5ApOptions::empty(), &ccache) {
+    Err(Krb5Error::LibraryError { code, .. }) => assert_eq!(code, libkrb5_sys::KRB5_SENDAUTH_BADAPPLVERS),
+    other => panic!("unexpected result: {:?}", other),
+  }
+
+  // the server rejects the request with a KRB-ERROR
+  let mut stream = TcpStream::connect(address).unwrap();
+  let mut auth_context = Krb5AuthContext::init(&context).unwrap();
+  match auth_context.sendauth_stream(
+    &mut stream,
+    "test_v1",
+    None,
+    &service,
+    Krb5ApOptions::MUTUAL_REQUIRED,
+    &bad_ccache,
+  ) {
+    Err(Krb5Error::LibraryError { code, .. }) => assert_eq!(code, libkrb5_sys::KRB5KRB_AP_ERR_BAD_INTEGRITY),
+    other => panic!("unexpected result: {:?}", other),
+  }
+
+  let clients = server.join().unwrap();
+  assert_eq!(clients[0].as_deref(), Ok("user@EXAMPLE.COM"));
+  assert_eq!(clients[1].as_deref(), Ok("user@EXAMPLE.COM"));
+  assert!(clients[2].is_err());
+  assert!(clients[3].is_err());
+}
+
+#[test]
+fn handshake_session_roundtrip() {
+  use std::net::{TcpListener, TcpStream};
+
+  let context = Krb5Context::init().unwrap();
+  let key = Krb5Keyblock::new(&context, Krb5Enctype::Aes256CtsHmacSha1_96, &[0x44; 32]).unwrap();
+
+  let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+  let client_stream = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+  let (server_stream, _) = listener.accept().unwrap();
+
+  // emulate a completed handshake by giving both sides the same session key
+  let mut sessions = Vec::new();
+  for stream in [client_stream, server_stream] {
+    let mut auth_context = Krb5AuthContext::init(&context).unwrap();
+    auth_context.set_flags(Krb5AuthContextFlags::DO_SEQUENCE).unwrap();
+    auth_context
+      .set_addrs(Some(&stream.local_addr().unwrap()), Some(&stream.peer_addr().unwrap()))
+      .unwrap();
+    auth_context.set_user_user_key(&key).unwrap();
+    sessions.push(handshake::KerberosSession {
+      auth_context,
+      stream,
+      peer: None,
+    });
+  }
+  let mut server = sessions.pop().unwrap();
+  let mut client = sessions.pop().unwrap();
+
+  client.send(b"ping").unwrap();
+  assert_eq!(server.receive().unwrap(), b"ping");
+  server.send(b"pong").unwrap();
+  assert_eq!(client.receive().unwrap(), b"pong");
+  assert!(client.peer().is_none());
+}
+
+#[test]
+fn handshake_failures() {
+  use std::io::Write;
+  use std::net::{TcpListener, TcpStream};
+
+  let context = Krb5Context::init().unwrap();
+  let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+
+  // the client has no credentials
+  let ccache = Krb5CCache::new_unique(&context, "MEMORY").unwrap();
+  let client = KerberosClient::new(&context, &ccache, "host", "localhost");
+  let stream = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+  assert!(client.connect(stream).is_err());
+  drop(listener.accept().unwrap());
+
+  // the client does not send an AP request
+  let keytab = Krb5Keytab::memory(&context, "handshake_failures").unwrap();
+  let server = KerberosServer::new(&context, Some(&keytab), None);
+  let mut stream = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+  stream.write_all(b"\x00\x00\x00\x05hello").unwrap();
+  let (server_stream, _) = listener.accept().unwrap();
+  assert!(server.accept(server_stream).is_err());
+
+  // oversized frames are rejected
+  let mut stream = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+  stream.write_all(b"\xff\xff\xff\xff").unwrap();
+  let (server_stream, _) = listener.accept().unwrap();
+  assert!(server.accept(server_stream).is_err());
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn handshake_async() {
+  use std::net::SocketAddr;
+  use tokio::io::AsyncWriteExt;
+
+  let context = Krb5Context::init().unwrap();
+  let key = Krb5Keyblock::new(&context, Krb5Enctype::Aes256CtsHmacSha1_96, &[0x44; 32]).unwrap();
+  let client_addr: SocketAddr = "192.0.2.1:40000".parse().unwrap();
+  let server_addr: SocketAddr = "192.0.2.2:4444".parse().unwrap();
+  let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+
+  // emulate a completed handshake by giving both sides the same session key
+  let (client_stream, server_stream) = tokio::io::duplex(1024);
+  let mut sessions = Vec::new();
+  for (stream, local, peer) in [
+    (client_stream, client_addr, server_addr),
+    (server_stream, server_addr, client_addr),
+  ] {
+    let mut auth_context = Krb5AuthContext::init(&context).unwrap();
+    auth_context.set_flags(Krb5AuthContextFlags::DO_SEQUENCE).unwrap();
+    auth_context.set_addrs(Some(&local), Some(&peer)).unwrap();
+    auth_context.set_user_user_key(&key).unwrap();
+    sessions.push(handshake::KerberosSession {
+      auth_context,
+      stream,
+      peer: None,
+    });
+  }
+  let mut server = sessions.pop().unwrap();
+  let mut client = sessions.pop().unwrap();
+
+  runtime.block_on(async {
+    client.send_async(b"ping").await.unwrap();
+    assert_eq!(server.receive_async().await.unwrap(), b"ping");
+    server.send_async(b"pong").await.unwrap();
+    assert_eq!(client.receive_async().await.unwrap(), b"pong");
+  });
+
+  // the client has no credentials
+  let ccache = Krb5CCache::new_unique(&context, "MEMORY").unwrap();
+  let client = KerberosClient::new(&context, &ccache, "host", "localhost");
+  let (stream, _peer) = tokio::io::duplex(1024);
+  assert!(runtime
+    .block_on(client.connect_async(stream, client_addr, server_addr))
+    .is_err());
+
+  // the client does not send an AP request
+  let keytab = Krb5Keytab::memory(&context, "handshake_async").unwrap();
+  let server = KerberosServer::new(&context, Some(&keytab), None);
+  let (stream, mut peer) = tokio::io::duplex(1024);
+  runtime.block_on(peer.write_all(b"\x00\x00\x00\x05hello")).unwrap();
+  assert!(runtime
+    .block_on(server.accept_async(stream, server_addr, client_addr))
+    .is_err());
+
+  // oversized frames are rejected
+  let (stream, mut peer) = tokio::io::duplex(1024);
+  runtime.block_on(peer.write_all(b"\xff\xff\xff\xff")).unwrap();
+  assert!(runtime
+    .block_on(server.accept_async(stream, server_addr, client_addr))
+    .is_err());
+}
+
+#[test]
+fn auth_context_mk_req_extended_without_ticket() {
+  let context = Krb5Context::init().unwrap();
+  let creds = Krb5Creds {
+    context: &context,
+    creds: unsafe { std::mem::zeroed() },
+  };
+  let mut auth_context = Krb5AuthContext::init(&context).unwrap();
+
+  assert!(auth_context
+    .mk_req_extended(&creds, Krb5ApOptions::empty(), Some(b"channel bindings"))
+    .is_err());
+}
+
+/**
+ * DER encode a value with the given tag.
+ */
+fn der(tag: u8, content: &[u8]) -> Vec<u8> {
+  let mut encoded = vec![tag];
+  let length = content.len();
+  if length < 0x80 {
+    encoded.push(length as u8);
+  } else {
+    let bytes: Vec<u8> = length
+      .to_be_bytes()
+      .iter()
+      .copied()
+      .skip_while(|byte| *byte == 0)
+      .collect();
+    encoded.push(0x80 | bytes.len() as u8);
+    encoded.extend(bytes);
+  }
+  encoded.extend_from_slice(content);
+  encoded
+}
+
+/**
+ * DER encode a PrincipalName.
+ */
+fn der_principal_name(name_type: u8, components: &[&str]) -> Vec<u8> {
+  let name_string: Vec<u8> = components
+    .iter()
+    .flat_map(|component| der(0x1b, component.as_bytes()))
+    .collect();
+
+  der(
+    0x30,
+    &[der(0xa0, &der(0x02, &[name_type])), der(0xa1, &der(0x30, &name_string))].concat(),
+  )
+}
+
+/**
+ * DER encode a KerberosTime (seconds since the epoch) as GeneralizedTime.
+ */
+fn der_time(time: i64) -> Vec<u8> {
+  // civil date from days since the epoch, see http://howardhinnant.github.io/date_algorithms.html
+  let days = time.div_euclid(86400);
+  let seconds = time.rem_euclid(86400);
+  let z = days + 719_468;
+  let era = z.div_euclid(146_097);
+  let doe = z - era * 146_097;
+  let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+  let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+  let mp = (5 * doy + 2) / 153;
+  let day = doy - (153 * mp + 2) / 5 + 1;
+  let month = if mp < 10 { mp + 3 } else { mp - 9 };
+  let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+
+  let string = format!(
+    "{:04}{:02}{:02}{:02}{:02}{:02}Z",
+    year,
+    month,
+    day,
+    seconds / 3600,
+    seconds / 60 % 60,
+    seconds % 60
+  );
+  der(0x18, string.as_bytes())
+}
+
+/**
+ * DER encode a Kerberos Ticket for a principal, with the given (opaque) encrypted part.
+ */
+fn der_ticket(realm: &str, components: &[&str], etype: u8, kvno: Option<u8>, cipher: &[u8]) -> Vec<u8> {
+  let mut enc_part = der(0xa0, &der(0x02, &[etype]));
+  if let Some(kvno) = kvno {
+    enc_part.extend(der(0xa1, &der(0x02, &[kvno])));
+  }
+  enc_part.extend(der(0xa2, &der(0x04, cipher)));
+
+  let ticket = [
+    der(0xa0, &der(0x02, &[5])),
+    der(0xa1, &der(0x1b, realm.as_bytes())),
+    der(0xa2, &der_principal_name(2, components)),
+    der(0xa3, &der(0x30, &enc_part)),
+  ]
+  .concat();
+
+  der(0x61, &der(0x30, &ticket))
+}
+
+/**
+ * DER encode authorization data, a sequence of (ad-type, contents) elements.
+ */
+fn der_authdata(elements: &[(i32, &[u8])]) -> Vec<u8> {
+  let mut encoded = Vec::new();
+
+  for (ad_type, contents) in elements {
+    let bytes = ad_type.to_be_bytes();
+    let skip = (0..3)
+      .take_while(|i| (bytes[*i] == 0 && bytes[i + 1] < 0x80) || (bytes[*i] == 0xff && bytes[i + 1] >= 0x80))
+      .count();
+    let element = [der(0xa0, &der(0x02, &bytes[skip..])), der(0xa1, &der(0x04, contents))].concat();
+    encoded.extend(der(0x30, &element));
+  }
+
+  der(0x30, &encoded)
+}
+
+/**
+ * Issue a ticket for `user@EXAMPLE.COM` to `host/example.com@EXAMPLE.COM` (kvno 1) like a KDC
+ * would, valid for the next hour, with the given DER encoded authorization data.
+ */
+fn issue_ticket(service_key: &Krb5Keyblock, session_key: &Krb5Keyblock, authorization_data: Option<&[u8]>) -> Vec<u8> {
+  let now = std::time::SystemTime::now()
+    .duration_since(std::time::UNIX_EPOCH)
+    .unwrap()
+    .as_secs() as i64;
+
+  issue_ticket_at(service_key, session_key, now - 60, authorization_data)
+}
+
+/**
+ * Like [issue_ticket], with the given authentication time (which has to be within the clock
+ * skew of now).
+ */
+fn issue_ticket_at(
+  service_key: &Krb5Keyblock,
+  session_key: &Krb5Keyblock,
+  authtime: i64,
+  authorization_data: Option<&[u8]>,
+) -> Vec<u8> {
+  let now = std::time::SystemTime::now()
+    .duration_since(std::time::UNIX_EPOCH)
+    .unwrap()
+    .as_secs() as i64;
+
+  let key = der(
+    0x30,
+    &[
+      der(0xa0, &der(0x02, &[session_key.enctype().as_raw() as u8])),
+      der(0xa1, &der(0x04, session_key.contents())),
+    ]
+    .concat(),
+  );
+  let transited = der(
+    0x30,
+    &[der(0xa0, &der(0x02, &[1])), der(0xa1, &der(0x04, &[]))].concat(),
+  );
+  let mut fields = [
+    der(0xa0, &der(0x03, &[0, 0, 0, 0, 0])),
+    der(0xa1, &key),
+    der(0xa2, &der(0x1b, b"EXAMPLE.COM")),
+    der(0xa3, &der_principal_name(1, &["user"])),
+    der(0xa4, &transited),
+    der(0xa5, &der_time(authtime)),
+    der(0xa7, &der_time(now + 3600)),
+  ]
+  .concat();
+  if let Some(authorization_data) = authorization_data {
+    fields.extend(der(0xaa, authorization_data));
+  }
+  let enc_ticket_part = der(0x63, &der(0x30, &fields));
+
+  let cipher = service_key
+    .encrypt(Krb5KeyUsage::KdcRepTicket, None, &enc_ticket_part)
+    .unwrap();
+
+  der_ticket(
+    "EXAMPLE.COM",
+    &["host", "example.com"],
+    service_key.enctype().as_raw() as u8,
+    Some(1),
+    &cipher,
+  )
+}
+
+/**
+ * Build credentials of `user@EXAMPLE.COM` for `host/example.com@EXAMPLE.COM` without contacting
+ * a KDC.
+ */
+fn fake_creds<'a>(context: &'a Krb5Context, ticket: &[u8], session_key: &Krb5Keyblock) -> Krb5Creds<'a> {
+  let client_components = [String::from("user")];
+  let client = context.build_principal("EXAMPLE.COM", &client_components).unwrap();
+  let server_components = [String::from("host"), String::from("example.com")];
+  let server = context.build_principal("EXAMPLE.COM", &server_components).unwrap();
+
+  let mut creds: libkrb5_sys::krb5_creds = unsafe { std::mem::zeroed() };
+  unsafe {
+    // the credentials take over the principals
+    creds.client = client.principal;
+    creds.server = server.principal;
+    std::mem::forget(client);
+    std::mem::forget(server);
+
+    libkrb5_sys::krb5_copy_keyblock_contents(context.context, session_key.keyblock, &mut creds.keyblock);
+
+    let mut ticket_data: *mut libkrb5_sys::krb5_data = std::ptr::null_mut();
+    libkrb5_sys::krb5_copy_data(context.context, &data::slice_to_krb5_data(ticket), &mut ticket_data);
+    creds.ticket = *ticket_data;
+    (*ticket_data).data = std::ptr::null_mut();
+    libkrb5_sys::krb5_free_data(context.context, ticket_data);
+  }
+  creds.times.authtime = 1_600_000_000;
+  creds.times.starttime = 1_600_000_000;
+  creds.times.endtime = i32::MAX;
+
+  Krb5Creds { context, creds }
+}
+
+#[test]
+fn auth_context_krb_cred_roundtrip() {
+  let context = Krb5Context::init().unwrap();
+  let enctype = Krb5Enctype::Aes256CtsHmacSha1_96;
+  let key = Krb5Keyblock::new(&context, enctype, &[0x55; 32]).unwrap();
+  let session_key = Krb5Keyblock::new(&context, enctype, &[0x66; 32]).unwrap();
+  let ticket = der_ticket("EXAMPLE.COM", &["host", "example.com"], 18, None, &[0u8; 64]);
+  let creds = fake_creds(&context, &ticket, &session_key);
+
+  let mut sender = Krb5AuthContext::init(&context).unwrap();
+  sender.set_user_user_key(&key).unwrap();
+  let message = sender.mk_1cred(&creds).unwrap();
+
+  let mut receiver = Krb5AuthContext::init(&context).unwrap();
+  receiver.set_user_user_key(&key).unwrap();
+  let received = receiver.rd_cred(&message).unwrap();
+  assert_eq!(received.len(), 1);
+  assert_eq!(
+    received[0].client().unwrap().unparse_name().unwrap(),
+    "user@EXAMPLE.COM"
+  );
+  assert_eq!(
+    received[0].server().unwrap().unparse_name().unwrap(),
+    "host/example.com@EXAMPLE.COM"
+  );
+  assert_eq!(received[0].end_time().as_raw(), i32::MAX);
+
+  // install the received credentials into a credential cache
+  let mut ccache = Krb5CCache::new_unique(&context, "MEMORY").unwrap();
+  ccache.initialize(&received[0].client().unwrap()).unwrap();
+  ccache.store_cred(&received[0]).unwrap();
+
+  // a different key cannot decrypt the message
+  let other_key = Krb5Keyblock::new(&context, enctype, &[0x77; 32]).unwrap();
+  let mut other = Krb5AuthContext::init(&context).unwrap();
+  other.set_user_user_key(&other_key).unwrap();
+  assert!(other.rd_cred(&message).is_err());
+}
+
+#[test]
+fn service_acceptor_ap_exchange() {
+  let context = Krb5Context::init().unwrap();
+  let enctype = Krb5Enctype::Aes256CtsHmacSha1_96;
+  let service_key = Krb5Keyblock::new(&context, enctype, &[0x12; 32]).unwrap();
+  let session_key = Krb5Keyblock::new(&context, enctype, &[0x34; 32]).unwrap();
+
+  let components = [String::from("host"), String::from("example.com")];
+  let service = context.build_principal("EXAMPLE.COM", &components).unwrap();
+  let mut keytab = Krb5Keytab::memory(&context, "service_acceptor_ap_exchange").unwrap();
+  keytab.add_key(&service, 1, enctype, service_key.contents()).unwrap();
+
+  let acceptor = ServiceAcceptor::new(
+    &context,
+    keytab,
+    Some(service),
+    Some(Krb5RCache::server_default(&context).unwrap()),
+  );
+
+  let ticket = issue_ticket(&service_key, &session_key, None);
+  let creds = fake_creds(&context, &ticket, &session_key);
+  let mut client = Krb5AuthContext::init(&context).unwrap();
+  let token = client
+    .mk_req_extended(&creds, Krb5ApOptions::MUTUAL_REQUIRED, None)
+    .unwrap();
+
+  let mut peer = acceptor.accept(&token).unwrap();
+  assert_eq!(peer.client.unparse_name().unwrap(), "user@EXAMPLE.COM");
+  assert_eq!(
+    peer.ticket.client().unwrap().unwrap().unparse_name().unwrap(),
+    "user@EXAMPLE.COM"
+  );
+  assert!(peer.options.contains(Krb5ApOptions::MUTUAL_REQUIRED));
+  assert!(peer.checksum.is_none());
+
+  let reply = peer.auth_context.mk_rep().unwrap();
+  client.rd_rep(&reply).unwrap();
+
+  // the same request is a replay
+  assert!(acceptor.accept(&token).is_err());
+  assert!(acceptor.accept(b"not an AP-REQ").is_err());
+}
+
+#[test]
+fn auth_context_authenticator_checksum() {
+  let context = Krb5Context::init().unwrap();
+  let enctype = Krb5Enctype::Aes256CtsHmacSha1_96;
+  let service_key = Krb5Keyblock::new(&context, enctype, &[0x12; 32]).unwrap();
+  let session_key = Krb5Keyblock::new(&context, enctype, &[0x34; 32]).unwrap();
+
+  let components = [String::from("host"), String::from("example.com")];
+  let service = context.build_principal("EXAMPLE.COM", &components).unwrap();
+  let mut keytab = Krb5Keytab::memory(&context, "auth_context_authenticator_checksum").unwrap();
+  keytab.add_key(&service, 1, enctype, service_key.contents()).unwrap();
+  let acceptor = ServiceAcceptor::new(&context, keytab, Some(service), None);
+
+  let ticket = issue_ticket(&service_key, &session_key, None);
+  let creds = fake_creds(&context, &ticket, &session_key);
+
+  // keyed checksum of the application data
+  let mut client = Krb5AuthContext::init(&context).unwrap();
+  let token = client
+    .mk_req_extended(&creds, Krb5ApOptions::empty(), Some(b"channel bindings"))
+    .unwrap();
+  let peer = acceptor.accept(&token).unwrap();
+  let checksum = peer.checksum.unwrap();
+  assert_ne!(checksum.contents, b"channel bindings");
+  assert!(peer
+    .auth_context
+    .verify_authenticator_checksum(&checksum, b"channel bindings")
+    .unwrap());
+  assert!(!peer
+    .auth_context
+    .verify_authenticator_checksum(&checksum, b"other bindings")
+    .unwrap());
+
+  // only the server side has the complete authenticator
+  assert!(client.get_authenticator().is_err());
+  let authenticator = peer.auth_context.get_authenticator().unwrap();
+  assert_eq!(authenticator.client.unparse_name().unwrap(), "user@EXAMPLE.COM");
+  assert_eq!(authenticator.checksum.unwrap().contents, checksum.contents);
+  assert!(authenticator.subkey.is_none());
+
+  // GSS-API checksums carry the data itself
+  let mut client = Krb5AuthContext::init(&context).unwrap();
+  client.set_req_cksumtype(Krb5ChecksumType::Unknown(0x8003)).unwrap();
+  let token = client
+    .mk_req_extended(&creds, Krb5ApOptions::empty(), Some(b"gss checksum"))
+    .unwrap();
+  let peer = acceptor.accept(&token).unwrap();
+  let checksum = peer.checksum.unwrap();
+  assert_eq!(checksum.checksum_type, Krb5ChecksumType::Unknown(0x8003));
+  assert_eq!(checksum.contents, b"gss checksum");
+  assert!(peer
+    .auth_context
+    .verify_authenticator_checksum(&checksum, b"gss checksum")
+    .unwrap());
+  assert!(!peer
+    .auth_context
+    .verify_authenticator_checksum(&checksum, b"other")
+    .unwrap());
+}
+
+#[test]
+fn keyblock_conversions() {
+  let context = Krb5Context::init().unwrap();
+  let enctype = Krb5Enctype::Aes128CtsHmacSha1_96;
+
+  let key = Krb5Keyblock::new(&context, enctype, &[0x5a; 16]).unwrap();
+  assert_eq!(key.enctype(), enctype);
+  assert_eq!(key.len(), 16);
+  assert!(!key.is_empty());
+  assert_eq!(key.as_ref(), &[0x5a; 16]);
+
+  let empty = Krb5Keyblock::new(&context, enctype, &[]).unwrap();
+  assert!(empty.is_empty());
+  assert_eq!(empty.contents(), &[] as &[u8]);
+
+  let components = [String::from("host"), String::from("example.com")];
+  let principal = context.build_principal("EXAMPLE.COM", &components).unwrap();
+  let mut keytab = Krb5Keytab::memory(&context, "keyblock_conversions").unwrap();
+  keytab.add_key(&principal, 1, enctype, key.contents()).unwrap();
+  let entry = keytab.entries().unwrap().next().unwrap().unwrap();
+  let from_keytab = Krb5Keyblock::from_keytab_key(&context, &entry.key()).unwrap();
+  assert_eq!(from_keytab.enctype(), enctype);
+  assert_eq!(from_keytab.contents(), key.contents());
+
+  let creds = fake_creds(&context, b"ticket", &key);
+  let from_creds = creds.keyblock().unwrap();
+  assert_eq!(from_creds.enctype(), enctype);
+  assert_eq!(from_creds.contents(), key.contents());
+}
+
+#[test]
+fn debug_redaction() {
+  let context = Krb5Context::init().unwrap();
+  let key = Krb5Keyblock::new(&context, Krb5Enctype::Aes128CtsHmacSha1_96, &[0x5a; 16]).unwrap();
+
+  let output = format!("{:?}", key);
+  assert!(output.contains("Aes128CtsHmacSha1_96"));
+  assert!(output.contains("<16 bytes redacted>"));
+
+  let components = [String::from("host"), String::from("example.com")];
+  let principal = context.build_principal("EXAMPLE.COM", &components).unwrap();
+  let mut keytab = Krb5Keytab::memory(&context, "debug_redaction").unwrap();
+  keytab.add_key(&principal, 1, key.enctype(), key.contents()).unwrap();
+  let entry = keytab.entries().unwrap().next().unwrap().unwrap();
+  let output = format!("{:?}", entry);
+  assert!(output.contains("host/example.com@EXAMPLE.COM"));
+  assert!(output.contains("<16 bytes redacted>"));
+
+  let creds = fake_creds(&context, b"secret ticket", &key);
+  let output = format!("{:?}", creds);
+  assert!(output.contains("user@EXAMPLE.COM"));
+  assert!(output.contains("<16 bytes redacted>"));
+  assert!(output.contains("<13 bytes redacted>"));
+  assert!(!output.contains("secret"));
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn zeroize_secrets() {
+  use zeroize::Zeroize;
+
+  let context = Krb5Context::init().unwrap();
+  let mut key = Krb5Keyblock::new(&context, Krb5Enctype::Aes128CtsHmacSha1_96, &[0x5a; 16]).unwrap();
+
+  let mut creds = fake_creds(&context, b"ticket", &key);
+  creds.zeroize();
+  assert_eq!(creds.keyblock().unwrap().contents(), &[0; 16]);
+
+  let components = [String::from("host"), String::from("example.com")];
+  let principal = context.build_principal("EXAMPLE.COM", &components).unwrap();
+  let mut keytab = Krb5Keytab::memory(&context, "zeroize_secrets").unwrap();
+  keytab.add_key(&principal, 1, key.enctype(), key.contents()).unwrap();
+  let mut keytab_key = keytab.entries().unwrap().next().unwrap().unwrap().key();
+  keytab_key.zeroize();
+  assert_eq!(keytab_key.as_bytes(), &[0; 16]);
+
+  let ticket = der_ticket("EXAMPLE.COM", &["host", "example.com"], 18, None, &[0u8; 64]);
+  let forwarded = fake_creds(&context, &ticket, &key);
+  let mut sender = Krb5AuthContext::init(&context).unwrap();
+  sender.set_user_user_key(&key).unwrap();
+  let mut message = sender.mk_1cred(&forwarded).unwrap();
+  assert!(message.iter().any(|byte| *byte != 0));
+  message.zeroize();
+  assert!(message.iter().all(|byte| *byte == 0));
+
+  let mut owned = Krb5Data::from(vec![0x5a; 8]);
+  owned.zeroize();
+  assert_eq!(owned, [0; 8]);
+
+  key.zeroize();
+  assert_eq!(key.contents(), &[0; 16]);
+  assert_eq!(key.enctype(), Krb5Enctype::Aes128CtsHmacSha1_96);
+}
+
+#[test]
+fn key_usages() {
+  assert_eq!(Krb5KeyUsage::from_raw(2), Krb5KeyUsage::KdcRepTicket);
+  assert_eq!(Krb5KeyUsage::ApReqAuthChecksum.as_raw(), 10);
+  assert_eq!(Krb5KeyUsage::from_raw(24), Krb5KeyUsage::GssTokenWrapPriv);
+  assert_eq!(Krb5KeyUsage::from_raw(1024), Krb5KeyUsage::Unknown(1024));
+  assert_eq!(Krb5KeyUsage::Unknown(1024).as_raw(), 1024);
+
+  let context = Krb5Context::init().unwrap();
+  let key = Krb5Keyblock::new(&context, Krb5Enctype::Aes128CtsHmacSha1_96, &[0x5a; 16]).unwrap();
+  let ciphertext = key.encrypt(Krb5KeyUsage::Unknown(1024), None, b"data").unwrap();
+  assert!(key.decrypt(Krb5KeyUsage::AppDataEncrypt, None, &ciphertext).is_err());
+  assert_eq!(
+    key.decrypt(Krb5KeyUsage::from(1024), None, &ciphertext).unwrap(),
+    b"data"
+  );
+}
+
+#[test]
+fn error_codes() {
+  assert_eq!(
+    Krb5ErrorCode::from_raw(libkrb5_sys::KRB5KDC_ERR_PREAUTH_FAILED),
+    Krb5ErrorCode::PreauthFailed
+  );
+  assert_eq!(Krb5ErrorCode::Skew.as_raw(), libkrb5_sys::KRB5KRB_AP_ERR_SKEW);
+  assert_eq!(Krb5ErrorCode::from_raw(22), Krb5ErrorCode::Unknown(22));
+  assert_eq!(Krb5ErrorCode::Unknown(22).as_raw(), 22);
+
+  let context = Krb5Context::init().unwrap();
+  let error = Krb5Keytab::resolve(&context, "BOGUS:keytab").unwrap_err();
+  assert_eq!(error.error_code(), Some(Krb5ErrorCode::KtUnknownType));
+  assert_eq!(error.raw_code(), Some(libkrb5_sys::KRB5_KT_UNKNOWN_TYPE));
+  assert!(error.is(Krb5ErrorCode::KtUnknownType));
+  assert!(error.is(libkrb5_sys::KRB5_KT_UNKNOWN_TYPE));
+  assert!(!error.is(Krb5ErrorCode::KtNotfound));
+
+  let auth_context = Krb5AuthContext::init(&context).unwrap();
+  let checksum = Krb5Checksum {
+    checksum_type: Krb5ChecksumType::HmacSha1_96Aes128,
+    contents: vec![0; 12],
+  };
+  let error = auth_context
+    .verify_authenticator_checksum(&checksum, b"data")
+    .unwrap_err();
+  assert_eq!(error.error_code(), Some(Krb5ErrorCode::Nokey));
+
+  assert!(Krb5Error::NullPointerDereference.error_code().is_none());
+  assert!(Krb5Error::NullPointerDereference.raw_code().is_none());
+}
+
+#[test]
+fn error_into_io_error() {
+  let context = Krb5Context::init().unwrap();
+  let keytab = Krb5Keytab::resolve(&context, "FILE:/nonexistent/keytab").unwrap();
+  let error: std::io::Error = keytab.entries().unwrap_err().into();
+  assert_eq!(error.kind(), std::io::ErrorKind::NotFound);
+
+  let error: std::io::Error = Krb5Error::LibraryError {
+    code: libkrb5_sys::KRB5_KDC_UNREACH,
+    message: String::from("Cannot contact any KDC"),
+  }
+  .into();
+  assert_eq!(error.kind(), std::io::ErrorKind::TimedOut);
+  let inner = error.into_inner().unwrap().downcast::<Krb5Error>().unwrap();
+  assert!(inner.is(Krb5ErrorCode::KdcUnreach));
+
+  let error: std::io::Error = Krb5Error::from(std::io::Error::from(std::io::ErrorKind::PermissionDenied)).into();
+  assert_eq!(error.kind(), std::io::ErrorKind::PermissionDenied);
+  assert!(error.into_inner().is_none());
+}
+
+#[test]
+fn error_classification() {
+  let library_error = |code| Krb5Error::LibraryError {
+    code,
+    message: String::new(),
+  };
+
+  let skew = library_error(libkrb5_sys::KRB5KRB_AP_ERR_SKEW);
+  assert!(skew.is_clock_skew());
+  assert!(!skew.is_retryable());
+
+  let expired = library_error(libkrb5_sys::KRB5KRB_AP_ERR_TKT_EXPIRED);
+  assert!(expired.is_expired_ticket());
+  assert!(!expired.is_clock_skew());
+  assert!(!expired.is_retryable());
+
+  let unreachable = library_error(libkrb5_sys::KRB5_KDC_UNREACH);
+  assert!(unreachable.is_kdc_unreachable());
+  assert!(unreachable.is_retryable());
+  assert!(library_error(libkrb5_sys::KRB5KDC_ERR_SVC_UNAVAILABLE).is_retryable());
+
+  // system error numbers are passed through by libkrb5 (ENOENT)
+  assert!(!library_error(2).is_retryable());
+
+  assert!(!library_error(libkrb5_sys::KRB5KDC_ERR_PREAUTH_FAILED).is_retryable());
+  assert!(Krb5Error::from(std::io::Error::from(std::io::ErrorKind::Interrupted)).is_retryable());
+  assert!(!Krb5Error::NullPointerDereference.is_retryable());
+}
+
+#[test]
+fn error_context() {
+  let context = Krb5Context::init().unwrap();
+
+  let error = Krb5Keytab::resolve(&context, "BOGUS:keytab").unwrap_err();
+  assert!(error.is(Krb5ErrorCode::KtUnknownType));
+  assert!(error.to_string().contains("while resolving keytab BOGUS:keytab: "));
+
+  let keytab = Krb5Keytab::resolve(&context, "FILE:/nonexistent/keytab").unwrap();
+  let error = keytab.entries().unwrap_err();
+  assert!(error
+    .to_string()
+    .contains("while reading keytab FILE:/nonexistent/keytab: "));
+
+  let ccache = Krb5CCache::resolve(&context, "FILE:/nonexistent/ccache").unwrap();
+  let error = ccache.get_principal().unwrap_err();
+  assert!(error.is(Krb5ErrorCode::FccNofile));
+  assert!(error
+    .to_string()
+    .contains("while reading ccache FILE:/nonexistent/ccache: "));
+
+  let error = Krb5Error::LibraryError {
+    code: libkrb5_sys::KRB5_KDC_UNREACH,
+    message: String::from("Cannot contact any KDC"),
+  }
+  .context("while renewing tickets");
+  assert!(error.is(Krb5ErrorCode::KdcUnreach));
+  assert_eq!(
+    error.to_string(),
+    "Library error: while renewing tickets: Cannot contact any KDC"
+  );
+  assert!(matches!(
+    Krb5Error::NullPointerDereference.context("while renewing tickets"),
+    Krb5Error::NullPointerDereference
+  ));
+}
+
+#[test]
+fn error_message_without_context() {
+  assert_eq!(
+    error_message(libkrb5_sys::KRB5_KT_NOTFOUND),
+    "Key table entry not found"
+  );
+  assert_eq!(Krb5ErrorCode::Skew.to_string(), "Clock skew too great");
+  assert!(!error_message(libkrb5_sys::KRB5_CC_NOTFOUND).is_empty());
+  assert!(!error_message(2).is_empty());
+}
+
+#[test]
+fn structured_errors() {
+  let expired = Krb5Error::PasswordExpired {
+    principal: String::from("user@EXAMPLE.COM"),
+  };
+  assert!(expired.is(Krb5ErrorCode::KeyExp));
+  assert_eq!(expired.to_string(), "Password for user@EXAMPLE.COM has expired");
+  let error: std::io::Error = expired.into();
+  assert_eq!(error.kind(), std::io::ErrorKind::PermissionDenied);
+
+  let preauth = Krb5Error::PreauthRequired { pa_types: vec![2, 141] };
+  assert_eq!(preauth.error_code(), Some(Krb5ErrorCode::PreauthRequired));
+  assert!(!preauth.is_retryable());
+
+  let rejected = Krb5Error::PasswordChangeRejected {
+    result: Krb5PasswordChangeResult {
+      code: 4,
+      code_string: String::from("Password change rejected"),
+      server_message: String::from("Password is too short"),
+      server_data: b"Password is too short".to_vec(),
+    },
+  };
+  assert!(rejected.is(Krb5ErrorCode::ChpwFail));
+  assert_eq!(
+    rejected.to_string(),
+    "Password change rejected: Password change rejected: Password is too short"
+  );
+  let error: std::io::Error = rejected.into();
+  assert_eq!(error.kind(), std::io::ErrorKind::PermissionDenied);
+}
+
+#[test]
+fn kdc_error_data() {
+  // METHOD-DATA with the padata types 2 (ENC-TIMESTAMP) and 136 (FX-FAST)
+  let e_data = vec![
+    0x30, 0x17, 0x30, 0x09, 0xa1, 0x03, 0x02, 0x01, 0x02, 0xa2, 0x02, 0x04, 0x00, 0x30, 0x0a, 0xa1, 0x04, 0x02, 0x02,
+    0x00, 0x88, 0xa2, 0x02, 0x04, 0x00,
+  ];
+  let mut kdc_error = Krb5KdcError {
+    error: 25,
+    stime: 1_600_000_000,
+    text: None,
+    e_data,
+  };
+  assert_eq!(kdc_error.library_code(), libkrb5_sys::KRB5KDC_ERR_PREAUTH_REQUIRED);
+  assert_eq!(kdc_error.pa_types(), vec![2, 136]);
+
+  kdc_error.e_data = vec![0x30, 0x05, 0x30];
+  assert!(kdc_error.pa_types().is_empty());
+  kdc_error.e_data = Vec::new();
+  assert!(kdc_error.pa_types().is_empty());
+
+  let error = Krb5Error::KdcError {
+    code: libkrb5_sys::KRB5KDC_ERR_C_PRINCIPAL_UNKNOWN,
+    message: String::from("Client not found in Kerberos database"),
+    kdc_error: kdc_error.clone(),
+  };
+  assert_eq!(error.kdc_error(), Some(&kdc_error));
+  assert!(error.is(Krb5ErrorCode::CPrincipalUnknown));
+  assert!(Krb5Error::NullPointerDereference.kdc_error().is_none());
+}
+
+#[test]
+fn error_sources() {
+  use std::error::Error;
+
+  let error = Krb5Error::from(std::io::Error::other("connection lost"));
+  assert_eq!(error.source().unwrap().to_string(), "connection lost");
+
+  let invalid = std::ffi::CString::new(vec![0xff]).unwrap().into_string().unwrap_err();
+  let error = Krb5Error::from(invalid);
+  assert!(error.source().unwrap().is::<std::ffi::IntoStringError>());
+
+  let error = Krb5Error::KdcError {
+    code: libkrb5_sys::KRB5KDC_ERR_POLICY,
+    message: String::from("KDC policy rejects request"),
+    kdc_error: Krb5KdcError {
+      error: 12,
+      stime: 1_600_000_000,
+      text: Some(String::from("account locked")),
+      e_data: Vec::new(),
+    },
+  };
+  assert_eq!(error.source().unwrap().to_string(), "KRB-ERROR 12: account locked");
+
+  assert!(Krb5Error::NullPointerDereference.source().is_none());
+}
+
+#[test]
+fn error_code_constants() {
+  fn resolve_missing_ccache(context: &Krb5Context) -> crate::Result<Krb5CCache<'_>> {
+    Krb5CCache::resolve(context, "FILE:/nonexistent/libkrb5-rs/ccache")
+  }
+
+  let context = Krb5Context::init().unwrap();
+  let ccache = resolve_missing_ccache(&context).unwrap();
+  let error = ccache.get_principal().unwrap_err();
+  assert_eq!(error.raw_code(), Some(codes::KRB5_FCC_NOFILE));
+  assert!(error.is(codes::KRB5_FCC_NOFILE));
+  assert_eq!(Krb5ErrorCode::from(codes::KRB5_KDC_UNREACH), Krb5ErrorCode::KdcUnreach);
+}
+
+#[test]
+fn data_wrapper() {
+  let bytes = [1u8, 2, 3];
+  let borrowed = Krb5Data::from(&bytes[..]);
+  assert_eq!(borrowed.as_ref(), &bytes);
+  assert_eq!(borrowed.len(), 3);
+  assert_eq!(format!("{:?}", borrowed), "Krb5Data(<3 bytes>)");
+
+  let owned = Krb5Data::from(bytes.to_vec());
+  assert_eq!(borrowed, owned);
+  assert_eq!(owned.into_vec(), bytes.to_vec());
+
+  let context = Krb5Context::init().unwrap();
+  let mut raw: *mut libkrb5_sys::krb5_data = std::ptr::null_mut();
+  let code = unsafe { libkrb5_sys::krb5_copy_data(context.context, &data::slice_to_krb5_data(&bytes), &mut raw) };
+  assert_eq!(code, 0);
+  let allocated = Krb5Data::from_raw(&context, unsafe { *raw });
+  unsafe {
+    (*raw).data = std::ptr::null_mut();
+    libkrb5_sys::krb5_free_data(context.context, raw);
+  }
+  assert_eq!(allocated.as_bytes(), &bytes);
+  assert!(!allocated.is_empty());
+  assert!(Krb5Data::from(Vec::new()).is_empty());
+}
+
+#[test]
+fn timestamps() {
+  use std::time::{Duration, UNIX_EPOCH};
+
+  let timestamp = Krb5Timestamp::from_raw(1_600_000_000);
+  assert_eq!(
+    timestamp.to_system_time(),
+    UNIX_EPOCH + Duration::from_secs(1_600_000_000)
+  );
+  assert_eq!(
+    Krb5Timestamp::from_system_time(timestamp.to_system_time()),
+    Some(timestamp)
+  );
+
+  // times after January 2038 wrap around to negative raw values
+  let after_2038 = Krb5Timestamp::from_system_time(UNIX_EPOCH + Duration::from_secs(3_000_000_000)).unwrap();
+  assert!(after_2038.as_raw() < 0);
+  assert_eq!(after_2038.as_secs(), 3_000_000_000);
+  assert!(after_2038 > timestamp);
+  assert_eq!(
+    Krb5Timestamp::from_system_time(UNIX_EPOCH + Duration::from_secs(1 << 32)),
+    None
+  );
+  assert_eq!(
+    Krb5Timestamp::from_system_time(UNIX_EPOCH - Duration::from_secs(1)),
+    None
+  );
+
+  assert!(Krb5Timestamp::default().is_unset());
+
+  let context = Krb5Context::init().unwrap();
+  let now = Krb5Timestamp::now(&context).unwrap();
+  assert!(now > timestamp);
+
+  let session_key = Krb5Keyblock::new(&context, Krb5Enctype::Aes256CtsHmacSha1_96, &[0x11; 32]).unwrap();
+  let creds = fake_creds(&context, b"ticket", &session_key);
+  assert_eq!(creds.auth_time(), timestamp);
+  assert!(creds.renew_till().is_unset());
+
+  #[cfg(feature = "chrono")]
+  assert_eq!(after_2038.to_date_time().timestamp(), 3_000_000_000);
+}
+
+#[test]
+fn deltat_conversion() {
+  use std::time::Duration;
+
+  assert_eq!(string_to_deltat("10h").unwrap(), Duration::from_secs(36_000));
+  assert_eq!(string_to_deltat("7d").unwrap(), Duration::from_secs(604_800));
+  assert_eq!(string_to_deltat("1h30m").unwrap(), Duration::from_secs(5_400));
+  assert_eq!(string_to_deltat("90").unwrap(), Duration::from_secs(90));
+
+  let error = string_to_deltat("ten hours").unwrap_err();
+  assert!(error.is(codes::KRB5_DELTAT_BADFORMAT));
+
+  let formatted = deltat_to_string(Duration::from_secs(36_000)).unwrap();
+  assert_eq!(string_to_deltat(&formatted).unwrap(), Duration::from_secs(36_000));
+  assert!(deltat_to_string(Duration::from_secs(1 << 40)).is_err());
+}
+
+#[test]
+fn address_conversion() {
+  use std::net::IpAddr;
+
+  let ips: Vec<IpAddr> = vec!["192.0.2.1".parse().unwrap(), "2001:db8::1".parse().unwrap()];
+  let mut list = address::AddressList::from_ips(&ips);
+  assert_eq!(address::addresses_to_ips(list.as_mut_ptr()), ips);
+  assert!(address::addresses_to_ips(std::ptr::null()).is_empty());
+
+  let mut port = address::AddressBuffer::from_port(88);
+  assert_eq!(address::address_to_ip(&port.as_krb5_address()), None);
+
+  let context = Krb5Context::init().unwrap();
+  let _local = context.local_addresses().unwrap();
+
+  let options = Krb5InitCredsOptions {
+    addresses: Some(ips),
+    ..Default::default()
+  };
+  let _opt = options.to_opt(&context).unwrap();
+
+  let session_key = Krb5Keyblock::new(&context, Krb5Enctype::Aes256CtsHmacSha1_96, &[0x11; 32]).unwrap();
+  let creds = fake_creds(&context, b"ticket", &session_key);
+  assert!(creds.addresses().is_empty());
+}
+
+#[test]
+fn timestamp_formatting() {
+  let timestamp = Krb5Timestamp::from_raw(1_600_000_000);
+
+  let short = timestamp.format_short().unwrap();
+  assert!(!short.is_empty() && short.len() < 20);
+  assert_eq!(timestamp.to_string(), short);
+  assert!(timestamp.format().unwrap().contains("2020"));
+}
+
+#[test]
+fn borrowed_ticket_data() {
+  let context = Krb5Context::init().unwrap();
+  let session_key = Krb5Keyblock::new(&context, Krb5Enctype::Aes256CtsHmacSha1_96, &[0x11; 32]).unwrap();
+  let creds = fake_creds(&context, b"ticket", &session_key);
+
+  assert_eq!(creds.ticket(), b"ticket");
+  assert!(creds.second_ticket().is_empty());
+}
+
+#[test]
+fn set_password_failures() {
+  let context = Krb5Context::init().unwrap();
+  let components = [String::from("user")];
+  let user = context.build_principal("EXAMPLE.COM", &components).unwrap();
+
+  // an uninitialized credential cache has no credentials to authenticate with
+  let ccache = Krb5CCache::new_unique(&context, "MEMORY").unwrap();
+  assert!(context.set_password(&ccache, "new password", None).is_err());
+  assert!(context.set_password(&ccache, "new password", Some(&user)).is_err());
+  assert!(context.set_password(&ccache, "nul\0byte", Some(&user)).is_err());
+}
+
+#[test]
+fn password_login_without_kdc() {
+  let context = Krb5Context::init().unwrap();
+  let components = [String::from("user")];
+  let principal = context.build_principal("EXAMPLE.COM", &components).unwrap();
+  let options = Krb5InitCredsOptions {
+    tkt_life: Some(3600),
+    ..Default::default()
+  };
+
+  assert!(context
+    .acquire_initial_creds_with_password(&principal, "password", &options)
+    .is_err());
+  assert!(context
+    .acquire_password_change_creds(&principal, "password", &options)
+    .is_err());
+  assert!(context.kpasswd(&principal, "password", "new password").is_err());
+
+  // the new password is only asked for if the KDC reports the password as expired
+  let mut asked = false;
+  let result = context.acquire_initial_creds_with_password_change(&principal, "password", &options, |_| {
+    asked = true;
+    None
+  });
+  assert!(result.is_err());
+  assert!(!asked);
+}
+
+#[cfg(all(krb5_mit, krb5_1_15))]
+#[test]
+fn kdc_send_hook() {
+  use std::cell::RefCell;
+  use std::rc::Rc;
+
+  // the hook is only called for realms with known KDCs, which are not contacted
+  let mut context = Krb5Context::init()
+    .unwrap()
+    .with_profile_values(&["realms", "EXAMPLE.COM", "kdc"], &["kdc.example.com"])
+    .unwrap();
+  let components = [String::from("user")];
+  let requests = Rc::new(RefCell::new(Vec::new()));
+
+  let seen = Rc::clone(&requests);
+  context.set_kdc_send_hook(move |realm, request| {
+    seen.borrow_mut().push((String::from(realm), request.to_vec()));
+    Ok(Some(b"not a KDC reply".to_vec()))
+  });
+  {
+    let principal = context.build_principal("EXAMPLE.COM", &components).unwrap();
+    let options = Krb5InitCredsOptions::default();
+    assert!(context
+      .acquire_initial_creds_with_password(&principal, "password", &options)
+      .is_err());
+  }
+  let (realm, request) = requests.borrow()[0].clone();
+  assert_eq!(realm, "EXAMPLE.COM");
+  // an AS-REQ is tagged with [APPLICATION 10]
+  assert_eq!(request[0], 0x6a);
+
+  context.set_kdc_send_hook(|_, _| {
+    Err(Krb5Error::LibraryError {
+      code: codes::KRB5_KDC_UNREACH,
+      message: String::from("proxy is down"),
+    })
+  });
+  let principal = context.build_principal("EXAMPLE.COM", &components).unwrap();
+  let error = context
+    .acquire_initial_creds_with_password(&principal, "password", &Krb5InitCredsOptions::default())
+    .unwrap_err();
+  assert!(error.is_kdc_unreachable());
+  assert!(error.to_string().contains("proxy is down"));
+  drop(principal);
+
+  context.clear_kdc_send_hook();
+}
+
+#[test]
+fn change_password_without_kdc() {
+  let context = Krb5Context::init().unwrap();
+  let session_key = Krb5Keyblock::new(&context, Krb5Enctype::Aes256CtsHmacSha1_96, &[0x11; 32]).unwrap();
+  let creds = fake_creds(&context, b"ticket", &session_key);
+
+  // the realm of the fake credentials has no kpasswd server
+  assert!(context.change_password(&creds, "new password").is_err());
+}
+
+#[cfg(krb5_1_11)]
+#[test]
+fn password_policy() {
+  use std::time::Duration;
+
+  let context = Krb5Context::init().unwrap();
+
+  let mut policy_info = vec![0, 0];
+  policy_info.extend_from_slice(&8u32.to_be_bytes());
+  policy_info.extend_from_slice(&24u32.to_be_bytes());
+  policy_info.extend_from_slice(&1u32.to_be_bytes());
+  policy_info.extend_from_slice(&(42 * 86400 * 10_000_000u64).to_be_bytes());
+  policy_info.extend_from_slice(&(86400 * 10_000_000u64).to_be_bytes());
+
+  let result = Krb5PasswordChangeResult::from_raw(
+    &context,
+    4,
+    copy_to_krb5_data(&context, b"Password change rejected"),
+    copy_to_krb5_data(&context, &policy_info),
+  );
+  let policy = result.policy().unwrap();
+  assert_eq!(policy.min_length, 8);
+  assert_eq!(policy.history_length, 24);
+  assert!(policy.complexity_required());
+  assert_eq!(policy.max_age, Some(Duration::from_secs(42 * 86400)));
+  assert_eq!(policy.min_age, Some(Duration::from_secs(86400)));
+  assert!(result.server_message.contains("at least 8 characters"));
+
+  policy_info[0] = 1;
+  assert_eq!(Krb5PasswordPolicy::from_bytes(&policy_info), None);
+  assert_eq!(Krb5PasswordPolicy::from_bytes(&[0; 29]), None);
+
+  let policy = Krb5PasswordPolicy::from_bytes(&[0; 30]).unwrap();
+  assert!(!policy.complexity_required());
+  assert_eq!(policy.max_age, None);
+}
+
+#[test]
+fn password_change_result() {
+  let context = Krb5Context::init().unwrap();
+
+  let code_string = b"Password changed".to_vec();
+  let result = Krb5PasswordChangeResult::from_raw(
+    &context,
+    0,
+    copy_to_krb5_data(&context, &code_string),
+    copy_to_krb5_data(&context, b""),
+  );
+  assert!(result.is_success());
+  assert_eq!(result.code_string, "Password changed");
+  assert_eq!(result.server_message, "");
+  assert_eq!(result.status(), Krb5PasswordChangeStatus::Success);
+
+  let result = Krb5PasswordChangeResult::from_raw(
+    &context,
+    4,
+    copy_to_krb5_data(&context, b"Password change rejected"),
+    copy_to_krb5_data(&context, b"Password is too short"),
+  );
+  assert!(!result.is_success());
+  assert_eq!(result.server_message, "Password is too short");
+  assert_eq!(result.server_data, b"Password is too short");
+  assert_eq!(result.policy(), None);
+  assert_eq!(
+    result.status(),
+    Krb5PasswordChangeStatus::SoftError {
+      message: String::from("Password is too short")
+    }
+  );
+
+  let result = Krb5PasswordChangeResult::from_raw(
+    &context,
+    5,
+    copy_to_krb5_data(&context, b"Access denied"),
+    copy_to_krb5_data(&context, b""),
+  );
+  assert_eq!(
+    result.status(),
+    Krb5PasswordChangeStatus::AccessDenied {
+      message: String::from("Access denied")
+    }
+  );
+}
+
+/**
+ * Copy bytes into a `krb5_data` allocated by libkrb5.
+ */
+fn copy_to_krb5_data(context: &Krb5Context, bytes: &[u8]) -> libkrb5_sys::krb5_data {
+  let mut raw: *mut libkrb5_sys::krb5_data = std::ptr::null_mut();
+  unsafe {
+    libkrb5_sys::krb5_copy_data(context.context, &data::slice_to_krb5_data(bytes), &mut raw);
+    let data = *raw;
+    (*raw).data = std::ptr::null_mut();
+    libkrb5_sys::krb5_free_data(context.context, raw);
+    data
+  }
+}
+
+#[test]
+fn keyblock_encrypt_decrypt() {
+  let context = Krb5Context::init().unwrap();
+  let enctype = Krb5Enctype::Aes256CtsHmacSha1_96;
+  let key = Krb5Keyblock::new(&context, enctype, &[0x42; 32]).unwrap();
+
+  let ciphertext = key
+    .encrypt(Krb5KeyUsage::AppDataEncrypt, None, b"attack at dawn")
+    .unwrap();
+  assert!(ciphertext.len() > 14);
+  assert!(!ciphertext.windows(6).any(|window| window == b"attack"));
+  assert_eq!(
+    key.decrypt(Krb5KeyUsage::AppDataEncrypt, None, &ciphertext).unwrap(),
+    b"attack at dawn"
+  );
+
+  // wrong key usage, tampered data and a different key fail the integrity check
+  assert!(key.decrypt(Krb5KeyUsage::KrbPrivEncPart, None, &ciphertext).is_err());
+  let mut tampered = ciphertext.clone();
+  tampered[20] ^= 1;
+  assert!(key.decrypt(Krb5KeyUsage::AppDataEncrypt, None, &tampered).is_err());
+  let other_key = Krb5Keyblock::new(&context, enctype, &[0x43; 32]).unwrap();
+  assert!(other_key
+    .decrypt(Krb5KeyUsage::AppDataEncrypt, None, &ciphertext)
+    .is_err());
+
+  // chained messages decrypt in order
+  let mut encrypt_state = key.init_state(Krb5KeyUsage::AppDataEncrypt).unwrap();
+  let first = key
+    .encrypt(Krb5KeyUsage::AppDataEncrypt, Some(&mut encrypt_state), b"first message")
+    .unwrap();
+  let second = key
+    .encrypt(
+      Krb5KeyUsage::AppDataEncrypt,
+      Some(&mut encrypt_state),
+      b"second message",
+    )
+    .unwrap();
+  let mut decrypt_state = key.init_state(Krb5KeyUsage::AppDataEncrypt).unwrap();
+  assert_eq!(
+    key
+      .decrypt(Krb5KeyUsage::AppDataEncrypt, Some(&mut decrypt_state), &first)
+      .unwrap(),
+    b"first message"
+  );
+  assert_eq!(
+    key
+      .decrypt(Krb5KeyUsage::AppDataEncrypt, Some(&mut decrypt_state), &second)
+      .unwrap(),
+    b"second message"
+  );
+}
+
+#[test]
+fn keyblock_encrypt_decrypt_iov() {
+  let context = Krb5Context::init().unwrap();
+  let enctype = Krb5Enctype::Aes256CtsHmacSha1_96;
+  let key = Krb5Keyblock::new(&context, enctype, &[0x42; 32]).unwrap();
+
+  let mut header = vec![0; key.crypto_length(Krb5CryptoType::Header).unwrap()];
+  let mut trailer = vec![0; key.crypto_length(Krb5CryptoType::Trailer).unwrap()];
+  let mut data = b"attack at dawn".to_vec();
+
+  let mut iov = Krb5CryptoIov::new();
+  iov.header(&mut header).data(&mut data).trailer(&mut trailer);
+  assert_eq!(iov.len(), 3);
+  key.encrypt_iov(Krb5KeyUsage::AppDataEncrypt, None, &mut iov).unwrap();
+  assert_ne!(data, b"attack at dawn");
+
+  // without sign only buffers the concatenated buffers are a regular ciphertext
+  let ciphertext = [&header[..], &data[..], &trailer[..]].concat();
+  assert_eq!(
+    key.decrypt(Krb5KeyUsage::AppDataEncrypt, None, &ciphertext).unwrap(),
+    b"attack at dawn"
+  );
+
+  let mut iov = Krb5CryptoIov::new();
+  iov.header(&mut header).data(&mut data).trailer(&mut trailer);
+  key.decrypt_iov(Krb5KeyUsage::AppDataEncrypt, None, &mut iov).unwrap();
+  assert_eq!(data, b"attack at dawn");
+
+  // sign only buffers are protected but not encrypted
+  let associated = b"associated data".to_vec();
+  let mut iov = Krb5CryptoIov::new();
+  iov
+    .header(&mut header)
+    .sign_only(&associated)
+    .data(&mut data)
+    .trailer(&mut trailer);
+  key.encrypt_iov(Krb5KeyUsage::AppDataEncrypt, None, &mut iov).unwrap();
+
+  // decryption works in place on all buffers, so use copies for the failing attempt
+  let (mut header_copy, mut data_copy, mut trailer_copy) = (header.clone(), data.clone(), trailer.clone());
+  let mut iov = Krb5CryptoIov::new();
+  iov
+    .header(&mut header_copy)
+    .sign_only(b"modified data!!")
+    .data(&mut data_copy)
+    .trailer(&mut trailer_copy);
+  assert!(key.decrypt_iov(Krb5KeyUsage::AppDataEncrypt, None, &mut iov).is_err());
+
+  let mut iov = Krb5CryptoIov::new();
+  iov
+    .header(&mut header)
+    .sign_only(&associated)
+    .data(&mut data)
+    .trailer(&mut trailer);
+  key.decrypt_iov(Krb5KeyUsage::AppDataEncrypt, None, &mut iov).unwrap();
+  assert_eq!(data, b"attack at dawn");
+
+  // the header buffer is too small
+  let mut iov = Krb5CryptoIov::new();
+  iov.header(&mut header[..4]).data(&mut data).trailer(&mut trailer);
+  assert!(key.encrypt_iov(Krb5KeyUsage::AppDataEncrypt, None, &mut iov).is_err());
+}
+
+#[test]
+fn keyblock_checksums() {
+  let context = Krb5Context::init().unwrap();
+  let enctype = Krb5Enctype::Aes256CtsHmacSha1_96;
+  let key = Krb5Keyblock::new(&context, enctype, &[0x42; 32]).unwrap();
+
+  // the mandatory checksum type of the enctype
+  let checksum = key
+    .make_checksum(None, Krb5KeyUsage::AppDataChecksum, b"signed data")
+    .unwrap();
+  assert_eq!(checksum.checksum_type, Krb5ChecksumType::HmacSha1_96Aes256);
+  assert_eq!(checksum.contents.len(), 12);
+  assert!(key
+    .verify_checksum(Krb5KeyUsage::AppDataChecksum, b"signed data", &checksum)
+    .unwrap());
+  assert!(!key
+    .verify_checksum(Krb5KeyUsage::AppDataChecksum, b"other data", &checksum)
+    .unwrap());
+  assert!(!key
+    .verify_checksum(Krb5KeyUsage::KrbErrorChecksum, b"signed data", &checksum)
+    .unwrap());
+
+  let other_key = Krb5Keyblock::new(&context, enctype, &[0x43; 32]).unwrap();
+  assert!(!other_key
+    .verify_checksum(Krb5KeyUsage::AppDataChecksum, b"signed data", &checksum)
+    .unwrap());
+
+  let checksum = key
+    .make_checksum(
+      Some(Krb5ChecksumType::NistSha),
+      Krb5KeyUsage::AppDataChecksum,
+      b"signed data",
+    )
+    .unwrap();
+  assert_eq!(checksum.contents.len(), 20);
+  assert!(key
+    .verify_checksum(Krb5KeyUsage::AppDataChecksum, b"signed data", &checksum)
+    .unwrap());
+
+  // the checksum type does not fit the key
+  assert!(key
+    .make_checksum(
+      Some(Krb5ChecksumType::HmacSha256_128Aes128),
+      Krb5KeyUsage::AppDataChecksum,
+      b"signed data"
+    )
+    .is_err());
+}
+
+#[test]
+fn keyblock_prf() {
+  let context = Krb5Context::init().unwrap();
+  let enctype = Krb5Enctype::Aes256CtsHmacSha1_96;
+  let key = Krb5Keyblock::new(&context, enctype, &[0x42; 32]).unwrap();
+
+  let prf = key.prf(b"input").unwrap();
+  assert_eq!(prf.len(), 16);
+  assert_eq!(key.prf(b"input").unwrap(), prf);
+  assert_ne!(key.prf(b"other input").unwrap(), prf);
+
+  // PRF+ is the concatenation of PRF(key, counter || input) for counter 1, 2, ...
+  let prf_plus = key.prf_plus(b"input", 40).unwrap();
+  assert_eq!(prf_plus.len(), 40);
+  assert_eq!(&prf_plus[..16], &key.prf(b"\x01input").unwrap()[..]);
+  assert_eq!(&prf_plus[16..32], &key.prf(b"\x02input").unwrap()[..]);
+  assert!(key.prf_plus(b"input", 255 * 16 + 1).is_err());
+
+  let derived = key.derive_prf_plus(b"input", None).unwrap();
+  assert_eq!(derived.enctype(), enctype);
+  assert_eq!(derived.len(), 32);
+  let derived = key
+    .derive_prf_plus(b"input", Some(Krb5Enctype::Aes128CtsHmacSha1_96))
+    .unwrap();
+  assert_eq!(derived.enctype(), Krb5Enctype::Aes128CtsHmacSha1_96);
+  assert_eq!(derived.len(), 16);
+}
+
+#[test]
+fn keyblock_generate() {
+  let context = Krb5Context::init().unwrap();
+  let enctype = Krb5Enctype::Aes256CtsHmacSha1_96;
+
+  let first = Krb5Keyblock::generate(&context, enctype).unwrap();
+  let second = Krb5Keyblock::generate(&context, enctype).unwrap();
+  assert_eq!(first.enctype(), enctype);
+  assert_eq!(first.len(), 32);
+  assert_ne!(first.contents(), second.contents());
+
+  let ciphertext = first.encrypt(Krb5KeyUsage::AppDataEncrypt, None, b"data").unwrap();
+  assert_eq!(
+    first.decrypt(Krb5KeyUsage::AppDataEncrypt, None, &ciphertext).unwrap(),
+    b"data"
+  );
+
+  assert!(Krb5Keyblock::generate(&context, Krb5Enctype::Unknown(-1)).is_err());
+}
+
+#[test]
+fn keyblock_string_to_key() {
+  let context = Krb5Context::init().unwrap();
+  let enctype = Krb5Enctype::Aes128CtsHmacSha1_96;
+
+  let components = [String::from("raeburn")];
+  let principal = context.build_principal("ATHENA.MIT.EDU", &components).unwrap();
+  let salt = principal.salt().unwrap();
+  assert_eq!(salt, b"ATHENA.MIT.EDUraeburn");
+
+  // PBKDF2-HMAC-SHA1 and the RFC 3962 key derivation, 4096 iterations by default
+  let key = Krb5Keyblock::string_to_key(&context, enctype, b"password", &salt, None).unwrap();
+  assert_eq!(key.enctype(), enctype);
+  assert_eq!(
+    key.contents(),
+    &[0xfc, 0xa8, 0x22, 0x95, 0x18, 0x13, 0xfb, 0x25, 0x21, 0x54, 0xc8, 0x83, 0xf5, 0xee, 0x1c, 0xf4]
+  );
+
+  let key = Krb5Keyblock::string_to_key(&context, enctype, b"password", &salt, Some(&8192u32.to_be_bytes())).unwrap();
+  assert_eq!(
+    key.contents(),
+    &[0x25, 0x80, 0xb1, 0x7e, 0xf2, 0x78, 0x61, 0x58, 0x60, 0xc1, 0x71, 0xca, 0xfe, 0x3b, 0xe9, 0xb1]
+  );
+
+  // iteration counts below the default are rejected as weak
+  assert!(Krb5Keyblock::string_to_key(&context, enctype, b"password", &salt, Some(&1u32.to_be_bytes())).is_err());
+  assert!(Krb5Keyblock::string_to_key(&context, enctype, b"password", &salt, Some(b"bad")).is_err());
+}
+
+#[test]
+fn enctype_names() {
+  let enctype = Krb5Enctype::from_name("aes256-cts-hmac-sha1-96").unwrap();
+  assert_eq!(enctype, Krb5Enctype::Aes256CtsHmacSha1_96);
+  assert_eq!(enctype.as_raw(), libkrb5_sys::ENCTYPE_AES256_CTS_HMAC_SHA1_96 as i32);
+  assert_eq!(Krb5Enctype::from_raw(enctype.as_raw()), enctype);
+  assert_eq!(enctype.name(false).unwrap(), "aes256-cts-hmac-sha1-96");
+  assert_eq!(enctype.name(true).unwrap(), "aes256-cts");
+  assert_eq!(enctype.to_string(), "aes256-cts-hmac-sha1-96");
+  assert!(enctype.description().unwrap().contains("AES-256"));
+
+  // aliases
+  assert_eq!("aes256-cts".parse::<Krb5Enctype>().unwrap(), enctype);
+  assert_eq!("rc4-hmac".parse::<Krb5Enctype>().unwrap(), Krb5Enctype::ArcfourHmac);
+
+  let unknown = Krb5Enctype::from_raw(4711);
+  assert_eq!(unknown, Krb5Enctype::Unknown(4711));
+  assert_eq!(unknown.as_raw(), 4711);
+  assert!(unknown.name(false).is_err());
+  assert!(unknown.description().is_err());
+  assert_eq!(unknown.to_string(), "etype 4711");
+  assert!(Krb5Enctype::from_name("no-such-enctype").is_err());
+}
+
+#[test]
+fn checksum_types() {
+  let context = Krb5Context::init().unwrap();
+
+  let checksum_type = Krb5ChecksumType::from_name("hmac-sha1-96-aes256").unwrap();
+  assert_eq!(checksum_type, Krb5ChecksumType::HmacSha1_96Aes256);
+  assert_eq!(
+    checksum_type.as_raw(),
+    libkrb5_sys::CKSUMTYPE_HMAC_SHA1_96_AES256 as i32
+  );
+  assert_eq!(Krb5ChecksumType::from_raw(checksum_type.as_raw()), checksum_type);
+  assert_eq!(checksum_type.length(&context).unwrap(), 12);
+  assert!(checksum_type.description().unwrap().contains("AES256"));
+  assert_eq!("sha".parse::<Krb5ChecksumType>().unwrap(), Krb5ChecksumType::NistSha);
+  assert_eq!(Krb5ChecksumType::NistSha.length(&context).unwrap(), 20);
+  assert_eq!(
+    Krb5ChecksumType::from_raw(libkrb5_sys::CKSUMTYPE_HMAC_MD5_ARCFOUR),
+    Krb5ChecksumType::HmacMd5Arcfour
+  );
+
+  let unknown = Krb5ChecksumType::from_raw(4711);
+  assert_eq!(unknown, Krb5ChecksumType::Unknown(4711));
+  assert_eq!(unknown.as_raw(), 4711);
+  assert!(unknown.description().is_err());
+  assert!(unknown.length(&context).is_err());
+  assert_eq!(unknown.to_string(), "cksumtype 4711");
+  assert!(Krb5ChecksumType::from_name("no-such-checksum").is_err());
+}
+
+#[test]
+fn enctype_properties() {
+  let context = Krb5Context::init().unwrap();
+
+  let aes256 = Krb5Enctype::Aes256CtsHmacSha1_96;
+  assert!(aes256.is_valid());
+  assert_eq!(aes256.key_length(&context).unwrap(), 32);
+  assert_eq!(aes256.random_length(&context).unwrap(), 32);
+  assert_eq!(aes256.block_size(&context).unwrap(), 16);
+
+  // DES3 keys carry parity bits
+  let des3 = Krb5Enctype::Des3CbcSha1;
+  assert_eq!(des3.key_length(&context).unwrap(), 24);
+  assert_eq!(des3.random_length(&context).unwrap(), 21);
+
+  let unknown = Krb5Enctype::Unknown(4711);
+  assert!(!unknown.is_valid());
+  assert!(unknown.key_length(&context).is_err());
+  assert!(unknown.block_size(&context).is_err());
+}
+
+#[test]
+fn keyblock_fx_cf2() {
+  let context = Krb5Context::init().unwrap();
+  let enctype = Krb5Enctype::Aes128CtsHmacSha1_96;
+  let key1 = Krb5Keyblock::string_to_key(&context, enctype, b"key1", b"key1", None).unwrap();
+  let key2 = Krb5Keyblock::string_to_key(&context, enctype, b"key2", b"key2", None).unwrap();
+
+  // test vector of the libkrb5 test suite (t_cf2)
+  let combined = key1.fx_cf2("a", &key2, "b").unwrap();
+  assert_eq!(combined.enctype(), enctype);
+  assert_eq!(
+    combined.contents(),
+    &[0x97, 0xdf, 0x97, 0xe4, 0xb7, 0x98, 0xb2, 0x9e, 0xb3, 0x1e, 0xd7, 0x28, 0x02, 0x87, 0xa9, 0x2a]
+  );
+
+  assert_ne!(key1.fx_cf2("b", &key2, "a").unwrap().contents(), combined.contents());
+}
+
+#[test]
+fn keyblock_lengths() {
+  let context = Krb5Context::init().unwrap();
+
+  // confounder and HMAC, no padding for the CTS mode
+  let aes = Krb5Keyblock::generate(&context, Krb5Enctype::Aes256CtsHmacSha1_96).unwrap();
+  assert_eq!(aes.encrypt_length(100).unwrap(), 16 + 100 + 12);
+  assert_eq!(
+    aes
+      .encrypt(Krb5KeyUsage::AppDataEncrypt, None, &[0; 100])
+      .unwrap()
+      .len(),
+    16 + 100 + 12
+  );
+  assert_eq!(aes.padding_length(100).unwrap(), 0);
+
+  // CBC mode pads the confounder and data to the block size
+  let des3 = Krb5Keyblock::generate(&context, Krb5Enctype::Des3CbcSha1).unwrap();
+  assert_eq!(des3.padding_length(100).unwrap(), 4);
+  assert_eq!(des3.encrypt_length(100).unwrap(), 8 + 100 + 4 + 20);
+}
+
+#[test]
+fn keyblock_from_random_bytes() {
+  let context = Krb5Context::init().unwrap();
+
+  // the AES random-to-key function is the identity
+  let enctype = Krb5Enctype::Aes256CtsHmacSha1_96;
+  let key = Krb5Keyblock::from_random_bytes(&context, enctype, &[0x42; 32]).unwrap();
+  assert_eq!(key.enctype(), enctype);
+  assert_eq!(key.contents(), &[0x42; 32]);
+  assert!(Krb5Keyblock::from_random_bytes(&context, enctype, &[0x42; 16]).is_err());
+
+  // DES3 expands 21 random bytes to a 24 byte key with parity bits
+  let enctype = Krb5Enctype::Des3CbcSha1;
+  let key = Krb5Keyblock::from_random_bytes(&context, enctype, &[0x42; 21]).unwrap();
+  assert_eq!(key.len(), 24);
+  assert!(key.contents().iter().all(|byte| byte.count_ones() % 2 == 1));
+
+  assert!(Krb5Keyblock::from_random_bytes(&context, Krb5Enctype::Unknown(4711), &[0x42; 32]).is_err());
+}
+
+#[test]
+fn keyblock_clone_and_compare() {
+  let context = Krb5Context::init().unwrap();
+  let enctype = Krb5Enctype::Aes256CtsHmacSha1_96;
+
+  let key = Krb5Keyblock::generate(&context, enctype).unwrap();
+  let copy = key.try_clone().unwrap();
+  assert_eq!(copy.enctype(), key.enctype());
+  assert_eq!(copy.contents(), key.contents());
+  assert_ne!(copy.contents().as_ptr(), key.contents().as_ptr());
+  assert_eq!(copy, key);
+  drop(key);
+  assert_eq!(copy.len(), 32);
+
+  let other = Krb5Keyblock::generate(&context, enctype).unwrap();
+  assert_ne!(copy, other);
+
+  // same bytes, different enctype
+  let aes128 = Krb5Keyblock::new(&context, Krb5Enctype::Aes128CtsHmacSha1_96, &[0x42; 16]).unwrap();
+  let camellia128 = Krb5Keyblock::new(&context, Krb5Enctype::Camellia128CtsCmac, &[0x42; 16]).unwrap();
+  assert_ne!(aes128, camellia128);
+  assert_ne!(
+    aes128,
+    Krb5Keyblock::new(&context, aes128.enctype(), &[0x42; 15]).unwrap()
+  );
+}
+
+#[test]
+#[cfg(krb5_mit)]
+fn profile_override() {
+  let context = Krb5Context::init().unwrap();
+  let default_realm = context.get_default_realm().ok().flatten();
+
+  let overridden = context
+    .with_profile_values(&["libdefaults", "default_realm"], &["OVERRIDE.TEST"])
+    .unwrap();
+  assert_eq!(
+    overridden.get_default_realm().unwrap(),
+    Some(String::from("OVERRIDE.TEST"))
+  );
+  assert_eq!(context.get_default_realm().ok().flatten(), default_realm);
+
+  // overrides can be chained, other relations are looked up in the base profile
+  let kpasswd = overridden
+    .with_kpasswd_server("OVERRIDE.TEST", &["kpasswd.override.test:464"])
+    .unwrap();
+  assert_eq!(
+    kpasswd.get_default_realm().unwrap(),
+    Some(String::from("OVERRIDE.TEST"))
+  );
+  drop(overridden);
+  assert_eq!(
+    kpasswd.get_default_realm().unwrap(),
+    Some(String::from("OVERRIDE.TEST"))
+  );
+
+  let removed = kpasswd
+    .with_profile_values(&["libdefaults", "default_realm"], &[] as &[&str])
+    .unwrap();
+  assert_ne!(
+    removed.get_default_realm().ok().flatten(),
+    Some(String::from("OVERRIDE.TEST"))
+  );
+}
+
+/**
+ * Encode a PAC with the given buffers by hand (MS-PAC PACTYPE and PAC_INFO_BUFFER entries).
+ */
+fn encode_pac(buffers: &[(u32, &[u8])]) -> Vec<u8> {
+  let header_len = 8 + 16 * buffers.len();
+  let mut header = Vec::new();
+  let mut contents = Vec::new();
+
+  header.extend_from_slice(&(buffers.len() as u32).to_le_bytes());
+  header.extend_from_slice(&0u32.to_le_bytes());
+
+  for (buffer_type, data) in buffers {
+    header.extend_from_slice(&buffer_type.to_le_bytes());
+    header.extend_from_slice(&(data.len() as u32).to_le_bytes());
+    header.extend_from_slice(&((header_len + contents.len()) as u64).to_le_bytes());
+
+    contents.extend_from_slice(data);
+    contents.resize(contents.len().div_ceil(8) * 8, 0);
+  }
+
+  header.extend_from_slice(&contents);
+  header
+}
+
+#[test]
+fn pac_parse() {
+  let context = Krb5Context::init().unwrap();
+
+  let encoded = encode_pac(&[
+    (libkrb5_sys::KRB5_PAC_CLIENT_INFO, b"client"),
+    (libkrb5_sys::KRB5_PAC_UPN_DNS_INFO, b"upn"),
+  ]);
+  let pac = Krb5Pac::parse(&context, &encoded).unwrap();
+
+  assert_eq!(
+    pac.buffer_types().unwrap(),
+    vec![Krb5PacBufferType::ClientInfo, Krb5PacBufferType::UpnDnsInfo]
+  );
+  assert_eq!(pac.buffer(libkrb5_sys::KRB5_PAC_UPN_DNS_INFO).unwrap(), b"upn");
+  assert_eq!(pac.buffer(Krb5PacBufferType::UpnDnsInfo).unwrap(), b"upn");
+  assert_eq!(
+    pac
+      .buffer(Krb5PacBufferType::Unknown(libkrb5_sys::KRB5_PAC_UPN_DNS_INFO))
+      .unwrap(),
+    b"upn"
+  );
+  assert!(pac.buffer(Krb5PacBufferType::LogonInfo).is_err());
+  assert!(pac.has_buffer(Krb5PacBufferType::ClientInfo));
+  assert!(!pac.has_buffer(Krb5PacBufferType::LogonInfo));
+
+  let buffers = pac.buffers();
+  assert_eq!(buffers.len(), 2);
+  assert_eq!(buffers[0].0, Krb5PacBufferType::ClientInfo);
+  assert_eq!(buffers[0].1, b"client");
+
+  assert!(Krb5Pac::parse(&context, b"not a PAC").is_err());
+}
+
+#[test]
+fn pac_buffer_types() {
+  for raw in 0..20 {
+    assert_eq!(Krb5PacBufferType::from_raw(raw).as_raw(), raw);
+  }
+
+  assert_eq!(Krb5PacBufferType::from(1), Krb5PacBufferType::LogonInfo);
+  assert_eq!(Krb5PacBufferType::from(99), Krb5PacBufferType::Unknown(99));
+  assert_eq!(Krb5PacBufferType::Unknown(1), Krb5PacBufferType::LogonInfo);
+  assert_ne!(Krb5PacBufferType::Unknown(99), Krb5PacBufferType::Unknown(98));
+  let types: std::collections::HashSet<_> = [Krb5PacBufferType::Unknown(1), Krb5PacBufferType::LogonInfo].into();
+  assert_eq!(types.len(), 1);
+  assert!(Krb5PacBufferType::PrivsvrChecksum.is_signature());
+  assert!(!Krb5PacBufferType::LogonInfo.is_signature());
+}
+
+fn ndr_u32(buffer: &mut Vec<u8>, value: u32) {
+  buffer.resize(buffer.len().div_ceil(4) * 4, 0);
+  buffer.extend_from_slice(&value.to_le_bytes());
+}
+
+fn ndr_string_header(buffer: &mut Vec<u8>, string: &str) {
+  let length = (string.encode_utf16().count() * 2) as u16;
+  buffer.extend_from_slice(&length.to_le_bytes());
+  buffer.extend_from_slice(&length.to_le_bytes());
+  ndr_u32(buffer, if string.is_empty() { 0 } else { 0x0002_0000 });
+}
+
+fn ndr_string(buffer: &mut Vec<u8>, string: &str) {
+  if string.is_empty() {
+    return;
+  }
+
+  let count = string.encode_utf16().count() as u32;
+  ndr_u32(buffer, count);
+  ndr_u32(buffer, 0);
+  ndr_u32(buffer, count);
+  for unit in string.encode_utf16() {
+    buffer.extend_from_slice(&unit.to_le_bytes());
+  }
+}
+
+#[cfg(feature = "pac-logon-info")]
+fn ndr_sid(buffer: &mut Vec<u8>, authority: u8, sub_authorities: &[u32]) {
+  ndr_u32(buffer, sub_authorities.len() as u32);
+  buffer.extend_from_slice(&[1, sub_authorities.len() as u8, 0, 0, 0, 0, 0, authority]);
+  for sub_authority in sub_authorities {
+    ndr_u32(buffer, *sub_authority);
+  }
+}
+
+/**
+ * Encode a KERB_VALIDATION_INFO for alice in the domain S-1-5-21-1-2-3, with two domain groups
+ * and one extra SID.
+ */
+#[cfg(feature = "pac-logon-info")]
+fn encode_logon_info() -> Vec<u8> {
+  let mut buffer = vec![0x01, 0x10, 0x08, 0x00, 0xcc, 0xcc, 0xcc, 0xcc];
+  buffer.extend_from_slice(&[0; 8]);
+  ndr_u32(&mut buffer, 0x0002_0000);
+
+  for filetime in &[1u64, 2, 0x7FFF_FFFF_FFFF_FFFF, 3, 4, 5] {
+    ndr_u32(&mut buffer, *filetime as u32);
+    ndr_u32(&mut buffer, (*filetime >> 32) as u32);
+  }
+  for string in &["alice", "Alice Example", "", "", "", ""] {
+    ndr_string_header(&mut buffer, string);
+  }
+  buffer.extend_from_slice(&7u16.to_le_bytes()); // logon count
+  buffer.extend_from_slice(&1u16.to_le_bytes()); // bad password count
+  ndr_u32(&mut buffer, 1105); // user id
+  ndr_u32(&mut buffer, 513); // primary group id
+  ndr_u32(&mut buffer, 2); // group count
+  ndr_u32(&mut buffer, 0x0002_0004); // group ids
+  ndr_u32(&mut buffer, 0x20); // user flags
+  buffer.extend_from_slice(&[0; 16]); // user session key
+  ndr_string_header(&mut buffer, "DC1");
+  ndr_string_header(&mut buffer, "EXAMPLE");
+  ndr_u32(&mut buffer, 0x0002_0008); // logon domain id
+  buffer.extend_from_slice(&[0; 8]); // reserved
+  ndr_u32(&mut buffer, 0x10); // user account control
+  for _ in 0..7 {
+    ndr_u32(&mut buffer, 0); // sub auth status, interactive logons, reserved
+  }
+  ndr_u32(&mut buffer, 1); // sid count
+  ndr_u32(&mut buffer, 0x0002_000c); // extra sids
+  ndr_u32(&mut buffer, 0); // resource group domain sid
+  ndr_u32(&mut buffer, 0); // resource group count
+  ndr_u32(&mut buffer, 0); // resource group ids
+
+  ndr_string(&mut buffer, "alice");
+  ndr_string(&mut buffer, "Alice Example");
+  ndr_u32(&mut buffer, 2);
+  for rid in &[513, 1108] {
+    ndr_u32(&mut buffer, *rid);
+    ndr_u32(&mut buffer, 7);
+  }
+  ndr_string(&mut buffer, "DC1");
+  ndr_string(&mut buffer, "EXAMPLE");
+  ndr_sid(&mut buffer, 5, &[21, 1, 2, 3]);
+  ndr_u32(&mut buffer, 1);
+  ndr_u32(&mut buffer, 0x0002_0010);
+  ndr_u32(&mut buffer, 7);
+  ndr_sid(&mut buffer, 18, &[1]);
+
+  let length = (buffer.len() - 16) as u32;
+  buffer[8..12].copy_from_slice(&length.to_le_bytes());
+  buffer
+}
+
+#[cfg(feature = "pac-logon-info")]
+#[test]
+fn pac_logon_info() {
+  let context = Krb5Context::init().unwrap();
+
+  let logon_info = encode_logon_info();
+  let encoded = encode_pac(&[(libkrb5_sys::KRB5_PAC_LOGON_INFO, &logon_info)]);
+  let pac = Krb5Pac::parse(&context, &encoded).unwrap();
+  let info = pac.logon_info().unwrap();
+
+  assert_eq!(info.logon_time, 1);
+  assert_eq!(info.kick_off_time, 0x7FFF_FFFF_FFFF_FFFF);
+  assert_eq!(info.password_must_change, 5);
+  assert_eq!(info.effective_name, "alice");
+  assert_eq!(info.full_name, "Alice Example");
+  assert_eq!(info.logon_script, "");
+  assert_eq!(info.logon_count, 7);
+  assert_eq!(info.bad_password_count, 1);
+  assert_eq!(info.primary_group_id, 513);
+  assert_eq!(info.logon_server, "DC1");
+  assert_eq!(info.logon_domain_name, "EXAMPLE");
+  assert_eq!(info.user_account_control, 0x10);
+  assert_eq!(
+    info.groups,
+    vec![
+      Krb5PacGroup {
+        rid: 513,
+        attributes: 7
+      },
+      Krb5PacGroup {
+        rid: 1108,
+        attributes: 7
+      },
+    ]
+  );
+  assert_eq!(info.resource_group_domain_sid, None);
+
+  assert_eq!(info.user_sid().unwrap().to_string(), "S-1-5-21-1-2-3-1105");
+  let groups: Vec<String> = info.group_sids().iter().map(|sid| sid.to_string()).collect();
+  assert_eq!(groups, vec!["S-1-5-21-1-2-3-513", "S-1-5-21-1-2-3-1108", "S-1-18-1"]);
+
+  assert!(Krb5PacLogonInfo::decode(&logon_info[..logon_info.len() - 4]).is_err());
+  assert!(Krb5PacLogonInfo::decode(&[0; 20]).is_err());
+}
+
+#[test]
+fn ticket_authorization_data() {
+  let context = Krb5Context::init().unwrap();
+  let enctype = Krb5Enctype::Aes256CtsHmacSha1_96;
+  let service_key = Krb5Keyblock::new(&context, enctype, &[0x12; 32]).unwrap();
+  let session_key = Krb5Keyblock::new(&context, enctype, &[0x34; 32]).unwrap();
+
+  let components = [String::from("host"), String::from("example.com")];
+  let service = context.build_principal("EXAMPLE.COM", &components).unwrap();
+  let mut keytab = Krb5Keytab::memory(&context, "ticket_authorization_data").unwrap();
+  keytab.add_key(&service, 1, enctype, service_key.contents()).unwrap();
+  let acceptor = ServiceAcceptor::new(&context, keytab, Some(service), None);
+
+  // a signed PAC wrapped in AD-IF-RELEVANT, and an element that is not wrapped
+  let authtime = std::time::SystemTime::now()
+    .duration_since(std::time::UNIX_EPOCH)
+    .unwrap()
+    .as_secs() as i64
+    - 60;
+  let privsvr_key = Krb5Keyblock::new(&context, enctype, &[0x56; 32]).unwrap();
+  let user = [String::from("user")];
+  let user_principal = context.build_principal("EXAMPLE.COM", &user).unwrap();
+  let mut unsigned = Krb5Pac::new(&context).unwrap();
+  unsigned.add_buffer(Krb5PacBufferType::UpnDnsInfo, b"upn").unwrap();
+  let pac = unsigned
+    .sign(
+      Krb5Timestamp::from_raw(authtime as _),
+      &user_principal,
+      &service_key,
+      &privsvr_key,
+    )
+    .unwrap()
+    .as_bytes()
+    .to_vec();
+  let if_relevant = der_authdata(&[(128, &pac)]);
+  let authorization_data = der_authdata(&[(1, &if_relevant), (-1000, b"private")]);
+
+  let ticket = issue_ticket_at(&service_key, &session_key, authtime, Some(&authorization_data));
+  let creds = fake_creds(&context, &ticket, &session_key);
+  let mut client = Krb5AuthContext::init(&context).unwrap();
+  let token = client.mk_req_extended(&creds, Krb5ApOptions::empty(), None).unwrap();
+  let peer = acceptor.accept(&token).unwrap();
+
+  let authdata = peer.ticket.authorization_data().unwrap().unwrap();
+  assert_eq!(
+    authdata,
+    vec![
+      Krb5Authdata {
+        ad_type: Krb5AuthdataType::Win2kPac,
+        contents: pac.clone(),
+      },
+      Krb5Authdata {
+        ad_type: Krb5AuthdataType::Unknown(-1000),
+        contents: b"private".to_vec(),
+      },
+    ]
+  );
+
+  let pac = peer.ticket.pac(&service_key).unwrap().unwrap();
+  assert_eq!(pac.buffer(Krb5PacBufferType::UpnDnsInfo).unwrap(), b"upn");
+
+  // the server signature has to match the key of the service
+  let other_key = Krb5Keyblock::new(&context, enctype, &[0x78; 32]).unwrap();
+  assert!(peer.ticket.pac(&other_key).is_err());
+
+  // a PAC signed for another authentication time is rejected
+  let mut unsigned = Krb5Pac::new(&context).unwrap();
+  unsigned.add_buffer(Krb5PacBufferType::UpnDnsInfo, b"upn").unwrap();
+  let stale = unsigned
+    .sign(
+      Krb5Timestamp::from_raw((authtime - 3600) as _),
+      &user_principal,
+      &service_key,
+      &privsvr_key,
+    )
+    .unwrap();
+  let authorization_data = der_authdata(&[(1, &der_authdata(&[(128, stale.as_bytes())]))]);
+  let ticket = issue_ticket_at(&service_key, &session_key, authtime, Some(&authorization_data));
+  let creds = fake_creds(&context, &ticket, &session_key);
+  let mut client_context = Krb5AuthContext::init(&context).unwrap();
+  let token = client_context
+    .mk_req_extended(&creds, Krb5ApOptions::empty(), None)
+    .unwrap();
+  let stale_peer = acceptor.accept(&token).unwrap();
+  assert!(stale_peer.ticket.pac(&service_key).is_err());
+
+  // the container itself can be decoded as well
+  let container = Krb5Authdata {
+    ad_type: Krb5AuthdataType::IfRelevant,
+    contents: if_relevant,
+  };
+  assert_eq!(
+    container.decode_container(&context).unwrap()[0].ad_type,
+    Krb5AuthdataType::Win2kPac
+  );
+  assert_eq!(Krb5AuthdataType::from(128), Krb5AuthdataType::Win2kPac);
+  assert_eq!(Krb5AuthdataType::SignTicket.as_raw(), 512);
+
+  // a ticket without authorization data has no PAC
+  let ticket = issue_ticket(&service_key, &session_key, None);
+  let creds = fake_creds(&context, &ticket, &session_key);
+  let mut client = Krb5AuthContext::init(&context).unwrap();
+  let token = client.mk_req_extended(&creds, Krb5ApOptions::empty(), None).unwrap();
+  let peer = acceptor.accept(&token).unwrap();
+  assert_eq!(peer.ticket.authorization_data().unwrap().unwrap(), vec![]);
+  assert!(peer.ticket.pac(&service_key).unwrap().is_none());
+}
+
+#[test]
+fn pac_sign() {
+  let context = Krb5Context::init().unwrap();
+  let enctype = Krb5Enctype::Aes256CtsHmacSha1_96;
+  let server_key = Krb5Keyblock::new(&context, enctype, &[0x12; 32]).unwrap();
+  let privsvr_key = Krb5Keyblock::new(&context, enctype, &[0x34; 32]).unwrap();
+  let user = [String::from("user")];
+  let client = context.build_principal("EXAMPLE.COM", &user).unwrap();
+  let authtime = Krb5Timestamp::from_raw(1_600_000_000);
+
+  let mut pac = Krb5Pac::new(&context).unwrap();
+  assert!(pac.buffer_types().unwrap().is_empty());
+  pac.add_buffer(Krb5PacBufferType::UpnDnsInfo, b"upn").unwrap();
+  let encoded = pac.sign(authtime, &client, &server_key, &privsvr_key).unwrap();
+
+  let parsed = Krb5Pac::parse(&context, &encoded).unwrap();
+  let types = parsed.buffer_types().unwrap();
+  assert!(types.contains(&Krb5PacBufferType::UpnDnsInfo));
+  assert!(types.contains(&Krb5PacBufferType::ClientInfo));
+  assert!(types.contains(&Krb5PacBufferType::ServerChecksum));
+  assert!(types.contains(&Krb5PacBufferType::PrivsvrChecksum));
+  assert_eq!(parsed.buffer(Krb5PacBufferType::UpnDnsInfo).unwrap(), b"upn");
+
+  // both signatures verify with the keys the PAC was signed with
+  parsed
+    .verify(authtime, Some(&client), &server_key, Some(&privsvr_key))
+    .unwrap();
+  parsed.verify(authtime, None, &server_key, None).unwrap();
+
+  let wrong_key = Krb5Keyblock::new(&context, enctype, &[0x56; 32]).unwrap();
+  assert!(parsed.verify(authtime, Some(&client), &wrong_key, None).is_err());
+  assert!(parsed
+    .verify(authtime, Some(&client), &server_key, Some(&wrong_key))
+    .is_err());
+  assert!(parsed
+    .verify(authtime, Some(&client), &privsvr_key, Some(&server_key))
+    .is_err());
+
+  // the client info has to match the ticket
+  let later = Krb5Timestamp::from_raw(1_600_000_001);
+  assert!(parsed.verify(later, Some(&client), &server_key, None).is_err());
+
+  // the client info has to match when signing again
+  let other_user = [String::from("other")];
+  let other = context.build_principal("EXAMPLE.COM", &other_user).unwrap();
+  assert!(parsed.verify(authtime, Some(&other), &server_key, None).is_err());
+  let mut resigned = Krb5Pac::parse(&context, &encoded).unwrap();
+  assert!(resigned.sign(authtime, &other, &server_key, &privsvr_key).is_err());
+}
+
+#[test]
+fn pac_delegation_info() {
+  let context = Krb5Context::init().unwrap();
+
+  let mut info = vec![0x01, 0x10, 0x08, 0x00, 0xcc, 0xcc, 0xcc, 0xcc];
+  info.extend_from_slice(&[0; 8]);
+  ndr_u32(&mut info, 0x0002_0000);
+  ndr_string_header(&mut info, "cifs/fs.example.com");
+  ndr_u32(&mut info, 2); // transited list size
+  ndr_u32(&mut info, 0x0002_0004); // transited services
+  ndr_string(&mut info, "cifs/fs.example.com");
+  ndr_u32(&mut info, 2);
+  ndr_string_header(&mut info, "http/web.example.com@EXAMPLE.COM");
+  ndr_string_header(&mut info, "http/gw.example.com@EXAMPLE.COM");
+  ndr_string(&mut info, "http/web.example.com@EXAMPLE.COM");
+  ndr_string(&mut info, "http/gw.example.com@EXAMPLE.COM");
+
+  let encoded = encode_pac(&[(libkrb5_sys::KRB5_PAC_DELEGATION_INFO, &info)]);
+  let pac = Krb5Pac::parse(&context, &encoded).unwrap();
+  let delegation_info = pac.delegation_info().unwrap().unwrap();
+  assert_eq!(delegation_info.proxy_target, "cifs/fs.example.com");
+  assert_eq!(
+    delegation_info.transited_services,
+    vec!["http/web.example.com@EXAMPLE.COM", "http/gw.example.com@EXAMPLE.COM"]
+  );
+
+  assert!(Krb5PacDelegationInfo::decode(&info[..info.len() - 8]).is_err());
+
+  let encoded = encode_pac(&[(libkrb5_sys::KRB5_PAC_CLIENT_INFO, b"client")]);
+  let pac = Krb5Pac::parse(&context, &encoded).unwrap();
+  assert!(pac.delegation_info().unwrap().is_none());
+}
+
+#[test]
+fn authdata_containers() {
+  let context = Krb5Context::init().unwrap();
+  let enctype = Krb5Enctype::Aes256CtsHmacSha1_96;
+  let key = Krb5Keyblock::new(&context, enctype, &[0x56; 32]).unwrap();
+
+  let elements = vec![
+    Krb5Authdata {
+      ad_type: Krb5AuthdataType::Unknown(-1000),
+      contents: b"token".to_vec(),
+    },
+    Krb5Authdata {
+      ad_type: Krb5AuthdataType::AuthIndicator,
+      contents: Vec::new(),
+    },
+  ];
+
+  assert_eq!(Krb5AuthdataType::Unknown(128), Krb5AuthdataType::Win2kPac);
+  assert_ne!(Krb5AuthdataType::Unknown(-1000), Krb5AuthdataType::Unknown(-1001));
+  let types: std::collections::HashSet<_> = [Krb5AuthdataType::Unknown(128), Krb5AuthdataType::Win2kPac].into();
+  assert_eq!(types.len(), 1);
+
+  let container = Krb5Authdata::encode_container(&context, Krb5AuthdataType::IfRelevant, &elements).unwrap();
+  assert_eq!(container.ad_type, Krb5AuthdataType::IfRelevant);
+  assert_eq!(container.decode_container(&context).unwrap(), elements);
+
+  let issuer_components = [String::from("token-service")];
+  let issuer = context.build_principal("EXAMPLE.COM", &issuer_components).unwrap();
+  let kdc_issued = Krb5Authdata::make_kdc_issued(&context, &key, Some(&issuer), &elements).unwrap();
+  assert_eq!(kdc_issued.ad_type, Krb5AuthdataType::KdcIssued);
+
+  let (verified_issuer, verified) = kdc_issued.verify_kdc_issued(&context, &key).unwrap();
+  assert_eq!(
+    verified_issuer.unwrap().unparse_name().unwrap(),
+    "token-service@EXAMPLE.COM"
+  );
+  assert_eq!(verified, elements);
+
+  let (verified_issuer, _) = Krb5Authdata::make_kdc_issued(&context, &key, None, &elements)
+    .unwrap()
+    .verify_kdc_issued(&context, &key)
+    .unwrap();
+  assert!(verified_issuer.is_none());
+
+  // a different key fails the verification
+  let other_key = Krb5Keyblock::new(&context, enctype, &[0x57; 32]).unwrap();
+  assert!(kdc_issued.verify_kdc_issued(&context, &other_key).is_err());
+}
+
+#[test]
+fn ticket_decode() {
+  let context = Krb5Context::init().unwrap();
+
+  let encoded = der_ticket("EXAMPLE.COM", &["HTTP", "www.example.com"], 18, Some(3), &[0u8; 64]);
+  let ticket = Krb5Ticket::decode(&context, &encoded).unwrap();
+  assert_eq!(
+    ticket.server().unwrap().unparse_name().unwrap(),
+    "HTTP/www.example.com@EXAMPLE.COM"
+  );
+  assert_eq!(ticket.enctype(), Krb5Enctype::Aes256CtsHmacSha1_96);
+  assert_eq!(ticket.kvno(), 3);
+  assert!(ticket.client().unwrap().is_none());
+  assert!(ticket.authorization_data().unwrap().is_none());
+
+  let encoded = der_ticket("EXAMPLE.COM", &["host", "example.com"], 17, None, &[0u8; 32]);
+  let ticket = Krb5Ticket::decode(&context, &encoded).unwrap();
+  assert_eq!(ticket.enctype(), Krb5Enctype::Aes128CtsHmacSha1_96);
+  assert_eq!(ticket.kvno(), 0);
+
+  assert!(Krb5Ticket::decode(&context, b"not a ticket").is_err());
+}
+
+#[test]
+fn ticket_decrypted_accessors() {
+  let context = Krb5Context::init().unwrap();
+  let enctype = Krb5Enctype::Aes256CtsHmacSha1_96;
+  let service_key = Krb5Keyblock::new(&context, enctype, &[0x12; 32]).unwrap();
+  let session_key = Krb5Keyblock::new(&context, enctype, &[0x34; 32]).unwrap();
+
+  let components = [String::from("host"), String::from("example.com")];
+  let service = context.build_principal("EXAMPLE.COM", &components).unwrap();
+  let mut keytab = Krb5Keytab::memory(&context, "ticket_decrypted_accessors").unwrap();
+  keytab.add_key(&service, 1, enctype, service_key.contents()).unwrap();
+  let acceptor = ServiceAcceptor::new(&context, keytab, Some(service), None);
+
+  let encoded = issue_ticket(&service_key, &session_key, None);
+
+  // the accessors of the encrypted part are not available before decryption
+  let ticket = Krb5Ticket::decode(&context, &encoded).unwrap();
+  assert!(ticket.session_key().unwrap().is_none());
+  assert!(ticket.ticket_flags().is_none());
+  assert!(ticket.transited().is_none());
+  assert!(ticket.end_time().is_none());
+  assert!(ticket.addresses().is_none());
+
+  let creds = fake_creds(&context, &encoded, &session_key);
+  let mut client = Krb5AuthContext::init(&context).unwrap();
+  let token = client.mk_req_extended(&creds, Krb5ApOptions::empty(), None).unwrap();
+  let peer = acceptor.accept(&token).unwrap();
+
+  // the copy owns the decrypted part as well
+  let ticket = peer.ticket.try_clone().unwrap();
+  drop(peer);
+
+  assert_eq!(
+    ticket.session_key().unwrap().unwrap().contents(),
+    session_key.contents()
+  );
+  assert_eq!(ticket.ticket_flags(), Some(0));
+  assert_eq!(ticket.transited(), Some(&b""[..]));
+  assert_eq!(ticket.addresses(), Some(vec![]));
+  assert!(ticket.start_time().unwrap().is_unset());
+  assert!(ticket.renew_till().unwrap().is_unset());
+  assert_eq!(
+    ticket.end_time().unwrap().as_raw() - ticket.auth_time().unwrap().as_raw(),
+    3660
+  );
+  assert_eq!(ticket.kvno(), 1);
+  assert_eq!(ticket.enctype(), enctype);
+}
+
+#[test]
+fn ticket_from_ap_req() {
+  let context = Krb5Context::init().unwrap();
+  let enctype = Krb5Enctype::Aes256CtsHmacSha1_96;
+  let service_key = Krb5Keyblock::new(&context, enctype, &[0x12; 32]).unwrap();
+  let session_key = Krb5Keyblock::new(&context, enctype, &[0x34; 32]).unwrap();
+
+  let encoded = issue_ticket(&service_key, &session_key, None);
+  let creds = fake_creds(&context, &encoded, &session_key);
+  let mut client = Krb5AuthContext::init(&context).unwrap();
+  let token = client.mk_req_extended(&creds, Krb5ApOptions::empty(), None).unwrap();
+
+  // the key can be selected without decrypting the ticket
+  let ticket = Krb5Ticket::from_ap_req(&context, &token).unwrap();
+  assert_eq!(
+    ticket.server().unwrap().unparse_name().unwrap(),
+    "host/example.com@EXAMPLE.COM"
+  );
+  assert_eq!(ticket.enctype(), enctype);
+  assert_eq!(ticket.kvno(), 1);
+  assert!(ticket.client().unwrap().is_none());
+
+  match Krb5Ticket::from_ap_req(&context, &encoded) {
+    Err(Krb5Error::LibraryError { code, .. }) => assert_eq!(code, libkrb5_sys::KRB5KRB_AP_ERR_MSG_TYPE),
+    other => panic!("unexpected result: {:?}", other),
+  }
+  assert!(Krb5Ticket::from_ap_req(&context, &token[..token.len() / 2]).is_err());
+}
+
+#[test]
+fn der_elements() {
+  use crate::der::der_element;
+
+  assert_eq!(der_element(&[0x04, 2, 1, 2, 3]), Some((0x04, &[1, 2][..], &[3][..])));
+  let long = [&[0x30, 0x81, 0x80][..], &[0; 128]].concat();
+  assert_eq!(der_element(&long), Some((0x30, &[0; 128][..], &[][..])));
+
+  assert_eq!(der_element(&[0x04, 3, 1, 2]), None);
+  assert_eq!(der_element(&[0x30, 0x80, 0, 0]), None);
+  assert_eq!(der_element(&[0x30, 0x82, 1]), None);
+  assert_eq!(der_element(&[0x30]), None);
+}
+
+#[test]
+fn ccache_get_service_ticket() {
+  let context = Krb5Context::init().unwrap();
+  let enctype = Krb5Enctype::Aes256CtsHmacSha1_96;
+  let service_key = Krb5Keyblock::new(&context, enctype, &[0x12; 32]).unwrap();
+  let session_key = Krb5Keyblock::new(&context, Krb5Enctype::Aes128CtsHmacSha1_96, &[0x34; 16]).unwrap();
+
+  let ticket = issue_ticket(&service_key, &session_key, None);
+  let creds = fake_creds(&context, &ticket, &session_key);
+
+  // credentials in the credential cache are returned without contacting the KDC
+  let mut ccache = Krb5CCache::new_unique(&context, "MEMORY").unwrap();
+  ccache.initialize(&creds.client().unwrap()).unwrap();
+  ccache.store_cred(&creds).unwrap();
+
+  let service = creds.server().unwrap();
+  let cached = ccache.get_credentials(&service).unwrap();
+  assert_eq!(cached.ticket(), &ticket[..]);
+
+  let info = ccache.get_service_ticket(&service).unwrap();
+  assert_eq!(info.kvno, 1);
+  assert_eq!(info.enctype, enctype);
+  assert_eq!(info.session_enctype, Krb5Enctype::Aes128CtsHmacSha1_96);
+  assert_eq!(info.end_time.as_raw(), i32::MAX);
+
+  let uninitialized = Krb5CCache::new_unique(&context, "MEMORY").unwrap();
+  assert!(uninitialized.get_service_ticket(&service).is_err());
+}
+
+#[test]
+fn ccache_credentials() {
+  let context = Krb5Context::init().unwrap();
+  let session_key = Krb5Keyblock::new(&context, Krb5Enctype::Aes256CtsHmacSha1_96, &[0x11; 32]).unwrap();
+  let creds = fake_creds(&context, b"ticket", &session_key);
+
+  let mut ccache = Krb5CCache::new_unique(&context, "MEMORY").unwrap();
+  ccache.initialize(&creds.client().unwrap()).unwrap();
+  assert_eq!(ccache.credentials().unwrap().count(), 0);
+
+  ccache.store_cred(&creds).unwrap();
+  let stored: Vec<Krb5Creds> = ccache.credentials().unwrap().map(Result::unwrap).collect();
+  assert_eq!(stored.len(), 1);
+  assert_eq!(stored[0].ticket(), b"ticket");
+  assert_eq!(
+    stored[0].server().unwrap().unparse_name().unwrap(),
+    "host/example.com@EXAMPLE.COM"
+  );
+
+  // a credential cache file that does not exist can not be read
+  let missing = Krb5CCache::resolve(&context, "FILE:/nonexistent/krb5cc_test").unwrap();
+  assert!(missing.credentials().is_err());
+}
+
+#[cfg(krb5_mit)]
+#[test]
+fn ccache_dup() {
+  let context = Krb5Context::init().unwrap();
+  let session_key = Krb5Keyblock::new(&context, Krb5Enctype::Aes256CtsHmacSha1_96, &[0x11; 32]).unwrap();
+  let creds = fake_creds(&context, b"ticket", &session_key);
+
+  let mut ccache = Krb5CCache::new_unique(&context, "MEMORY").unwrap();
+  ccache.initialize(&creds.client().unwrap()).unwrap();
+
+  // the duplicate refers to the same credential cache, and outlives the original handle
+  let dup = ccache.dup().unwrap();
+  assert_eq!(dup.get_name().unwrap(), ccache.get_name().unwrap());
+  ccache.store_cred(&creds).unwrap();
+  drop(ccache);
+  assert_eq!(dup.credentials().unwrap().count(), 1);
+}
+
+#[test]
+fn flavor() {
+  let flavor = Krb5Flavor::current();
+  assert_eq!(flavor == Krb5Flavor::Mit, cfg!(krb5_mit));
+  assert_eq!(flavor.to_string(), if cfg!(krb5_mit) { "MIT" } else { "Heimdal" });
+}
+
+#[test]
+fn library_version() {
+  let context = Krb5Context::init().unwrap();
+
+  if let Some(version) = context.library_version() {
+    assert!(version.starts_with(|c: char| c.is_ascii_digit()), "{}", version);
+  }
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn ccache_streams() {
+  use futures_core::Stream;
+  use std::pin::Pin;
+
+  let context = Krb5Context::init().unwrap();
+  let session_key = Krb5Keyblock::new(&context, Krb5Enctype::Aes256CtsHmacSha1_96, &[0x11; 32]).unwrap();
+  let creds = fake_creds(&context, b"ticket", &session_key);
+  let mut ccache = Krb5CCache::new_unique(&context, "MEMORY").unwrap();
+  ccache.initialize(&creds.client().unwrap()).unwrap();
+  ccache.store_cred(&creds).unwrap();
+
+  let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+  runtime.block_on(async {
+    let mut stream = ccache.credentials().unwrap();
+    let first = std::future::poll_fn(|cx| Pin::new(&mut stream).poll_next(cx)).await;
+    assert_eq!(first.unwrap().unwrap().ticket(), b"ticket");
+    assert!(std::future::poll_fn(|cx| Pin::new(&mut stream).poll_next(cx))
+      .await
+      .is_none());
+
+    let mut collection = Krb5CCCol::new(&context).unwrap();
+    while let Some(ccache) = std::future::poll_fn(|cx| Pin::new(&mut collection).poll_next(cx)).await {
+      ccache.unwrap();
+    }
+  });
+}
+
+#[test]
+fn ccache_renew_without_kdc() {
+  let context = Krb5Context::init().unwrap();
+  let session_key = Krb5Keyblock::new(&context, Krb5Enctype::Aes256CtsHmacSha1_96, &[0x11; 32]).unwrap();
+  let creds = fake_creds(&context, b"ticket", &session_key);
+
+  let mut uninitialized = Krb5CCache::new_unique(&context, "MEMORY").unwrap();
+  assert!(uninitialized.renew().is_err());
+
+  // the credential cache has no ticket granting ticket, and the realm has no KDC
+  let mut ccache = Krb5CCache::new_unique(&context, "MEMORY").unwrap();
+  ccache.initialize(&creds.client().unwrap()).unwrap();
+  ccache.store_cred(&creds).unwrap();
+  assert!(ccache.renew().is_err());
+  assert!(ccache.get_credentials(&creds.server().unwrap()).is_ok());
+}
+
+/**
+ * Create credentials with a fake ticket granting ticket for the client of [fake_creds].
+ */
+#[cfg(feature = "async")]
+fn fake_tgt(context: &Krb5Context, end_time: i32) -> Krb5Creds<'_> {
+  let session_key = Krb5Keyblock::new(context, Krb5Enctype::Aes256CtsHmacSha1_96, &[0x56; 32]).unwrap();
+  let mut creds = fake_creds(context, b"ticket", &session_key);
+
+  let components = [String::from("krbtgt"), String::from("EXAMPLE.COM")];
+  let server = context.build_principal("EXAMPLE.COM", &components).unwrap();
+  unsafe {
+    libkrb5_sys::krb5_free_principal(context.context, creds.creds.server);
+    creds.creds.server = server.principal;
+    std::mem::forget(server);
+  }
+  creds.creds.times.endtime = end_time;
+
+  creds
+}
+
+#[cfg(all(feature = "async", krb5_mit, krb5_1_15))]
+#[test]
+fn async_kdc_transport() {
+  use std::sync::{Arc, Mutex};
+
+  struct Recorder(Arc<Mutex<Vec<String>>>);
+
+  impl Krb5KdcTransport for Recorder {
+    fn send<'a>(&'a self, realm: &'a str, _request: &'a [u8]) -> Krb5KdcReplyFuture<'a> {
+      Box::pin(async move {
+        tokio::task::yield_now().await;
+        self.0.lock().unwrap().push(String::from(realm));
+        Err(Krb5Error::LibraryError {
+          code: codes::KRB5_KDC_UNREACH,
+          message: String::from("no route to the proxy"),
+        })
+      })
+    }
+  }
+
+  let realms = Arc::new(Mutex::new(Vec::new()));
+  let client = Krb5AsyncClient::with_context(|| {
+    Krb5Context::init()?.with_profile_values(&["realms", "EXAMPLE.COM", "kdc"], &["kdc.example.com"])
+  })
+  .with_transport(Recorder(Arc::clone(&realms)));
+
+  let context = Krb5Context::init().unwrap();
+  let ccache = Krb5CCache::new_unique(&context, "MEMORY").unwrap();
+  let name = format!("MEMORY:{}", ccache.get_name().unwrap().to_string_lossy());
+
+  let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+  let options = Krb5InitCredsOptions::default();
+  let error = runtime
+    .block_on(client.get_init_creds_password(&name, "user@EXAMPLE.COM", String::from("password"), &options))
+    .unwrap_err();
+  assert!(error.is_kdc_unreachable());
+  assert_eq!(realms.lock().unwrap()[0], "EXAMPLE.COM");
+}
+
+#[cfg(all(feature = "async", krb5_mit, krb5_1_15))]
+#[test]
+fn async_client_keeps_context_kdc_send_hook() {
+  use std::sync::atomic::{AtomicUsize, Ordering};
+  use std::sync::Arc;
+
+  struct Passthrough(Arc<AtomicUsize>);
+
+  impl Krb5KdcTransport for Passthrough {
+    fn send<'a>(&'a self, _realm: &'a str, _request: &'a [u8]) -> Krb5KdcReplyFuture<'a> {
+      self.0.fetch_add(1, Ordering::SeqCst);
+      Box::pin(async { Ok(None) })
+    }
+  }
+
+  let hooked = Arc::new(AtomicUsize::new(0));
+  let counter = Arc::clone(&hooked);
+  let new_context = move || {
+    let mut context =
+      Krb5Context::init()?.with_profile_values(&["realms", "EXAMPLE.COM", "kdc"], &["kdc.example.com"])?;
+    let counter = Arc::clone(&counter);
+    context.set_kdc_send_hook(move |_realm, _request| {
+      counter.fetch_add(1, Ordering::SeqCst);
+      Err(Krb5Error::LibraryError {
+        code: codes::KRB5_KDC_UNREACH,
+        message: String::from("sent by the hook of the context"),
+      })
+    });
+    Ok(context)
+  };
+
+  let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+  let change_password = |client: &Krb5AsyncClient| {
+    runtime
+      .block_on(client.change_password("user@EXAMPLE.COM", String::from("old"), String::from("new")))
+      .unwrap_err()
+  };
+
+  // without a transport or a timeout, the hook of the context is left alone
+  let client = Krb5AsyncClient::with_context(new_context.clone());
+  assert!(change_password(&client).is_kdc_unreachable());
+  assert!(hooked.load(Ordering::SeqCst) > 0);
+
+  // requests the transport does not answer are passed on to the hook of the context
+  hooked.store(0, Ordering::SeqCst);
+  let transported = Arc::new(AtomicUsize::new(0));
+  let client = Krb5AsyncClient::with_context(new_context).with_transport(Passthrough(Arc::clone(&transported)));
+  assert!(change_password(&client).is_kdc_unreachable());
+  assert!(transported.load(Ordering::SeqCst) > 0);
+  assert_eq!(hooked.load(Ordering::SeqCst), transported.load(Ordering::SeqCst));
+}
+
+#[cfg(all(feature = "async", krb5_mit, krb5_1_15))]
+#[test]
+fn async_client_timeout() {
+  use std::time::Duration;
+
+  struct Unresponsive;
+
+  impl Krb5KdcTransport for Unresponsive {
+    fn send<'a>(&'a self, _realm: &'a str, _request: &'a [u8]) -> Krb5KdcReplyFuture<'a> {
+      Box::pin(std::future::pending())
+    }
+  }
+
+  let client = Krb5AsyncClient::with_context(|| {
+    Krb5Context::init()?.with_profile_values(&["realms", "EXAMPLE.COM", "kdc"], &["kdc.example.com"])
+  })
+  .with_transport(Unresponsive)
+  .with_timeout(Duration::from_millis(50));
+
+  let context = Krb5Context::init().unwrap();
+  let ccache = Krb5CCache::new_unique(&context, "MEMORY").unwrap();
+  let name = format!("MEMORY:{}", ccache.get_name().unwrap().to_string_lossy());
+
+  // dropping the runtime waits for the blocking requests, which only finish once they notice
+  // the cancellation
+  let runtime = tokio::runtime::Builder::new_current_thread()
+    .enable_time()
+    .build()
+    .unwrap();
+  let options = Krb5InitCredsOptions::default();
+  let error = runtime
+    .block_on(client.get_init_creds_password(&name, "user@EXAMPLE.COM", String::from("password"), &options))
+    .unwrap_err();
+  assert!(matches!(&error, Krb5Error::Io { error } if error.kind() == std::io::ErrorKind::TimedOut));
+
+  let error = runtime
+    .block_on(client.change_password("user@EXAMPLE.COM", String::from("old"), String::from("new")))
+    .unwrap_err();
+  assert!(matches!(&error, Krb5Error::Io { error } if error.kind() == std::io::ErrorKind::TimedOut));
+  drop(runtime);
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn ticket_renewal_task() {
+  use std::time::Duration;
+
+  let context = Krb5Context::init().unwrap();
+  let runtime = tokio::runtime::Builder::new_current_thread()
+    .enable_time()
+    .build()
+    .unwrap();
+
+  let store_tgt = |end_time: i32| {
+    let creds = fake_tgt(&context, end_time);
+    let mut ccache = Krb5CCache::new_unique(&context, "MEMORY").unwrap();
+    ccache.initialize(&creds.client().unwrap()).unwrap();
+    ccache.store_cred(&creds).unwrap();
+    format!("MEMORY:{}", ccache.get_name().unwrap().to_string_lossy())
+  };
+
+  // a ticket that does not expire soon is left alone
+  let valid = store_tgt(i32::MAX);
+  let task = TicketRenewalTask::new(Krb5AsyncClient::new(), &valid);
+  let mut state = task.subscribe();
+  assert_eq!(*state.borrow(), TicketRenewalState::Pending);
+  runtime.spawn(task.run());
+  runtime.block_on(state.changed()).unwrap();
+  assert_eq!(
+    *state.borrow(),
+    TicketRenewalState::Valid {
+      end_time: Krb5Timestamp::from_raw(i32::MAX),
+      renew_till: Krb5Timestamp::default(),
+    }
+  );
+
+  // a ticket that expires soon and is not renewable can not be refreshed without a key table
+  let end_time = Krb5Timestamp::now(&context).unwrap().as_raw() + 60;
+  let expiring = store_tgt(end_time);
+  let task = TicketRenewalTask::new(Krb5AsyncClient::new(), &expiring).with_refresh_before(Duration::from_secs(120));
+  let state = runtime.block_on(task.refresh());
+  assert!(matches!(state, TicketRenewalState::Failed { .. }));
+  assert_eq!(state.end_time(), Some(Krb5Timestamp::from_raw(end_time)));
+  assert_eq!(*task.subscribe().borrow(), state);
+
+  let task = TicketRenewalTask::new(Krb5AsyncClient::new(), &expiring).with_refresh_before(Duration::from_secs(30));
+  assert!(matches!(
+    runtime.block_on(task.refresh()),
+    TicketRenewalState::Valid { .. }
+  ));
+
+  // the key table has no key for the principal
+  let keytab = Krb5Keytab::memory(&context, "ticket_renewal_task").unwrap();
+  let keytab_name = format!("MEMORY:{}", keytab.get_name().unwrap().to_string_lossy());
+  let empty = Krb5CCache::new_unique(&context, "MEMORY").unwrap();
+  let empty = format!("MEMORY:{}", empty.get_name().unwrap().to_string_lossy());
+  let task = TicketRenewalTask::new(Krb5AsyncClient::new(), &empty);
+  let state = runtime.block_on(task.refresh());
+  assert!(matches!(state, TicketRenewalState::Failed { end_time: None, .. }));
+  let task = task.with_keytab(Some(&keytab_name), "user@EXAMPLE.COM", &Krb5InitCredsOptions::default());
+  assert!(matches!(
+    runtime.block_on(task.refresh()),
+    TicketRenewalState::Failed { end_time: None, .. }
+  ));
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn async_client() {
+  let context = Krb5Context::init().unwrap();
+  let service_key = Krb5Keyblock::new(&context, Krb5Enctype::Aes256CtsHmacSha1_96, &[0x12; 32]).unwrap();
+  let session_key = Krb5Keyblock::new(&context, Krb5Enctype::Aes128CtsHmacSha1_96, &[0x34; 16]).unwrap();
+
+  let ticket = issue_ticket(&service_key, &session_key, None);
+  let creds = fake_creds(&context, &ticket, &session_key);
+
+  // memory credential caches are shared by the contexts of a process
+  let mut ccache = Krb5CCache::new_unique(&context, "MEMORY").unwrap();
+  ccache.initialize(&creds.client().unwrap()).unwrap();
+  ccache.store_cred(&creds).unwrap();
+  let name = format!("MEMORY:{}", ccache.get_name().unwrap().to_string_lossy());
+  let service = creds.server().unwrap().unparse_name().unwrap();
+
+  let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+  let client = Krb5AsyncClient::new();
+
+  let info = runtime.block_on(client.get_credentials(&name, &service)).unwrap();
+  assert_eq!(info.kvno, 1);
+  assert_eq!(info.session_enctype, Krb5Enctype::Aes128CtsHmacSha1_96);
+
+  assert!(runtime
+    .block_on(client.get_credentials(&name, "not@@a principal"))
+    .is_err());
+
+  // there is no KDC for the realm
+  let options = Krb5InitCredsOptions::default();
+  let result =
+    runtime.block_on(client.get_init_creds_password(&name, "user@EXAMPLE.COM", String::from("password"), &options));
+  assert!(result.is_err());
+
+  let secure = Krb5AsyncClient::with_context(Krb5Context::init_secure);
+  assert!(runtime
+    .block_on(secure.run(|context| context.get_default_realm()))
+    .is_ok());
+}
@@ -0,0 +1,302 @@
+/*!
+ * Rustic wrapper for krb5 tickets.
+ */
+use std::mem::MaybeUninit;
+use std::net::IpAddr;
+
+use libkrb5_sys::*;
+
+use crate::address::addresses_to_ips;
+use crate::authdata::{authdata_list_from_raw, unwrap_if_relevant, Krb5Authdata, Krb5AuthdataType};
+use crate::context::Krb5Context;
+use crate::data::{krb5_data_as_slice, slice_to_krb5_data};
+use crate::der::der_element;
+use crate::enctype::Krb5Enctype;
+use crate::error::{krb5_error_code_escape_hatch, Krb5Error};
+use crate::keyblock::Krb5Keyblock;
+use crate::pac::Krb5Pac;
+use crate::principal::{copy_principal, Krb5Principal};
+use crate::timestamp::Krb5Timestamp;
+
+/**
+ * Wrapper struct for a krb5 ticket, which owns the ticket and frees it when dropped.
+ *
+ * https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/types/krb5_ticket.html
+ */
+#[derive(Debug)]
+pub struct Krb5Ticket<'a> {
+  pub(crate) context: &'a Krb5Context,
+  pub(crate) ticket: *mut krb5_ticket,
+}
+
+/**
+ * Free a ticket.
+ *
+ * [krb5_free_ticket](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_free_ticket.html)
+ */
+impl<'a> Drop for Krb5Ticket<'a> {
+  fn drop(&mut self) {
+    unsafe {
+      krb5_free_ticket(self.context.context, self.ticket);
+    }
+  }
+}
+
+impl<'a> Krb5Ticket<'a> {
+  /**
+   * Decode a DER encoded ticket, e.g. from an AP-REQ or from credentials. The encrypted part is
+   * not decrypted.
+   *
+   * # Arguments
+   *
+   *  * context: the Krb5Context instance
+   *  * data: the encoded ticket
+   */
+  pub fn decode(context: &'a Krb5Context, data: &[u8]) -> Result<Krb5Ticket<'a>, Krb5Error> {
+    let data = slice_to_krb5_data(data);
+    let mut ticket: MaybeUninit<*mut krb5_ticket> = MaybeUninit::zeroed();
+
+    let code: krb5_error_code = unsafe { krb5_decode_ticket(&data, ticket.as_mut_ptr()) };
+
+    krb5_error_code_escape_hatch(context, code)?;
+
+    Ok(Krb5Ticket {
+      context,
+      ticket: unsafe { ticket.assume_init() },
+    })
+  }
+
+  /**
+   * Decode the ticket of an AP request without decrypting it, e.g. to select the service key
+   * by [Krb5Ticket::server], [Krb5Ticket::enctype] and [Krb5Ticket::kvno] before calling
+   * [crate::Krb5AuthContext::rd_req], or to report which key version is missing from a key
+   * table.
+   *
+   * # Arguments
+   *
+   *  * context: the Krb5Context instance
+   *  * ap_req: the encoded AP request, without a GSS-API token header
+   */
+  pub fn from_ap_req(context: &'a Krb5Context, ap_req: &[u8]) -> Result<Krb5Ticket<'a>, Krb5Error> {
+    let ticket = ap_req_ticket(ap_req).ok_or_else(|| Krb5Error::LibraryError {
+      code: KRB5KRB_AP_ERR_MSG_TYPE,
+      message: String::from("Not an AP request"),
+    })?;
+
+    Krb5Ticket::decode(context, ticket)
+  }
+
+  /**
+   * Create a copy of the ticket, including the decrypted part if the ticket has been decrypted.
+   *
+   * [krb5_copy_ticket](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_copy_ticket.html)
+   */
+  pub fn try_clone(&self) -> Result<Krb5Ticket<'a>, Krb5Error> {
+    let mut ticket: MaybeUninit<*mut krb5_ticket> = MaybeUninit::zeroed();
+
+    let code: krb5_error_code = unsafe { krb5_copy_ticket(self.context.context, self.ticket, ticket.as_mut_ptr()) };
+
+    krb5_error_code_escape_hatch(self.context, code)?;
+
+    Ok(Krb5Ticket {
+      context: self.context,
+      ticket: unsafe { ticket.assume_init() },
+    })
+  }
+
+  /**
+   * Return a copy of the client principal of the ticket, or `None` if the ticket has not been
+   * decrypted.
+   */
+  pub fn client(&self) -> Result<Option<Krb5Principal<'a>>, Krb5Error> {
+    match self.enc_part2() {
+      Some(enc_part2) => Ok(Some(copy_principal(self.context, enc_part2.client)?)),
+      None => Ok(None),
+    }
+  }
+
+  /**
+   * Return a copy of the session key of the ticket, or `None` if the ticket has not been
+   * decrypted.
+   */
+  pub fn session_key(&self) -> Result<Option<Krb5Keyblock<'a>>, Krb5Error> {
+    match self.enc_part2() {
+      Some(enc_part2) => Ok(Some(Krb5Keyblock::copy_from(self.context, enc_part2.session)?)),
+      None => Ok(None),
+    }
+  }
+
+  /**
+   * Return the ticket flags (`TKT_FLG_*`), or `None` if the ticket has not been decrypted.
+   */
+  pub fn ticket_flags(&self) -> Option<krb5_flags> {
+    self.enc_part2().map(|enc_part2| enc_part2.flags)
+  }
+
+  /**
+   * Return the encoded realms the client was authenticated through (usually in the
+   * DOMAIN-X500-COMPRESS encoding, empty for a client of the realm of the service), or `None`
+   * if the ticket has not been decrypted. The slice borrows the ticket and is not copied.
+   */
+  pub fn transited(&self) -> Option<&[u8]> {
+    self
+      .enc_part2()
+      .map(|enc_part2| krb5_data_as_slice(&enc_part2.transited.tr_contents))
+  }
+
+  /**
+   * Return the time of the initial authentication, or `None` if the ticket has not been
+   * decrypted.
+   */
+  pub fn auth_time(&self) -> Option<Krb5Timestamp> {
+    self
+      .enc_part2()
+      .map(|enc_part2| Krb5Timestamp::from_raw(enc_part2.times.authtime))
+  }
+
+  /**
+   * Return the time the ticket becomes valid, or `None` if the ticket has not been decrypted.
+   */
+  pub fn start_time(&self) -> Option<Krb5Timestamp> {
+    self
+      .enc_part2()
+      .map(|enc_part2| Krb5Timestamp::from_raw(enc_part2.times.starttime))
+  }
+
+  /**
+   * Return the time the ticket expires, or `None` if the ticket has not been decrypted.
+   */
+  pub fn end_time(&self) -> Option<Krb5Timestamp> {
+    self
+      .enc_part2()
+      .map(|enc_part2| Krb5Timestamp::from_raw(enc_part2.times.endtime))
+  }
+
+  /**
+   * Return the latest time the ticket can be renewed until, which is unset if the ticket is
+   * not renewable, or `None` if the ticket has not been decrypted.
+   */
+  pub fn renew_till(&self) -> Option<Krb5Timestamp> {
+    self
+      .enc_part2()
+      .map(|enc_part2| Krb5Timestamp::from_raw(enc_part2.times.renew_till))
+  }
+
+  /**
+   * Return the IP addresses the ticket is restricted to, or an empty list if the ticket can be
+   * used from any address, or `None` if the ticket has not been decrypted. Addresses of other
+   * types are skipped.
+   */
+  pub fn addresses(&self) -> Option<Vec<IpAddr>> {
+    self.enc_part2().map(|enc_part2| addresses_to_ips(enc_part2.caddrs))
+  }
+
+  /**
+   * Return the decrypted part of the ticket, or `None` if the ticket has not been decrypted.
+   */
+  fn enc_part2(&self) -> Option<&krb5_enc_tkt_part> {
+    unsafe { (*self.ticket).enc_part2.as_ref() }
+  }
+
+  /**
+   * Return a copy of the server principal of the ticket.
+   */
+  pub fn server(&self) -> Result<Krb5Principal<'a>, Krb5Error> {
+    copy_principal(self.context, unsafe { (*self.ticket).server })
+  }
+
+  /**
+   * Return the encryption type of the encrypted part, i.e. of the service key needed to decrypt
+   * the ticket.
+   */
+  pub fn enctype(&self) -> Krb5Enctype {
+    Krb5Enctype::from_raw(unsafe { (*self.ticket).enc_part.enctype })
+  }
+
+  /**
+   * Return the version number of the service key needed to decrypt the ticket, or 0 if the
+   * ticket does not specify it.
+   */
+  pub fn kvno(&self) -> krb5_kvno {
+    unsafe { (*self.ticket).enc_part.kvno }
+  }
+
+  /**
+   * Return the authorization data of the ticket, or `None` if the ticket has not been
+   * decrypted.
+   *
+   * The elements of AD-IF-RELEVANT containers are returned in place of the containers, so a PAC
+   * is returned as a [Krb5AuthdataType::Win2kPac] element. Other containers are returned as
+   * they are.
+   */
+  pub fn authorization_data(&self) -> Result<Option<Vec<Krb5Authdata>>, Krb5Error> {
+    let enc_part2 = match self.enc_part2() {
+      Some(enc_part2) => enc_part2,
+      None => return Ok(None),
+    };
+
+    let authdata = unsafe { authdata_list_from_raw(enc_part2.authorization_data) };
+
+    Ok(Some(unwrap_if_relevant(self.context, authdata)?))
+  }
+
+  /**
+   * Parse and verify the PAC of the ticket, or return `None` if the ticket has not been
+   * decrypted or does not contain a PAC.
+   *
+   * The server signature of the PAC is verified with the key of the service, and its client
+   * info has to match the client and the authentication time of the ticket, see
+   * [Krb5Pac::verify]. Use [Krb5Ticket::authorization_data] and [Krb5Pac::parse] to inspect a
+   * PAC without verifying it.
+   *
+   * # Arguments
+   *
+   *  * server_key: the key of the service the ticket was decrypted with, e.g. from its keytab
+   */
+  pub fn pac(&self, server_key: &Krb5Keyblock) -> Result<Option<Krb5Pac<'a>>, Krb5Error> {
+    let authdata = match self.authorization_data()? {
+      Some(authdata) => authdata,
+      None => return Ok(None),
+    };
+
+    let element = match authdata
+      .iter()
+      .find(|element| element.ad_type == Krb5AuthdataType::Win2kPac)
+    {
+      Some(element) => element,
+      None => return Ok(None),
+    };
+
+    let pac = Krb5Pac::parse(self.context, &element.contents)?;
+    let client = self.client()?;
+    let authtime = self.auth_time().expect("the ticket has been decrypted");
+    pac.verify(authtime, client.as_ref(), server_key, None)?;
+
+    Ok(Some(pac))
+  }
+}
+
+/**
+ * Return the encoded ticket of an AP request (`[APPLICATION 14] SEQUENCE { ..., ticket [3] }`).
+ */
+fn ap_req_ticket(ap_req: &[u8]) -> Option<&[u8]> {
+  let (tag, ap_req, _) = der_element(ap_req)?;
+  if tag != 0x6e {
+    return None;
+  }
+
+  let (tag, mut fields, _) = der_element(ap_req)?;
+  if tag != 0x30 {
+    return None;
+  }
+
+  while !fields.is_empty() {
+    let (tag, contents, rest) = der_element(fields)?;
+    if tag == 0xa3 {
+      return Some(contents);
+    }
+    fields = rest;
+  }
+
+  None
+}
@@ -0,0 +1,212 @@
+/*!
+ * Conversion of krb5 timestamps.
+ */
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::os::raw::c_char;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use libkrb5_sys::*;
+
+use crate::context::Krb5Context;
+use crate::error::{error_message, krb5_error_code_escape_hatch, Krb5Error};
+use crate::strconv::c_string_to_string;
+
+/**
+ * Buffer size klist uses for short timestamps, which selects the first format of
+ * `krb5_timestamp_to_sfstring` whose output is shorter than 20 characters.
+ */
+const KLIST_TIMESTAMP_BUFFER_LEN: usize = 20;
+
+/**
+ * A krb5 timestamp (`krb5_timestamp`), in seconds since the Unix epoch.
+ *
+ * libkrb5 stores timestamps in a signed 32-bit integer, but treats them as unsigned since
+ * krb5 1.17, so times after January 2038 are represented as negative raw values. This type
+ * applies the same interpretation for conversions and comparisons, so it covers the years 1970
+ * to 2106.
+ *
+ * A raw value of 0 is used by libkrb5 for times that are not set, e.g. the renewal time of
+ * credentials that are not renewable.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Krb5Timestamp(krb5_timestamp);
+
+impl Krb5Timestamp {
+  /**
+   * Wrap a raw timestamp.
+   */
+  pub fn from_raw(timestamp: krb5_timestamp) -> Self {
+    Krb5Timestamp(timestamp)
+  }
+
+  /**
+   * Return the raw timestamp.
+   */
+  pub fn as_raw(self) -> krb5_timestamp {
+    self.0
+  }
+
+  /**
+   * Return the current time, adjusted by the clock offset to the KDC if one is known.
+   *
+   * [krb5_timeofday](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_timeofday.html)
+   *
+   * # Arguments
+   *
+   *  * context: the Krb5Context instance
+   */
+  pub fn now(context: &Krb5Context) -> Result<Self, Krb5Error> {
+    let mut timestamp: krb5_timestamp = 0;
+
+    let code: krb5_error_code = unsafe { krb5_timeofday(context.context, &mut timestamp) };
+
+    krb5_error_code_escape_hatch(context, code)?;
+
+    Ok(Krb5Timestamp(timestamp))
+  }
+
+  /**
+   * Convert a system time, or return `None` if it is before the Unix epoch or after the last
+   * time representable by a krb5 timestamp (February 2106).
+   */
+  pub fn from_system_time(time: SystemTime) -> Option<Self> {
+    let seconds = time.duration_since(UNIX_EPOCH).ok()?.as_secs();
+
+    if seconds > u64::from(u32::MAX) {
+      return None;
+    }
+
+    Some(Krb5Timestamp(seconds as u32 as krb5_timestamp))
+  }
+
+  /**
+   * Return the number of seconds since the Unix epoch.
+   */
+  pub fn as_secs(self) -> u32 {
+    self.0 as u32
+  }
+
+  /**
+   * Return `true` if the timestamp is 0, which libkrb5 uses for times that are not set.
+   */
+  pub fn is_unset(self) -> bool {
+    self.0 == 0
+  }
+
+  /**
+   * Convert to a system time.
+   */
+  pub fn to_system_time(self) -> SystemTime {
+    UNIX_EPOCH + Duration::from_secs(u64::from(self.as_secs()))
+  }
+
+  /**
+   * Format the timestamp in the local time zone in the default locale-dependent format
+   * (`%c`), e.g. "Thu Sep 17 14:26:40 2020".
+   *
+   * [krb5_timestamp_to_string](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_timestamp_to_string.html)
+   */
+  pub fn format(self) -> Result<String, Krb5Error> {
+    let mut buffer: [c_char; 128] = [0; 128];
+
+    let code: krb5_error_code =
+      unsafe { krb5_timestamp_to_string(self.0, buffer.as_mut_ptr(), buffer.len() as size_t) };
+
+    format_result(code, &buffer)
+  }
+
+  /**
+   * Format the timestamp in the local time zone in the short format of klist, e.g.
+   * "09/17/20 14:26:40" (the format depends on the locale).
+   *
+   * [krb5_timestamp_to_sfstring](https://web.mit.edu/kerberos/krb5-1.16/doc/appdev/refs/api/krb5_timestamp_to_sfstring.html)
+   */
+  pub fn format_short(self) -> Result<String, Krb5Error> {
+    let mut buffer: [c_char; KLIST_TIMESTAMP_BUFFER_LEN] = [0; KLIST_TIMESTAMP_BUFFER_LEN];
+
+    let code: krb5_error_code = unsafe {
+      krb5_timestamp_to_sfstring(
+        self.0,
+        buffer.as_mut_ptr(),
+        buffer.len() as size_t,
+        std::ptr::null_mut(),
+      )
+    };
+
+    format_result(code, &buffer)
+  }
+
+  /**
+   * Convert to a chrono date and time in UTC.
+   */
+  #[cfg(feature = "chrono")]
+  pub fn to_date_time(self) -> chrono::DateTime<chrono::Utc> {
+    chrono::DateTime::from(self.to_system_time())
+  }
+}
+
+/**
+ * Display the timestamp in the short format of klist, see [Krb5Timestamp::format_short], or the
+ * number of seconds since the Unix epoch if it cannot be formatted.
+ */
+impl Display for Krb5Timestamp {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    match self.format_short() {
+      Ok(string) => write!(f, "{}", string),
+      Err(_) => write!(f, "{}", self.as_secs()),
+    }
+  }
+}
+
+impl Ord for Krb5Timestamp {
+  fn cmp(&self, other: &Self) -> Ordering {
+    self.as_secs().cmp(&other.as_secs())
+  }
+}
+
+impl PartialOrd for Krb5Timestamp {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl From<krb5_timestamp> for Krb5Timestamp {
+  fn from(timestamp: krb5_timestamp) -> Self {
+    Krb5Timestamp::from_raw(timestamp)
+  }
+}
+
+impl From<Krb5Timestamp> for krb5_timestamp {
+  fn from(timestamp: Krb5Timestamp) -> Self {
+    timestamp.as_raw()
+  }
+}
+
+impl From<Krb5Timestamp> for SystemTime {
+  fn from(timestamp: Krb5Timestamp) -> Self {
+    timestamp.to_system_time()
+  }
+}
+
+#[cfg(feature = "chrono")]
+impl From<Krb5Timestamp> for chrono::DateTime<chrono::Utc> {
+  fn from(timestamp: Krb5Timestamp) -> Self {
+    timestamp.to_date_time()
+  }
+}
+
+/**
+ * Convert the result of a timestamp formatting function. The error codes of these functions are
+ * system error numbers.
+ */
+fn format_result(code: krb5_error_code, buffer: &[c_char]) -> Result<String, Krb5Error> {
+  if code != 0 {
+    return Err(Krb5Error::LibraryError {
+      code,
+      message: error_message(code),
+    });
+  }
+
+  c_string_to_string(buffer.as_ptr())
+}